@@ -9,6 +9,7 @@ import * as web3 from '@solana/web3.js';
 import * as beet from '@metaplex-foundation/beet';
 import * as beetSolana from '@metaplex-foundation/beet-solana';
 import { DecompressibleState, decompressibleStateBeet } from '../types/DecompressibleState';
+import { Version, versionBeet } from '../types/Version';
 
 /**
  * Arguments used to create {@link TreeConfig}
@@ -22,6 +23,7 @@ export type TreeConfigArgs = {
   numMinted: beet.bignum;
   isPublic: boolean;
   isDecompressible: DecompressibleState;
+  version: Version;
 };
 
 export const treeConfigDiscriminator = [122, 245, 175, 248, 171, 34, 0, 207];
@@ -40,6 +42,7 @@ export class TreeConfig implements TreeConfigArgs {
     readonly numMinted: beet.bignum,
     readonly isPublic: boolean,
     readonly isDecompressible: DecompressibleState,
+    readonly version: Version,
   ) {}
 
   /**
@@ -53,6 +56,7 @@ export class TreeConfig implements TreeConfigArgs {
       args.numMinted,
       args.isPublic,
       args.isDecompressible,
+      args.version,
     );
   }
 
@@ -174,6 +178,7 @@ export class TreeConfig implements TreeConfigArgs {
       })(),
       isPublic: this.isPublic,
       isDecompressible: 'DecompressibleState.' + DecompressibleState[this.isDecompressible],
+      version: 'Version.' + Version[this.version],
     };
   }
 }
@@ -196,6 +201,7 @@ export const treeConfigBeet = new beet.BeetStruct<
     ['numMinted', beet.u64],
     ['isPublic', beet.bool],
     ['isDecompressible', decompressibleStateBeet],
+    ['version', versionBeet],
   ],
   TreeConfig.fromArgs,
   'TreeConfig',
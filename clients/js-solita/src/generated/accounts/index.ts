@@ -1,7 +1,34 @@
+export * from './FeeConfig';
+export * from './MasterEdition';
+export * from './StakeRecord';
 export * from './TreeConfig';
+export * from './TreeCreatorFees';
+export * from './TreeCreatorProposal';
+export * from './TreeDelegateRecord';
+export * from './TreeGroup';
+export * from './TreePlugins';
 export * from './Voucher';
 
+import { FeeConfig } from './FeeConfig';
+import { MasterEdition } from './MasterEdition';
+import { StakeRecord } from './StakeRecord';
 import { TreeConfig } from './TreeConfig';
+import { TreeCreatorFees } from './TreeCreatorFees';
+import { TreeCreatorProposal } from './TreeCreatorProposal';
+import { TreeDelegateRecord } from './TreeDelegateRecord';
+import { TreeGroup } from './TreeGroup';
+import { TreePlugins } from './TreePlugins';
 import { Voucher } from './Voucher';
 
-export const accountProviders = { TreeConfig, Voucher };
+export const accountProviders = {
+  FeeConfig,
+  MasterEdition,
+  StakeRecord,
+  TreeConfig,
+  TreeCreatorFees,
+  TreeCreatorProposal,
+  TreeDelegateRecord,
+  TreeGroup,
+  TreePlugins,
+  Voucher,
+};
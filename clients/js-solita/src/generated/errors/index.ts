@@ -887,6 +887,945 @@ export class InvalidCanopySizeError extends Error {
 createErrorFromCodeLookup.set(0x1799, () => new InvalidCanopySizeError());
 createErrorFromNameLookup.set('InvalidCanopySize', () => new InvalidCanopySizeError());
 
+/**
+ * InvalidLogWrapper: 'Invalid log wrapper program'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidLogWrapperError extends Error {
+  readonly code: number = 0x179a;
+  readonly name: string = 'InvalidLogWrapper';
+  constructor() {
+    super('Invalid log wrapper program');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidLogWrapperError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179a, () => new InvalidLogWrapperError());
+createErrorFromNameLookup.set('InvalidLogWrapper', () => new InvalidLogWrapperError());
+
+/**
+ * InvalidCompressionProgram: 'Invalid compression program'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidCompressionProgramError extends Error {
+  readonly code: number = 0x179b;
+  readonly name: string = 'InvalidCompressionProgram';
+  constructor() {
+    super('Invalid compression program');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidCompressionProgramError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179b, () => new InvalidCompressionProgramError());
+createErrorFromNameLookup.set(
+  'InvalidCompressionProgram',
+  () => new InvalidCompressionProgramError(),
+);
+
+/**
+ * LeafMustBeDelegated: 'Leaf must be delegated to someone other than the leaf owner'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class LeafMustBeDelegatedError extends Error {
+  readonly code: number = 0x179c;
+  readonly name: string = 'LeafMustBeDelegated';
+  constructor() {
+    super('Leaf must be delegated to someone other than the leaf owner');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, LeafMustBeDelegatedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179c, () => new LeafMustBeDelegatedError());
+createErrorFromNameLookup.set('LeafMustBeDelegated', () => new LeafMustBeDelegatedError());
+
+/**
+ * AssetIsFrozen: 'Asset is frozen'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AssetIsFrozenError extends Error {
+  readonly code: number = 0x179d;
+  readonly name: string = 'AssetIsFrozen';
+  constructor() {
+    super('Asset is frozen');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AssetIsFrozenError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179d, () => new AssetIsFrozenError());
+createErrorFromNameLookup.set('AssetIsFrozen', () => new AssetIsFrozenError());
+
+/**
+ * AssetIsNonTransferable: 'Asset is non-transferable'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AssetIsNonTransferableError extends Error {
+  readonly code: number = 0x179e;
+  readonly name: string = 'AssetIsNonTransferable';
+  constructor() {
+    super('Asset is non-transferable');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AssetIsNonTransferableError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179e, () => new AssetIsNonTransferableError());
+createErrorFromNameLookup.set('AssetIsNonTransferable', () => new AssetIsNonTransferableError());
+
+/**
+ * InvalidAuthority: 'Invalid authority'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidAuthorityError extends Error {
+  readonly code: number = 0x179f;
+  readonly name: string = 'InvalidAuthority';
+  constructor() {
+    super('Invalid authority');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidAuthorityError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179f, () => new InvalidAuthorityError());
+createErrorFromNameLookup.set('InvalidAuthority', () => new InvalidAuthorityError());
+
+/**
+ * CollectionIsFrozen: 'Collection is frozen'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class CollectionIsFrozenError extends Error {
+  readonly code: number = 0x17a0;
+  readonly name: string = 'CollectionIsFrozen';
+  constructor() {
+    super('Collection is frozen');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, CollectionIsFrozenError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a0, () => new CollectionIsFrozenError());
+createErrorFromNameLookup.set('CollectionIsFrozen', () => new CollectionIsFrozenError());
+
+/**
+ * CollectionMustHaveBubblegumPlugin: 'Core collections must have the Bubblegum V2 plugin on them'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class CollectionMustHaveBubblegumPluginError extends Error {
+  readonly code: number = 0x17a1;
+  readonly name: string = 'CollectionMustHaveBubblegumPlugin';
+  constructor() {
+    super('Core collections must have the Bubblegum V2 plugin on them');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, CollectionMustHaveBubblegumPluginError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a1, () => new CollectionMustHaveBubblegumPluginError());
+createErrorFromNameLookup.set(
+  'CollectionMustHaveBubblegumPlugin',
+  () => new CollectionMustHaveBubblegumPluginError(),
+);
+
+/**
+ * NotAvailable: 'Feature not currently available'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class NotAvailableError extends Error {
+  readonly code: number = 0x17a2;
+  readonly name: string = 'NotAvailable';
+  constructor() {
+    super('Feature not currently available');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, NotAvailableError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a2, () => new NotAvailableError());
+createErrorFromNameLookup.set('NotAvailable', () => new NotAvailableError());
+
+/**
+ * MissingCollectionAccount: 'Missing collection account'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MissingCollectionAccountError extends Error {
+  readonly code: number = 0x17a3;
+  readonly name: string = 'MissingCollectionAccount';
+  constructor() {
+    super('Missing collection account');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MissingCollectionAccountError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a3, () => new MissingCollectionAccountError());
+createErrorFromNameLookup.set(
+  'MissingCollectionAccount',
+  () => new MissingCollectionAccountError(),
+);
+
+/**
+ * AssetDataLengthTooLong: 'Asset data length too long'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AssetDataLengthTooLongError extends Error {
+  readonly code: number = 0x17a4;
+  readonly name: string = 'AssetDataLengthTooLong';
+  constructor() {
+    super('Asset data length too long');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AssetDataLengthTooLongError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a4, () => new AssetDataLengthTooLongError());
+createErrorFromNameLookup.set('AssetDataLengthTooLong', () => new AssetDataLengthTooLongError());
+
+/**
+ * AlreadyInCollection: 'Item is already in the collection'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AlreadyInCollectionError extends Error {
+  readonly code: number = 0x17a5;
+  readonly name: string = 'AlreadyInCollection';
+  constructor() {
+    super('Item is already in the collection');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AlreadyInCollectionError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a5, () => new AlreadyInCollectionError());
+createErrorFromNameLookup.set('AlreadyInCollection', () => new AlreadyInCollectionError());
+
+/**
+ * AlreadyNotInCollection: 'Item is already not in a collection'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AlreadyNotInCollectionError extends Error {
+  readonly code: number = 0x17a6;
+  readonly name: string = 'AlreadyNotInCollection';
+  constructor() {
+    super('Item is already not in a collection');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AlreadyNotInCollectionError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a6, () => new AlreadyNotInCollectionError());
+createErrorFromNameLookup.set('AlreadyNotInCollection', () => new AlreadyNotInCollectionError());
+
+/**
+ * MissingMplCoreCpiSignerAccount: 'Missing mpl-core CPI signer account'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MissingMplCoreCpiSignerAccountError extends Error {
+  readonly code: number = 0x17a7;
+  readonly name: string = 'MissingMplCoreCpiSignerAccount';
+  constructor() {
+    super('Missing mpl-core CPI signer account');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MissingMplCoreCpiSignerAccountError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a7, () => new MissingMplCoreCpiSignerAccountError());
+createErrorFromNameLookup.set(
+  'MissingMplCoreCpiSignerAccount',
+  () => new MissingMplCoreCpiSignerAccountError(),
+);
+
+/**
+ * AssetIsNotFrozen: 'Asset is not frozen'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AssetIsNotFrozenError extends Error {
+  readonly code: number = 0x17a8;
+  readonly name: string = 'AssetIsNotFrozen';
+  constructor() {
+    super('Asset is not frozen');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AssetIsNotFrozenError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a8, () => new AssetIsNotFrozenError());
+createErrorFromNameLookup.set('AssetIsNotFrozen', () => new AssetIsNotFrozenError());
+
+/**
+ * InvalidTotalMintCapacity: 'Total mint capacity can only be lowered, and not below the number minted'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidTotalMintCapacityError extends Error {
+  readonly code: number = 0x17a9;
+  readonly name: string = 'InvalidTotalMintCapacity';
+  constructor() {
+    super('Total mint capacity can only be lowered, and not below the number minted');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidTotalMintCapacityError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a9, () => new InvalidTotalMintCapacityError());
+createErrorFromNameLookup.set(
+  'InvalidTotalMintCapacity',
+  () => new InvalidTotalMintCapacityError(),
+);
+
+/**
+ * MissingTreeCreatorProposalAccount: 'Missing tree creator proposal account'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MissingTreeCreatorProposalAccountError extends Error {
+  readonly code: number = 0x17aa;
+  readonly name: string = 'MissingTreeCreatorProposalAccount';
+  constructor() {
+    super('Missing tree creator proposal account');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MissingTreeCreatorProposalAccountError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17aa, () => new MissingTreeCreatorProposalAccountError());
+createErrorFromNameLookup.set(
+  'MissingTreeCreatorProposalAccount',
+  () => new MissingTreeCreatorProposalAccountError(),
+);
+
+/**
+ * InvalidTreeDelegateRoles: 'Invalid tree delegate roles'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidTreeDelegateRolesError extends Error {
+  readonly code: number = 0x17ab;
+  readonly name: string = 'InvalidTreeDelegateRoles';
+  constructor() {
+    super('Invalid tree delegate roles');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidTreeDelegateRolesError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ab, () => new InvalidTreeDelegateRolesError());
+createErrorFromNameLookup.set(
+  'InvalidTreeDelegateRoles',
+  () => new InvalidTreeDelegateRolesError(),
+);
+
+/**
+ * TreePaused: 'Tree is paused'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TreePausedError extends Error {
+  readonly code: number = 0x17ac;
+  readonly name: string = 'TreePaused';
+  constructor() {
+    super('Tree is paused');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TreePausedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ac, () => new TreePausedError());
+createErrorFromNameLookup.set('TreePaused', () => new TreePausedError());
+
+/**
+ * TreeGroupFull: 'Tree group cannot hold more trees'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TreeGroupFullError extends Error {
+  readonly code: number = 0x17ad;
+  readonly name: string = 'TreeGroupFull';
+  constructor() {
+    super('Tree group cannot hold more trees');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TreeGroupFullError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ad, () => new TreeGroupFullError());
+createErrorFromNameLookup.set('TreeGroupFull', () => new TreeGroupFullError());
+
+/**
+ * TreeAlreadyInGroup: 'Tree is already in the tree group'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TreeAlreadyInGroupError extends Error {
+  readonly code: number = 0x17ae;
+  readonly name: string = 'TreeAlreadyInGroup';
+  constructor() {
+    super('Tree is already in the tree group');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TreeAlreadyInGroupError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ae, () => new TreeAlreadyInGroupError());
+createErrorFromNameLookup.set('TreeAlreadyInGroup', () => new TreeAlreadyInGroupError());
+
+/**
+ * TreeNotInGroup: 'Tree is not available for minting in the tree group'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TreeNotInGroupError extends Error {
+  readonly code: number = 0x17af;
+  readonly name: string = 'TreeNotInGroup';
+  constructor() {
+    super('Tree is not available for minting in the tree group');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TreeNotInGroupError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17af, () => new TreeNotInGroupError());
+createErrorFromNameLookup.set('TreeNotInGroup', () => new TreeNotInGroupError());
+
+/**
+ * TreePluginsLocked: 'Permanent tree plugins can only be added before minting'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TreePluginsLockedError extends Error {
+  readonly code: number = 0x17b0;
+  readonly name: string = 'TreePluginsLocked';
+  constructor() {
+    super('Permanent tree plugins can only be added before minting');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TreePluginsLockedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b0, () => new TreePluginsLockedError());
+createErrorFromNameLookup.set('TreePluginsLocked', () => new TreePluginsLockedError());
+
+/**
+ * TreeRuleSetTooLong: 'Too many programs in tree rule set'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TreeRuleSetTooLongError extends Error {
+  readonly code: number = 0x17b1;
+  readonly name: string = 'TreeRuleSetTooLong';
+  constructor() {
+    super('Too many programs in tree rule set');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TreeRuleSetTooLongError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b1, () => new TreeRuleSetTooLongError());
+createErrorFromNameLookup.set('TreeRuleSetTooLong', () => new TreeRuleSetTooLongError());
+
+/**
+ * MissingTreePluginsAccount: 'Missing tree plugins account'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MissingTreePluginsAccountError extends Error {
+  readonly code: number = 0x17b2;
+  readonly name: string = 'MissingTreePluginsAccount';
+  constructor() {
+    super('Missing tree plugins account');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MissingTreePluginsAccountError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b2, () => new MissingTreePluginsAccountError());
+createErrorFromNameLookup.set(
+  'MissingTreePluginsAccount',
+  () => new MissingTreePluginsAccountError(),
+);
+
+/**
+ * TreeHasFrozenLeaves: 'Tree has frozen leaves'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TreeHasFrozenLeavesError extends Error {
+  readonly code: number = 0x17b3;
+  readonly name: string = 'TreeHasFrozenLeaves';
+  constructor() {
+    super('Tree has frozen leaves');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TreeHasFrozenLeavesError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b3, () => new TreeHasFrozenLeavesError());
+createErrorFromNameLookup.set('TreeHasFrozenLeaves', () => new TreeHasFrozenLeavesError());
+
+/**
+ * FeeExceedsMaximum: 'Fee exceeds the maximum fee'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class FeeExceedsMaximumError extends Error {
+  readonly code: number = 0x17b4;
+  readonly name: string = 'FeeExceedsMaximum';
+  constructor() {
+    super('Fee exceeds the maximum fee');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, FeeExceedsMaximumError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b4, () => new FeeExceedsMaximumError());
+createErrorFromNameLookup.set('FeeExceedsMaximum', () => new FeeExceedsMaximumError());
+
+/**
+ * MissingTreeCreatorFeesAccount: 'Missing tree creator fees account'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MissingTreeCreatorFeesAccountError extends Error {
+  readonly code: number = 0x17b5;
+  readonly name: string = 'MissingTreeCreatorFeesAccount';
+  constructor() {
+    super('Missing tree creator fees account');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MissingTreeCreatorFeesAccountError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b5, () => new MissingTreeCreatorFeesAccountError());
+createErrorFromNameLookup.set(
+  'MissingTreeCreatorFeesAccount',
+  () => new MissingTreeCreatorFeesAccountError(),
+);
+
+/**
+ * TreeNotEmpty: 'Tree still contains assets'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TreeNotEmptyError extends Error {
+  readonly code: number = 0x17b6;
+  readonly name: string = 'TreeNotEmpty';
+  constructor() {
+    super('Tree still contains assets');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TreeNotEmptyError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b6, () => new TreeNotEmptyError());
+createErrorFromNameLookup.set('TreeNotEmpty', () => new TreeNotEmptyError());
+
+/**
+ * InvalidTreeExtension: 'Invalid tree extension'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidTreeExtensionError extends Error {
+  readonly code: number = 0x17b7;
+  readonly name: string = 'InvalidTreeExtension';
+  constructor() {
+    super('Invalid tree extension');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidTreeExtensionError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b7, () => new InvalidTreeExtensionError());
+createErrorFromNameLookup.set('InvalidTreeExtension', () => new InvalidTreeExtensionError());
+
+/**
+ * TreeExtensionAlreadyExists: 'Tree extension already exists'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TreeExtensionAlreadyExistsError extends Error {
+  readonly code: number = 0x17b8;
+  readonly name: string = 'TreeExtensionAlreadyExists';
+  constructor() {
+    super('Tree extension already exists');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TreeExtensionAlreadyExistsError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b8, () => new TreeExtensionAlreadyExistsError());
+createErrorFromNameLookup.set(
+  'TreeExtensionAlreadyExists',
+  () => new TreeExtensionAlreadyExistsError(),
+);
+
+/**
+ * TreeExtensionRequiresEmptyTree: 'Tree extension can only be added before the first mint'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TreeExtensionRequiresEmptyTreeError extends Error {
+  readonly code: number = 0x17b9;
+  readonly name: string = 'TreeExtensionRequiresEmptyTree';
+  constructor() {
+    super('Tree extension can only be added before the first mint');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TreeExtensionRequiresEmptyTreeError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b9, () => new TreeExtensionRequiresEmptyTreeError());
+createErrorFromNameLookup.set(
+  'TreeExtensionRequiresEmptyTree',
+  () => new TreeExtensionRequiresEmptyTreeError(),
+);
+
+/**
+ * MasterEditionSupplyExhausted: 'Master edition has reached its max supply'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MasterEditionSupplyExhaustedError extends Error {
+  readonly code: number = 0x17ba;
+  readonly name: string = 'MasterEditionSupplyExhausted';
+  constructor() {
+    super('Master edition has reached its max supply');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MasterEditionSupplyExhaustedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ba, () => new MasterEditionSupplyExhaustedError());
+createErrorFromNameLookup.set(
+  'MasterEditionSupplyExhausted',
+  () => new MasterEditionSupplyExhaustedError(),
+);
+
+/**
+ * EditionMetadataMismatch: 'Metadata does not match the master edition'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class EditionMetadataMismatchError extends Error {
+  readonly code: number = 0x17bb;
+  readonly name: string = 'EditionMetadataMismatch';
+  constructor() {
+    super('Metadata does not match the master edition');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, EditionMetadataMismatchError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17bb, () => new EditionMetadataMismatchError());
+createErrorFromNameLookup.set('EditionMetadataMismatch', () => new EditionMetadataMismatchError());
+
+/**
+ * Unusable: 'Asset has no uses'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class UnusableError extends Error {
+  readonly code: number = 0x17bc;
+  readonly name: string = 'Unusable';
+  constructor() {
+    super('Asset has no uses');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, UnusableError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17bc, () => new UnusableError());
+createErrorFromNameLookup.set('Unusable', () => new UnusableError());
+
+/**
+ * NotEnoughUses: 'Not enough uses remaining'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class NotEnoughUsesError extends Error {
+  readonly code: number = 0x17bd;
+  readonly name: string = 'NotEnoughUses';
+  constructor() {
+    super('Not enough uses remaining');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, NotEnoughUsesError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17bd, () => new NotEnoughUsesError());
+createErrorFromNameLookup.set('NotEnoughUses', () => new NotEnoughUsesError());
+
+/**
+ * InvalidUses: 'Uses must have remaining uses, no more than the total, and a total of one if single'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidUsesError extends Error {
+  readonly code: number = 0x17be;
+  readonly name: string = 'InvalidUses';
+  constructor() {
+    super('Uses must have remaining uses, no more than the total, and a total of one if single');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidUsesError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17be, () => new InvalidUsesError());
+createErrorFromNameLookup.set('InvalidUses', () => new InvalidUsesError());
+
+/**
+ * InvalidAmount: 'Invalid amount'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidAmountError extends Error {
+  readonly code: number = 0x17bf;
+  readonly name: string = 'InvalidAmount';
+  constructor() {
+    super('Invalid amount');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidAmountError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17bf, () => new InvalidAmountError());
+createErrorFromNameLookup.set('InvalidAmount', () => new InvalidAmountError());
+
+/**
+ * CannotMergeLeafWithItself: 'Leaf cannot be merged with itself'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class CannotMergeLeafWithItselfError extends Error {
+  readonly code: number = 0x17c0;
+  readonly name: string = 'CannotMergeLeafWithItself';
+  constructor() {
+    super('Leaf cannot be merged with itself');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, CannotMergeLeafWithItselfError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17c0, () => new CannotMergeLeafWithItselfError());
+createErrorFromNameLookup.set(
+  'CannotMergeLeafWithItself',
+  () => new CannotMergeLeafWithItselfError(),
+);
+
+/**
+ * InvalidBatch: 'Batch must contain leaves and the same number of proof accounts for each leaf'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidBatchError extends Error {
+  readonly code: number = 0x17c1;
+  readonly name: string = 'InvalidBatch';
+  constructor() {
+    super('Batch must contain leaves and the same number of proof accounts for each leaf');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidBatchError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17c1, () => new InvalidBatchError());
+createErrorFromNameLookup.set('InvalidBatch', () => new InvalidBatchError());
+
+/**
+ * BaseUriFrozen: 'Base URI is frozen since the tree has immutable or collection leaves'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class BaseUriFrozenError extends Error {
+  readonly code: number = 0x17c2;
+  readonly name: string = 'BaseUriFrozen';
+  constructor() {
+    super('Base URI is frozen since the tree has immutable or collection leaves');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, BaseUriFrozenError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17c2, () => new BaseUriFrozenError());
+createErrorFromNameLookup.set('BaseUriFrozen', () => new BaseUriFrozenError());
+
+/**
+ * AssetIsStaked: 'Asset is staked'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AssetIsStakedError extends Error {
+  readonly code: number = 0x17c3;
+  readonly name: string = 'AssetIsStaked';
+  constructor() {
+    super('Asset is staked');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AssetIsStakedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17c3, () => new AssetIsStakedError());
+createErrorFromNameLookup.set('AssetIsStaked', () => new AssetIsStakedError());
+
+/**
+ * AssetIsTransferable: 'Only non-transferable assets can be recovered'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AssetIsTransferableError extends Error {
+  readonly code: number = 0x17c4;
+  readonly name: string = 'AssetIsTransferable';
+  constructor() {
+    super('Only non-transferable assets can be recovered');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AssetIsTransferableError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17c4, () => new AssetIsTransferableError());
+createErrorFromNameLookup.set('AssetIsTransferable', () => new AssetIsTransferableError());
+
+/**
+ * RecoveryReasonTooLong: 'Recovery reason is too long'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RecoveryReasonTooLongError extends Error {
+  readonly code: number = 0x17c5;
+  readonly name: string = 'RecoveryReasonTooLong';
+  constructor() {
+    super('Recovery reason is too long');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RecoveryReasonTooLongError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17c5, () => new RecoveryReasonTooLongError());
+createErrorFromNameLookup.set('RecoveryReasonTooLong', () => new RecoveryReasonTooLongError());
+
+/**
+ * AssetIsRevoked: 'Asset is revoked'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AssetIsRevokedError extends Error {
+  readonly code: number = 0x17c6;
+  readonly name: string = 'AssetIsRevoked';
+  constructor() {
+    super('Asset is revoked');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AssetIsRevokedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17c6, () => new AssetIsRevokedError());
+createErrorFromNameLookup.set('AssetIsRevoked', () => new AssetIsRevokedError());
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
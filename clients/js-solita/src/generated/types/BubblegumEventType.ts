@@ -13,6 +13,9 @@ import * as beet from '@metaplex-foundation/beet';
 export enum BubblegumEventType {
   Uninitialized,
   LeafSchemaEvent,
+  TreeConfigEvent,
+  PrintEditionEvent,
+  AssetEvent,
 }
 
 /**
@@ -26,6 +26,17 @@ export type LeafSchemaRecord = {
     dataHash: number[] /* size: 32 */;
     creatorHash: number[] /* size: 32 */;
   };
+  V2: {
+    id: web3.PublicKey;
+    owner: web3.PublicKey;
+    delegate: web3.PublicKey;
+    nonce: beet.bignum;
+    dataHash: number[] /* size: 32 */;
+    creatorHash: number[] /* size: 32 */;
+    collectionHash: number[] /* size: 32 */;
+    assetDataHash: number[] /* size: 32 */;
+    flags: number;
+  };
 };
 
 /**
@@ -43,6 +54,8 @@ export type LeafSchema = beet.DataEnumKeyAsKind<LeafSchemaRecord>;
 
 export const isLeafSchemaV1 = (x: LeafSchema): x is LeafSchema & { __kind: 'V1' } =>
   x.__kind === 'V1';
+export const isLeafSchemaV2 = (x: LeafSchema): x is LeafSchema & { __kind: 'V2' } =>
+  x.__kind === 'V2';
 
 /**
  * @category userTypes
@@ -63,4 +76,21 @@ export const leafSchemaBeet = beet.dataEnum<LeafSchemaRecord>([
       'LeafSchemaRecord["V1"]',
     ),
   ],
+  [
+    'V2',
+    new beet.BeetArgsStruct<LeafSchemaRecord['V2']>(
+      [
+        ['id', beetSolana.publicKey],
+        ['owner', beetSolana.publicKey],
+        ['delegate', beetSolana.publicKey],
+        ['nonce', beet.u64],
+        ['dataHash', beet.uniformFixedSizeArray(beet.u8, 32)],
+        ['creatorHash', beet.uniformFixedSizeArray(beet.u8, 32)],
+        ['collectionHash', beet.uniformFixedSizeArray(beet.u8, 32)],
+        ['assetDataHash', beet.uniformFixedSizeArray(beet.u8, 32)],
+        ['flags', beet.u8],
+      ],
+      'LeafSchemaRecord["V2"]',
+    ),
+  ],
 ]) as beet.FixableBeet<LeafSchema, LeafSchema>;
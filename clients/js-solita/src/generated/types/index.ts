@@ -1,12 +1,23 @@
+export * from './AssetDataSchema';
+export * from './AssetUpdate';
 export * from './BubblegumEventType';
 export * from './Collection';
 export * from './Creator';
 export * from './DecompressibleState';
+export * from './LeafMetadataUpdate';
 export * from './LeafSchema';
+export * from './LeafUpdate';
 export * from './MetadataArgs';
+export * from './MetadataArgsV2';
 export * from './TokenProgramVersion';
 export * from './TokenStandard';
+export * from './TreeConfigUpdate';
+export * from './TreeCounters';
+export * from './TreeExtensionType';
+export * from './TreeRuleSet';
+export * from './TreeSettings';
 export * from './UpdateArgs';
+export * from './UriTemplate';
 export * from './UseMethod';
 export * from './Uses';
 export * from './Version';
@@ -0,0 +1,182 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+/**
+ * Protocol fee schedule, set by the program upgrade authority.  Fees are paid into the tree
+ * config and swept to `fee_recipient` by `collect_v2`.
+ */
+export type FeeConfig = Account<FeeConfigAccountData>;
+
+export type FeeConfigAccountData = {
+  discriminator: Array<number>;
+  feeRecipient: PublicKey;
+  mintV2FeeLamports: bigint;
+  transferV2FeeLamports: bigint;
+};
+
+export type FeeConfigAccountDataArgs = {
+  feeRecipient: PublicKey;
+  mintV2FeeLamports: number | bigint;
+  transferV2FeeLamports: number | bigint;
+};
+
+export function getFeeConfigAccountDataSerializer(): Serializer<
+  FeeConfigAccountDataArgs,
+  FeeConfigAccountData
+> {
+  return mapSerializer<FeeConfigAccountDataArgs, any, FeeConfigAccountData>(
+    struct<FeeConfigAccountData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['feeRecipient', publicKeySerializer()],
+        ['mintV2FeeLamports', u64()],
+        ['transferV2FeeLamports', u64()],
+      ],
+      { description: 'FeeConfigAccountData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [143, 52, 146, 187, 219, 123, 76, 155],
+    })
+  ) as Serializer<FeeConfigAccountDataArgs, FeeConfigAccountData>;
+}
+
+export function deserializeFeeConfig(rawAccount: RpcAccount): FeeConfig {
+  return deserializeAccount(rawAccount, getFeeConfigAccountDataSerializer());
+}
+
+export async function fetchFeeConfig(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<FeeConfig> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'FeeConfig');
+  return deserializeFeeConfig(maybeAccount);
+}
+
+export async function safeFetchFeeConfig(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<FeeConfig | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeFeeConfig(maybeAccount) : null;
+}
+
+export async function fetchAllFeeConfig(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<FeeConfig[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'FeeConfig');
+    return deserializeFeeConfig(maybeAccount);
+  });
+}
+
+export async function safeFetchAllFeeConfig(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<FeeConfig[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) => deserializeFeeConfig(maybeAccount as RpcAccount));
+}
+
+export function getFeeConfigGpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplBubblegum',
+    'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      discriminator: Array<number>;
+      feeRecipient: PublicKey;
+      mintV2FeeLamports: number | bigint;
+      transferV2FeeLamports: number | bigint;
+    }>({
+      discriminator: [0, array(u8(), { size: 8 })],
+      feeRecipient: [8, publicKeySerializer()],
+      mintV2FeeLamports: [40, u64()],
+      transferV2FeeLamports: [48, u64()],
+    })
+    .deserializeUsing<FeeConfig>((account) => deserializeFeeConfig(account))
+    .whereField('discriminator', [143, 52, 146, 187, 219, 123, 76, 155]);
+}
+
+export function getFeeConfigSize(): number {
+  return 56;
+}
+
+export function findFeeConfigPda(
+  context: Pick<Context, 'eddsa' | 'programs'>
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplBubblegum',
+    'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('fee_config'),
+  ]);
+}
+
+export async function fetchFeeConfigFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  options?: RpcGetAccountOptions
+): Promise<FeeConfig> {
+  return fetchFeeConfig(context, findFeeConfigPda(context), options);
+}
+
+export async function safeFetchFeeConfigFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  options?: RpcGetAccountOptions
+): Promise<FeeConfig | null> {
+  return safeFetchFeeConfig(context, findFeeConfigPda(context), options);
+}
@@ -6,5 +6,13 @@
  * @see https://github.com/metaplex-foundation/kinobi
  */
 
+export * from './feeConfig';
+export * from './masterEdition';
+export * from './stakeRecord';
 export * from './treeConfig';
+export * from './treeCreatorFees';
+export * from './treeCreatorProposal';
+export * from './treeDelegateRecord';
+export * from './treeGroup';
+export * from './treePlugins';
 export * from './voucher';
@@ -0,0 +1,234 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bytes,
+  mapSerializer,
+  option,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+/**
+ * Master edition of compressed prints.  Each print is a V2 leaf minted with the metadata of the
+ * master edition by `print_edition_v2`, numbered from 1.
+ */
+export type MasterEdition = Account<MasterEditionAccountData>;
+
+export type MasterEditionAccountData = {
+  discriminator: Array<number>;
+  authority: PublicKey;
+  /** Arbitrary key chosen at creation so an authority can own several master editions. */
+  editionSeed: PublicKey;
+  /** `data_hash` of the metadata every print is minted with. */
+  dataHash: Uint8Array;
+  /** Maximum number of prints, unlimited if `None`. */
+  maxSupply: Option<bigint>;
+  /** Number of prints so far, which is also the edition number of the last print. */
+  supply: bigint;
+};
+
+export type MasterEditionAccountDataArgs = {
+  authority: PublicKey;
+  /** Arbitrary key chosen at creation so an authority can own several master editions. */
+  editionSeed: PublicKey;
+  /** `data_hash` of the metadata every print is minted with. */
+  dataHash: Uint8Array;
+  /** Maximum number of prints, unlimited if `None`. */
+  maxSupply: OptionOrNullable<number | bigint>;
+  /** Number of prints so far, which is also the edition number of the last print. */
+  supply: number | bigint;
+};
+
+export function getMasterEditionAccountDataSerializer(): Serializer<
+  MasterEditionAccountDataArgs,
+  MasterEditionAccountData
+> {
+  return mapSerializer<
+    MasterEditionAccountDataArgs,
+    any,
+    MasterEditionAccountData
+  >(
+    struct<MasterEditionAccountData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['authority', publicKeySerializer()],
+        ['editionSeed', publicKeySerializer()],
+        ['dataHash', bytes({ size: 32 })],
+        ['maxSupply', option(u64())],
+        ['supply', u64()],
+      ],
+      { description: 'MasterEditionAccountData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [58, 104, 215, 125, 177, 54, 116, 225],
+    })
+  ) as Serializer<MasterEditionAccountDataArgs, MasterEditionAccountData>;
+}
+
+export function deserializeMasterEdition(
+  rawAccount: RpcAccount
+): MasterEdition {
+  return deserializeAccount(
+    rawAccount,
+    getMasterEditionAccountDataSerializer()
+  );
+}
+
+export async function fetchMasterEdition(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<MasterEdition> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'MasterEdition');
+  return deserializeMasterEdition(maybeAccount);
+}
+
+export async function safeFetchMasterEdition(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<MasterEdition | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeMasterEdition(maybeAccount) : null;
+}
+
+export async function fetchAllMasterEdition(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<MasterEdition[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'MasterEdition');
+    return deserializeMasterEdition(maybeAccount);
+  });
+}
+
+export async function safeFetchAllMasterEdition(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<MasterEdition[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializeMasterEdition(maybeAccount as RpcAccount)
+    );
+}
+
+export function getMasterEditionGpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplBubblegum',
+    'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      discriminator: Array<number>;
+      authority: PublicKey;
+      editionSeed: PublicKey;
+      dataHash: Uint8Array;
+      maxSupply: OptionOrNullable<number | bigint>;
+      supply: number | bigint;
+    }>({
+      discriminator: [0, array(u8(), { size: 8 })],
+      authority: [8, publicKeySerializer()],
+      editionSeed: [40, publicKeySerializer()],
+      dataHash: [72, bytes({ size: 32 })],
+      maxSupply: [104, option(u64())],
+      supply: [null, u64()],
+    })
+    .deserializeUsing<MasterEdition>((account) =>
+      deserializeMasterEdition(account)
+    )
+    .whereField('discriminator', [58, 104, 215, 125, 177, 54, 116, 225]);
+}
+
+export function getMasterEditionSize(): number {
+  return 121;
+}
+
+export function findMasterEditionPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    authority: PublicKey;
+
+    editionSeed: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplBubblegum',
+    'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('master_edition'),
+    publicKeySerializer().serialize(seeds.authority),
+    publicKeySerializer().serialize(seeds.editionSeed),
+  ]);
+}
+
+export async function fetchMasterEditionFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findMasterEditionPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<MasterEdition> {
+  return fetchMasterEdition(
+    context,
+    findMasterEditionPda(context, seeds),
+    options
+  );
+}
+
+export async function safeFetchMasterEditionFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findMasterEditionPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<MasterEdition | null> {
+  return safeFetchMasterEdition(
+    context,
+    findMasterEditionPda(context, seeds),
+    options
+  );
+}
@@ -0,0 +1,170 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  i64,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+/**
+ * Stake of a V2 asset by `stake_v2`.  The record is the leaf delegate of the asset, which stays
+ * frozen in the wallet of its owner until the staking authority signs `unstake_v2`.
+ */
+export type StakeRecord = Account<StakeRecordAccountData>;
+
+export type StakeRecordAccountData = {
+  discriminator: Array<number>;
+  merkleTree: PublicKey;
+  owner: PublicKey;
+  /** Authority of the staking program, which must sign the unstake. */
+  authority: PublicKey;
+  nonce: bigint;
+  /** Unix timestamp of the stake. */
+  stakedAt: bigint;
+};
+
+export type StakeRecordAccountDataArgs = {
+  merkleTree: PublicKey;
+  owner: PublicKey;
+  /** Authority of the staking program, which must sign the unstake. */
+  authority: PublicKey;
+  nonce: number | bigint;
+  /** Unix timestamp of the stake. */
+  stakedAt: number | bigint;
+};
+
+export function getStakeRecordAccountDataSerializer(): Serializer<
+  StakeRecordAccountDataArgs,
+  StakeRecordAccountData
+> {
+  return mapSerializer<StakeRecordAccountDataArgs, any, StakeRecordAccountData>(
+    struct<StakeRecordAccountData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['merkleTree', publicKeySerializer()],
+        ['owner', publicKeySerializer()],
+        ['authority', publicKeySerializer()],
+        ['nonce', u64()],
+        ['stakedAt', i64()],
+      ],
+      { description: 'StakeRecordAccountData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [174, 163, 11, 208, 150, 236, 11, 205],
+    })
+  ) as Serializer<StakeRecordAccountDataArgs, StakeRecordAccountData>;
+}
+
+export function deserializeStakeRecord(rawAccount: RpcAccount): StakeRecord {
+  return deserializeAccount(rawAccount, getStakeRecordAccountDataSerializer());
+}
+
+export async function fetchStakeRecord(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<StakeRecord> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'StakeRecord');
+  return deserializeStakeRecord(maybeAccount);
+}
+
+export async function safeFetchStakeRecord(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<StakeRecord | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeStakeRecord(maybeAccount) : null;
+}
+
+export async function fetchAllStakeRecord(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<StakeRecord[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'StakeRecord');
+    return deserializeStakeRecord(maybeAccount);
+  });
+}
+
+export async function safeFetchAllStakeRecord(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<StakeRecord[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) => deserializeStakeRecord(maybeAccount as RpcAccount));
+}
+
+export function getStakeRecordGpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplBubblegum',
+    'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      discriminator: Array<number>;
+      merkleTree: PublicKey;
+      owner: PublicKey;
+      authority: PublicKey;
+      nonce: number | bigint;
+      stakedAt: number | bigint;
+    }>({
+      discriminator: [0, array(u8(), { size: 8 })],
+      merkleTree: [8, publicKeySerializer()],
+      owner: [40, publicKeySerializer()],
+      authority: [72, publicKeySerializer()],
+      nonce: [104, u64()],
+      stakedAt: [112, i64()],
+    })
+    .deserializeUsing<StakeRecord>((account) => deserializeStakeRecord(account))
+    .whereField('discriminator', [174, 163, 11, 208, 150, 236, 11, 205]);
+}
+
+export function getStakeRecordSize(): number {
+  return 120;
+}
@@ -0,0 +1,209 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+/**
+ * Creator fee charged on third-party mints into a public tree.  The fees are held by this
+ * account rather than the tree config, so that `collect_v2` never sweeps them, and are withdrawn
+ * by the tree creator that set the fee, which does not need the tree config to still exist.
+ */
+export type TreeCreatorFees = Account<TreeCreatorFeesAccountData>;
+
+export type TreeCreatorFeesAccountData = {
+  discriminator: Array<number>;
+  merkleTree: PublicKey;
+  /** Tree creator that last set the fee, and can withdraw the fees. */
+  treeCreator: PublicKey;
+  creatorFeeLamports: bigint;
+};
+
+export type TreeCreatorFeesAccountDataArgs = {
+  merkleTree: PublicKey;
+  /** Tree creator that last set the fee, and can withdraw the fees. */
+  treeCreator: PublicKey;
+  creatorFeeLamports: number | bigint;
+};
+
+export function getTreeCreatorFeesAccountDataSerializer(): Serializer<
+  TreeCreatorFeesAccountDataArgs,
+  TreeCreatorFeesAccountData
+> {
+  return mapSerializer<
+    TreeCreatorFeesAccountDataArgs,
+    any,
+    TreeCreatorFeesAccountData
+  >(
+    struct<TreeCreatorFeesAccountData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['merkleTree', publicKeySerializer()],
+        ['treeCreator', publicKeySerializer()],
+        ['creatorFeeLamports', u64()],
+      ],
+      { description: 'TreeCreatorFeesAccountData' }
+    ),
+    (value) => ({ ...value, discriminator: [38, 132, 43, 121, 83, 25, 76, 81] })
+  ) as Serializer<TreeCreatorFeesAccountDataArgs, TreeCreatorFeesAccountData>;
+}
+
+export function deserializeTreeCreatorFees(
+  rawAccount: RpcAccount
+): TreeCreatorFees {
+  return deserializeAccount(
+    rawAccount,
+    getTreeCreatorFeesAccountDataSerializer()
+  );
+}
+
+export async function fetchTreeCreatorFees(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<TreeCreatorFees> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'TreeCreatorFees');
+  return deserializeTreeCreatorFees(maybeAccount);
+}
+
+export async function safeFetchTreeCreatorFees(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<TreeCreatorFees | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeTreeCreatorFees(maybeAccount) : null;
+}
+
+export async function fetchAllTreeCreatorFees(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<TreeCreatorFees[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'TreeCreatorFees');
+    return deserializeTreeCreatorFees(maybeAccount);
+  });
+}
+
+export async function safeFetchAllTreeCreatorFees(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<TreeCreatorFees[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializeTreeCreatorFees(maybeAccount as RpcAccount)
+    );
+}
+
+export function getTreeCreatorFeesGpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplBubblegum',
+    'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      discriminator: Array<number>;
+      merkleTree: PublicKey;
+      treeCreator: PublicKey;
+      creatorFeeLamports: number | bigint;
+    }>({
+      discriminator: [0, array(u8(), { size: 8 })],
+      merkleTree: [8, publicKeySerializer()],
+      treeCreator: [40, publicKeySerializer()],
+      creatorFeeLamports: [72, u64()],
+    })
+    .deserializeUsing<TreeCreatorFees>((account) =>
+      deserializeTreeCreatorFees(account)
+    )
+    .whereField('discriminator', [38, 132, 43, 121, 83, 25, 76, 81]);
+}
+
+export function getTreeCreatorFeesSize(): number {
+  return 80;
+}
+
+export function findTreeCreatorFeesPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    merkleTree: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplBubblegum',
+    'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('tree_creator_fees'),
+    publicKeySerializer().serialize(seeds.merkleTree),
+  ]);
+}
+
+export async function fetchTreeCreatorFeesFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findTreeCreatorFeesPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<TreeCreatorFees> {
+  return fetchTreeCreatorFees(
+    context,
+    findTreeCreatorFeesPda(context, seeds),
+    options
+  );
+}
+
+export async function safeFetchTreeCreatorFeesFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findTreeCreatorFeesPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<TreeCreatorFees | null> {
+  return safeFetchTreeCreatorFees(
+    context,
+    findTreeCreatorFeesPda(context, seeds),
+    options
+  );
+}
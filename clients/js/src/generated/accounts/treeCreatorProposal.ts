@@ -0,0 +1,200 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+/** Pending hand-off of `tree_creator`, completed when the proposed creator accepts it. */
+export type TreeCreatorProposal = Account<TreeCreatorProposalAccountData>;
+
+export type TreeCreatorProposalAccountData = {
+  discriminator: Array<number>;
+  proposedTreeCreator: PublicKey;
+};
+
+export type TreeCreatorProposalAccountDataArgs = {
+  proposedTreeCreator: PublicKey;
+};
+
+export function getTreeCreatorProposalAccountDataSerializer(): Serializer<
+  TreeCreatorProposalAccountDataArgs,
+  TreeCreatorProposalAccountData
+> {
+  return mapSerializer<
+    TreeCreatorProposalAccountDataArgs,
+    any,
+    TreeCreatorProposalAccountData
+  >(
+    struct<TreeCreatorProposalAccountData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['proposedTreeCreator', publicKeySerializer()],
+      ],
+      { description: 'TreeCreatorProposalAccountData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [116, 87, 216, 73, 109, 112, 58, 142],
+    })
+  ) as Serializer<
+    TreeCreatorProposalAccountDataArgs,
+    TreeCreatorProposalAccountData
+  >;
+}
+
+export function deserializeTreeCreatorProposal(
+  rawAccount: RpcAccount
+): TreeCreatorProposal {
+  return deserializeAccount(
+    rawAccount,
+    getTreeCreatorProposalAccountDataSerializer()
+  );
+}
+
+export async function fetchTreeCreatorProposal(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<TreeCreatorProposal> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'TreeCreatorProposal');
+  return deserializeTreeCreatorProposal(maybeAccount);
+}
+
+export async function safeFetchTreeCreatorProposal(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<TreeCreatorProposal | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists
+    ? deserializeTreeCreatorProposal(maybeAccount)
+    : null;
+}
+
+export async function fetchAllTreeCreatorProposal(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<TreeCreatorProposal[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'TreeCreatorProposal');
+    return deserializeTreeCreatorProposal(maybeAccount);
+  });
+}
+
+export async function safeFetchAllTreeCreatorProposal(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<TreeCreatorProposal[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializeTreeCreatorProposal(maybeAccount as RpcAccount)
+    );
+}
+
+export function getTreeCreatorProposalGpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplBubblegum',
+    'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      discriminator: Array<number>;
+      proposedTreeCreator: PublicKey;
+    }>({
+      discriminator: [0, array(u8(), { size: 8 })],
+      proposedTreeCreator: [8, publicKeySerializer()],
+    })
+    .deserializeUsing<TreeCreatorProposal>((account) =>
+      deserializeTreeCreatorProposal(account)
+    )
+    .whereField('discriminator', [116, 87, 216, 73, 109, 112, 58, 142]);
+}
+
+export function getTreeCreatorProposalSize(): number {
+  return 40;
+}
+
+export function findTreeCreatorProposalPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    merkleTree: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplBubblegum',
+    'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('tree_creator_proposal'),
+    publicKeySerializer().serialize(seeds.merkleTree),
+  ]);
+}
+
+export async function fetchTreeCreatorProposalFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findTreeCreatorProposalPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<TreeCreatorProposal> {
+  return fetchTreeCreatorProposal(
+    context,
+    findTreeCreatorProposalPda(context, seeds),
+    options
+  );
+}
+
+export async function safeFetchTreeCreatorProposalFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findTreeCreatorProposalPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<TreeCreatorProposal | null> {
+  return safeFetchTreeCreatorProposal(
+    context,
+    findTreeCreatorProposalPda(context, seeds),
+    options
+  );
+}
@@ -0,0 +1,225 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+/**
+ * Delegate limited to a set of roles on a single tree, set by the tree creator.  A tree creator
+ * hand-off revokes the records of the previous creator, which the new creator can then close.
+ */
+export type TreeDelegateRecord = Account<TreeDelegateRecordAccountData>;
+
+export type TreeDelegateRecordAccountData = {
+  discriminator: Array<number>;
+  merkleTree: PublicKey;
+  delegate: PublicKey;
+  /** Tree creator that set the record. */
+  treeCreator: PublicKey;
+  /** Bitmask of `TreeDelegateRole` values. */
+  roles: number;
+};
+
+export type TreeDelegateRecordAccountDataArgs = {
+  merkleTree: PublicKey;
+  delegate: PublicKey;
+  /** Tree creator that set the record. */
+  treeCreator: PublicKey;
+  /** Bitmask of `TreeDelegateRole` values. */
+  roles: number;
+};
+
+export function getTreeDelegateRecordAccountDataSerializer(): Serializer<
+  TreeDelegateRecordAccountDataArgs,
+  TreeDelegateRecordAccountData
+> {
+  return mapSerializer<
+    TreeDelegateRecordAccountDataArgs,
+    any,
+    TreeDelegateRecordAccountData
+  >(
+    struct<TreeDelegateRecordAccountData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['merkleTree', publicKeySerializer()],
+        ['delegate', publicKeySerializer()],
+        ['treeCreator', publicKeySerializer()],
+        ['roles', u8()],
+      ],
+      { description: 'TreeDelegateRecordAccountData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [187, 196, 74, 185, 43, 82, 20, 143],
+    })
+  ) as Serializer<
+    TreeDelegateRecordAccountDataArgs,
+    TreeDelegateRecordAccountData
+  >;
+}
+
+export function deserializeTreeDelegateRecord(
+  rawAccount: RpcAccount
+): TreeDelegateRecord {
+  return deserializeAccount(
+    rawAccount,
+    getTreeDelegateRecordAccountDataSerializer()
+  );
+}
+
+export async function fetchTreeDelegateRecord(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<TreeDelegateRecord> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'TreeDelegateRecord');
+  return deserializeTreeDelegateRecord(maybeAccount);
+}
+
+export async function safeFetchTreeDelegateRecord(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<TreeDelegateRecord | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists
+    ? deserializeTreeDelegateRecord(maybeAccount)
+    : null;
+}
+
+export async function fetchAllTreeDelegateRecord(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<TreeDelegateRecord[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'TreeDelegateRecord');
+    return deserializeTreeDelegateRecord(maybeAccount);
+  });
+}
+
+export async function safeFetchAllTreeDelegateRecord(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<TreeDelegateRecord[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializeTreeDelegateRecord(maybeAccount as RpcAccount)
+    );
+}
+
+export function getTreeDelegateRecordGpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplBubblegum',
+    'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      discriminator: Array<number>;
+      merkleTree: PublicKey;
+      delegate: PublicKey;
+      treeCreator: PublicKey;
+      roles: number;
+    }>({
+      discriminator: [0, array(u8(), { size: 8 })],
+      merkleTree: [8, publicKeySerializer()],
+      delegate: [40, publicKeySerializer()],
+      treeCreator: [72, publicKeySerializer()],
+      roles: [104, u8()],
+    })
+    .deserializeUsing<TreeDelegateRecord>((account) =>
+      deserializeTreeDelegateRecord(account)
+    )
+    .whereField('discriminator', [187, 196, 74, 185, 43, 82, 20, 143]);
+}
+
+export function getTreeDelegateRecordSize(): number {
+  return 105;
+}
+
+export function findTreeDelegateRecordPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    merkleTree: PublicKey;
+
+    delegate: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplBubblegum',
+    'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('tree_delegate_record'),
+    publicKeySerializer().serialize(seeds.merkleTree),
+    publicKeySerializer().serialize(seeds.delegate),
+  ]);
+}
+
+export async function fetchTreeDelegateRecordFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findTreeDelegateRecordPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<TreeDelegateRecord> {
+  return fetchTreeDelegateRecord(
+    context,
+    findTreeDelegateRecordPda(context, seeds),
+    options
+  );
+}
+
+export async function safeFetchTreeDelegateRecordFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findTreeDelegateRecordPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<TreeDelegateRecord | null> {
+  return safeFetchTreeDelegateRecord(
+    context,
+    findTreeDelegateRecordPda(context, seeds),
+    options
+  );
+}
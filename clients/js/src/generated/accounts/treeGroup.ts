@@ -0,0 +1,198 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u32,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+/** Ordered set of V2 trees sharing one authority, minted into one after the other. */
+export type TreeGroup = Account<TreeGroupAccountData>;
+
+export type TreeGroupAccountData = {
+  discriminator: Array<number>;
+  authority: PublicKey;
+  /** Arbitrary key chosen at creation so an authority can own several groups. */
+  groupSeed: PublicKey;
+  maxTrees: number;
+  /** Index in `trees` of the tree currently being minted into. */
+  currentTreeIndex: number;
+  trees: Array<PublicKey>;
+};
+
+export type TreeGroupAccountDataArgs = {
+  authority: PublicKey;
+  /** Arbitrary key chosen at creation so an authority can own several groups. */
+  groupSeed: PublicKey;
+  maxTrees: number;
+  /** Index in `trees` of the tree currently being minted into. */
+  currentTreeIndex: number;
+  trees: Array<PublicKey>;
+};
+
+export function getTreeGroupAccountDataSerializer(): Serializer<
+  TreeGroupAccountDataArgs,
+  TreeGroupAccountData
+> {
+  return mapSerializer<TreeGroupAccountDataArgs, any, TreeGroupAccountData>(
+    struct<TreeGroupAccountData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['authority', publicKeySerializer()],
+        ['groupSeed', publicKeySerializer()],
+        ['maxTrees', u8()],
+        ['currentTreeIndex', u32()],
+        ['trees', array(publicKeySerializer())],
+      ],
+      { description: 'TreeGroupAccountData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [142, 27, 56, 255, 137, 155, 49, 159],
+    })
+  ) as Serializer<TreeGroupAccountDataArgs, TreeGroupAccountData>;
+}
+
+export function deserializeTreeGroup(rawAccount: RpcAccount): TreeGroup {
+  return deserializeAccount(rawAccount, getTreeGroupAccountDataSerializer());
+}
+
+export async function fetchTreeGroup(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<TreeGroup> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'TreeGroup');
+  return deserializeTreeGroup(maybeAccount);
+}
+
+export async function safeFetchTreeGroup(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<TreeGroup | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeTreeGroup(maybeAccount) : null;
+}
+
+export async function fetchAllTreeGroup(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<TreeGroup[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'TreeGroup');
+    return deserializeTreeGroup(maybeAccount);
+  });
+}
+
+export async function safeFetchAllTreeGroup(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<TreeGroup[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) => deserializeTreeGroup(maybeAccount as RpcAccount));
+}
+
+export function getTreeGroupGpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplBubblegum',
+    'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      discriminator: Array<number>;
+      authority: PublicKey;
+      groupSeed: PublicKey;
+      maxTrees: number;
+      currentTreeIndex: number;
+      trees: Array<PublicKey>;
+    }>({
+      discriminator: [0, array(u8(), { size: 8 })],
+      authority: [8, publicKeySerializer()],
+      groupSeed: [40, publicKeySerializer()],
+      maxTrees: [72, u8()],
+      currentTreeIndex: [73, u32()],
+      trees: [77, array(publicKeySerializer())],
+    })
+    .deserializeUsing<TreeGroup>((account) => deserializeTreeGroup(account))
+    .whereField('discriminator', [142, 27, 56, 255, 137, 155, 49, 159]);
+}
+
+export function findTreeGroupPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    authority: PublicKey;
+
+    groupSeed: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplBubblegum',
+    'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('tree_group'),
+    publicKeySerializer().serialize(seeds.authority),
+    publicKeySerializer().serialize(seeds.groupSeed),
+  ]);
+}
+
+export async function fetchTreeGroupFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findTreeGroupPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<TreeGroup> {
+  return fetchTreeGroup(context, findTreeGroupPda(context, seeds), options);
+}
+
+export async function safeFetchTreeGroupFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findTreeGroupPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<TreeGroup | null> {
+  return safeFetchTreeGroup(context, findTreeGroupPda(context, seeds), options);
+}
@@ -0,0 +1,212 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  option,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  TreeRuleSet,
+  TreeRuleSetArgs,
+  getTreeRuleSetSerializer,
+} from '../types';
+
+/**
+ * Tree-level counterpart of the mpl-core collection plugins, evaluated for V2 assets that are
+ * not in a collection.
+ */
+export type TreePlugins = Account<TreePluginsAccountData>;
+
+export type TreePluginsAccountData = {
+  discriminator: Array<number>;
+  /** Program allow or deny list applied to the current and new owner on transfer. */
+  royaltiesRuleSet: TreeRuleSet;
+  /** Can freeze and thaw any asset, and frozen assets cannot be transferred by their owner. */
+  permanentFreezeDelegate: Option<PublicKey>;
+  /** Can transfer any asset, including frozen ones. */
+  permanentTransferDelegate: Option<PublicKey>;
+  /** Can burn any asset, including frozen ones. */
+  permanentBurnDelegate: Option<PublicKey>;
+};
+
+export type TreePluginsAccountDataArgs = {
+  /** Program allow or deny list applied to the current and new owner on transfer. */
+  royaltiesRuleSet: TreeRuleSetArgs;
+  /** Can freeze and thaw any asset, and frozen assets cannot be transferred by their owner. */
+  permanentFreezeDelegate: OptionOrNullable<PublicKey>;
+  /** Can transfer any asset, including frozen ones. */
+  permanentTransferDelegate: OptionOrNullable<PublicKey>;
+  /** Can burn any asset, including frozen ones. */
+  permanentBurnDelegate: OptionOrNullable<PublicKey>;
+};
+
+export function getTreePluginsAccountDataSerializer(): Serializer<
+  TreePluginsAccountDataArgs,
+  TreePluginsAccountData
+> {
+  return mapSerializer<TreePluginsAccountDataArgs, any, TreePluginsAccountData>(
+    struct<TreePluginsAccountData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['royaltiesRuleSet', getTreeRuleSetSerializer()],
+        ['permanentFreezeDelegate', option(publicKeySerializer())],
+        ['permanentTransferDelegate', option(publicKeySerializer())],
+        ['permanentBurnDelegate', option(publicKeySerializer())],
+      ],
+      { description: 'TreePluginsAccountData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [81, 101, 208, 86, 240, 116, 59, 95],
+    })
+  ) as Serializer<TreePluginsAccountDataArgs, TreePluginsAccountData>;
+}
+
+export function deserializeTreePlugins(rawAccount: RpcAccount): TreePlugins {
+  return deserializeAccount(rawAccount, getTreePluginsAccountDataSerializer());
+}
+
+export async function fetchTreePlugins(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<TreePlugins> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'TreePlugins');
+  return deserializeTreePlugins(maybeAccount);
+}
+
+export async function safeFetchTreePlugins(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<TreePlugins | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeTreePlugins(maybeAccount) : null;
+}
+
+export async function fetchAllTreePlugins(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<TreePlugins[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'TreePlugins');
+    return deserializeTreePlugins(maybeAccount);
+  });
+}
+
+export async function safeFetchAllTreePlugins(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<TreePlugins[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) => deserializeTreePlugins(maybeAccount as RpcAccount));
+}
+
+export function getTreePluginsGpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplBubblegum',
+    'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      discriminator: Array<number>;
+      royaltiesRuleSet: TreeRuleSetArgs;
+      permanentFreezeDelegate: OptionOrNullable<PublicKey>;
+      permanentTransferDelegate: OptionOrNullable<PublicKey>;
+      permanentBurnDelegate: OptionOrNullable<PublicKey>;
+    }>({
+      discriminator: [0, array(u8(), { size: 8 })],
+      royaltiesRuleSet: [8, getTreeRuleSetSerializer()],
+      permanentFreezeDelegate: [null, option(publicKeySerializer())],
+      permanentTransferDelegate: [null, option(publicKeySerializer())],
+      permanentBurnDelegate: [null, option(publicKeySerializer())],
+    })
+    .deserializeUsing<TreePlugins>((account) => deserializeTreePlugins(account))
+    .whereField('discriminator', [81, 101, 208, 86, 240, 116, 59, 95]);
+}
+
+export function getTreePluginsSize(): number {
+  return 368;
+}
+
+export function findTreePluginsPda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    merkleTree: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplBubblegum',
+    'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('tree_plugins'),
+    publicKeySerializer().serialize(seeds.merkleTree),
+  ]);
+}
+
+export async function fetchTreePluginsFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findTreePluginsPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<TreePlugins> {
+  return fetchTreePlugins(context, findTreePluginsPda(context, seeds), options);
+}
+
+export async function safeFetchTreePluginsFromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findTreePluginsPda>[1],
+  options?: RpcGetAccountOptions
+): Promise<TreePlugins | null> {
+  return safeFetchTreePlugins(
+    context,
+    findTreePluginsPda(context, seeds),
+    options
+  );
+}
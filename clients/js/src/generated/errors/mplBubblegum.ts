@@ -814,6 +814,439 @@ export class AssetIsNotFrozenError extends ProgramError {
 codeToErrorMap.set(0x17a8, AssetIsNotFrozenError);
 nameToErrorMap.set('AssetIsNotFrozen', AssetIsNotFrozenError);
 
+/** InvalidTotalMintCapacity: Total mint capacity can only be lowered, and not below the number minted */
+export class InvalidTotalMintCapacityError extends ProgramError {
+  override readonly name: string = 'InvalidTotalMintCapacity';
+
+  readonly code: number = 0x17a9; // 6057
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Total mint capacity can only be lowered, and not below the number minted',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17a9, InvalidTotalMintCapacityError);
+nameToErrorMap.set('InvalidTotalMintCapacity', InvalidTotalMintCapacityError);
+
+/** MissingTreeCreatorProposalAccount: Missing tree creator proposal account */
+export class MissingTreeCreatorProposalAccountError extends ProgramError {
+  override readonly name: string = 'MissingTreeCreatorProposalAccount';
+
+  readonly code: number = 0x17aa; // 6058
+
+  constructor(program: Program, cause?: Error) {
+    super('Missing tree creator proposal account', program, cause);
+  }
+}
+codeToErrorMap.set(0x17aa, MissingTreeCreatorProposalAccountError);
+nameToErrorMap.set(
+  'MissingTreeCreatorProposalAccount',
+  MissingTreeCreatorProposalAccountError
+);
+
+/** InvalidTreeDelegateRoles: Invalid tree delegate roles */
+export class InvalidTreeDelegateRolesError extends ProgramError {
+  override readonly name: string = 'InvalidTreeDelegateRoles';
+
+  readonly code: number = 0x17ab; // 6059
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid tree delegate roles', program, cause);
+  }
+}
+codeToErrorMap.set(0x17ab, InvalidTreeDelegateRolesError);
+nameToErrorMap.set('InvalidTreeDelegateRoles', InvalidTreeDelegateRolesError);
+
+/** TreePaused: Tree is paused */
+export class TreePausedError extends ProgramError {
+  override readonly name: string = 'TreePaused';
+
+  readonly code: number = 0x17ac; // 6060
+
+  constructor(program: Program, cause?: Error) {
+    super('Tree is paused', program, cause);
+  }
+}
+codeToErrorMap.set(0x17ac, TreePausedError);
+nameToErrorMap.set('TreePaused', TreePausedError);
+
+/** TreeGroupFull: Tree group cannot hold more trees */
+export class TreeGroupFullError extends ProgramError {
+  override readonly name: string = 'TreeGroupFull';
+
+  readonly code: number = 0x17ad; // 6061
+
+  constructor(program: Program, cause?: Error) {
+    super('Tree group cannot hold more trees', program, cause);
+  }
+}
+codeToErrorMap.set(0x17ad, TreeGroupFullError);
+nameToErrorMap.set('TreeGroupFull', TreeGroupFullError);
+
+/** TreeAlreadyInGroup: Tree is already in the tree group */
+export class TreeAlreadyInGroupError extends ProgramError {
+  override readonly name: string = 'TreeAlreadyInGroup';
+
+  readonly code: number = 0x17ae; // 6062
+
+  constructor(program: Program, cause?: Error) {
+    super('Tree is already in the tree group', program, cause);
+  }
+}
+codeToErrorMap.set(0x17ae, TreeAlreadyInGroupError);
+nameToErrorMap.set('TreeAlreadyInGroup', TreeAlreadyInGroupError);
+
+/** TreeNotInGroup: Tree is not available for minting in the tree group */
+export class TreeNotInGroupError extends ProgramError {
+  override readonly name: string = 'TreeNotInGroup';
+
+  readonly code: number = 0x17af; // 6063
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Tree is not available for minting in the tree group',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17af, TreeNotInGroupError);
+nameToErrorMap.set('TreeNotInGroup', TreeNotInGroupError);
+
+/** TreePluginsLocked: Permanent tree plugins can only be added before minting */
+export class TreePluginsLockedError extends ProgramError {
+  override readonly name: string = 'TreePluginsLocked';
+
+  readonly code: number = 0x17b0; // 6064
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Permanent tree plugins can only be added before minting',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17b0, TreePluginsLockedError);
+nameToErrorMap.set('TreePluginsLocked', TreePluginsLockedError);
+
+/** TreeRuleSetTooLong: Too many programs in tree rule set */
+export class TreeRuleSetTooLongError extends ProgramError {
+  override readonly name: string = 'TreeRuleSetTooLong';
+
+  readonly code: number = 0x17b1; // 6065
+
+  constructor(program: Program, cause?: Error) {
+    super('Too many programs in tree rule set', program, cause);
+  }
+}
+codeToErrorMap.set(0x17b1, TreeRuleSetTooLongError);
+nameToErrorMap.set('TreeRuleSetTooLong', TreeRuleSetTooLongError);
+
+/** MissingTreePluginsAccount: Missing tree plugins account */
+export class MissingTreePluginsAccountError extends ProgramError {
+  override readonly name: string = 'MissingTreePluginsAccount';
+
+  readonly code: number = 0x17b2; // 6066
+
+  constructor(program: Program, cause?: Error) {
+    super('Missing tree plugins account', program, cause);
+  }
+}
+codeToErrorMap.set(0x17b2, MissingTreePluginsAccountError);
+nameToErrorMap.set('MissingTreePluginsAccount', MissingTreePluginsAccountError);
+
+/** TreeHasFrozenLeaves: Tree has frozen leaves */
+export class TreeHasFrozenLeavesError extends ProgramError {
+  override readonly name: string = 'TreeHasFrozenLeaves';
+
+  readonly code: number = 0x17b3; // 6067
+
+  constructor(program: Program, cause?: Error) {
+    super('Tree has frozen leaves', program, cause);
+  }
+}
+codeToErrorMap.set(0x17b3, TreeHasFrozenLeavesError);
+nameToErrorMap.set('TreeHasFrozenLeaves', TreeHasFrozenLeavesError);
+
+/** FeeExceedsMaximum: Fee exceeds the maximum fee */
+export class FeeExceedsMaximumError extends ProgramError {
+  override readonly name: string = 'FeeExceedsMaximum';
+
+  readonly code: number = 0x17b4; // 6068
+
+  constructor(program: Program, cause?: Error) {
+    super('Fee exceeds the maximum fee', program, cause);
+  }
+}
+codeToErrorMap.set(0x17b4, FeeExceedsMaximumError);
+nameToErrorMap.set('FeeExceedsMaximum', FeeExceedsMaximumError);
+
+/** MissingTreeCreatorFeesAccount: Missing tree creator fees account */
+export class MissingTreeCreatorFeesAccountError extends ProgramError {
+  override readonly name: string = 'MissingTreeCreatorFeesAccount';
+
+  readonly code: number = 0x17b5; // 6069
+
+  constructor(program: Program, cause?: Error) {
+    super('Missing tree creator fees account', program, cause);
+  }
+}
+codeToErrorMap.set(0x17b5, MissingTreeCreatorFeesAccountError);
+nameToErrorMap.set(
+  'MissingTreeCreatorFeesAccount',
+  MissingTreeCreatorFeesAccountError
+);
+
+/** TreeNotEmpty: Tree still contains assets */
+export class TreeNotEmptyError extends ProgramError {
+  override readonly name: string = 'TreeNotEmpty';
+
+  readonly code: number = 0x17b6; // 6070
+
+  constructor(program: Program, cause?: Error) {
+    super('Tree still contains assets', program, cause);
+  }
+}
+codeToErrorMap.set(0x17b6, TreeNotEmptyError);
+nameToErrorMap.set('TreeNotEmpty', TreeNotEmptyError);
+
+/** InvalidTreeExtension: Invalid tree extension */
+export class InvalidTreeExtensionError extends ProgramError {
+  override readonly name: string = 'InvalidTreeExtension';
+
+  readonly code: number = 0x17b7; // 6071
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid tree extension', program, cause);
+  }
+}
+codeToErrorMap.set(0x17b7, InvalidTreeExtensionError);
+nameToErrorMap.set('InvalidTreeExtension', InvalidTreeExtensionError);
+
+/** TreeExtensionAlreadyExists: Tree extension already exists */
+export class TreeExtensionAlreadyExistsError extends ProgramError {
+  override readonly name: string = 'TreeExtensionAlreadyExists';
+
+  readonly code: number = 0x17b8; // 6072
+
+  constructor(program: Program, cause?: Error) {
+    super('Tree extension already exists', program, cause);
+  }
+}
+codeToErrorMap.set(0x17b8, TreeExtensionAlreadyExistsError);
+nameToErrorMap.set(
+  'TreeExtensionAlreadyExists',
+  TreeExtensionAlreadyExistsError
+);
+
+/** TreeExtensionRequiresEmptyTree: Tree extension can only be added before the first mint */
+export class TreeExtensionRequiresEmptyTreeError extends ProgramError {
+  override readonly name: string = 'TreeExtensionRequiresEmptyTree';
+
+  readonly code: number = 0x17b9; // 6073
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Tree extension can only be added before the first mint',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17b9, TreeExtensionRequiresEmptyTreeError);
+nameToErrorMap.set(
+  'TreeExtensionRequiresEmptyTree',
+  TreeExtensionRequiresEmptyTreeError
+);
+
+/** MasterEditionSupplyExhausted: Master edition has reached its max supply */
+export class MasterEditionSupplyExhaustedError extends ProgramError {
+  override readonly name: string = 'MasterEditionSupplyExhausted';
+
+  readonly code: number = 0x17ba; // 6074
+
+  constructor(program: Program, cause?: Error) {
+    super('Master edition has reached its max supply', program, cause);
+  }
+}
+codeToErrorMap.set(0x17ba, MasterEditionSupplyExhaustedError);
+nameToErrorMap.set(
+  'MasterEditionSupplyExhausted',
+  MasterEditionSupplyExhaustedError
+);
+
+/** EditionMetadataMismatch: Metadata does not match the master edition */
+export class EditionMetadataMismatchError extends ProgramError {
+  override readonly name: string = 'EditionMetadataMismatch';
+
+  readonly code: number = 0x17bb; // 6075
+
+  constructor(program: Program, cause?: Error) {
+    super('Metadata does not match the master edition', program, cause);
+  }
+}
+codeToErrorMap.set(0x17bb, EditionMetadataMismatchError);
+nameToErrorMap.set('EditionMetadataMismatch', EditionMetadataMismatchError);
+
+/** Unusable: Asset has no uses */
+export class UnusableError extends ProgramError {
+  override readonly name: string = 'Unusable';
+
+  readonly code: number = 0x17bc; // 6076
+
+  constructor(program: Program, cause?: Error) {
+    super('Asset has no uses', program, cause);
+  }
+}
+codeToErrorMap.set(0x17bc, UnusableError);
+nameToErrorMap.set('Unusable', UnusableError);
+
+/** NotEnoughUses: Not enough uses remaining */
+export class NotEnoughUsesError extends ProgramError {
+  override readonly name: string = 'NotEnoughUses';
+
+  readonly code: number = 0x17bd; // 6077
+
+  constructor(program: Program, cause?: Error) {
+    super('Not enough uses remaining', program, cause);
+  }
+}
+codeToErrorMap.set(0x17bd, NotEnoughUsesError);
+nameToErrorMap.set('NotEnoughUses', NotEnoughUsesError);
+
+/** InvalidUses: Uses must have remaining uses, no more than the total, and a total of one if single */
+export class InvalidUsesError extends ProgramError {
+  override readonly name: string = 'InvalidUses';
+
+  readonly code: number = 0x17be; // 6078
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Uses must have remaining uses, no more than the total, and a total of one if single',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17be, InvalidUsesError);
+nameToErrorMap.set('InvalidUses', InvalidUsesError);
+
+/** InvalidAmount: Invalid amount */
+export class InvalidAmountError extends ProgramError {
+  override readonly name: string = 'InvalidAmount';
+
+  readonly code: number = 0x17bf; // 6079
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid amount', program, cause);
+  }
+}
+codeToErrorMap.set(0x17bf, InvalidAmountError);
+nameToErrorMap.set('InvalidAmount', InvalidAmountError);
+
+/** CannotMergeLeafWithItself: Leaf cannot be merged with itself */
+export class CannotMergeLeafWithItselfError extends ProgramError {
+  override readonly name: string = 'CannotMergeLeafWithItself';
+
+  readonly code: number = 0x17c0; // 6080
+
+  constructor(program: Program, cause?: Error) {
+    super('Leaf cannot be merged with itself', program, cause);
+  }
+}
+codeToErrorMap.set(0x17c0, CannotMergeLeafWithItselfError);
+nameToErrorMap.set('CannotMergeLeafWithItself', CannotMergeLeafWithItselfError);
+
+/** InvalidBatch: Batch must contain leaves and the same number of proof accounts for each leaf */
+export class InvalidBatchError extends ProgramError {
+  override readonly name: string = 'InvalidBatch';
+
+  readonly code: number = 0x17c1; // 6081
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Batch must contain leaves and the same number of proof accounts for each leaf',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17c1, InvalidBatchError);
+nameToErrorMap.set('InvalidBatch', InvalidBatchError);
+
+/** BaseUriFrozen: Base URI is frozen since the tree has immutable or collection leaves */
+export class BaseUriFrozenError extends ProgramError {
+  override readonly name: string = 'BaseUriFrozen';
+
+  readonly code: number = 0x17c2; // 6082
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Base URI is frozen since the tree has immutable or collection leaves',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x17c2, BaseUriFrozenError);
+nameToErrorMap.set('BaseUriFrozen', BaseUriFrozenError);
+
+/** AssetIsStaked: Asset is staked */
+export class AssetIsStakedError extends ProgramError {
+  override readonly name: string = 'AssetIsStaked';
+
+  readonly code: number = 0x17c3; // 6083
+
+  constructor(program: Program, cause?: Error) {
+    super('Asset is staked', program, cause);
+  }
+}
+codeToErrorMap.set(0x17c3, AssetIsStakedError);
+nameToErrorMap.set('AssetIsStaked', AssetIsStakedError);
+
+/** AssetIsTransferable: Only non-transferable assets can be recovered */
+export class AssetIsTransferableError extends ProgramError {
+  override readonly name: string = 'AssetIsTransferable';
+
+  readonly code: number = 0x17c4; // 6084
+
+  constructor(program: Program, cause?: Error) {
+    super('Only non-transferable assets can be recovered', program, cause);
+  }
+}
+codeToErrorMap.set(0x17c4, AssetIsTransferableError);
+nameToErrorMap.set('AssetIsTransferable', AssetIsTransferableError);
+
+/** RecoveryReasonTooLong: Recovery reason is too long */
+export class RecoveryReasonTooLongError extends ProgramError {
+  override readonly name: string = 'RecoveryReasonTooLong';
+
+  readonly code: number = 0x17c5; // 6085
+
+  constructor(program: Program, cause?: Error) {
+    super('Recovery reason is too long', program, cause);
+  }
+}
+codeToErrorMap.set(0x17c5, RecoveryReasonTooLongError);
+nameToErrorMap.set('RecoveryReasonTooLong', RecoveryReasonTooLongError);
+
+/** AssetIsRevoked: Asset is revoked */
+export class AssetIsRevokedError extends ProgramError {
+  override readonly name: string = 'AssetIsRevoked';
+
+  readonly code: number = 0x17c6; // 6086
+
+  constructor(program: Program, cause?: Error) {
+    super('Asset is revoked', program, cause);
+  }
+}
+codeToErrorMap.set(0x17c6, AssetIsRevokedError);
+nameToErrorMap.set('AssetIsRevoked', AssetIsRevokedError);
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
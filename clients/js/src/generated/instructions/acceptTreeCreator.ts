@@ -0,0 +1,150 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findTreeConfigPda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type AcceptTreeCreatorInstructionAccounts = {
+  treeConfig?: PublicKey | Pda;
+  newTreeCreator: Signer;
+  merkleTree: PublicKey | Pda;
+  treeCreatorProposal: PublicKey | Pda;
+  logWrapper: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type AcceptTreeCreatorInstructionData = { discriminator: Array<number> };
+
+export type AcceptTreeCreatorInstructionDataArgs = {};
+
+export function getAcceptTreeCreatorInstructionDataSerializer(): Serializer<
+  AcceptTreeCreatorInstructionDataArgs,
+  AcceptTreeCreatorInstructionData
+> {
+  return mapSerializer<
+    AcceptTreeCreatorInstructionDataArgs,
+    any,
+    AcceptTreeCreatorInstructionData
+  >(
+    struct<AcceptTreeCreatorInstructionData>(
+      [['discriminator', array(u8(), { size: 8 })]],
+      { description: 'AcceptTreeCreatorInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [106, 65, 242, 169, 157, 90, 154, 67],
+    })
+  ) as Serializer<
+    AcceptTreeCreatorInstructionDataArgs,
+    AcceptTreeCreatorInstructionData
+  >;
+}
+
+// Instruction.
+export function acceptTreeCreator(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  input: AcceptTreeCreatorInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplBubblegum',
+    'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    treeConfig: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.treeConfig ?? null,
+    },
+    newTreeCreator: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.newTreeCreator ?? null,
+    },
+    merkleTree: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.merkleTree ?? null,
+    },
+    treeCreatorProposal: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.treeCreatorProposal ?? null,
+    },
+    logWrapper: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.logWrapper ?? null,
+    },
+    systemProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.treeConfig.value) {
+    resolvedAccounts.treeConfig.value = findTreeConfigPda(context, {
+      merkleTree: expectPublicKey(resolvedAccounts.merkleTree.value),
+    });
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getAcceptTreeCreatorInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
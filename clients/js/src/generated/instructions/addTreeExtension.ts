@@ -0,0 +1,172 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findTreeConfigPda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  TreeExtensionType,
+  TreeExtensionTypeArgs,
+  getTreeExtensionTypeSerializer,
+} from '../types';
+
+// Accounts.
+export type AddTreeExtensionInstructionAccounts = {
+  treeConfig?: PublicKey | Pda;
+  payer?: Signer;
+  treeCreator?: Signer;
+  merkleTree: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type AddTreeExtensionInstructionData = {
+  discriminator: Array<number>;
+  extensionType: TreeExtensionType;
+};
+
+export type AddTreeExtensionInstructionDataArgs = {
+  extensionType: TreeExtensionTypeArgs;
+};
+
+export function getAddTreeExtensionInstructionDataSerializer(): Serializer<
+  AddTreeExtensionInstructionDataArgs,
+  AddTreeExtensionInstructionData
+> {
+  return mapSerializer<
+    AddTreeExtensionInstructionDataArgs,
+    any,
+    AddTreeExtensionInstructionData
+  >(
+    struct<AddTreeExtensionInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['extensionType', getTreeExtensionTypeSerializer()],
+      ],
+      { description: 'AddTreeExtensionInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [255, 207, 116, 51, 26, 93, 109, 28],
+    })
+  ) as Serializer<
+    AddTreeExtensionInstructionDataArgs,
+    AddTreeExtensionInstructionData
+  >;
+}
+
+// Args.
+export type AddTreeExtensionInstructionArgs =
+  AddTreeExtensionInstructionDataArgs;
+
+// Instruction.
+export function addTreeExtension(
+  context: Pick<Context, 'eddsa' | 'identity' | 'payer' | 'programs'>,
+  input: AddTreeExtensionInstructionAccounts & AddTreeExtensionInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplBubblegum',
+    'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    treeConfig: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.treeConfig ?? null,
+    },
+    payer: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    treeCreator: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.treeCreator ?? null,
+    },
+    merkleTree: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.merkleTree ?? null,
+    },
+    systemProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: AddTreeExtensionInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.treeConfig.value) {
+    resolvedAccounts.treeConfig.value = findTreeConfigPda(context, {
+      merkleTree: expectPublicKey(resolvedAccounts.merkleTree.value),
+    });
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.treeCreator.value) {
+    resolvedAccounts.treeCreator.value = context.identity;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getAddTreeExtensionInstructionDataSerializer().serialize(
+    resolvedArgs as AddTreeExtensionInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,134 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findTreeConfigPda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type AddTreeToGroupInstructionAccounts = {
+  treeGroup: PublicKey | Pda;
+  authority?: Signer;
+  treeConfig?: PublicKey | Pda;
+  merkleTree: PublicKey | Pda;
+};
+
+// Data.
+export type AddTreeToGroupInstructionData = { discriminator: Array<number> };
+
+export type AddTreeToGroupInstructionDataArgs = {};
+
+export function getAddTreeToGroupInstructionDataSerializer(): Serializer<
+  AddTreeToGroupInstructionDataArgs,
+  AddTreeToGroupInstructionData
+> {
+  return mapSerializer<
+    AddTreeToGroupInstructionDataArgs,
+    any,
+    AddTreeToGroupInstructionData
+  >(
+    struct<AddTreeToGroupInstructionData>(
+      [['discriminator', array(u8(), { size: 8 })]],
+      { description: 'AddTreeToGroupInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [96, 100, 174, 138, 209, 59, 133, 10],
+    })
+  ) as Serializer<
+    AddTreeToGroupInstructionDataArgs,
+    AddTreeToGroupInstructionData
+  >;
+}
+
+// Instruction.
+export function addTreeToGroup(
+  context: Pick<Context, 'eddsa' | 'identity' | 'programs'>,
+  input: AddTreeToGroupInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplBubblegum',
+    'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    treeGroup: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.treeGroup ?? null,
+    },
+    authority: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    treeConfig: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.treeConfig ?? null,
+    },
+    merkleTree: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.merkleTree ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.treeConfig.value) {
+    resolvedAccounts.treeConfig.value = findTreeConfigPda(context, {
+      merkleTree: expectPublicKey(resolvedAccounts.merkleTree.value),
+    });
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getAddTreeToGroupInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,217 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bytes,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findTreeConfigPda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  LeafMetadataUpdate,
+  LeafMetadataUpdateArgs,
+  UpdateArgs,
+  UpdateArgsArgs,
+  getLeafMetadataUpdateSerializer,
+  getUpdateArgsSerializer,
+} from '../types';
+
+// Accounts.
+export type BatchUpdateMetadataV2InstructionAccounts = {
+  treeConfig?: PublicKey | Pda;
+  payer?: Signer;
+  /**
+   * Either collection authority or tree owner/delegate, depending
+   * on whether the items are in a verified collection.  Defaults to `payer`
+   */
+
+  authority?: Signer;
+  merkleTree: PublicKey | Pda;
+  coreCollection?: PublicKey | Pda;
+  logWrapper?: PublicKey | Pda;
+  compressionProgram?: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type BatchUpdateMetadataV2InstructionData = {
+  discriminator: Array<number>;
+  root: Uint8Array;
+  leaves: Array<LeafMetadataUpdate>;
+  updateArgs: UpdateArgs;
+};
+
+export type BatchUpdateMetadataV2InstructionDataArgs = {
+  root: Uint8Array;
+  leaves: Array<LeafMetadataUpdateArgs>;
+  updateArgs: UpdateArgsArgs;
+};
+
+export function getBatchUpdateMetadataV2InstructionDataSerializer(): Serializer<
+  BatchUpdateMetadataV2InstructionDataArgs,
+  BatchUpdateMetadataV2InstructionData
+> {
+  return mapSerializer<
+    BatchUpdateMetadataV2InstructionDataArgs,
+    any,
+    BatchUpdateMetadataV2InstructionData
+  >(
+    struct<BatchUpdateMetadataV2InstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['root', bytes({ size: 32 })],
+        ['leaves', array(getLeafMetadataUpdateSerializer())],
+        ['updateArgs', getUpdateArgsSerializer()],
+      ],
+      { description: 'BatchUpdateMetadataV2InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [216, 104, 210, 61, 210, 190, 0, 132],
+    })
+  ) as Serializer<
+    BatchUpdateMetadataV2InstructionDataArgs,
+    BatchUpdateMetadataV2InstructionData
+  >;
+}
+
+// Args.
+export type BatchUpdateMetadataV2InstructionArgs =
+  BatchUpdateMetadataV2InstructionDataArgs;
+
+// Instruction.
+export function batchUpdateMetadataV2(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: BatchUpdateMetadataV2InstructionAccounts &
+    BatchUpdateMetadataV2InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplBubblegum',
+    'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    treeConfig: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.treeConfig ?? null,
+    },
+    payer: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    authority: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    merkleTree: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.merkleTree ?? null,
+    },
+    coreCollection: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.coreCollection ?? null,
+    },
+    logWrapper: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.logWrapper ?? null,
+    },
+    compressionProgram: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.compressionProgram ?? null,
+    },
+    systemProgram: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: BatchUpdateMetadataV2InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.treeConfig.value) {
+    resolvedAccounts.treeConfig.value = findTreeConfigPda(context, {
+      merkleTree: expectPublicKey(resolvedAccounts.merkleTree.value),
+    });
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.logWrapper.value) {
+    resolvedAccounts.logWrapper.value = context.programs.getPublicKey(
+      'mplNoop',
+      'mnoopTCrg4p8ry25e4bcWA9XZjbNjMTfgYVGGEdRsf3'
+    );
+    resolvedAccounts.logWrapper.isWritable = false;
+  }
+  if (!resolvedAccounts.compressionProgram.value) {
+    resolvedAccounts.compressionProgram.value = context.programs.getPublicKey(
+      'mplAccountCompression',
+      'mcmt6YrQEMKw8Mw43FmpRLmf7BqRnFMKmAcbxE3xkAW'
+    );
+    resolvedAccounts.compressionProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getBatchUpdateMetadataV2InstructionDataSerializer().serialize(
+    resolvedArgs as BatchUpdateMetadataV2InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
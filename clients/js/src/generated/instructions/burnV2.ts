@@ -44,7 +44,7 @@ export type BurnV2InstructionAccounts = {
   payer?: Signer;
   /**
    * Optional authority, defaults to `payer`.  Must be either
-   * the leaf owner or collection collection permanent burn delegate.
+   * the leaf owner or collection or tree permanent burn delegate.
    */
 
   authority?: Signer;
@@ -0,0 +1,176 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findTreeConfigPda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type CloseTreeInstructionAccounts = {
+  treeConfig?: PublicKey | Pda;
+  /** Tree creator or delegate, or a scoped delegate with the close role. */
+  authority?: Signer;
+  merkleTree: PublicKey | Pda;
+  /**
+   * Recipient for reclaimed lamports (tree + config PDA). Must be the creator
+   * or the delegate.
+   */
+
+  recipient: PublicKey | Pda;
+  compressionProgram?: PublicKey | Pda;
+  logWrapper?: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type CloseTreeInstructionData = { discriminator: Array<number> };
+
+export type CloseTreeInstructionDataArgs = {};
+
+export function getCloseTreeInstructionDataSerializer(): Serializer<
+  CloseTreeInstructionDataArgs,
+  CloseTreeInstructionData
+> {
+  return mapSerializer<
+    CloseTreeInstructionDataArgs,
+    any,
+    CloseTreeInstructionData
+  >(
+    struct<CloseTreeInstructionData>(
+      [['discriminator', array(u8(), { size: 8 })]],
+      { description: 'CloseTreeInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [9, 124, 164, 131, 238, 218, 148, 212],
+    })
+  ) as Serializer<CloseTreeInstructionDataArgs, CloseTreeInstructionData>;
+}
+
+// Instruction.
+export function closeTree(
+  context: Pick<Context, 'eddsa' | 'identity' | 'programs'>,
+  input: CloseTreeInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplBubblegum',
+    'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    treeConfig: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.treeConfig ?? null,
+    },
+    authority: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    merkleTree: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.merkleTree ?? null,
+    },
+    recipient: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.recipient ?? null,
+    },
+    compressionProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.compressionProgram ?? null,
+    },
+    logWrapper: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.logWrapper ?? null,
+    },
+    systemProgram: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.treeConfig.value) {
+    resolvedAccounts.treeConfig.value = findTreeConfigPda(context, {
+      merkleTree: expectPublicKey(resolvedAccounts.merkleTree.value),
+    });
+  }
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.compressionProgram.value) {
+    resolvedAccounts.compressionProgram.value = context.programs.getPublicKey(
+      'splAccountCompression',
+      'cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK'
+    );
+    resolvedAccounts.compressionProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.logWrapper.value) {
+    resolvedAccounts.logWrapper.value = context.programs.getPublicKey(
+      'splNoop',
+      'noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV'
+    );
+    resolvedAccounts.logWrapper.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getCloseTreeInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
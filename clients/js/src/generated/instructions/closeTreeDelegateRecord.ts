@@ -0,0 +1,157 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findTreeConfigPda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type CloseTreeDelegateRecordInstructionAccounts = {
+  treeConfig?: PublicKey | Pda;
+  treeCreator?: Signer;
+  delegate: PublicKey | Pda;
+  merkleTree: PublicKey | Pda;
+  treeDelegateRecord: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type CloseTreeDelegateRecordInstructionData = {
+  discriminator: Array<number>;
+};
+
+export type CloseTreeDelegateRecordInstructionDataArgs = {};
+
+export function getCloseTreeDelegateRecordInstructionDataSerializer(): Serializer<
+  CloseTreeDelegateRecordInstructionDataArgs,
+  CloseTreeDelegateRecordInstructionData
+> {
+  return mapSerializer<
+    CloseTreeDelegateRecordInstructionDataArgs,
+    any,
+    CloseTreeDelegateRecordInstructionData
+  >(
+    struct<CloseTreeDelegateRecordInstructionData>(
+      [['discriminator', array(u8(), { size: 8 })]],
+      { description: 'CloseTreeDelegateRecordInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [215, 161, 41, 143, 89, 92, 138, 150],
+    })
+  ) as Serializer<
+    CloseTreeDelegateRecordInstructionDataArgs,
+    CloseTreeDelegateRecordInstructionData
+  >;
+}
+
+// Instruction.
+export function closeTreeDelegateRecord(
+  context: Pick<Context, 'eddsa' | 'identity' | 'programs'>,
+  input: CloseTreeDelegateRecordInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplBubblegum',
+    'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    treeConfig: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.treeConfig ?? null,
+    },
+    treeCreator: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.treeCreator ?? null,
+    },
+    delegate: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.delegate ?? null,
+    },
+    merkleTree: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.merkleTree ?? null,
+    },
+    treeDelegateRecord: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.treeDelegateRecord ?? null,
+    },
+    systemProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.treeConfig.value) {
+    resolvedAccounts.treeConfig.value = findTreeConfigPda(context, {
+      merkleTree: expectPublicKey(resolvedAccounts.merkleTree.value),
+    });
+  }
+  if (!resolvedAccounts.treeCreator.value) {
+    resolvedAccounts.treeCreator.value = context.identity;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getCloseTreeDelegateRecordInstructionDataSerializer().serialize(
+    {}
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -32,7 +32,7 @@ import {
 // Accounts.
 export type CloseTreeV2InstructionAccounts = {
   treeConfig?: PublicKey | Pda;
-  /** Tree creator or delegate. */
+  /** Tree creator or delegate, or a scoped delegate with the close role. */
   authority?: Signer;
   merkleTree: PublicKey | Pda;
   /**
@@ -31,6 +31,7 @@ import {
 export type CollectV2InstructionAccounts = {
   treeConfig: PublicKey | Pda;
   destination?: PublicKey | Pda;
+  feeConfig?: PublicKey | Pda;
 };
 
 // Data.
@@ -78,6 +79,11 @@ export function collectV2(
       isWritable: true as boolean,
       value: input.destination ?? null,
     },
+    feeConfig: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.feeConfig ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
   // Default values.
@@ -86,6 +92,11 @@ export function collectV2(
       '2dgJVPC5fjLTBTmMvKDRig9JJUGK2Fgwr3EHShFxckhv'
     );
   }
+  if (!resolvedAccounts.feeConfig.value) {
+    resolvedAccounts.feeConfig.value = publicKey(
+      'CZ6RYvLgRnpxwZtAgJ2ozqYxYF4sYmxQikZpMQCZ1ZKE'
+    );
+  }
 
   // Accounts in order.
   const orderedAccounts: ResolvedAccount[] = Object.values(
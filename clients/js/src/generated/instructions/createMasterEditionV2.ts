@@ -0,0 +1,171 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  option,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  MetadataArgsV2,
+  MetadataArgsV2Args,
+  getMetadataArgsV2Serializer,
+} from '../types';
+
+// Accounts.
+export type CreateMasterEditionV2InstructionAccounts = {
+  masterEdition: PublicKey | Pda;
+  payer?: Signer;
+  authority?: Signer;
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type CreateMasterEditionV2InstructionData = {
+  discriminator: Array<number>;
+  editionSeed: PublicKey;
+  metadata: MetadataArgsV2;
+  maxSupply: Option<bigint>;
+};
+
+export type CreateMasterEditionV2InstructionDataArgs = {
+  editionSeed: PublicKey;
+  metadata: MetadataArgsV2Args;
+  maxSupply: OptionOrNullable<number | bigint>;
+};
+
+export function getCreateMasterEditionV2InstructionDataSerializer(): Serializer<
+  CreateMasterEditionV2InstructionDataArgs,
+  CreateMasterEditionV2InstructionData
+> {
+  return mapSerializer<
+    CreateMasterEditionV2InstructionDataArgs,
+    any,
+    CreateMasterEditionV2InstructionData
+  >(
+    struct<CreateMasterEditionV2InstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['editionSeed', publicKeySerializer()],
+        ['metadata', getMetadataArgsV2Serializer()],
+        ['maxSupply', option(u64())],
+      ],
+      { description: 'CreateMasterEditionV2InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [189, 116, 24, 32, 114, 188, 147, 33],
+    })
+  ) as Serializer<
+    CreateMasterEditionV2InstructionDataArgs,
+    CreateMasterEditionV2InstructionData
+  >;
+}
+
+// Args.
+export type CreateMasterEditionV2InstructionArgs =
+  CreateMasterEditionV2InstructionDataArgs;
+
+// Instruction.
+export function createMasterEditionV2(
+  context: Pick<Context, 'identity' | 'payer' | 'programs'>,
+  input: CreateMasterEditionV2InstructionAccounts &
+    CreateMasterEditionV2InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplBubblegum',
+    'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    masterEdition: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.masterEdition ?? null,
+    },
+    payer: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    authority: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    systemProgram: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: CreateMasterEditionV2InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getCreateMasterEditionV2InstructionDataSerializer().serialize(
+    resolvedArgs as CreateMasterEditionV2InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -198,7 +198,7 @@ export function createTreeConfigV2(
   );
 
   // Bytes Created On Chain.
-  const bytesCreatedOnChain = 96 + ACCOUNT_HEADER_SIZE;
+  const bytesCreatedOnChain = 140 + ACCOUNT_HEADER_SIZE;
 
   return transactionBuilder([
     { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
@@ -0,0 +1,157 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type CreateTreeGroupInstructionAccounts = {
+  treeGroup: PublicKey | Pda;
+  payer?: Signer;
+  authority?: Signer;
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type CreateTreeGroupInstructionData = {
+  discriminator: Array<number>;
+  groupSeed: PublicKey;
+  maxTrees: number;
+};
+
+export type CreateTreeGroupInstructionDataArgs = {
+  groupSeed: PublicKey;
+  maxTrees: number;
+};
+
+export function getCreateTreeGroupInstructionDataSerializer(): Serializer<
+  CreateTreeGroupInstructionDataArgs,
+  CreateTreeGroupInstructionData
+> {
+  return mapSerializer<
+    CreateTreeGroupInstructionDataArgs,
+    any,
+    CreateTreeGroupInstructionData
+  >(
+    struct<CreateTreeGroupInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['groupSeed', publicKeySerializer()],
+        ['maxTrees', u8()],
+      ],
+      { description: 'CreateTreeGroupInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [136, 220, 135, 210, 201, 1, 66, 85],
+    })
+  ) as Serializer<
+    CreateTreeGroupInstructionDataArgs,
+    CreateTreeGroupInstructionData
+  >;
+}
+
+// Args.
+export type CreateTreeGroupInstructionArgs = CreateTreeGroupInstructionDataArgs;
+
+// Instruction.
+export function createTreeGroup(
+  context: Pick<Context, 'identity' | 'payer' | 'programs'>,
+  input: CreateTreeGroupInstructionAccounts & CreateTreeGroupInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplBubblegum',
+    'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    treeGroup: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.treeGroup ?? null,
+    },
+    payer: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    authority: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    systemProgram: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: CreateTreeGroupInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getCreateTreeGroupInstructionDataSerializer().serialize(
+    resolvedArgs as CreateTreeGroupInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
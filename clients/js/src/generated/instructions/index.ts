@@ -6,37 +6,69 @@
  * @see https://github.com/metaplex-foundation/kinobi
  */
 
+export * from './acceptTreeCreator';
+export * from './addTreeExtension';
+export * from './addTreeToGroup';
+export * from './batchUpdateMetadataV2';
 export * from './burn';
 export * from './burnV2';
 export * from './cancelRedeem';
+export * from './closeTree';
+export * from './closeTreeDelegateRecord';
 export * from './closeTreeV2';
 export * from './collectV2';
+export * from './createMasterEditionV2';
 export * from './createTreeConfig';
 export * from './createTreeConfigV2';
+export * from './createTreeGroup';
 export * from './decompressV1';
 export * from './delegate';
 export * from './delegateAndFreezeV2';
 export * from './delegateV2';
 export * from './freezeV2';
+export * from './mergeV2';
+export * from './mintFungibleV2';
 export * from './mintToCollectionV1';
+export * from './mintToGroupV2';
+export * from './mintUsableV2';
 export * from './mintV1';
 export * from './mintV2';
+export * from './printEditionV2';
+export * from './recoverNonTransferableV2';
 export * from './redeem';
+export * from './revokeV2';
 export * from './setAndVerifyCollection';
+export * from './setBaseUriV2';
 export * from './setCollectionV2';
 export * from './setDecompressibleState';
+export * from './setFeeConfig';
 export * from './setNonTransferableV2';
+export * from './setTreeCreatorFee';
 export * from './setTreeDelegate';
+export * from './setTreeDelegateRecord';
+export * from './setTreeFeeExempt';
+export * from './setTreePaused';
+export * from './setTreePlugins';
+export * from './splitV2';
+export * from './stakeV2';
 export * from './thawAndRevokeV2';
 export * from './thawV2';
 export * from './transfer';
 export * from './transferV2';
+export * from './unstakeV2';
 export * from './unverifyCollection';
 export * from './unverifyCreator';
 export * from './unverifyCreatorV2';
 export * from './updateAssetDataV2';
+export * from './updateCreatorV2';
 export * from './updateMetadata';
 export * from './updateMetadataV2';
+export * from './updateTreeConfig';
+export * from './utilizeV1';
+export * from './utilizeV2';
 export * from './verifyCollection';
 export * from './verifyCreator';
 export * from './verifyCreatorV2';
+export * from './verifyCreatorsV2';
+export * from './verifyLeafV2';
+export * from './withdrawCreatorFees';
@@ -0,0 +1,245 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  none,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bytes,
+  mapSerializer,
+  option,
+  publicKey as publicKeySerializer,
+  struct,
+  u32,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findTreeConfigPda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type MergeV2InstructionAccounts = {
+  treeConfig?: PublicKey | Pda;
+  payer?: Signer;
+  /** Optional authority, defaults to `payer`.  Must be the owner of both leaves. */
+  authority?: Signer;
+  leafOwner: PublicKey | Pda;
+  /**
+   * Delegate of both leaves.
+   * Defaults to `leaf_owner`
+   */
+
+  leafDelegate?: PublicKey | Pda;
+  merkleTree: PublicKey | Pda;
+  coreCollection?: PublicKey | Pda;
+  logWrapper?: PublicKey | Pda;
+  compressionProgram?: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type MergeV2InstructionData = {
+  discriminator: Array<number>;
+  root: Uint8Array;
+  dataHash: Uint8Array;
+  creatorHash: Uint8Array;
+  flags: Option<number>;
+  nonce: bigint;
+  index: number;
+  classId: PublicKey;
+  amount: bigint;
+  otherNonce: bigint;
+  otherIndex: number;
+  otherAmount: bigint;
+};
+
+export type MergeV2InstructionDataArgs = {
+  root: Uint8Array;
+  dataHash: Uint8Array;
+  creatorHash: Uint8Array;
+  flags?: OptionOrNullable<number>;
+  nonce: number | bigint;
+  index: number;
+  classId: PublicKey;
+  amount: number | bigint;
+  otherNonce: number | bigint;
+  otherIndex: number;
+  otherAmount: number | bigint;
+};
+
+export function getMergeV2InstructionDataSerializer(): Serializer<
+  MergeV2InstructionDataArgs,
+  MergeV2InstructionData
+> {
+  return mapSerializer<MergeV2InstructionDataArgs, any, MergeV2InstructionData>(
+    struct<MergeV2InstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['root', bytes({ size: 32 })],
+        ['dataHash', bytes({ size: 32 })],
+        ['creatorHash', bytes({ size: 32 })],
+        ['flags', option(u8())],
+        ['nonce', u64()],
+        ['index', u32()],
+        ['classId', publicKeySerializer()],
+        ['amount', u64()],
+        ['otherNonce', u64()],
+        ['otherIndex', u32()],
+        ['otherAmount', u64()],
+      ],
+      { description: 'MergeV2InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [152, 61, 247, 211, 114, 135, 166, 47],
+      flags: value.flags ?? none(),
+    })
+  ) as Serializer<MergeV2InstructionDataArgs, MergeV2InstructionData>;
+}
+
+// Args.
+export type MergeV2InstructionArgs = MergeV2InstructionDataArgs;
+
+// Instruction.
+export function mergeV2(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: MergeV2InstructionAccounts & MergeV2InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplBubblegum',
+    'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    treeConfig: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.treeConfig ?? null,
+    },
+    payer: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    authority: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    leafOwner: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.leafOwner ?? null,
+    },
+    leafDelegate: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.leafDelegate ?? null,
+    },
+    merkleTree: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.merkleTree ?? null,
+    },
+    coreCollection: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.coreCollection ?? null,
+    },
+    logWrapper: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.logWrapper ?? null,
+    },
+    compressionProgram: {
+      index: 8,
+      isWritable: false as boolean,
+      value: input.compressionProgram ?? null,
+    },
+    systemProgram: {
+      index: 9,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: MergeV2InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.treeConfig.value) {
+    resolvedAccounts.treeConfig.value = findTreeConfigPda(context, {
+      merkleTree: expectPublicKey(resolvedAccounts.merkleTree.value),
+    });
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.logWrapper.value) {
+    resolvedAccounts.logWrapper.value = context.programs.getPublicKey(
+      'mplNoop',
+      'mnoopTCrg4p8ry25e4bcWA9XZjbNjMTfgYVGGEdRsf3'
+    );
+    resolvedAccounts.logWrapper.isWritable = false;
+  }
+  if (!resolvedAccounts.compressionProgram.value) {
+    resolvedAccounts.compressionProgram.value = context.programs.getPublicKey(
+      'mplAccountCompression',
+      'mcmt6YrQEMKw8Mw43FmpRLmf7BqRnFMKmAcbxE3xkAW'
+    );
+    resolvedAccounts.compressionProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getMergeV2InstructionDataSerializer().serialize(
+    resolvedArgs as MergeV2InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,251 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findTreeConfigPda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  MetadataArgsV2,
+  MetadataArgsV2Args,
+  getMetadataArgsV2Serializer,
+} from '../types';
+
+// Accounts.
+export type MintFungibleV2InstructionAccounts = {
+  treeConfig?: PublicKey | Pda;
+  payer?: Signer;
+  /** Optional tree delegate, defaults to `payer` */
+  treeCreatorOrDelegate?: Signer;
+  /** Optional collection authority, defaults to `tree_delegate` */
+  collectionAuthority?: Signer;
+  leafOwner: PublicKey | Pda;
+  leafDelegate?: PublicKey | Pda;
+  merkleTree: PublicKey | Pda;
+  coreCollection?: PublicKey | Pda;
+  mplCoreCpiSigner?: PublicKey | Pda;
+  logWrapper?: PublicKey | Pda;
+  compressionProgram?: PublicKey | Pda;
+  mplCoreProgram?: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type MintFungibleV2InstructionData = {
+  discriminator: Array<number>;
+  metadata: MetadataArgsV2;
+  amount: bigint;
+};
+
+export type MintFungibleV2InstructionDataArgs = {
+  metadata: MetadataArgsV2Args;
+  amount: number | bigint;
+};
+
+export function getMintFungibleV2InstructionDataSerializer(): Serializer<
+  MintFungibleV2InstructionDataArgs,
+  MintFungibleV2InstructionData
+> {
+  return mapSerializer<
+    MintFungibleV2InstructionDataArgs,
+    any,
+    MintFungibleV2InstructionData
+  >(
+    struct<MintFungibleV2InstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['metadata', getMetadataArgsV2Serializer()],
+        ['amount', u64()],
+      ],
+      { description: 'MintFungibleV2InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [246, 68, 49, 115, 136, 72, 3, 255],
+    })
+  ) as Serializer<
+    MintFungibleV2InstructionDataArgs,
+    MintFungibleV2InstructionData
+  >;
+}
+
+// Args.
+export type MintFungibleV2InstructionArgs = MintFungibleV2InstructionDataArgs;
+
+// Instruction.
+export function mintFungibleV2(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: MintFungibleV2InstructionAccounts & MintFungibleV2InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplBubblegum',
+    'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    treeConfig: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.treeConfig ?? null,
+    },
+    payer: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    treeCreatorOrDelegate: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.treeCreatorOrDelegate ?? null,
+    },
+    collectionAuthority: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.collectionAuthority ?? null,
+    },
+    leafOwner: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.leafOwner ?? null,
+    },
+    leafDelegate: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.leafDelegate ?? null,
+    },
+    merkleTree: {
+      index: 6,
+      isWritable: true as boolean,
+      value: input.merkleTree ?? null,
+    },
+    coreCollection: {
+      index: 7,
+      isWritable: true as boolean,
+      value: input.coreCollection ?? null,
+    },
+    mplCoreCpiSigner: {
+      index: 8,
+      isWritable: false as boolean,
+      value: input.mplCoreCpiSigner ?? null,
+    },
+    logWrapper: {
+      index: 9,
+      isWritable: false as boolean,
+      value: input.logWrapper ?? null,
+    },
+    compressionProgram: {
+      index: 10,
+      isWritable: false as boolean,
+      value: input.compressionProgram ?? null,
+    },
+    mplCoreProgram: {
+      index: 11,
+      isWritable: false as boolean,
+      value: input.mplCoreProgram ?? null,
+    },
+    systemProgram: {
+      index: 12,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: MintFungibleV2InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.treeConfig.value) {
+    resolvedAccounts.treeConfig.value = findTreeConfigPda(context, {
+      merkleTree: expectPublicKey(resolvedAccounts.merkleTree.value),
+    });
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.mplCoreCpiSigner.value) {
+    if (resolvedAccounts.coreCollection.value) {
+      resolvedAccounts.mplCoreCpiSigner.value = publicKey(
+        'CbNY3JiXdXNE9tPNEk1aRZVEkWdj2v7kfJLNQwZZgpXk'
+      );
+    }
+  }
+  if (!resolvedAccounts.logWrapper.value) {
+    resolvedAccounts.logWrapper.value = context.programs.getPublicKey(
+      'mplNoop',
+      'mnoopTCrg4p8ry25e4bcWA9XZjbNjMTfgYVGGEdRsf3'
+    );
+    resolvedAccounts.logWrapper.isWritable = false;
+  }
+  if (!resolvedAccounts.compressionProgram.value) {
+    resolvedAccounts.compressionProgram.value = context.programs.getPublicKey(
+      'mplAccountCompression',
+      'mcmt6YrQEMKw8Mw43FmpRLmf7BqRnFMKmAcbxE3xkAW'
+    );
+    resolvedAccounts.compressionProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.mplCoreProgram.value) {
+    resolvedAccounts.mplCoreProgram.value = context.programs.getPublicKey(
+      'mplCore',
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+    resolvedAccounts.mplCoreProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getMintFungibleV2InstructionDataSerializer().serialize(
+    resolvedArgs as MintFungibleV2InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
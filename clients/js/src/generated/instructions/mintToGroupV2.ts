@@ -0,0 +1,275 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  none,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bytes,
+  mapSerializer,
+  option,
+  struct,
+  u32,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findTreeConfigPda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  AssetDataSchema,
+  AssetDataSchemaArgs,
+  MetadataArgsV2,
+  MetadataArgsV2Args,
+  getAssetDataSchemaSerializer,
+  getMetadataArgsV2Serializer,
+} from '../types';
+
+// Accounts.
+export type MintToGroupV2InstructionAccounts = {
+  treeGroup: PublicKey | Pda;
+  treeConfig?: PublicKey | Pda;
+  payer?: Signer;
+  /** Optional tree delegate, defaults to `payer` */
+  treeCreatorOrDelegate?: Signer;
+  /** Optional collection authority, defaults to `tree_delegate` */
+  collectionAuthority?: Signer;
+  leafOwner: PublicKey | Pda;
+  leafDelegate?: PublicKey | Pda;
+  /**
+   * The group's current tree, or a later one in the group if the current tree was filled
+   * outside of the group and `tree_delegate` is the group authority.
+   */
+
+  merkleTree: PublicKey | Pda;
+  coreCollection?: PublicKey | Pda;
+  mplCoreCpiSigner?: PublicKey | Pda;
+  logWrapper?: PublicKey | Pda;
+  compressionProgram?: PublicKey | Pda;
+  mplCoreProgram?: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type MintToGroupV2InstructionData = {
+  discriminator: Array<number>;
+  metadata: MetadataArgsV2;
+  assetData: Option<Uint8Array>;
+  assetDataSchema: Option<AssetDataSchema>;
+};
+
+export type MintToGroupV2InstructionDataArgs = {
+  metadata: MetadataArgsV2Args;
+  assetData?: OptionOrNullable<Uint8Array>;
+  assetDataSchema?: OptionOrNullable<AssetDataSchemaArgs>;
+};
+
+export function getMintToGroupV2InstructionDataSerializer(): Serializer<
+  MintToGroupV2InstructionDataArgs,
+  MintToGroupV2InstructionData
+> {
+  return mapSerializer<
+    MintToGroupV2InstructionDataArgs,
+    any,
+    MintToGroupV2InstructionData
+  >(
+    struct<MintToGroupV2InstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['metadata', getMetadataArgsV2Serializer()],
+        ['assetData', option(bytes({ size: u32() }))],
+        ['assetDataSchema', option(getAssetDataSchemaSerializer())],
+      ],
+      { description: 'MintToGroupV2InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [2, 28, 150, 57, 64, 19, 146, 236],
+      assetData: value.assetData ?? none(),
+      assetDataSchema: value.assetDataSchema ?? none(),
+    })
+  ) as Serializer<
+    MintToGroupV2InstructionDataArgs,
+    MintToGroupV2InstructionData
+  >;
+}
+
+// Args.
+export type MintToGroupV2InstructionArgs = MintToGroupV2InstructionDataArgs;
+
+// Instruction.
+export function mintToGroupV2(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: MintToGroupV2InstructionAccounts & MintToGroupV2InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplBubblegum',
+    'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    treeGroup: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.treeGroup ?? null,
+    },
+    treeConfig: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.treeConfig ?? null,
+    },
+    payer: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    treeCreatorOrDelegate: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.treeCreatorOrDelegate ?? null,
+    },
+    collectionAuthority: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.collectionAuthority ?? null,
+    },
+    leafOwner: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.leafOwner ?? null,
+    },
+    leafDelegate: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.leafDelegate ?? null,
+    },
+    merkleTree: {
+      index: 7,
+      isWritable: true as boolean,
+      value: input.merkleTree ?? null,
+    },
+    coreCollection: {
+      index: 8,
+      isWritable: true as boolean,
+      value: input.coreCollection ?? null,
+    },
+    mplCoreCpiSigner: {
+      index: 9,
+      isWritable: false as boolean,
+      value: input.mplCoreCpiSigner ?? null,
+    },
+    logWrapper: {
+      index: 10,
+      isWritable: false as boolean,
+      value: input.logWrapper ?? null,
+    },
+    compressionProgram: {
+      index: 11,
+      isWritable: false as boolean,
+      value: input.compressionProgram ?? null,
+    },
+    mplCoreProgram: {
+      index: 12,
+      isWritable: false as boolean,
+      value: input.mplCoreProgram ?? null,
+    },
+    systemProgram: {
+      index: 13,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: MintToGroupV2InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.treeConfig.value) {
+    resolvedAccounts.treeConfig.value = findTreeConfigPda(context, {
+      merkleTree: expectPublicKey(resolvedAccounts.merkleTree.value),
+    });
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.mplCoreCpiSigner.value) {
+    if (resolvedAccounts.coreCollection.value) {
+      resolvedAccounts.mplCoreCpiSigner.value = publicKey(
+        'CbNY3JiXdXNE9tPNEk1aRZVEkWdj2v7kfJLNQwZZgpXk'
+      );
+    }
+  }
+  if (!resolvedAccounts.logWrapper.value) {
+    resolvedAccounts.logWrapper.value = context.programs.getPublicKey(
+      'mplNoop',
+      'mnoopTCrg4p8ry25e4bcWA9XZjbNjMTfgYVGGEdRsf3'
+    );
+    resolvedAccounts.logWrapper.isWritable = false;
+  }
+  if (!resolvedAccounts.compressionProgram.value) {
+    resolvedAccounts.compressionProgram.value = context.programs.getPublicKey(
+      'mplAccountCompression',
+      'mcmt6YrQEMKw8Mw43FmpRLmf7BqRnFMKmAcbxE3xkAW'
+    );
+    resolvedAccounts.compressionProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.mplCoreProgram.value) {
+    resolvedAccounts.mplCoreProgram.value = context.programs.getPublicKey(
+      'mplCore',
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+    resolvedAccounts.mplCoreProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getMintToGroupV2InstructionDataSerializer().serialize(
+    resolvedArgs as MintToGroupV2InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,268 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  none,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bytes,
+  mapSerializer,
+  option,
+  string,
+  struct,
+  u32,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findTreeConfigPda } from '../accounts';
+import {
+  PickPartial,
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type RecoverNonTransferableV2InstructionAccounts = {
+  treeConfig?: PublicKey | Pda;
+  payer?: Signer;
+  /** Collection update authority, defaults to `payer` */
+  authority?: Signer;
+  leafOwner: PublicKey | Pda;
+  /** Defaults to `leaf_owner` */
+  leafDelegate?: PublicKey | Pda;
+  newLeafOwner: PublicKey | Pda;
+  merkleTree: PublicKey | Pda;
+  coreCollection: PublicKey | Pda;
+  logWrapper?: PublicKey | Pda;
+  compressionProgram?: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type RecoverNonTransferableV2InstructionData = {
+  discriminator: Array<number>;
+  root: Uint8Array;
+  dataHash: Uint8Array;
+  creatorHash: Uint8Array;
+  assetDataHash: Option<Uint8Array>;
+  flags: Option<number>;
+  nonce: bigint;
+  index: number;
+  reason: string;
+};
+
+export type RecoverNonTransferableV2InstructionDataArgs = {
+  root: Uint8Array;
+  dataHash: Uint8Array;
+  creatorHash: Uint8Array;
+  assetDataHash?: OptionOrNullable<Uint8Array>;
+  flags?: OptionOrNullable<number>;
+  nonce: number | bigint;
+  index: number;
+  reason: string;
+};
+
+export function getRecoverNonTransferableV2InstructionDataSerializer(): Serializer<
+  RecoverNonTransferableV2InstructionDataArgs,
+  RecoverNonTransferableV2InstructionData
+> {
+  return mapSerializer<
+    RecoverNonTransferableV2InstructionDataArgs,
+    any,
+    RecoverNonTransferableV2InstructionData
+  >(
+    struct<RecoverNonTransferableV2InstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['root', bytes({ size: 32 })],
+        ['dataHash', bytes({ size: 32 })],
+        ['creatorHash', bytes({ size: 32 })],
+        ['assetDataHash', option(bytes({ size: 32 }))],
+        ['flags', option(u8())],
+        ['nonce', u64()],
+        ['index', u32()],
+        ['reason', string()],
+      ],
+      { description: 'RecoverNonTransferableV2InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [48, 227, 123, 92, 95, 55, 241, 55],
+      assetDataHash: value.assetDataHash ?? none(),
+      flags: value.flags ?? none(),
+    })
+  ) as Serializer<
+    RecoverNonTransferableV2InstructionDataArgs,
+    RecoverNonTransferableV2InstructionData
+  >;
+}
+
+// Extra Args.
+export type RecoverNonTransferableV2InstructionExtraArgs = {
+  proof?: Array<PublicKey>;
+};
+
+// Args.
+export type RecoverNonTransferableV2InstructionArgs = PickPartial<
+  RecoverNonTransferableV2InstructionDataArgs &
+    RecoverNonTransferableV2InstructionExtraArgs,
+  'proof'
+>;
+
+// Instruction.
+export function recoverNonTransferableV2(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: RecoverNonTransferableV2InstructionAccounts &
+    RecoverNonTransferableV2InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplBubblegum',
+    'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    treeConfig: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.treeConfig ?? null,
+    },
+    payer: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    authority: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    leafOwner: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.leafOwner ?? null,
+    },
+    leafDelegate: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.leafDelegate ?? null,
+    },
+    newLeafOwner: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.newLeafOwner ?? null,
+    },
+    merkleTree: {
+      index: 6,
+      isWritable: true as boolean,
+      value: input.merkleTree ?? null,
+    },
+    coreCollection: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.coreCollection ?? null,
+    },
+    logWrapper: {
+      index: 8,
+      isWritable: false as boolean,
+      value: input.logWrapper ?? null,
+    },
+    compressionProgram: {
+      index: 9,
+      isWritable: false as boolean,
+      value: input.compressionProgram ?? null,
+    },
+    systemProgram: {
+      index: 10,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: RecoverNonTransferableV2InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.treeConfig.value) {
+    resolvedAccounts.treeConfig.value = findTreeConfigPda(context, {
+      merkleTree: expectPublicKey(resolvedAccounts.merkleTree.value),
+    });
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.logWrapper.value) {
+    resolvedAccounts.logWrapper.value = context.programs.getPublicKey(
+      'mplNoop',
+      'mnoopTCrg4p8ry25e4bcWA9XZjbNjMTfgYVGGEdRsf3'
+    );
+    resolvedAccounts.logWrapper.isWritable = false;
+  }
+  if (!resolvedAccounts.compressionProgram.value) {
+    resolvedAccounts.compressionProgram.value = context.programs.getPublicKey(
+      'mplAccountCompression',
+      'mcmt6YrQEMKw8Mw43FmpRLmf7BqRnFMKmAcbxE3xkAW'
+    );
+    resolvedAccounts.compressionProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedArgs.proof) {
+    resolvedArgs.proof = [];
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Remaining Accounts.
+  const remainingAccounts = resolvedArgs.proof.map((value, index) => ({
+    index,
+    value,
+    isWritable: false,
+  }));
+  orderedAccounts.push(...remainingAccounts);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getRecoverNonTransferableV2InstructionDataSerializer().serialize(
+    resolvedArgs as RecoverNonTransferableV2InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
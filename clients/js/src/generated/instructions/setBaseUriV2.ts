@@ -0,0 +1,173 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  string,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findTreeConfigPda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type SetBaseUriV2InstructionAccounts = {
+  treeConfig?: PublicKey | Pda;
+  payer?: Signer;
+  /** Tree creator or delegate with the update metadata role, defaults to `payer` */
+  authority?: Signer;
+  merkleTree: PublicKey | Pda;
+  logWrapper?: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type SetBaseUriV2InstructionData = {
+  discriminator: Array<number>;
+  baseUri: string;
+};
+
+export type SetBaseUriV2InstructionDataArgs = { baseUri: string };
+
+export function getSetBaseUriV2InstructionDataSerializer(): Serializer<
+  SetBaseUriV2InstructionDataArgs,
+  SetBaseUriV2InstructionData
+> {
+  return mapSerializer<
+    SetBaseUriV2InstructionDataArgs,
+    any,
+    SetBaseUriV2InstructionData
+  >(
+    struct<SetBaseUriV2InstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['baseUri', string()],
+      ],
+      { description: 'SetBaseUriV2InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [35, 115, 98, 35, 1, 187, 251, 246],
+    })
+  ) as Serializer<SetBaseUriV2InstructionDataArgs, SetBaseUriV2InstructionData>;
+}
+
+// Args.
+export type SetBaseUriV2InstructionArgs = SetBaseUriV2InstructionDataArgs;
+
+// Instruction.
+export function setBaseUriV2(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: SetBaseUriV2InstructionAccounts & SetBaseUriV2InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplBubblegum',
+    'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    treeConfig: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.treeConfig ?? null,
+    },
+    payer: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.payer ?? null,
+    },
+    authority: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    merkleTree: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.merkleTree ?? null,
+    },
+    logWrapper: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.logWrapper ?? null,
+    },
+    systemProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: SetBaseUriV2InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.treeConfig.value) {
+    resolvedAccounts.treeConfig.value = findTreeConfigPda(context, {
+      merkleTree: expectPublicKey(resolvedAccounts.merkleTree.value),
+    });
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.logWrapper.value) {
+    resolvedAccounts.logWrapper.value = context.programs.getPublicKey(
+      'mplNoop',
+      'mnoopTCrg4p8ry25e4bcWA9XZjbNjMTfgYVGGEdRsf3'
+    );
+    resolvedAccounts.logWrapper.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSetBaseUriV2InstructionDataSerializer().serialize(
+    resolvedArgs as SetBaseUriV2InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,176 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type SetFeeConfigInstructionAccounts = {
+  feeConfig?: PublicKey | Pda;
+  payer?: Signer;
+  /** Upgrade authority of the Bubblegum program. */
+  authority?: Signer;
+  programData?: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type SetFeeConfigInstructionData = {
+  discriminator: Array<number>;
+  feeRecipient: PublicKey;
+  mintV2FeeLamports: bigint;
+  transferV2FeeLamports: bigint;
+};
+
+export type SetFeeConfigInstructionDataArgs = {
+  feeRecipient: PublicKey;
+  mintV2FeeLamports: number | bigint;
+  transferV2FeeLamports: number | bigint;
+};
+
+export function getSetFeeConfigInstructionDataSerializer(): Serializer<
+  SetFeeConfigInstructionDataArgs,
+  SetFeeConfigInstructionData
+> {
+  return mapSerializer<
+    SetFeeConfigInstructionDataArgs,
+    any,
+    SetFeeConfigInstructionData
+  >(
+    struct<SetFeeConfigInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['feeRecipient', publicKeySerializer()],
+        ['mintV2FeeLamports', u64()],
+        ['transferV2FeeLamports', u64()],
+      ],
+      { description: 'SetFeeConfigInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [221, 222, 52, 206, 114, 198, 64, 91],
+    })
+  ) as Serializer<SetFeeConfigInstructionDataArgs, SetFeeConfigInstructionData>;
+}
+
+// Args.
+export type SetFeeConfigInstructionArgs = SetFeeConfigInstructionDataArgs;
+
+// Instruction.
+export function setFeeConfig(
+  context: Pick<Context, 'identity' | 'payer' | 'programs'>,
+  input: SetFeeConfigInstructionAccounts & SetFeeConfigInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplBubblegum',
+    'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    feeConfig: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.feeConfig ?? null,
+    },
+    payer: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    authority: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    programData: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.programData ?? null,
+    },
+    systemProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: SetFeeConfigInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.feeConfig.value) {
+    resolvedAccounts.feeConfig.value = publicKey(
+      'CZ6RYvLgRnpxwZtAgJ2ozqYxYF4sYmxQikZpMQCZ1ZKE'
+    );
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.programData.value) {
+    resolvedAccounts.programData.value = publicKey(
+      'BuBmqo7ehiQf5svTpw54air9bveqqFZQV9BjX277rqm7'
+    );
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSetFeeConfigInstructionDataSerializer().serialize(
+    resolvedArgs as SetFeeConfigInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
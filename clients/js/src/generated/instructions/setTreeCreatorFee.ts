@@ -0,0 +1,174 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findTreeConfigPda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type SetTreeCreatorFeeInstructionAccounts = {
+  treeConfig?: PublicKey | Pda;
+  payer?: Signer;
+  treeCreator?: Signer;
+  merkleTree: PublicKey | Pda;
+  treeCreatorFees: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type SetTreeCreatorFeeInstructionData = {
+  discriminator: Array<number>;
+  creatorFeeLamports: bigint;
+};
+
+export type SetTreeCreatorFeeInstructionDataArgs = {
+  creatorFeeLamports: number | bigint;
+};
+
+export function getSetTreeCreatorFeeInstructionDataSerializer(): Serializer<
+  SetTreeCreatorFeeInstructionDataArgs,
+  SetTreeCreatorFeeInstructionData
+> {
+  return mapSerializer<
+    SetTreeCreatorFeeInstructionDataArgs,
+    any,
+    SetTreeCreatorFeeInstructionData
+  >(
+    struct<SetTreeCreatorFeeInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['creatorFeeLamports', u64()],
+      ],
+      { description: 'SetTreeCreatorFeeInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [223, 14, 232, 229, 118, 137, 253, 198],
+    })
+  ) as Serializer<
+    SetTreeCreatorFeeInstructionDataArgs,
+    SetTreeCreatorFeeInstructionData
+  >;
+}
+
+// Args.
+export type SetTreeCreatorFeeInstructionArgs =
+  SetTreeCreatorFeeInstructionDataArgs;
+
+// Instruction.
+export function setTreeCreatorFee(
+  context: Pick<Context, 'eddsa' | 'identity' | 'payer' | 'programs'>,
+  input: SetTreeCreatorFeeInstructionAccounts & SetTreeCreatorFeeInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplBubblegum',
+    'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    treeConfig: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.treeConfig ?? null,
+    },
+    payer: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    treeCreator: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.treeCreator ?? null,
+    },
+    merkleTree: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.merkleTree ?? null,
+    },
+    treeCreatorFees: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.treeCreatorFees ?? null,
+    },
+    systemProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: SetTreeCreatorFeeInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.treeConfig.value) {
+    resolvedAccounts.treeConfig.value = findTreeConfigPda(context, {
+      merkleTree: expectPublicKey(resolvedAccounts.merkleTree.value),
+    });
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.treeCreator.value) {
+    resolvedAccounts.treeCreator.value = context.identity;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSetTreeCreatorFeeInstructionDataSerializer().serialize(
+    resolvedArgs as SetTreeCreatorFeeInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,178 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findTreeConfigPda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type SetTreeDelegateRecordInstructionAccounts = {
+  treeConfig?: PublicKey | Pda;
+  payer?: Signer;
+  treeCreator?: Signer;
+  delegate: PublicKey | Pda;
+  merkleTree: PublicKey | Pda;
+  treeDelegateRecord: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type SetTreeDelegateRecordInstructionData = {
+  discriminator: Array<number>;
+  roles: number;
+};
+
+export type SetTreeDelegateRecordInstructionDataArgs = { roles: number };
+
+export function getSetTreeDelegateRecordInstructionDataSerializer(): Serializer<
+  SetTreeDelegateRecordInstructionDataArgs,
+  SetTreeDelegateRecordInstructionData
+> {
+  return mapSerializer<
+    SetTreeDelegateRecordInstructionDataArgs,
+    any,
+    SetTreeDelegateRecordInstructionData
+  >(
+    struct<SetTreeDelegateRecordInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['roles', u8()],
+      ],
+      { description: 'SetTreeDelegateRecordInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [145, 41, 224, 38, 100, 66, 250, 182],
+    })
+  ) as Serializer<
+    SetTreeDelegateRecordInstructionDataArgs,
+    SetTreeDelegateRecordInstructionData
+  >;
+}
+
+// Args.
+export type SetTreeDelegateRecordInstructionArgs =
+  SetTreeDelegateRecordInstructionDataArgs;
+
+// Instruction.
+export function setTreeDelegateRecord(
+  context: Pick<Context, 'eddsa' | 'identity' | 'payer' | 'programs'>,
+  input: SetTreeDelegateRecordInstructionAccounts &
+    SetTreeDelegateRecordInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplBubblegum',
+    'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    treeConfig: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.treeConfig ?? null,
+    },
+    payer: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    treeCreator: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.treeCreator ?? null,
+    },
+    delegate: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.delegate ?? null,
+    },
+    merkleTree: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.merkleTree ?? null,
+    },
+    treeDelegateRecord: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.treeDelegateRecord ?? null,
+    },
+    systemProgram: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: SetTreeDelegateRecordInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.treeConfig.value) {
+    resolvedAccounts.treeConfig.value = findTreeConfigPda(context, {
+      merkleTree: expectPublicKey(resolvedAccounts.merkleTree.value),
+    });
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.treeCreator.value) {
+    resolvedAccounts.treeCreator.value = context.identity;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSetTreeDelegateRecordInstructionDataSerializer().serialize(
+    resolvedArgs as SetTreeDelegateRecordInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
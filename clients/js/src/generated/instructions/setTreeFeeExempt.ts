@@ -0,0 +1,179 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  publicKey,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bool,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findTreeConfigPda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type SetTreeFeeExemptInstructionAccounts = {
+  treeConfig?: PublicKey | Pda;
+  merkleTree: PublicKey | Pda;
+  payer?: Signer;
+  /** Upgrade authority of the Bubblegum program. */
+  authority?: Signer;
+  programData?: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type SetTreeFeeExemptInstructionData = {
+  discriminator: Array<number>;
+  isFeeExempt: boolean;
+};
+
+export type SetTreeFeeExemptInstructionDataArgs = { isFeeExempt: boolean };
+
+export function getSetTreeFeeExemptInstructionDataSerializer(): Serializer<
+  SetTreeFeeExemptInstructionDataArgs,
+  SetTreeFeeExemptInstructionData
+> {
+  return mapSerializer<
+    SetTreeFeeExemptInstructionDataArgs,
+    any,
+    SetTreeFeeExemptInstructionData
+  >(
+    struct<SetTreeFeeExemptInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['isFeeExempt', bool()],
+      ],
+      { description: 'SetTreeFeeExemptInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [87, 237, 144, 246, 204, 218, 27, 192],
+    })
+  ) as Serializer<
+    SetTreeFeeExemptInstructionDataArgs,
+    SetTreeFeeExemptInstructionData
+  >;
+}
+
+// Args.
+export type SetTreeFeeExemptInstructionArgs =
+  SetTreeFeeExemptInstructionDataArgs;
+
+// Instruction.
+export function setTreeFeeExempt(
+  context: Pick<Context, 'eddsa' | 'identity' | 'payer' | 'programs'>,
+  input: SetTreeFeeExemptInstructionAccounts & SetTreeFeeExemptInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplBubblegum',
+    'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    treeConfig: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.treeConfig ?? null,
+    },
+    merkleTree: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.merkleTree ?? null,
+    },
+    payer: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    authority: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    programData: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.programData ?? null,
+    },
+    systemProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: SetTreeFeeExemptInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.treeConfig.value) {
+    resolvedAccounts.treeConfig.value = findTreeConfigPda(context, {
+      merkleTree: expectPublicKey(resolvedAccounts.merkleTree.value),
+    });
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = context.identity;
+  }
+  if (!resolvedAccounts.programData.value) {
+    resolvedAccounts.programData.value = publicKey(
+      'BuBmqo7ehiQf5svTpw54air9bveqqFZQV9BjX277rqm7'
+    );
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSetTreeFeeExemptInstructionDataSerializer().serialize(
+    resolvedArgs as SetTreeFeeExemptInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,190 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  option,
+  publicKey as publicKeySerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findTreeConfigPda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  TreeRuleSet,
+  TreeRuleSetArgs,
+  getTreeRuleSetSerializer,
+} from '../types';
+
+// Accounts.
+export type SetTreePluginsInstructionAccounts = {
+  treeConfig?: PublicKey | Pda;
+  payer?: Signer;
+  treeCreator?: Signer;
+  merkleTree: PublicKey | Pda;
+  treePlugins: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type SetTreePluginsInstructionData = {
+  discriminator: Array<number>;
+  royaltiesRuleSet: TreeRuleSet;
+  permanentFreezeDelegate: Option<PublicKey>;
+  permanentTransferDelegate: Option<PublicKey>;
+  permanentBurnDelegate: Option<PublicKey>;
+};
+
+export type SetTreePluginsInstructionDataArgs = {
+  royaltiesRuleSet: TreeRuleSetArgs;
+  permanentFreezeDelegate: OptionOrNullable<PublicKey>;
+  permanentTransferDelegate: OptionOrNullable<PublicKey>;
+  permanentBurnDelegate: OptionOrNullable<PublicKey>;
+};
+
+export function getSetTreePluginsInstructionDataSerializer(): Serializer<
+  SetTreePluginsInstructionDataArgs,
+  SetTreePluginsInstructionData
+> {
+  return mapSerializer<
+    SetTreePluginsInstructionDataArgs,
+    any,
+    SetTreePluginsInstructionData
+  >(
+    struct<SetTreePluginsInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['royaltiesRuleSet', getTreeRuleSetSerializer()],
+        ['permanentFreezeDelegate', option(publicKeySerializer())],
+        ['permanentTransferDelegate', option(publicKeySerializer())],
+        ['permanentBurnDelegate', option(publicKeySerializer())],
+      ],
+      { description: 'SetTreePluginsInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [183, 37, 252, 10, 131, 163, 233, 7],
+    })
+  ) as Serializer<
+    SetTreePluginsInstructionDataArgs,
+    SetTreePluginsInstructionData
+  >;
+}
+
+// Args.
+export type SetTreePluginsInstructionArgs = SetTreePluginsInstructionDataArgs;
+
+// Instruction.
+export function setTreePlugins(
+  context: Pick<Context, 'eddsa' | 'identity' | 'payer' | 'programs'>,
+  input: SetTreePluginsInstructionAccounts & SetTreePluginsInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplBubblegum',
+    'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    treeConfig: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.treeConfig ?? null,
+    },
+    payer: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    treeCreator: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.treeCreator ?? null,
+    },
+    merkleTree: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.merkleTree ?? null,
+    },
+    treePlugins: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.treePlugins ?? null,
+    },
+    systemProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: SetTreePluginsInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.treeConfig.value) {
+    resolvedAccounts.treeConfig.value = findTreeConfigPda(context, {
+      merkleTree: expectPublicKey(resolvedAccounts.merkleTree.value),
+    });
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.treeCreator.value) {
+    resolvedAccounts.treeCreator.value = context.identity;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSetTreePluginsInstructionDataSerializer().serialize(
+    resolvedArgs as SetTreePluginsInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,242 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  none,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bytes,
+  mapSerializer,
+  option,
+  struct,
+  u32,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findTreeConfigPda } from '../accounts';
+import {
+  PickPartial,
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type StakeV2InstructionAccounts = {
+  treeConfig?: PublicKey | Pda;
+  leafOwner: Signer;
+  /** Authority of the staking program, which must sign the unstake */
+  stakingAuthority: Signer;
+  /** Defaults to `leaf_owner` */
+  previousLeafDelegate?: PublicKey | Pda;
+  merkleTree: PublicKey | Pda;
+  stakeRecord: PublicKey | Pda;
+  logWrapper?: PublicKey | Pda;
+  compressionProgram?: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type StakeV2InstructionData = {
+  discriminator: Array<number>;
+  root: Uint8Array;
+  dataHash: Uint8Array;
+  creatorHash: Uint8Array;
+  collectionHash: Option<Uint8Array>;
+  assetDataHash: Option<Uint8Array>;
+  flags: Option<number>;
+  nonce: bigint;
+  index: number;
+};
+
+export type StakeV2InstructionDataArgs = {
+  root: Uint8Array;
+  dataHash: Uint8Array;
+  creatorHash: Uint8Array;
+  collectionHash?: OptionOrNullable<Uint8Array>;
+  assetDataHash?: OptionOrNullable<Uint8Array>;
+  flags?: OptionOrNullable<number>;
+  nonce: number | bigint;
+  index: number;
+};
+
+export function getStakeV2InstructionDataSerializer(): Serializer<
+  StakeV2InstructionDataArgs,
+  StakeV2InstructionData
+> {
+  return mapSerializer<StakeV2InstructionDataArgs, any, StakeV2InstructionData>(
+    struct<StakeV2InstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['root', bytes({ size: 32 })],
+        ['dataHash', bytes({ size: 32 })],
+        ['creatorHash', bytes({ size: 32 })],
+        ['collectionHash', option(bytes({ size: 32 }))],
+        ['assetDataHash', option(bytes({ size: 32 }))],
+        ['flags', option(u8())],
+        ['nonce', u64()],
+        ['index', u32()],
+      ],
+      { description: 'StakeV2InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [138, 220, 129, 193, 15, 244, 74, 53],
+      collectionHash: value.collectionHash ?? none(),
+      assetDataHash: value.assetDataHash ?? none(),
+      flags: value.flags ?? none(),
+    })
+  ) as Serializer<StakeV2InstructionDataArgs, StakeV2InstructionData>;
+}
+
+// Extra Args.
+export type StakeV2InstructionExtraArgs = { proof?: Array<PublicKey> };
+
+// Args.
+export type StakeV2InstructionArgs = PickPartial<
+  StakeV2InstructionDataArgs & StakeV2InstructionExtraArgs,
+  'proof'
+>;
+
+// Instruction.
+export function stakeV2(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  input: StakeV2InstructionAccounts & StakeV2InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplBubblegum',
+    'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    treeConfig: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.treeConfig ?? null,
+    },
+    leafOwner: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.leafOwner ?? null,
+    },
+    stakingAuthority: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.stakingAuthority ?? null,
+    },
+    previousLeafDelegate: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.previousLeafDelegate ?? null,
+    },
+    merkleTree: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.merkleTree ?? null,
+    },
+    stakeRecord: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.stakeRecord ?? null,
+    },
+    logWrapper: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.logWrapper ?? null,
+    },
+    compressionProgram: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.compressionProgram ?? null,
+    },
+    systemProgram: {
+      index: 8,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: StakeV2InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.treeConfig.value) {
+    resolvedAccounts.treeConfig.value = findTreeConfigPda(context, {
+      merkleTree: expectPublicKey(resolvedAccounts.merkleTree.value),
+    });
+  }
+  if (!resolvedAccounts.logWrapper.value) {
+    resolvedAccounts.logWrapper.value = context.programs.getPublicKey(
+      'mplNoop',
+      'mnoopTCrg4p8ry25e4bcWA9XZjbNjMTfgYVGGEdRsf3'
+    );
+    resolvedAccounts.logWrapper.isWritable = false;
+  }
+  if (!resolvedAccounts.compressionProgram.value) {
+    resolvedAccounts.compressionProgram.value = context.programs.getPublicKey(
+      'mplAccountCompression',
+      'mcmt6YrQEMKw8Mw43FmpRLmf7BqRnFMKmAcbxE3xkAW'
+    );
+    resolvedAccounts.compressionProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedArgs.proof) {
+    resolvedArgs.proof = [];
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Remaining Accounts.
+  const remainingAccounts = resolvedArgs.proof.map((value, index) => ({
+    index,
+    value,
+    isWritable: false,
+  }));
+  orderedAccounts.push(...remainingAccounts);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getStakeV2InstructionDataSerializer().serialize(
+    resolvedArgs as StakeV2InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
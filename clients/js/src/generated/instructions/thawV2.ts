@@ -43,7 +43,7 @@ export type ThawV2InstructionAccounts = {
   payer?: Signer;
   /**
    * Optional authority, defaults to `payer`.  Must be either
-   * the leaf delegate or collection permanent freeze delegate.
+   * the leaf delegate or collection or tree permanent freeze delegate.
    */
 
   authority?: Signer;
@@ -43,7 +43,7 @@ export type TransferV2InstructionAccounts = {
   payer?: Signer;
   /**
    * Optional authority, defaults to `payer`.  Must be either
-   * the leaf owner or collection permanent transfer delegate.
+   * the leaf owner or collection or tree permanent transfer delegate.
    */
 
   authority?: Signer;
@@ -0,0 +1,238 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  none,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bytes,
+  mapSerializer,
+  option,
+  struct,
+  u32,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findTreeConfigPda } from '../accounts';
+import {
+  PickPartial,
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type UnstakeV2InstructionAccounts = {
+  treeConfig?: PublicKey | Pda;
+  leafOwner: PublicKey | Pda;
+  stakingAuthority: Signer;
+  merkleTree: PublicKey | Pda;
+  stakeRecord: PublicKey | Pda;
+  logWrapper?: PublicKey | Pda;
+  compressionProgram?: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type UnstakeV2InstructionData = {
+  discriminator: Array<number>;
+  root: Uint8Array;
+  dataHash: Uint8Array;
+  creatorHash: Uint8Array;
+  collectionHash: Option<Uint8Array>;
+  assetDataHash: Option<Uint8Array>;
+  flags: Option<number>;
+  nonce: bigint;
+  index: number;
+};
+
+export type UnstakeV2InstructionDataArgs = {
+  root: Uint8Array;
+  dataHash: Uint8Array;
+  creatorHash: Uint8Array;
+  collectionHash?: OptionOrNullable<Uint8Array>;
+  assetDataHash?: OptionOrNullable<Uint8Array>;
+  flags?: OptionOrNullable<number>;
+  nonce: number | bigint;
+  index: number;
+};
+
+export function getUnstakeV2InstructionDataSerializer(): Serializer<
+  UnstakeV2InstructionDataArgs,
+  UnstakeV2InstructionData
+> {
+  return mapSerializer<
+    UnstakeV2InstructionDataArgs,
+    any,
+    UnstakeV2InstructionData
+  >(
+    struct<UnstakeV2InstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['root', bytes({ size: 32 })],
+        ['dataHash', bytes({ size: 32 })],
+        ['creatorHash', bytes({ size: 32 })],
+        ['collectionHash', option(bytes({ size: 32 }))],
+        ['assetDataHash', option(bytes({ size: 32 }))],
+        ['flags', option(u8())],
+        ['nonce', u64()],
+        ['index', u32()],
+      ],
+      { description: 'UnstakeV2InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [88, 53, 113, 37, 217, 40, 248, 41],
+      collectionHash: value.collectionHash ?? none(),
+      assetDataHash: value.assetDataHash ?? none(),
+      flags: value.flags ?? none(),
+    })
+  ) as Serializer<UnstakeV2InstructionDataArgs, UnstakeV2InstructionData>;
+}
+
+// Extra Args.
+export type UnstakeV2InstructionExtraArgs = { proof?: Array<PublicKey> };
+
+// Args.
+export type UnstakeV2InstructionArgs = PickPartial<
+  UnstakeV2InstructionDataArgs & UnstakeV2InstructionExtraArgs,
+  'proof'
+>;
+
+// Instruction.
+export function unstakeV2(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  input: UnstakeV2InstructionAccounts & UnstakeV2InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplBubblegum',
+    'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    treeConfig: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.treeConfig ?? null,
+    },
+    leafOwner: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.leafOwner ?? null,
+    },
+    stakingAuthority: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.stakingAuthority ?? null,
+    },
+    merkleTree: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.merkleTree ?? null,
+    },
+    stakeRecord: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.stakeRecord ?? null,
+    },
+    logWrapper: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.logWrapper ?? null,
+    },
+    compressionProgram: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.compressionProgram ?? null,
+    },
+    systemProgram: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: UnstakeV2InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.treeConfig.value) {
+    resolvedAccounts.treeConfig.value = findTreeConfigPda(context, {
+      merkleTree: expectPublicKey(resolvedAccounts.merkleTree.value),
+    });
+  }
+  if (!resolvedAccounts.logWrapper.value) {
+    resolvedAccounts.logWrapper.value = context.programs.getPublicKey(
+      'mplNoop',
+      'mnoopTCrg4p8ry25e4bcWA9XZjbNjMTfgYVGGEdRsf3'
+    );
+    resolvedAccounts.logWrapper.isWritable = false;
+  }
+  if (!resolvedAccounts.compressionProgram.value) {
+    resolvedAccounts.compressionProgram.value = context.programs.getPublicKey(
+      'mplAccountCompression',
+      'mcmt6YrQEMKw8Mw43FmpRLmf7BqRnFMKmAcbxE3xkAW'
+    );
+    resolvedAccounts.compressionProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedArgs.proof) {
+    resolvedArgs.proof = [];
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Remaining Accounts.
+  const remainingAccounts = resolvedArgs.proof.map((value, index) => ({
+    index,
+    value,
+    isWritable: false,
+  }));
+  orderedAccounts.push(...remainingAccounts);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getUnstakeV2InstructionDataSerializer().serialize(
+    resolvedArgs as UnstakeV2InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,238 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  none,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bytes,
+  mapSerializer,
+  option,
+  struct,
+  u32,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findTreeConfigPda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  MetadataArgsV2,
+  MetadataArgsV2Args,
+  getMetadataArgsV2Serializer,
+} from '../types';
+
+// Accounts.
+export type UpdateCreatorV2InstructionAccounts = {
+  treeConfig?: PublicKey | Pda;
+  payer?: Signer;
+  /** Optional creator, defaults to `payer` */
+  creator?: Signer;
+  newCreator: Signer;
+  leafOwner: PublicKey | Pda;
+  /** Defaults to `leaf_owner` */
+  leafDelegate?: PublicKey | Pda;
+  merkleTree: PublicKey | Pda;
+  logWrapper?: PublicKey | Pda;
+  compressionProgram?: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type UpdateCreatorV2InstructionData = {
+  discriminator: Array<number>;
+  root: Uint8Array;
+  assetDataHash: Option<Uint8Array>;
+  flags: Option<number>;
+  nonce: bigint;
+  index: number;
+  currentMetadata: MetadataArgsV2;
+};
+
+export type UpdateCreatorV2InstructionDataArgs = {
+  root: Uint8Array;
+  assetDataHash?: OptionOrNullable<Uint8Array>;
+  flags?: OptionOrNullable<number>;
+  nonce: number | bigint;
+  index: number;
+  currentMetadata: MetadataArgsV2Args;
+};
+
+export function getUpdateCreatorV2InstructionDataSerializer(): Serializer<
+  UpdateCreatorV2InstructionDataArgs,
+  UpdateCreatorV2InstructionData
+> {
+  return mapSerializer<
+    UpdateCreatorV2InstructionDataArgs,
+    any,
+    UpdateCreatorV2InstructionData
+  >(
+    struct<UpdateCreatorV2InstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['root', bytes({ size: 32 })],
+        ['assetDataHash', option(bytes({ size: 32 }))],
+        ['flags', option(u8())],
+        ['nonce', u64()],
+        ['index', u32()],
+        ['currentMetadata', getMetadataArgsV2Serializer()],
+      ],
+      { description: 'UpdateCreatorV2InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [136, 105, 105, 120, 87, 81, 148, 47],
+      assetDataHash: value.assetDataHash ?? none(),
+      flags: value.flags ?? none(),
+    })
+  ) as Serializer<
+    UpdateCreatorV2InstructionDataArgs,
+    UpdateCreatorV2InstructionData
+  >;
+}
+
+// Args.
+export type UpdateCreatorV2InstructionArgs = UpdateCreatorV2InstructionDataArgs;
+
+// Instruction.
+export function updateCreatorV2(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: UpdateCreatorV2InstructionAccounts & UpdateCreatorV2InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplBubblegum',
+    'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    treeConfig: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.treeConfig ?? null,
+    },
+    payer: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    creator: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.creator ?? null,
+    },
+    newCreator: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.newCreator ?? null,
+    },
+    leafOwner: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.leafOwner ?? null,
+    },
+    leafDelegate: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.leafDelegate ?? null,
+    },
+    merkleTree: {
+      index: 6,
+      isWritable: true as boolean,
+      value: input.merkleTree ?? null,
+    },
+    logWrapper: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.logWrapper ?? null,
+    },
+    compressionProgram: {
+      index: 8,
+      isWritable: false as boolean,
+      value: input.compressionProgram ?? null,
+    },
+    systemProgram: {
+      index: 9,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: UpdateCreatorV2InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.treeConfig.value) {
+    resolvedAccounts.treeConfig.value = findTreeConfigPda(context, {
+      merkleTree: expectPublicKey(resolvedAccounts.merkleTree.value),
+    });
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.logWrapper.value) {
+    resolvedAccounts.logWrapper.value = context.programs.getPublicKey(
+      'mplNoop',
+      'mnoopTCrg4p8ry25e4bcWA9XZjbNjMTfgYVGGEdRsf3'
+    );
+    resolvedAccounts.logWrapper.isWritable = false;
+  }
+  if (!resolvedAccounts.compressionProgram.value) {
+    resolvedAccounts.compressionProgram.value = context.programs.getPublicKey(
+      'mplAccountCompression',
+      'mcmt6YrQEMKw8Mw43FmpRLmf7BqRnFMKmAcbxE3xkAW'
+    );
+    resolvedAccounts.compressionProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getUpdateCreatorV2InstructionDataSerializer().serialize(
+    resolvedArgs as UpdateCreatorV2InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
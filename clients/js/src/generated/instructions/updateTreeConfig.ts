@@ -0,0 +1,192 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bool,
+  mapSerializer,
+  option,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findTreeConfigPda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type UpdateTreeConfigInstructionAccounts = {
+  treeConfig?: PublicKey | Pda;
+  payer?: Signer;
+  treeCreator?: Signer;
+  merkleTree: PublicKey | Pda;
+  /** Only required when proposing a new tree creator. */
+  treeCreatorProposal?: PublicKey | Pda;
+  logWrapper: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type UpdateTreeConfigInstructionData = {
+  discriminator: Array<number>;
+  totalMintCapacity: Option<bigint>;
+  isPublic: Option<boolean>;
+  proposedTreeCreator: Option<PublicKey>;
+};
+
+export type UpdateTreeConfigInstructionDataArgs = {
+  totalMintCapacity: OptionOrNullable<number | bigint>;
+  isPublic: OptionOrNullable<boolean>;
+  proposedTreeCreator: OptionOrNullable<PublicKey>;
+};
+
+export function getUpdateTreeConfigInstructionDataSerializer(): Serializer<
+  UpdateTreeConfigInstructionDataArgs,
+  UpdateTreeConfigInstructionData
+> {
+  return mapSerializer<
+    UpdateTreeConfigInstructionDataArgs,
+    any,
+    UpdateTreeConfigInstructionData
+  >(
+    struct<UpdateTreeConfigInstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['totalMintCapacity', option(u64())],
+        ['isPublic', option(bool())],
+        ['proposedTreeCreator', option(publicKeySerializer())],
+      ],
+      { description: 'UpdateTreeConfigInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [75, 1, 47, 108, 102, 26, 239, 207],
+    })
+  ) as Serializer<
+    UpdateTreeConfigInstructionDataArgs,
+    UpdateTreeConfigInstructionData
+  >;
+}
+
+// Args.
+export type UpdateTreeConfigInstructionArgs =
+  UpdateTreeConfigInstructionDataArgs;
+
+// Instruction.
+export function updateTreeConfig(
+  context: Pick<Context, 'eddsa' | 'identity' | 'payer' | 'programs'>,
+  input: UpdateTreeConfigInstructionAccounts & UpdateTreeConfigInstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplBubblegum',
+    'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    treeConfig: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.treeConfig ?? null,
+    },
+    payer: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    treeCreator: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.treeCreator ?? null,
+    },
+    merkleTree: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.merkleTree ?? null,
+    },
+    treeCreatorProposal: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.treeCreatorProposal ?? null,
+    },
+    logWrapper: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.logWrapper ?? null,
+    },
+    systemProgram: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: UpdateTreeConfigInstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.treeConfig.value) {
+    resolvedAccounts.treeConfig.value = findTreeConfigPda(context, {
+      merkleTree: expectPublicKey(resolvedAccounts.merkleTree.value),
+    });
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.treeCreator.value) {
+    resolvedAccounts.treeCreator.value = context.identity;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getUpdateTreeConfigInstructionDataSerializer().serialize(
+    resolvedArgs as UpdateTreeConfigInstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,209 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bytes,
+  mapSerializer,
+  struct,
+  u32,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findTreeConfigPda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  expectSome,
+  getAccountMetasAndSigners,
+} from '../shared';
+import {
+  MetadataArgs,
+  MetadataArgsArgs,
+  getMetadataArgsSerializer,
+} from '../types';
+
+// Accounts.
+export type UtilizeV1InstructionAccounts = {
+  treeConfig?: PublicKey | Pda;
+  leafOwner: PublicKey | Pda | Signer;
+  leafDelegate?: PublicKey | Pda | Signer;
+  merkleTree: PublicKey | Pda;
+  logWrapper?: PublicKey | Pda;
+  compressionProgram?: PublicKey | Pda;
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type UtilizeV1InstructionData = {
+  discriminator: Array<number>;
+  root: Uint8Array;
+  nonce: bigint;
+  index: number;
+  currentMetadata: MetadataArgs;
+  numberOfUses: bigint;
+};
+
+export type UtilizeV1InstructionDataArgs = {
+  root: Uint8Array;
+  nonce: number | bigint;
+  index: number;
+  currentMetadata: MetadataArgsArgs;
+  numberOfUses: number | bigint;
+};
+
+export function getUtilizeV1InstructionDataSerializer(): Serializer<
+  UtilizeV1InstructionDataArgs,
+  UtilizeV1InstructionData
+> {
+  return mapSerializer<
+    UtilizeV1InstructionDataArgs,
+    any,
+    UtilizeV1InstructionData
+  >(
+    struct<UtilizeV1InstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['root', bytes({ size: 32 })],
+        ['nonce', u64()],
+        ['index', u32()],
+        ['currentMetadata', getMetadataArgsSerializer()],
+        ['numberOfUses', u64()],
+      ],
+      { description: 'UtilizeV1InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [123, 251, 135, 20, 124, 105, 198, 124],
+    })
+  ) as Serializer<UtilizeV1InstructionDataArgs, UtilizeV1InstructionData>;
+}
+
+// Args.
+export type UtilizeV1InstructionArgs = UtilizeV1InstructionDataArgs;
+
+// Instruction.
+export function utilizeV1(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  input: UtilizeV1InstructionAccounts & UtilizeV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplBubblegum',
+    'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    treeConfig: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.treeConfig ?? null,
+    },
+    leafOwner: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.leafOwner ?? null,
+    },
+    leafDelegate: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.leafDelegate ?? null,
+    },
+    merkleTree: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.merkleTree ?? null,
+    },
+    logWrapper: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.logWrapper ?? null,
+    },
+    compressionProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.compressionProgram ?? null,
+    },
+    systemProgram: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: UtilizeV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.treeConfig.value) {
+    resolvedAccounts.treeConfig.value = findTreeConfigPda(context, {
+      merkleTree: expectPublicKey(resolvedAccounts.merkleTree.value),
+    });
+  }
+  if (!resolvedAccounts.leafDelegate.value) {
+    resolvedAccounts.leafDelegate.value = expectSome(
+      resolvedAccounts.leafOwner.value
+    );
+  }
+  if (!resolvedAccounts.logWrapper.value) {
+    resolvedAccounts.logWrapper.value = context.programs.getPublicKey(
+      'splNoop',
+      'noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV'
+    );
+    resolvedAccounts.logWrapper.isWritable = false;
+  }
+  if (!resolvedAccounts.compressionProgram.value) {
+    resolvedAccounts.compressionProgram.value = context.programs.getPublicKey(
+      'splAccountCompression',
+      'cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK'
+    );
+    resolvedAccounts.compressionProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getUtilizeV1InstructionDataSerializer().serialize(
+    resolvedArgs as UtilizeV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,199 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  TransactionBuilder,
+  none,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bool,
+  bytes,
+  mapSerializer,
+  option,
+  struct,
+  u32,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findTreeConfigPda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type VerifyLeafV2InstructionAccounts = {
+  treeConfig?: PublicKey | Pda;
+  leafOwner: PublicKey | Pda;
+  /** Defaults to `leaf_owner` */
+  leafDelegate?: PublicKey | Pda;
+  merkleTree: PublicKey | Pda;
+  /** Optional mpl-core collection the asset must belong to. */
+  coreCollection?: PublicKey | Pda;
+  compressionProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type VerifyLeafV2InstructionData = {
+  discriminator: Array<number>;
+  root: Uint8Array;
+  dataHash: Uint8Array;
+  creatorHash: Uint8Array;
+  collectionHash: Option<Uint8Array>;
+  assetDataHash: Option<Uint8Array>;
+  flags: Option<number>;
+  nonce: bigint;
+  index: number;
+  requireNotFrozen: boolean;
+};
+
+export type VerifyLeafV2InstructionDataArgs = {
+  root: Uint8Array;
+  dataHash: Uint8Array;
+  creatorHash: Uint8Array;
+  collectionHash?: OptionOrNullable<Uint8Array>;
+  assetDataHash?: OptionOrNullable<Uint8Array>;
+  flags?: OptionOrNullable<number>;
+  nonce: number | bigint;
+  index: number;
+  requireNotFrozen: boolean;
+};
+
+export function getVerifyLeafV2InstructionDataSerializer(): Serializer<
+  VerifyLeafV2InstructionDataArgs,
+  VerifyLeafV2InstructionData
+> {
+  return mapSerializer<
+    VerifyLeafV2InstructionDataArgs,
+    any,
+    VerifyLeafV2InstructionData
+  >(
+    struct<VerifyLeafV2InstructionData>(
+      [
+        ['discriminator', array(u8(), { size: 8 })],
+        ['root', bytes({ size: 32 })],
+        ['dataHash', bytes({ size: 32 })],
+        ['creatorHash', bytes({ size: 32 })],
+        ['collectionHash', option(bytes({ size: 32 }))],
+        ['assetDataHash', option(bytes({ size: 32 }))],
+        ['flags', option(u8())],
+        ['nonce', u64()],
+        ['index', u32()],
+        ['requireNotFrozen', bool()],
+      ],
+      { description: 'VerifyLeafV2InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [69, 197, 64, 216, 112, 212, 255, 168],
+      collectionHash: value.collectionHash ?? none(),
+      assetDataHash: value.assetDataHash ?? none(),
+      flags: value.flags ?? none(),
+    })
+  ) as Serializer<VerifyLeafV2InstructionDataArgs, VerifyLeafV2InstructionData>;
+}
+
+// Args.
+export type VerifyLeafV2InstructionArgs = VerifyLeafV2InstructionDataArgs;
+
+// Instruction.
+export function verifyLeafV2(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  input: VerifyLeafV2InstructionAccounts & VerifyLeafV2InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplBubblegum',
+    'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    treeConfig: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.treeConfig ?? null,
+    },
+    leafOwner: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.leafOwner ?? null,
+    },
+    leafDelegate: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.leafDelegate ?? null,
+    },
+    merkleTree: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.merkleTree ?? null,
+    },
+    coreCollection: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.coreCollection ?? null,
+    },
+    compressionProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.compressionProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: VerifyLeafV2InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.treeConfig.value) {
+    resolvedAccounts.treeConfig.value = findTreeConfigPda(context, {
+      merkleTree: expectPublicKey(resolvedAccounts.merkleTree.value),
+    });
+  }
+  if (!resolvedAccounts.compressionProgram.value) {
+    resolvedAccounts.compressionProgram.value = context.programs.getPublicKey(
+      'mplAccountCompression',
+      'mcmt6YrQEMKw8Mw43FmpRLmf7BqRnFMKmAcbxE3xkAW'
+    );
+    resolvedAccounts.compressionProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getVerifyLeafV2InstructionDataSerializer().serialize(
+    resolvedArgs as VerifyLeafV2InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,142 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findTreeConfigPda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type WithdrawCreatorFeesInstructionAccounts = {
+  treeConfig?: PublicKey | Pda;
+  treeCreator?: Signer;
+  merkleTree: PublicKey | Pda;
+  treeCreatorFees: PublicKey | Pda;
+  destination: PublicKey | Pda;
+};
+
+// Data.
+export type WithdrawCreatorFeesInstructionData = {
+  discriminator: Array<number>;
+};
+
+export type WithdrawCreatorFeesInstructionDataArgs = {};
+
+export function getWithdrawCreatorFeesInstructionDataSerializer(): Serializer<
+  WithdrawCreatorFeesInstructionDataArgs,
+  WithdrawCreatorFeesInstructionData
+> {
+  return mapSerializer<
+    WithdrawCreatorFeesInstructionDataArgs,
+    any,
+    WithdrawCreatorFeesInstructionData
+  >(
+    struct<WithdrawCreatorFeesInstructionData>(
+      [['discriminator', array(u8(), { size: 8 })]],
+      { description: 'WithdrawCreatorFeesInstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: [8, 30, 213, 18, 121, 105, 129, 222],
+    })
+  ) as Serializer<
+    WithdrawCreatorFeesInstructionDataArgs,
+    WithdrawCreatorFeesInstructionData
+  >;
+}
+
+// Instruction.
+export function withdrawCreatorFees(
+  context: Pick<Context, 'eddsa' | 'identity' | 'programs'>,
+  input: WithdrawCreatorFeesInstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplBubblegum',
+    'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    treeConfig: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.treeConfig ?? null,
+    },
+    treeCreator: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.treeCreator ?? null,
+    },
+    merkleTree: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.merkleTree ?? null,
+    },
+    treeCreatorFees: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.treeCreatorFees ?? null,
+    },
+    destination: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.destination ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.treeConfig.value) {
+    resolvedAccounts.treeConfig.value = findTreeConfigPda(context, {
+      merkleTree: expectPublicKey(resolvedAccounts.merkleTree.value),
+    });
+  }
+  if (!resolvedAccounts.treeCreator.value) {
+    resolvedAccounts.treeCreator.value = context.identity;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getWithdrawCreatorFeesInstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
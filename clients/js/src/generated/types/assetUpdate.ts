@@ -0,0 +1,384 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Option, OptionOrNullable, PublicKey } from '@metaplex-foundation/umi';
+import {
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  Serializer,
+  array,
+  bool,
+  dataEnum,
+  option,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  MetadataArgsV2,
+  MetadataArgsV2Args,
+  UpdateArgs,
+  UpdateArgsArgs,
+  Uses,
+  UsesArgs,
+  getMetadataArgsV2Serializer,
+  getUpdateArgsSerializer,
+  getUsesSerializer,
+} from '.';
+
+export type AssetUpdate =
+  | {
+      __kind: 'MintedV2';
+      authority: PublicKey;
+      leafOwner: PublicKey;
+      metadata: MetadataArgsV2;
+    }
+  | {
+      __kind: 'TransferredV2';
+      authority: PublicKey;
+      previousOwner: PublicKey;
+      newOwner: PublicKey;
+    }
+  | { __kind: 'FrozenV2'; authority: PublicKey; permanent: boolean }
+  | { __kind: 'ThawedV2'; authority: PublicKey; permanent: boolean }
+  | {
+      __kind: 'MetadataUpdatedV2';
+      authority: PublicKey;
+      updateArgs: UpdateArgs;
+    }
+  | {
+      __kind: 'RecoveredV2';
+      authority: PublicKey;
+      previousOwner: PublicKey;
+      newOwner: PublicKey;
+      reason: string;
+    }
+  | { __kind: 'RevokedV2'; authority: PublicKey }
+  | {
+      __kind: 'UsesUpdatedV2';
+      authority: PublicKey;
+      uses: Uses;
+      burned: boolean;
+    }
+  | { __kind: 'BurnedV2'; authority: PublicKey }
+  | {
+      __kind: 'DelegatedV2';
+      authority: PublicKey;
+      previousDelegate: PublicKey;
+      newDelegate: PublicKey;
+    }
+  | {
+      __kind: 'CollectionSetV2';
+      authority: PublicKey;
+      collection: Option<PublicKey>;
+    }
+  | {
+      __kind: 'CreatorsVerifiedV2';
+      creators: Array<PublicKey>;
+      verified: boolean;
+    }
+  | { __kind: 'NonTransferableSetV2'; authority: PublicKey }
+  | {
+      __kind: 'SplitV2';
+      authority: PublicKey;
+      splitAssetId: PublicKey;
+      newOwner: PublicKey;
+      splitAmount: bigint;
+    }
+  | {
+      __kind: 'MergedV2';
+      authority: PublicKey;
+      mergedAssetId: PublicKey;
+      mergedAmount: bigint;
+    }
+  | {
+      __kind: 'CreatorUpdatedV2';
+      previousCreator: PublicKey;
+      newCreator: PublicKey;
+    };
+
+export type AssetUpdateArgs =
+  | {
+      __kind: 'MintedV2';
+      authority: PublicKey;
+      leafOwner: PublicKey;
+      metadata: MetadataArgsV2Args;
+    }
+  | {
+      __kind: 'TransferredV2';
+      authority: PublicKey;
+      previousOwner: PublicKey;
+      newOwner: PublicKey;
+    }
+  | { __kind: 'FrozenV2'; authority: PublicKey; permanent: boolean }
+  | { __kind: 'ThawedV2'; authority: PublicKey; permanent: boolean }
+  | {
+      __kind: 'MetadataUpdatedV2';
+      authority: PublicKey;
+      updateArgs: UpdateArgsArgs;
+    }
+  | {
+      __kind: 'RecoveredV2';
+      authority: PublicKey;
+      previousOwner: PublicKey;
+      newOwner: PublicKey;
+      reason: string;
+    }
+  | { __kind: 'RevokedV2'; authority: PublicKey }
+  | {
+      __kind: 'UsesUpdatedV2';
+      authority: PublicKey;
+      uses: UsesArgs;
+      burned: boolean;
+    }
+  | { __kind: 'BurnedV2'; authority: PublicKey }
+  | {
+      __kind: 'DelegatedV2';
+      authority: PublicKey;
+      previousDelegate: PublicKey;
+      newDelegate: PublicKey;
+    }
+  | {
+      __kind: 'CollectionSetV2';
+      authority: PublicKey;
+      collection: OptionOrNullable<PublicKey>;
+    }
+  | {
+      __kind: 'CreatorsVerifiedV2';
+      creators: Array<PublicKey>;
+      verified: boolean;
+    }
+  | { __kind: 'NonTransferableSetV2'; authority: PublicKey }
+  | {
+      __kind: 'SplitV2';
+      authority: PublicKey;
+      splitAssetId: PublicKey;
+      newOwner: PublicKey;
+      splitAmount: number | bigint;
+    }
+  | {
+      __kind: 'MergedV2';
+      authority: PublicKey;
+      mergedAssetId: PublicKey;
+      mergedAmount: number | bigint;
+    }
+  | {
+      __kind: 'CreatorUpdatedV2';
+      previousCreator: PublicKey;
+      newCreator: PublicKey;
+    };
+
+export function getAssetUpdateSerializer(): Serializer<
+  AssetUpdateArgs,
+  AssetUpdate
+> {
+  return dataEnum<AssetUpdate>(
+    [
+      [
+        'MintedV2',
+        struct<GetDataEnumKindContent<AssetUpdate, 'MintedV2'>>([
+          ['authority', publicKeySerializer()],
+          ['leafOwner', publicKeySerializer()],
+          ['metadata', getMetadataArgsV2Serializer()],
+        ]),
+      ],
+      [
+        'TransferredV2',
+        struct<GetDataEnumKindContent<AssetUpdate, 'TransferredV2'>>([
+          ['authority', publicKeySerializer()],
+          ['previousOwner', publicKeySerializer()],
+          ['newOwner', publicKeySerializer()],
+        ]),
+      ],
+      [
+        'FrozenV2',
+        struct<GetDataEnumKindContent<AssetUpdate, 'FrozenV2'>>([
+          ['authority', publicKeySerializer()],
+          ['permanent', bool()],
+        ]),
+      ],
+      [
+        'ThawedV2',
+        struct<GetDataEnumKindContent<AssetUpdate, 'ThawedV2'>>([
+          ['authority', publicKeySerializer()],
+          ['permanent', bool()],
+        ]),
+      ],
+      [
+        'MetadataUpdatedV2',
+        struct<GetDataEnumKindContent<AssetUpdate, 'MetadataUpdatedV2'>>([
+          ['authority', publicKeySerializer()],
+          ['updateArgs', getUpdateArgsSerializer()],
+        ]),
+      ],
+      [
+        'RecoveredV2',
+        struct<GetDataEnumKindContent<AssetUpdate, 'RecoveredV2'>>([
+          ['authority', publicKeySerializer()],
+          ['previousOwner', publicKeySerializer()],
+          ['newOwner', publicKeySerializer()],
+          ['reason', string()],
+        ]),
+      ],
+      [
+        'RevokedV2',
+        struct<GetDataEnumKindContent<AssetUpdate, 'RevokedV2'>>([
+          ['authority', publicKeySerializer()],
+        ]),
+      ],
+      [
+        'UsesUpdatedV2',
+        struct<GetDataEnumKindContent<AssetUpdate, 'UsesUpdatedV2'>>([
+          ['authority', publicKeySerializer()],
+          ['uses', getUsesSerializer()],
+          ['burned', bool()],
+        ]),
+      ],
+      [
+        'BurnedV2',
+        struct<GetDataEnumKindContent<AssetUpdate, 'BurnedV2'>>([
+          ['authority', publicKeySerializer()],
+        ]),
+      ],
+      [
+        'DelegatedV2',
+        struct<GetDataEnumKindContent<AssetUpdate, 'DelegatedV2'>>([
+          ['authority', publicKeySerializer()],
+          ['previousDelegate', publicKeySerializer()],
+          ['newDelegate', publicKeySerializer()],
+        ]),
+      ],
+      [
+        'CollectionSetV2',
+        struct<GetDataEnumKindContent<AssetUpdate, 'CollectionSetV2'>>([
+          ['authority', publicKeySerializer()],
+          ['collection', option(publicKeySerializer())],
+        ]),
+      ],
+      [
+        'CreatorsVerifiedV2',
+        struct<GetDataEnumKindContent<AssetUpdate, 'CreatorsVerifiedV2'>>([
+          ['creators', array(publicKeySerializer())],
+          ['verified', bool()],
+        ]),
+      ],
+      [
+        'NonTransferableSetV2',
+        struct<GetDataEnumKindContent<AssetUpdate, 'NonTransferableSetV2'>>([
+          ['authority', publicKeySerializer()],
+        ]),
+      ],
+      [
+        'SplitV2',
+        struct<GetDataEnumKindContent<AssetUpdate, 'SplitV2'>>([
+          ['authority', publicKeySerializer()],
+          ['splitAssetId', publicKeySerializer()],
+          ['newOwner', publicKeySerializer()],
+          ['splitAmount', u64()],
+        ]),
+      ],
+      [
+        'MergedV2',
+        struct<GetDataEnumKindContent<AssetUpdate, 'MergedV2'>>([
+          ['authority', publicKeySerializer()],
+          ['mergedAssetId', publicKeySerializer()],
+          ['mergedAmount', u64()],
+        ]),
+      ],
+      [
+        'CreatorUpdatedV2',
+        struct<GetDataEnumKindContent<AssetUpdate, 'CreatorUpdatedV2'>>([
+          ['previousCreator', publicKeySerializer()],
+          ['newCreator', publicKeySerializer()],
+        ]),
+      ],
+    ],
+    { description: 'AssetUpdate' }
+  ) as Serializer<AssetUpdateArgs, AssetUpdate>;
+}
+
+// Data Enum Helpers.
+export function assetUpdate(
+  kind: 'MintedV2',
+  data: GetDataEnumKindContent<AssetUpdateArgs, 'MintedV2'>
+): GetDataEnumKind<AssetUpdateArgs, 'MintedV2'>;
+export function assetUpdate(
+  kind: 'TransferredV2',
+  data: GetDataEnumKindContent<AssetUpdateArgs, 'TransferredV2'>
+): GetDataEnumKind<AssetUpdateArgs, 'TransferredV2'>;
+export function assetUpdate(
+  kind: 'FrozenV2',
+  data: GetDataEnumKindContent<AssetUpdateArgs, 'FrozenV2'>
+): GetDataEnumKind<AssetUpdateArgs, 'FrozenV2'>;
+export function assetUpdate(
+  kind: 'ThawedV2',
+  data: GetDataEnumKindContent<AssetUpdateArgs, 'ThawedV2'>
+): GetDataEnumKind<AssetUpdateArgs, 'ThawedV2'>;
+export function assetUpdate(
+  kind: 'MetadataUpdatedV2',
+  data: GetDataEnumKindContent<AssetUpdateArgs, 'MetadataUpdatedV2'>
+): GetDataEnumKind<AssetUpdateArgs, 'MetadataUpdatedV2'>;
+export function assetUpdate(
+  kind: 'RecoveredV2',
+  data: GetDataEnumKindContent<AssetUpdateArgs, 'RecoveredV2'>
+): GetDataEnumKind<AssetUpdateArgs, 'RecoveredV2'>;
+export function assetUpdate(
+  kind: 'RevokedV2',
+  data: GetDataEnumKindContent<AssetUpdateArgs, 'RevokedV2'>
+): GetDataEnumKind<AssetUpdateArgs, 'RevokedV2'>;
+export function assetUpdate(
+  kind: 'UsesUpdatedV2',
+  data: GetDataEnumKindContent<AssetUpdateArgs, 'UsesUpdatedV2'>
+): GetDataEnumKind<AssetUpdateArgs, 'UsesUpdatedV2'>;
+export function assetUpdate(
+  kind: 'BurnedV2',
+  data: GetDataEnumKindContent<AssetUpdateArgs, 'BurnedV2'>
+): GetDataEnumKind<AssetUpdateArgs, 'BurnedV2'>;
+export function assetUpdate(
+  kind: 'DelegatedV2',
+  data: GetDataEnumKindContent<AssetUpdateArgs, 'DelegatedV2'>
+): GetDataEnumKind<AssetUpdateArgs, 'DelegatedV2'>;
+export function assetUpdate(
+  kind: 'CollectionSetV2',
+  data: GetDataEnumKindContent<AssetUpdateArgs, 'CollectionSetV2'>
+): GetDataEnumKind<AssetUpdateArgs, 'CollectionSetV2'>;
+export function assetUpdate(
+  kind: 'CreatorsVerifiedV2',
+  data: GetDataEnumKindContent<AssetUpdateArgs, 'CreatorsVerifiedV2'>
+): GetDataEnumKind<AssetUpdateArgs, 'CreatorsVerifiedV2'>;
+export function assetUpdate(
+  kind: 'NonTransferableSetV2',
+  data: GetDataEnumKindContent<AssetUpdateArgs, 'NonTransferableSetV2'>
+): GetDataEnumKind<AssetUpdateArgs, 'NonTransferableSetV2'>;
+export function assetUpdate(
+  kind: 'SplitV2',
+  data: GetDataEnumKindContent<AssetUpdateArgs, 'SplitV2'>
+): GetDataEnumKind<AssetUpdateArgs, 'SplitV2'>;
+export function assetUpdate(
+  kind: 'MergedV2',
+  data: GetDataEnumKindContent<AssetUpdateArgs, 'MergedV2'>
+): GetDataEnumKind<AssetUpdateArgs, 'MergedV2'>;
+export function assetUpdate(
+  kind: 'CreatorUpdatedV2',
+  data: GetDataEnumKindContent<AssetUpdateArgs, 'CreatorUpdatedV2'>
+): GetDataEnumKind<AssetUpdateArgs, 'CreatorUpdatedV2'>;
+export function assetUpdate<K extends AssetUpdateArgs['__kind']>(
+  kind: K,
+  data?: any
+): Extract<AssetUpdateArgs, { __kind: K }> {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+export function isAssetUpdate<K extends AssetUpdate['__kind']>(
+  kind: K,
+  value: AssetUpdate
+): value is AssetUpdate & { __kind: K } {
+  return value.__kind === kind;
+}
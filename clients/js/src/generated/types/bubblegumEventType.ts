@@ -11,6 +11,9 @@ import { Serializer, scalarEnum } from '@metaplex-foundation/umi/serializers';
 export enum BubblegumEventType {
   Uninitialized,
   LeafSchemaEvent,
+  TreeConfigEvent,
+  PrintEditionEvent,
+  AssetEvent,
 }
 
 export type BubblegumEventTypeArgs = BubblegumEventType;
@@ -7,16 +7,25 @@
  */
 
 export * from './assetDataSchema';
+export * from './assetUpdate';
 export * from './bubblegumEventType';
 export * from './collection';
 export * from './creator';
 export * from './decompressibleState';
+export * from './leafMetadataUpdate';
 export * from './leafSchema';
+export * from './leafUpdate';
 export * from './metadataArgs';
 export * from './metadataArgsV2';
 export * from './tokenProgramVersion';
 export * from './tokenStandard';
+export * from './treeConfigUpdate';
+export * from './treeCounters';
+export * from './treeExtensionType';
+export * from './treeRuleSet';
+export * from './treeSettings';
 export * from './updateArgs';
+export * from './uriTemplate';
 export * from './useMethod';
 export * from './uses';
 export * from './version';
@@ -0,0 +1,68 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Option, OptionOrNullable, PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  option,
+  publicKey as publicKeySerializer,
+  struct,
+  u32,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  MetadataArgsV2,
+  MetadataArgsV2Args,
+  getMetadataArgsV2Serializer,
+} from '.';
+
+/**
+ * A leaf updated by `batch_update_metadata_v2`, with the current values of its `LeafSchema` V2
+ * fields.
+ */
+export type LeafMetadataUpdate = {
+  leafOwner: PublicKey;
+  /** Defaults to `leaf_owner` */
+  leafDelegate: Option<PublicKey>;
+  assetDataHash: Option<Uint8Array>;
+  flags: Option<number>;
+  nonce: bigint;
+  index: number;
+  currentMetadata: MetadataArgsV2;
+};
+
+export type LeafMetadataUpdateArgs = {
+  leafOwner: PublicKey;
+  /** Defaults to `leaf_owner` */
+  leafDelegate: OptionOrNullable<PublicKey>;
+  assetDataHash: OptionOrNullable<Uint8Array>;
+  flags: OptionOrNullable<number>;
+  nonce: number | bigint;
+  index: number;
+  currentMetadata: MetadataArgsV2Args;
+};
+
+export function getLeafMetadataUpdateSerializer(): Serializer<
+  LeafMetadataUpdateArgs,
+  LeafMetadataUpdate
+> {
+  return struct<LeafMetadataUpdate>(
+    [
+      ['leafOwner', publicKeySerializer()],
+      ['leafDelegate', option(publicKeySerializer())],
+      ['assetDataHash', option(bytes({ size: 32 }))],
+      ['flags', option(u8())],
+      ['nonce', u64()],
+      ['index', u32()],
+      ['currentMetadata', getMetadataArgsV2Serializer()],
+    ],
+    { description: 'LeafMetadataUpdate' }
+  ) as Serializer<LeafMetadataUpdateArgs, LeafMetadataUpdate>;
+}
@@ -0,0 +1,45 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  struct,
+  u32,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+import { LeafSchema, LeafSchemaArgs, getLeafSchemaSerializer } from '.';
+
+export type LeafUpdate = {
+  leaf: LeafSchema;
+  /** Index of the leaf in the tree. */
+  index: number;
+  /** Sequence number of the tree after the update. */
+  seq: bigint;
+};
+
+export type LeafUpdateArgs = {
+  leaf: LeafSchemaArgs;
+  /** Index of the leaf in the tree. */
+  index: number;
+  /** Sequence number of the tree after the update. */
+  seq: number | bigint;
+};
+
+export function getLeafUpdateSerializer(): Serializer<
+  LeafUpdateArgs,
+  LeafUpdate
+> {
+  return struct<LeafUpdate>(
+    [
+      ['leaf', getLeafSchemaSerializer()],
+      ['index', u32()],
+      ['seq', u64()],
+    ],
+    { description: 'LeafUpdate' }
+  ) as Serializer<LeafUpdateArgs, LeafUpdate>;
+}
@@ -37,7 +37,7 @@ export type MetadataArgsV2 = {
   name: string;
   /** The symbol for the asset */
   symbol: string;
-  /** URI pointing to JSON representing the asset */
+  /** URI pointing to JSON representing the asset, or its suffix for trees with a base URI */
   uri: string;
   /** Royalty basis points that goes to creators in secondary sales (0-10000) */
   sellerFeeBasisPoints: number;
@@ -45,7 +45,7 @@ export type MetadataArgsV2 = {
   primarySaleHappened: boolean;
   /** Whether or not the data struct is mutable, default is not */
   isMutable: boolean;
-  /** Token standard.  Currently only `NonFungible` is allowed. */
+  /** Token standard.  Only `NonFungible` is allowed, or `FungibleAsset` with `mint_fungible_v2`. */
   tokenStandard: Option<TokenStandard>;
   /** Creator array */
   creators: Array<Creator>;
@@ -58,7 +58,7 @@ export type MetadataArgsV2Args = {
   name: string;
   /** The symbol for the asset */
   symbol?: string;
-  /** URI pointing to JSON representing the asset */
+  /** URI pointing to JSON representing the asset, or its suffix for trees with a base URI */
   uri: string;
   /** Royalty basis points that goes to creators in secondary sales (0-10000) */
   sellerFeeBasisPoints: number;
@@ -66,7 +66,7 @@ export type MetadataArgsV2Args = {
   primarySaleHappened?: boolean;
   /** Whether or not the data struct is mutable, default is not */
   isMutable?: boolean;
-  /** Token standard.  Currently only `NonFungible` is allowed. */
+  /** Token standard.  Only `NonFungible` is allowed, or `FungibleAsset` with `mint_fungible_v2`. */
   tokenStandard?: OptionOrNullable<TokenStandardArgs>;
   /** Creator array */
   creators: Array<CreatorArgs>;
@@ -0,0 +1,139 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  Serializer,
+  bool,
+  dataEnum,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+export type TreeConfigUpdate =
+  | { __kind: 'TotalMintCapacity'; previous: bigint; new: bigint }
+  | { __kind: 'IsPublic'; isPublic: boolean }
+  | { __kind: 'TreeCreatorProposed'; proposedTreeCreator: PublicKey }
+  | {
+      __kind: 'TreeCreatorAccepted';
+      previousTreeCreator: PublicKey;
+      newTreeCreator: PublicKey;
+    }
+  | { __kind: 'IsPaused'; isPaused: boolean }
+  | { __kind: 'BaseUri'; baseUri: string };
+
+export type TreeConfigUpdateArgs =
+  | {
+      __kind: 'TotalMintCapacity';
+      previous: number | bigint;
+      new: number | bigint;
+    }
+  | { __kind: 'IsPublic'; isPublic: boolean }
+  | { __kind: 'TreeCreatorProposed'; proposedTreeCreator: PublicKey }
+  | {
+      __kind: 'TreeCreatorAccepted';
+      previousTreeCreator: PublicKey;
+      newTreeCreator: PublicKey;
+    }
+  | { __kind: 'IsPaused'; isPaused: boolean }
+  | { __kind: 'BaseUri'; baseUri: string };
+
+export function getTreeConfigUpdateSerializer(): Serializer<
+  TreeConfigUpdateArgs,
+  TreeConfigUpdate
+> {
+  return dataEnum<TreeConfigUpdate>(
+    [
+      [
+        'TotalMintCapacity',
+        struct<GetDataEnumKindContent<TreeConfigUpdate, 'TotalMintCapacity'>>([
+          ['previous', u64()],
+          ['new', u64()],
+        ]),
+      ],
+      [
+        'IsPublic',
+        struct<GetDataEnumKindContent<TreeConfigUpdate, 'IsPublic'>>([
+          ['isPublic', bool()],
+        ]),
+      ],
+      [
+        'TreeCreatorProposed',
+        struct<GetDataEnumKindContent<TreeConfigUpdate, 'TreeCreatorProposed'>>(
+          [['proposedTreeCreator', publicKeySerializer()]]
+        ),
+      ],
+      [
+        'TreeCreatorAccepted',
+        struct<GetDataEnumKindContent<TreeConfigUpdate, 'TreeCreatorAccepted'>>(
+          [
+            ['previousTreeCreator', publicKeySerializer()],
+            ['newTreeCreator', publicKeySerializer()],
+          ]
+        ),
+      ],
+      [
+        'IsPaused',
+        struct<GetDataEnumKindContent<TreeConfigUpdate, 'IsPaused'>>([
+          ['isPaused', bool()],
+        ]),
+      ],
+      [
+        'BaseUri',
+        struct<GetDataEnumKindContent<TreeConfigUpdate, 'BaseUri'>>([
+          ['baseUri', string()],
+        ]),
+      ],
+    ],
+    { description: 'TreeConfigUpdate' }
+  ) as Serializer<TreeConfigUpdateArgs, TreeConfigUpdate>;
+}
+
+// Data Enum Helpers.
+export function treeConfigUpdate(
+  kind: 'TotalMintCapacity',
+  data: GetDataEnumKindContent<TreeConfigUpdateArgs, 'TotalMintCapacity'>
+): GetDataEnumKind<TreeConfigUpdateArgs, 'TotalMintCapacity'>;
+export function treeConfigUpdate(
+  kind: 'IsPublic',
+  data: GetDataEnumKindContent<TreeConfigUpdateArgs, 'IsPublic'>
+): GetDataEnumKind<TreeConfigUpdateArgs, 'IsPublic'>;
+export function treeConfigUpdate(
+  kind: 'TreeCreatorProposed',
+  data: GetDataEnumKindContent<TreeConfigUpdateArgs, 'TreeCreatorProposed'>
+): GetDataEnumKind<TreeConfigUpdateArgs, 'TreeCreatorProposed'>;
+export function treeConfigUpdate(
+  kind: 'TreeCreatorAccepted',
+  data: GetDataEnumKindContent<TreeConfigUpdateArgs, 'TreeCreatorAccepted'>
+): GetDataEnumKind<TreeConfigUpdateArgs, 'TreeCreatorAccepted'>;
+export function treeConfigUpdate(
+  kind: 'IsPaused',
+  data: GetDataEnumKindContent<TreeConfigUpdateArgs, 'IsPaused'>
+): GetDataEnumKind<TreeConfigUpdateArgs, 'IsPaused'>;
+export function treeConfigUpdate(
+  kind: 'BaseUri',
+  data: GetDataEnumKindContent<TreeConfigUpdateArgs, 'BaseUri'>
+): GetDataEnumKind<TreeConfigUpdateArgs, 'BaseUri'>;
+export function treeConfigUpdate<K extends TreeConfigUpdateArgs['__kind']>(
+  kind: K,
+  data?: any
+): Extract<TreeConfigUpdateArgs, { __kind: K }> {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+export function isTreeConfigUpdate<K extends TreeConfigUpdate['__kind']>(
+  kind: K,
+  value: TreeConfigUpdate
+): value is TreeConfigUpdate & { __kind: K } {
+  return value.__kind === kind;
+}
@@ -0,0 +1,59 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, struct, u64 } from '@metaplex-foundation/umi/serializers';
+
+/**
+ * Lifecycle counters for the assets of a tree, kept next to `TreeConfig::num_minted`.  Stored as
+ * a tree config extension, which V2 trees get on creation.
+ */
+export type TreeCounters = {
+  numBurned: bigint;
+  /**
+   * Leaves redeemed for a voucher that was neither cancelled nor decompressed yet.  Always zero
+   * until V2 trees support redeeming.
+   */
+  numRedeemed: bigint;
+  /** Always zero until V2 trees support decompression. */
+  numDecompressed: bigint;
+  /** Leaves currently frozen, at the asset level or the permanent level. */
+  numFrozen: bigint;
+  /** Leaves currently non-transferable. */
+  numNonTransferable: bigint;
+};
+
+export type TreeCountersArgs = {
+  numBurned: number | bigint;
+  /**
+   * Leaves redeemed for a voucher that was neither cancelled nor decompressed yet.  Always zero
+   * until V2 trees support redeeming.
+   */
+  numRedeemed: number | bigint;
+  /** Always zero until V2 trees support decompression. */
+  numDecompressed: number | bigint;
+  /** Leaves currently frozen, at the asset level or the permanent level. */
+  numFrozen: number | bigint;
+  /** Leaves currently non-transferable. */
+  numNonTransferable: number | bigint;
+};
+
+export function getTreeCountersSerializer(): Serializer<
+  TreeCountersArgs,
+  TreeCounters
+> {
+  return struct<TreeCounters>(
+    [
+      ['numBurned', u64()],
+      ['numRedeemed', u64()],
+      ['numDecompressed', u64()],
+      ['numFrozen', u64()],
+      ['numNonTransferable', u64()],
+    ],
+    { description: 'TreeCounters' }
+  ) as Serializer<TreeCountersArgs, TreeCounters>;
+}
@@ -0,0 +1,32 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, scalarEnum } from '@metaplex-foundation/umi/serializers';
+
+/**
+ * Types of the extensions stored after the `TreeConfig` in the tree config account.  Each
+ * extension is stored as a little-endian `u16` type, a little-endian `u16` length and the
+ * Borsh-serialized value, one after another.
+ */
+export enum TreeExtensionType {
+  Uninitialized,
+  Counters,
+  Settings,
+  UriTemplate,
+}
+
+export type TreeExtensionTypeArgs = TreeExtensionType;
+
+export function getTreeExtensionTypeSerializer(): Serializer<
+  TreeExtensionTypeArgs,
+  TreeExtensionType
+> {
+  return scalarEnum<TreeExtensionType>(TreeExtensionType, {
+    description: 'TreeExtensionType',
+  }) as Serializer<TreeExtensionTypeArgs, TreeExtensionType>;
+}
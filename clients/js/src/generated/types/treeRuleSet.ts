@@ -0,0 +1,82 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  Serializer,
+  array,
+  dataEnum,
+  publicKey as publicKeySerializer,
+  struct,
+  tuple,
+  unit,
+} from '@metaplex-foundation/umi/serializers';
+
+export type TreeRuleSet =
+  | { __kind: 'None' }
+  | { __kind: 'ProgramAllowList'; fields: [Array<PublicKey>] }
+  | { __kind: 'ProgramDenyList'; fields: [Array<PublicKey>] };
+
+export type TreeRuleSetArgs =
+  | { __kind: 'None' }
+  | { __kind: 'ProgramAllowList'; fields: [Array<PublicKey>] }
+  | { __kind: 'ProgramDenyList'; fields: [Array<PublicKey>] };
+
+export function getTreeRuleSetSerializer(): Serializer<
+  TreeRuleSetArgs,
+  TreeRuleSet
+> {
+  return dataEnum<TreeRuleSet>(
+    [
+      ['None', unit()],
+      [
+        'ProgramAllowList',
+        struct<GetDataEnumKindContent<TreeRuleSet, 'ProgramAllowList'>>([
+          ['fields', tuple([array(publicKeySerializer())])],
+        ]),
+      ],
+      [
+        'ProgramDenyList',
+        struct<GetDataEnumKindContent<TreeRuleSet, 'ProgramDenyList'>>([
+          ['fields', tuple([array(publicKeySerializer())])],
+        ]),
+      ],
+    ],
+    { description: 'TreeRuleSet' }
+  ) as Serializer<TreeRuleSetArgs, TreeRuleSet>;
+}
+
+// Data Enum Helpers.
+export function treeRuleSet(kind: 'None'): GetDataEnumKind<
+  TreeRuleSetArgs,
+  'None'
+>;
+export function treeRuleSet(
+  kind: 'ProgramAllowList',
+  data: GetDataEnumKindContent<TreeRuleSetArgs, 'ProgramAllowList'>['fields']
+): GetDataEnumKind<TreeRuleSetArgs, 'ProgramAllowList'>;
+export function treeRuleSet(
+  kind: 'ProgramDenyList',
+  data: GetDataEnumKindContent<TreeRuleSetArgs, 'ProgramDenyList'>['fields']
+): GetDataEnumKind<TreeRuleSetArgs, 'ProgramDenyList'>;
+export function treeRuleSet<K extends TreeRuleSetArgs['__kind']>(
+  kind: K,
+  data?: any
+): Extract<TreeRuleSetArgs, { __kind: K }> {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+export function isTreeRuleSet<K extends TreeRuleSet['__kind']>(
+  kind: K,
+  value: TreeRuleSet
+): value is TreeRuleSet & { __kind: K } {
+  return value.__kind === kind;
+}
@@ -0,0 +1,44 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, bool, struct } from '@metaplex-foundation/umi/serializers';
+
+/**
+ * Switches of a tree that are off by default.  Stored as a tree config extension, which is added
+ * the first time one of them is set.
+ */
+export type TreeSettings = {
+  /** Blocks minting, transferring, delegating and burning while set. */
+  isPaused: boolean;
+  /** Whether a `TreePlugins` account applies to uncollected assets in the tree. */
+  hasPlugins: boolean;
+  /** Exempts the tree from the protocol fees in `FeeConfig`. */
+  isFeeExempt: boolean;
+  /**
+   * Whether mints into a public tree by anyone other than the tree creator or delegates pay the
+   * creator fee in `TreeCreatorFees`.
+   */
+  hasCreatorFee: boolean;
+};
+
+export type TreeSettingsArgs = TreeSettings;
+
+export function getTreeSettingsSerializer(): Serializer<
+  TreeSettingsArgs,
+  TreeSettings
+> {
+  return struct<TreeSettings>(
+    [
+      ['isPaused', bool()],
+      ['hasPlugins', bool()],
+      ['isFeeExempt', bool()],
+      ['hasCreatorFee', bool()],
+    ],
+    { description: 'TreeSettings' }
+  ) as Serializer<TreeSettingsArgs, TreeSettings>;
+}
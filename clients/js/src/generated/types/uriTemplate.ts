@@ -0,0 +1,46 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  bool,
+  string,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+
+/**
+ * Base URI shared by the leaves of a tree, which then only store the rest of their URI, or
+ * nothing, in their metadata.  Stored as a tree config extension.
+ *
+ * Leaf data hashes commit to the URI stored in the leaf, so the base URI can be changed for every
+ * leaf at once with `set_base_uri_v2`.  Only the tree creator or its delegates sign that change,
+ * so the base URI is frozen for good once the tree has an immutable leaf, whose URI must not
+ * change, or a leaf in a collection, whose metadata only the collection authority may change.
+ * Trees mixing such leaves with mutable ones give up changing the base URI of the latter.
+ */
+export type UriTemplate = {
+  /** Prefix of every leaf URI, in which `{index}` is replaced by the leaf nonce. */
+  baseUri: string;
+  /** Whether the base URI can no longer be changed. */
+  isFrozen: boolean;
+};
+
+export type UriTemplateArgs = UriTemplate;
+
+export function getUriTemplateSerializer(): Serializer<
+  UriTemplateArgs,
+  UriTemplate
+> {
+  return struct<UriTemplate>(
+    [
+      ['baseUri', string()],
+      ['isFrozen', bool()],
+    ],
+    { description: 'UriTemplate' }
+  ) as Serializer<UriTemplateArgs, UriTemplate>;
+}
@@ -0,0 +1,51 @@
+import { generateSigner, none, some } from '@metaplex-foundation/umi';
+import { generateSignerWithSol } from '@metaplex-foundation/umi-bundle-tests';
+import { MPL_NOOP_PROGRAM_ID } from '@metaplex-foundation/mpl-account-compression';
+import test from 'ava';
+import {
+  TreeConfig,
+  acceptTreeCreator,
+  fetchTreeConfigFromSeeds,
+  findTreeCreatorProposalPda,
+  setTreeDelegate,
+  updateTreeConfig,
+} from '../src';
+import { createTreeV2, createUmi } from './_setup';
+
+test('it resets the tree delegate when the new tree creator accepts', async (t) => {
+  // Given a V2 Bubblegum tree with a tree delegate.
+  const umi = await createUmi();
+  const merkleTree = await createTreeV2(umi);
+  const treeDelegate = generateSigner(umi).publicKey;
+  await setTreeDelegate(umi, {
+    merkleTree,
+    newTreeDelegate: treeDelegate,
+  }).sendAndConfirm(umi);
+
+  // And a new tree creator proposed by the current one.
+  const newTreeCreator = await generateSignerWithSol(umi);
+  const treeCreatorProposal = findTreeCreatorProposalPda(umi, { merkleTree });
+  await updateTreeConfig(umi, {
+    merkleTree,
+    treeCreatorProposal,
+    logWrapper: MPL_NOOP_PROGRAM_ID,
+    totalMintCapacity: none(),
+    isPublic: none(),
+    proposedTreeCreator: some(newTreeCreator.publicKey),
+  }).sendAndConfirm(umi);
+
+  // When the new tree creator accepts the hand-off.
+  await acceptTreeCreator(umi, {
+    merkleTree,
+    newTreeCreator,
+    treeCreatorProposal,
+    logWrapper: MPL_NOOP_PROGRAM_ID,
+  }).sendAndConfirm(umi);
+
+  // Then the tree delegate is reset to the new tree creator.
+  const treeConfig = await fetchTreeConfigFromSeeds(umi, { merkleTree });
+  t.like(treeConfig, <TreeConfig>{
+    treeCreator: newTreeCreator.publicKey,
+    treeDelegate: newTreeCreator.publicKey,
+  });
+});
@@ -0,0 +1,97 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MasterEdition {
+    pub discriminator: [u8; 8],
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub authority: Pubkey,
+    /// Arbitrary key chosen at creation so an authority can own several master editions.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub edition_seed: Pubkey,
+    /// `data_hash` of the metadata every print is minted with.
+    pub data_hash: [u8; 32],
+    /// Maximum number of prints, unlimited if `None`.
+    pub max_supply: Option<u64>,
+    /// Number of prints so far, which is also the edition number of the last print.
+    pub supply: u64,
+}
+
+impl MasterEdition {
+    pub const LEN: usize = 121;
+
+    /// Prefix values used to generate a PDA for this account.
+    ///
+    /// Values are positional and appear in the following order:
+    ///
+    ///   0. `MasterEdition::PREFIX`
+    ///   1. authority (`Pubkey`)
+    ///   2. edition_seed (`Pubkey`)
+    pub const PREFIX: &'static [u8] = "master_edition".as_bytes();
+
+    pub fn create_pda(
+        authority: Pubkey,
+        edition_seed: Pubkey,
+        bump: u8,
+    ) -> Result<solana_program::pubkey::Pubkey, solana_program::pubkey::PubkeyError> {
+        solana_program::pubkey::Pubkey::create_program_address(
+            &[
+                "master_edition".as_bytes(),
+                authority.as_ref(),
+                edition_seed.as_ref(),
+                &[bump],
+            ],
+            &crate::MPL_BUBBLEGUM_ID,
+        )
+    }
+
+    pub fn find_pda(
+        authority: &Pubkey,
+        edition_seed: &Pubkey,
+    ) -> (solana_program::pubkey::Pubkey, u8) {
+        solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                "master_edition".as_bytes(),
+                authority.as_ref(),
+                edition_seed.as_ref(),
+            ],
+            &crate::MPL_BUBBLEGUM_ID,
+        )
+    }
+
+    #[inline(always)]
+    pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
+        let mut data = data;
+        Self::deserialize(&mut data)
+    }
+}
+
+impl<'a> TryFrom<&solana_program::account_info::AccountInfo<'a>> for MasterEdition {
+    type Error = std::io::Error;
+
+    fn try_from(
+        account_info: &solana_program::account_info::AccountInfo<'a>,
+    ) -> Result<Self, Self::Error> {
+        let mut data: &[u8] = &(*account_info.data).borrow();
+        Self::deserialize(&mut data)
+    }
+}
@@ -5,8 +5,24 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+pub(crate) mod r#fee_config;
+pub(crate) mod r#master_edition;
+pub(crate) mod r#stake_record;
 pub(crate) mod r#tree_config;
+pub(crate) mod r#tree_creator_fees;
+pub(crate) mod r#tree_creator_proposal;
+pub(crate) mod r#tree_delegate_record;
+pub(crate) mod r#tree_group;
+pub(crate) mod r#tree_plugins;
 pub(crate) mod r#voucher;
 
+pub use self::r#fee_config::*;
+pub use self::r#master_edition::*;
+pub use self::r#stake_record::*;
 pub use self::r#tree_config::*;
+pub use self::r#tree_creator_fees::*;
+pub use self::r#tree_creator_proposal::*;
+pub use self::r#tree_delegate_record::*;
+pub use self::r#tree_group::*;
+pub use self::r#tree_plugins::*;
 pub use self::r#voucher::*;
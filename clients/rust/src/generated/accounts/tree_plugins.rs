@@ -0,0 +1,75 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+use crate::generated::types::TreeRuleSet;
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TreePlugins {
+    pub discriminator: [u8; 8],
+    /// Program allow or deny list applied to the current and new owner on transfer.
+    pub royalties_rule_set: TreeRuleSet,
+    /// Can freeze and thaw any asset, and frozen assets cannot be transferred by their owner.
+    pub permanent_freeze_delegate: Option<Pubkey>,
+    /// Can transfer any asset, including frozen ones.
+    pub permanent_transfer_delegate: Option<Pubkey>,
+    /// Can burn any asset, including frozen ones.
+    pub permanent_burn_delegate: Option<Pubkey>,
+}
+
+impl TreePlugins {
+    pub const LEN: usize = 368;
+
+    /// Prefix values used to generate a PDA for this account.
+    ///
+    /// Values are positional and appear in the following order:
+    ///
+    ///   0. `TreePlugins::PREFIX`
+    ///   1. merkle_tree (`Pubkey`)
+    pub const PREFIX: &'static [u8] = "tree_plugins".as_bytes();
+
+    pub fn create_pda(
+        merkle_tree: Pubkey,
+        bump: u8,
+    ) -> Result<solana_program::pubkey::Pubkey, solana_program::pubkey::PubkeyError> {
+        solana_program::pubkey::Pubkey::create_program_address(
+            &["tree_plugins".as_bytes(), merkle_tree.as_ref(), &[bump]],
+            &crate::MPL_BUBBLEGUM_ID,
+        )
+    }
+
+    pub fn find_pda(merkle_tree: &Pubkey) -> (solana_program::pubkey::Pubkey, u8) {
+        solana_program::pubkey::Pubkey::find_program_address(
+            &["tree_plugins".as_bytes(), merkle_tree.as_ref()],
+            &crate::MPL_BUBBLEGUM_ID,
+        )
+    }
+
+    #[inline(always)]
+    pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
+        let mut data = data;
+        Self::deserialize(&mut data)
+    }
+}
+
+impl<'a> TryFrom<&solana_program::account_info::AccountInfo<'a>> for TreePlugins {
+    type Error = std::io::Error;
+
+    fn try_from(
+        account_info: &solana_program::account_info::AccountInfo<'a>,
+    ) -> Result<Self, Self::Error> {
+        let mut data: &[u8] = &(*account_info.data).borrow();
+        Self::deserialize(&mut data)
+    }
+}
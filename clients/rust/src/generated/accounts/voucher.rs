@@ -39,27 +39,18 @@ impl Voucher {
 
     pub fn create_pda(
         merkle_tree: Pubkey,
-        nonce: u64,
+        nonce: [u8; 8],
         bump: u8,
     ) -> Result<solana_program::pubkey::Pubkey, solana_program::pubkey::PubkeyError> {
         solana_program::pubkey::Pubkey::create_program_address(
-            &[
-                "voucher".as_bytes(),
-                merkle_tree.as_ref(),
-                nonce.to_string().as_ref(),
-                &[bump],
-            ],
+            &["voucher".as_bytes(), merkle_tree.as_ref(), &nonce, &[bump]],
             &crate::MPL_BUBBLEGUM_ID,
         )
     }
 
-    pub fn find_pda(merkle_tree: &Pubkey, nonce: u64) -> (solana_program::pubkey::Pubkey, u8) {
+    pub fn find_pda(merkle_tree: &Pubkey, nonce: [u8; 8]) -> (solana_program::pubkey::Pubkey, u8) {
         solana_program::pubkey::Pubkey::find_program_address(
-            &[
-                "voucher".as_bytes(),
-                merkle_tree.as_ref(),
-                nonce.to_string().as_ref(),
-            ],
+            &["voucher".as_bytes(), merkle_tree.as_ref(), &nonce],
             &crate::MPL_BUBBLEGUM_ID,
         )
     }
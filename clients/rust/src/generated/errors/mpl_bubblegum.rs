@@ -182,6 +182,96 @@ pub enum MplBubblegumError {
     /// 6056 (0x17A8) - Asset is not frozen
     #[error("Asset is not frozen")]
     AssetIsNotFrozen,
+    /// 6057 (0x17A9) - Total mint capacity can only be lowered, and not below the number minted
+    #[error("Total mint capacity can only be lowered, and not below the number minted")]
+    InvalidTotalMintCapacity,
+    /// 6058 (0x17AA) - Missing tree creator proposal account
+    #[error("Missing tree creator proposal account")]
+    MissingTreeCreatorProposalAccount,
+    /// 6059 (0x17AB) - Invalid tree delegate roles
+    #[error("Invalid tree delegate roles")]
+    InvalidTreeDelegateRoles,
+    /// 6060 (0x17AC) - Tree is paused
+    #[error("Tree is paused")]
+    TreePaused,
+    /// 6061 (0x17AD) - Tree group cannot hold more trees
+    #[error("Tree group cannot hold more trees")]
+    TreeGroupFull,
+    /// 6062 (0x17AE) - Tree is already in the tree group
+    #[error("Tree is already in the tree group")]
+    TreeAlreadyInGroup,
+    /// 6063 (0x17AF) - Tree is not available for minting in the tree group
+    #[error("Tree is not available for minting in the tree group")]
+    TreeNotInGroup,
+    /// 6064 (0x17B0) - Permanent tree plugins can only be added before minting
+    #[error("Permanent tree plugins can only be added before minting")]
+    TreePluginsLocked,
+    /// 6065 (0x17B1) - Too many programs in tree rule set
+    #[error("Too many programs in tree rule set")]
+    TreeRuleSetTooLong,
+    /// 6066 (0x17B2) - Missing tree plugins account
+    #[error("Missing tree plugins account")]
+    MissingTreePluginsAccount,
+    /// 6067 (0x17B3) - Tree has frozen leaves
+    #[error("Tree has frozen leaves")]
+    TreeHasFrozenLeaves,
+    /// 6068 (0x17B4) - Fee exceeds the maximum fee
+    #[error("Fee exceeds the maximum fee")]
+    FeeExceedsMaximum,
+    /// 6069 (0x17B5) - Missing tree creator fees account
+    #[error("Missing tree creator fees account")]
+    MissingTreeCreatorFeesAccount,
+    /// 6070 (0x17B6) - Tree still contains assets
+    #[error("Tree still contains assets")]
+    TreeNotEmpty,
+    /// 6071 (0x17B7) - Invalid tree extension
+    #[error("Invalid tree extension")]
+    InvalidTreeExtension,
+    /// 6072 (0x17B8) - Tree extension already exists
+    #[error("Tree extension already exists")]
+    TreeExtensionAlreadyExists,
+    /// 6073 (0x17B9) - Tree extension can only be added before the first mint
+    #[error("Tree extension can only be added before the first mint")]
+    TreeExtensionRequiresEmptyTree,
+    /// 6074 (0x17BA) - Master edition has reached its max supply
+    #[error("Master edition has reached its max supply")]
+    MasterEditionSupplyExhausted,
+    /// 6075 (0x17BB) - Metadata does not match the master edition
+    #[error("Metadata does not match the master edition")]
+    EditionMetadataMismatch,
+    /// 6076 (0x17BC) - Asset has no uses
+    #[error("Asset has no uses")]
+    Unusable,
+    /// 6077 (0x17BD) - Not enough uses remaining
+    #[error("Not enough uses remaining")]
+    NotEnoughUses,
+    /// 6078 (0x17BE) - Uses must have remaining uses, no more than the total, and a total of one if single
+    #[error("Uses must have remaining uses, no more than the total, and a total of one if single")]
+    InvalidUses,
+    /// 6079 (0x17BF) - Invalid amount
+    #[error("Invalid amount")]
+    InvalidAmount,
+    /// 6080 (0x17C0) - Leaf cannot be merged with itself
+    #[error("Leaf cannot be merged with itself")]
+    CannotMergeLeafWithItself,
+    /// 6081 (0x17C1) - Batch must contain leaves and the same number of proof accounts for each leaf
+    #[error("Batch must contain leaves and the same number of proof accounts for each leaf")]
+    InvalidBatch,
+    /// 6082 (0x17C2) - Base URI is frozen since the tree has immutable or collection leaves
+    #[error("Base URI is frozen since the tree has immutable or collection leaves")]
+    BaseUriFrozen,
+    /// 6083 (0x17C3) - Asset is staked
+    #[error("Asset is staked")]
+    AssetIsStaked,
+    /// 6084 (0x17C4) - Only non-transferable assets can be recovered
+    #[error("Only non-transferable assets can be recovered")]
+    AssetIsTransferable,
+    /// 6085 (0x17C5) - Recovery reason is too long
+    #[error("Recovery reason is too long")]
+    RecoveryReasonTooLong,
+    /// 6086 (0x17C6) - Asset is revoked
+    #[error("Asset is revoked")]
+    AssetIsRevoked,
 }
 
 impl From<MplBubblegumError> for ProgramError {
@@ -264,94 +354,88 @@ impl ToStr for MplBubblegumError {
             MplBubblegumError::HashingMismatch => "Hashing Mismatch Within Leaf Schema",
             MplBubblegumError::UnsupportedSchemaVersion => "Unsupported Schema Version",
             MplBubblegumError::CreatorShareTotalMustBe100 => "Creator shares must sum to 100",
-            MplBubblegumError::DuplicateCreatorAddress => {
-                "No duplicate creator addresses in metadata"
-            }
+            MplBubblegumError::DuplicateCreatorAddress => "No duplicate creator addresses in metadata",
             MplBubblegumError::CreatorDidNotVerify => "Creator did not verify the metadata",
             MplBubblegumError::CreatorNotFound => "Creator not found in creator Vec",
             MplBubblegumError::NoCreatorsPresent => "No creators in creator Vec",
-            MplBubblegumError::CreatorHashMismatch => {
-                "User-provided creator Vec must result in same user-provided creator hash"
-            }
-            MplBubblegumError::DataHashMismatch => {
-                "User-provided metadata must result in same user-provided data hash"
-            }
+            MplBubblegumError::CreatorHashMismatch => "User-provided creator Vec must result in same user-provided creator hash",
+            MplBubblegumError::DataHashMismatch => "User-provided metadata must result in same user-provided data hash",
             MplBubblegumError::CreatorsTooLong => "Creators list too long",
             MplBubblegumError::MetadataNameTooLong => "Name in metadata is too long",
             MplBubblegumError::MetadataSymbolTooLong => "Symbol in metadata is too long",
             MplBubblegumError::MetadataUriTooLong => "Uri in metadata is too long",
-            MplBubblegumError::MetadataBasisPointsTooHigh => {
-                "Basis points in metadata cannot exceed 10000"
-            }
+            MplBubblegumError::MetadataBasisPointsTooHigh => "Basis points in metadata cannot exceed 10000",
             MplBubblegumError::TreeAuthorityIncorrect => "Tree creator or tree delegate must sign.",
             MplBubblegumError::InsufficientMintCapacity => "Not enough unapproved mints left",
             MplBubblegumError::NumericalOverflowError => "NumericalOverflowError",
             MplBubblegumError::IncorrectOwner => "Incorrect account owner",
-            MplBubblegumError::CollectionCannotBeVerifiedInThisInstruction => {
-                "Cannot Verify Collection in this Instruction"
-            }
+            MplBubblegumError::CollectionCannotBeVerifiedInThisInstruction => "Cannot Verify Collection in this Instruction",
             MplBubblegumError::CollectionNotFound => "Collection Not Found on Metadata",
             MplBubblegumError::AlreadyVerified => "Collection item is already verified.",
             MplBubblegumError::AlreadyUnverified => "Collection item is already unverified.",
-            MplBubblegumError::UpdateAuthorityIncorrect => {
-                "Incorrect leaf metadata update authority."
-            }
-            MplBubblegumError::LeafAuthorityMustSign => {
-                "This transaction must be signed by either the leaf owner or leaf delegate"
-            }
-            MplBubblegumError::CollectionMustBeSized => {
-                "Collection Not Compatable with Compression, Must be Sized"
-            }
-            MplBubblegumError::MetadataMintMismatch => {
-                "Metadata mint does not match collection mint"
-            }
+            MplBubblegumError::UpdateAuthorityIncorrect => "Incorrect leaf metadata update authority.",
+            MplBubblegumError::LeafAuthorityMustSign => "This transaction must be signed by either the leaf owner or leaf delegate",
+            MplBubblegumError::CollectionMustBeSized => "Collection Not Compatable with Compression, Must be Sized",
+            MplBubblegumError::MetadataMintMismatch => "Metadata mint does not match collection mint",
             MplBubblegumError::InvalidCollectionAuthority => "Invalid collection authority",
             MplBubblegumError::InvalidDelegateRecord => "Invalid delegate record pda derivation",
-            MplBubblegumError::CollectionMasterEditionAccountInvalid => {
-                "Edition account doesnt match collection"
-            }
-            MplBubblegumError::CollectionMustBeAUniqueMasterEdition => {
-                "Collection Must Be a Unique Master Edition v2"
-            }
-            MplBubblegumError::UnknownExternalError => {
-                "Could not convert external error to BubblegumError"
-            }
+            MplBubblegumError::CollectionMasterEditionAccountInvalid => "Edition account doesnt match collection",
+            MplBubblegumError::CollectionMustBeAUniqueMasterEdition => "Collection Must Be a Unique Master Edition v2",
+            MplBubblegumError::UnknownExternalError => "Could not convert external error to BubblegumError",
             MplBubblegumError::DecompressionDisabled => "Decompression is disabled for this tree.",
             MplBubblegumError::MissingCollectionMintAccount => "Missing collection mint account",
-            MplBubblegumError::MissingCollectionMetadataAccount => {
-                "Missing collection metadata account"
-            }
+            MplBubblegumError::MissingCollectionMetadataAccount => "Missing collection metadata account",
             MplBubblegumError::CollectionMismatch => "Collection mismatch",
             MplBubblegumError::MetadataImmutable => "Metadata not mutable",
-            MplBubblegumError::PrimarySaleCanOnlyBeFlippedToTrue => {
-                "Can only update primary sale to true"
-            }
+            MplBubblegumError::PrimarySaleCanOnlyBeFlippedToTrue => "Can only update primary sale to true",
             MplBubblegumError::CreatorDidNotUnverify => "Creator did not unverify the metadata",
             MplBubblegumError::InvalidTokenStandard => "Only NonFungible standard is supported",
-            MplBubblegumError::InvalidCanopySize => {
-                "Canopy size should be set bigger for this tree"
-            }
+            MplBubblegumError::InvalidCanopySize => "Canopy size should be set bigger for this tree",
             MplBubblegumError::InvalidLogWrapper => "Invalid log wrapper program",
             MplBubblegumError::InvalidCompressionProgram => "Invalid compression program",
-            MplBubblegumError::LeafMustBeDelegated => {
-                "Leaf must be delegated to someone other than the leaf owner"
-            }
+            MplBubblegumError::LeafMustBeDelegated => "Leaf must be delegated to someone other than the leaf owner",
             MplBubblegumError::AssetIsFrozen => "Asset is frozen",
             MplBubblegumError::AssetIsNonTransferable => "Asset is non-transferable",
             MplBubblegumError::InvalidAuthority => "Invalid authority",
             MplBubblegumError::CollectionIsFrozen => "Collection is frozen",
-            MplBubblegumError::CollectionMustHaveBubblegumPlugin => {
-                "Core collections must have the Bubblegum V2 plugin on them"
-            }
+            MplBubblegumError::CollectionMustHaveBubblegumPlugin => "Core collections must have the Bubblegum V2 plugin on them",
             MplBubblegumError::NotAvailable => "Feature not currently available",
             MplBubblegumError::MissingCollectionAccount => "Missing collection account",
             MplBubblegumError::AssetDataLengthTooLong => "Asset data length too long",
             MplBubblegumError::AlreadyInCollection => "Item is already in the collection",
             MplBubblegumError::AlreadyNotInCollection => "Item is already not in a collection",
-            MplBubblegumError::MissingMplCoreCpiSignerAccount => {
-                "Missing mpl-core CPI signer account"
-            }
+            MplBubblegumError::MissingMplCoreCpiSignerAccount => "Missing mpl-core CPI signer account",
             MplBubblegumError::AssetIsNotFrozen => "Asset is not frozen",
+            MplBubblegumError::InvalidTotalMintCapacity => "Total mint capacity can only be lowered, and not below the number minted",
+            MplBubblegumError::MissingTreeCreatorProposalAccount => "Missing tree creator proposal account",
+            MplBubblegumError::InvalidTreeDelegateRoles => "Invalid tree delegate roles",
+            MplBubblegumError::TreePaused => "Tree is paused",
+            MplBubblegumError::TreeGroupFull => "Tree group cannot hold more trees",
+            MplBubblegumError::TreeAlreadyInGroup => "Tree is already in the tree group",
+            MplBubblegumError::TreeNotInGroup => "Tree is not available for minting in the tree group",
+            MplBubblegumError::TreePluginsLocked => "Permanent tree plugins can only be added before minting",
+            MplBubblegumError::TreeRuleSetTooLong => "Too many programs in tree rule set",
+            MplBubblegumError::MissingTreePluginsAccount => "Missing tree plugins account",
+            MplBubblegumError::TreeHasFrozenLeaves => "Tree has frozen leaves",
+            MplBubblegumError::FeeExceedsMaximum => "Fee exceeds the maximum fee",
+            MplBubblegumError::MissingTreeCreatorFeesAccount => "Missing tree creator fees account",
+            MplBubblegumError::TreeNotEmpty => "Tree still contains assets",
+            MplBubblegumError::InvalidTreeExtension => "Invalid tree extension",
+            MplBubblegumError::TreeExtensionAlreadyExists => "Tree extension already exists",
+            MplBubblegumError::TreeExtensionRequiresEmptyTree => "Tree extension can only be added before the first mint",
+            MplBubblegumError::MasterEditionSupplyExhausted => "Master edition has reached its max supply",
+            MplBubblegumError::EditionMetadataMismatch => "Metadata does not match the master edition",
+            MplBubblegumError::Unusable => "Asset has no uses",
+            MplBubblegumError::NotEnoughUses => "Not enough uses remaining",
+            MplBubblegumError::InvalidUses => "Uses must have remaining uses, no more than the total, and a total of one if single",
+            MplBubblegumError::InvalidAmount => "Invalid amount",
+            MplBubblegumError::CannotMergeLeafWithItself => "Leaf cannot be merged with itself",
+            MplBubblegumError::InvalidBatch => "Batch must contain leaves and the same number of proof accounts for each leaf",
+            MplBubblegumError::BaseUriFrozen => "Base URI is frozen since the tree has immutable or collection leaves",
+            MplBubblegumError::AssetIsStaked => "Asset is staked",
+            MplBubblegumError::AssetIsTransferable => "Only non-transferable assets can be recovered",
+            MplBubblegumError::RecoveryReasonTooLong => "Recovery reason is too long",
+            MplBubblegumError::AssetIsRevoked => "Asset is revoked",
         }
     }
 }
@@ -0,0 +1,497 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct AcceptTreeCreator {
+    pub tree_config: solana_program::pubkey::Pubkey,
+
+    pub new_tree_creator: solana_program::pubkey::Pubkey,
+
+    pub merkle_tree: solana_program::pubkey::Pubkey,
+
+    pub tree_creator_proposal: solana_program::pubkey::Pubkey,
+
+    pub log_wrapper: solana_program::pubkey::Pubkey,
+
+    pub system_program: solana_program::pubkey::Pubkey,
+}
+
+impl AcceptTreeCreator {
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.tree_config,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.new_tree_creator,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.merkle_tree,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.tree_creator_proposal,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.log_wrapper,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let data = borsh::to_vec(&(AcceptTreeCreatorInstructionData::new())).unwrap();
+
+        solana_program::instruction::Instruction {
+            program_id: crate::MPL_BUBBLEGUM_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct AcceptTreeCreatorInstructionData {
+    discriminator: [u8; 8],
+}
+
+impl AcceptTreeCreatorInstructionData {
+    pub fn new() -> Self {
+        Self {
+            discriminator: [106, 65, 242, 169, 157, 90, 154, 67],
+        }
+    }
+}
+
+/// Instruction builder for `AcceptTreeCreator`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` tree_config
+///   1. `[writable, signer]` new_tree_creator
+///   2. `[]` merkle_tree
+///   3. `[writable]` tree_creator_proposal
+///   4. `[]` log_wrapper
+///   5. `[optional]` system_program (default to `11111111111111111111111111111111`)
+#[derive(Default)]
+pub struct AcceptTreeCreatorBuilder {
+    tree_config: Option<solana_program::pubkey::Pubkey>,
+    new_tree_creator: Option<solana_program::pubkey::Pubkey>,
+    merkle_tree: Option<solana_program::pubkey::Pubkey>,
+    tree_creator_proposal: Option<solana_program::pubkey::Pubkey>,
+    log_wrapper: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl AcceptTreeCreatorBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[inline(always)]
+    pub fn tree_config(&mut self, tree_config: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.tree_config = Some(tree_config);
+        self
+    }
+    #[inline(always)]
+    pub fn new_tree_creator(
+        &mut self,
+        new_tree_creator: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.new_tree_creator = Some(new_tree_creator);
+        self
+    }
+    #[inline(always)]
+    pub fn merkle_tree(&mut self, merkle_tree: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.merkle_tree = Some(merkle_tree);
+        self
+    }
+    #[inline(always)]
+    pub fn tree_creator_proposal(
+        &mut self,
+        tree_creator_proposal: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.tree_creator_proposal = Some(tree_creator_proposal);
+        self
+    }
+    #[inline(always)]
+    pub fn log_wrapper(&mut self, log_wrapper: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.log_wrapper = Some(log_wrapper);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = AcceptTreeCreator {
+            tree_config: self.tree_config.expect("tree_config is not set"),
+            new_tree_creator: self.new_tree_creator.expect("new_tree_creator is not set"),
+            merkle_tree: self.merkle_tree.expect("merkle_tree is not set"),
+            tree_creator_proposal: self
+                .tree_creator_proposal
+                .expect("tree_creator_proposal is not set"),
+            log_wrapper: self.log_wrapper.expect("log_wrapper is not set"),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+        };
+
+        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+    }
+}
+
+/// `accept_tree_creator` CPI accounts.
+pub struct AcceptTreeCreatorCpiAccounts<'a, 'b> {
+    pub tree_config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub new_tree_creator: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub merkle_tree: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub tree_creator_proposal: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub log_wrapper: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `accept_tree_creator` CPI instruction.
+pub struct AcceptTreeCreatorCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub tree_config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub new_tree_creator: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub merkle_tree: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub tree_creator_proposal: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub log_wrapper: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+impl<'a, 'b> AcceptTreeCreatorCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: AcceptTreeCreatorCpiAccounts<'a, 'b>,
+    ) -> Self {
+        Self {
+            __program: program,
+            tree_config: accounts.tree_config,
+            new_tree_creator: accounts.new_tree_creator,
+            merkle_tree: accounts.merkle_tree,
+            tree_creator_proposal: accounts.tree_creator_proposal,
+            log_wrapper: accounts.log_wrapper,
+            system_program: accounts.system_program,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.tree_config.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.new_tree_creator.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.merkle_tree.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.tree_creator_proposal.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.log_wrapper.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_writable: remaining_account.1,
+                is_signer: remaining_account.2,
+            })
+        });
+        let data = borsh::to_vec(&(AcceptTreeCreatorInstructionData::new())).unwrap();
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::MPL_BUBBLEGUM_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(6 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.tree_config.clone());
+        account_infos.push(self.new_tree_creator.clone());
+        account_infos.push(self.merkle_tree.clone());
+        account_infos.push(self.tree_creator_proposal.clone());
+        account_infos.push(self.log_wrapper.clone());
+        account_infos.push(self.system_program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `AcceptTreeCreator` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` tree_config
+///   1. `[writable, signer]` new_tree_creator
+///   2. `[]` merkle_tree
+///   3. `[writable]` tree_creator_proposal
+///   4. `[]` log_wrapper
+///   5. `[]` system_program
+pub struct AcceptTreeCreatorCpiBuilder<'a, 'b> {
+    instruction: Box<AcceptTreeCreatorCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> AcceptTreeCreatorCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(AcceptTreeCreatorCpiBuilderInstruction {
+            __program: program,
+            tree_config: None,
+            new_tree_creator: None,
+            merkle_tree: None,
+            tree_creator_proposal: None,
+            log_wrapper: None,
+            system_program: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    #[inline(always)]
+    pub fn tree_config(
+        &mut self,
+        tree_config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.tree_config = Some(tree_config);
+        self
+    }
+    #[inline(always)]
+    pub fn new_tree_creator(
+        &mut self,
+        new_tree_creator: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.new_tree_creator = Some(new_tree_creator);
+        self
+    }
+    #[inline(always)]
+    pub fn merkle_tree(
+        &mut self,
+        merkle_tree: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.merkle_tree = Some(merkle_tree);
+        self
+    }
+    #[inline(always)]
+    pub fn tree_creator_proposal(
+        &mut self,
+        tree_creator_proposal: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.tree_creator_proposal = Some(tree_creator_proposal);
+        self
+    }
+    #[inline(always)]
+    pub fn log_wrapper(
+        &mut self,
+        log_wrapper: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.log_wrapper = Some(log_wrapper);
+        self
+    }
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let instruction = AcceptTreeCreatorCpi {
+            __program: self.instruction.__program,
+
+            tree_config: self
+                .instruction
+                .tree_config
+                .expect("tree_config is not set"),
+
+            new_tree_creator: self
+                .instruction
+                .new_tree_creator
+                .expect("new_tree_creator is not set"),
+
+            merkle_tree: self
+                .instruction
+                .merkle_tree
+                .expect("merkle_tree is not set"),
+
+            tree_creator_proposal: self
+                .instruction
+                .tree_creator_proposal
+                .expect("tree_creator_proposal is not set"),
+
+            log_wrapper: self
+                .instruction
+                .log_wrapper
+                .expect("log_wrapper is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct AcceptTreeCreatorCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    tree_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    new_tree_creator: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    merkle_tree: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    tree_creator_proposal: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    log_wrapper: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -0,0 +1,688 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+use crate::generated::types::LeafMetadataUpdate;
+use crate::generated::types::UpdateArgs;
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct BatchUpdateMetadataV2 {
+    pub tree_config: solana_program::pubkey::Pubkey,
+
+    pub payer: solana_program::pubkey::Pubkey,
+    /// Either collection authority or tree owner/delegate, depending
+    /// on whether the items are in a verified collection.  Defaults to `payer`
+    pub authority: Option<solana_program::pubkey::Pubkey>,
+
+    pub merkle_tree: solana_program::pubkey::Pubkey,
+
+    pub core_collection: Option<solana_program::pubkey::Pubkey>,
+
+    pub log_wrapper: solana_program::pubkey::Pubkey,
+
+    pub compression_program: solana_program::pubkey::Pubkey,
+
+    pub system_program: solana_program::pubkey::Pubkey,
+}
+
+impl BatchUpdateMetadataV2 {
+    pub fn instruction(
+        &self,
+        args: BatchUpdateMetadataV2InstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: BatchUpdateMetadataV2InstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.tree_config,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.payer, true,
+        ));
+        if let Some(authority) = self.authority {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                authority, true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_BUBBLEGUM_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.merkle_tree,
+            false,
+        ));
+        if let Some(core_collection) = self.core_collection {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                core_collection,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_BUBBLEGUM_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.log_wrapper,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.compression_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = borsh::to_vec(&(BatchUpdateMetadataV2InstructionData::new())).unwrap();
+        let mut args = borsh::to_vec(&args).unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::MPL_BUBBLEGUM_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct BatchUpdateMetadataV2InstructionData {
+    discriminator: [u8; 8],
+}
+
+impl BatchUpdateMetadataV2InstructionData {
+    pub fn new() -> Self {
+        Self {
+            discriminator: [216, 104, 210, 61, 210, 190, 0, 132],
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchUpdateMetadataV2InstructionArgs {
+    pub root: [u8; 32],
+    pub leaves: Vec<LeafMetadataUpdate>,
+    pub update_args: UpdateArgs,
+}
+
+/// Instruction builder for `BatchUpdateMetadataV2`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` tree_config
+///   1. `[writable, signer]` payer
+///   2. `[signer, optional]` authority
+///   3. `[writable]` merkle_tree
+///   4. `[optional]` core_collection
+///   5. `[optional]` log_wrapper (default to `mnoopTCrg4p8ry25e4bcWA9XZjbNjMTfgYVGGEdRsf3`)
+///   6. `[optional]` compression_program (default to `mcmt6YrQEMKw8Mw43FmpRLmf7BqRnFMKmAcbxE3xkAW`)
+///   7. `[optional]` system_program (default to `11111111111111111111111111111111`)
+#[derive(Default)]
+pub struct BatchUpdateMetadataV2Builder {
+    tree_config: Option<solana_program::pubkey::Pubkey>,
+    payer: Option<solana_program::pubkey::Pubkey>,
+    authority: Option<solana_program::pubkey::Pubkey>,
+    merkle_tree: Option<solana_program::pubkey::Pubkey>,
+    core_collection: Option<solana_program::pubkey::Pubkey>,
+    log_wrapper: Option<solana_program::pubkey::Pubkey>,
+    compression_program: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    root: Option<[u8; 32]>,
+    leaves: Option<Vec<LeafMetadataUpdate>>,
+    update_args: Option<UpdateArgs>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl BatchUpdateMetadataV2Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[inline(always)]
+    pub fn tree_config(&mut self, tree_config: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.tree_config = Some(tree_config);
+        self
+    }
+    #[inline(always)]
+    pub fn payer(&mut self, payer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    /// `[optional account]`
+    /// Either collection authority or tree owner/delegate, depending
+    /// on whether the items are in a verified collection.  Defaults to `payer`
+    #[inline(always)]
+    pub fn authority(&mut self, authority: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.authority = authority;
+        self
+    }
+    #[inline(always)]
+    pub fn merkle_tree(&mut self, merkle_tree: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.merkle_tree = Some(merkle_tree);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn core_collection(
+        &mut self,
+        core_collection: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.core_collection = core_collection;
+        self
+    }
+    /// `[optional account, default to 'mnoopTCrg4p8ry25e4bcWA9XZjbNjMTfgYVGGEdRsf3']`
+    #[inline(always)]
+    pub fn log_wrapper(&mut self, log_wrapper: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.log_wrapper = Some(log_wrapper);
+        self
+    }
+    /// `[optional account, default to 'mcmt6YrQEMKw8Mw43FmpRLmf7BqRnFMKmAcbxE3xkAW']`
+    #[inline(always)]
+    pub fn compression_program(
+        &mut self,
+        compression_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.compression_program = Some(compression_program);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn root(&mut self, root: [u8; 32]) -> &mut Self {
+        self.root = Some(root);
+        self
+    }
+    #[inline(always)]
+    pub fn leaves(&mut self, leaves: Vec<LeafMetadataUpdate>) -> &mut Self {
+        self.leaves = Some(leaves);
+        self
+    }
+    #[inline(always)]
+    pub fn update_args(&mut self, update_args: UpdateArgs) -> &mut Self {
+        self.update_args = Some(update_args);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = BatchUpdateMetadataV2 {
+            tree_config: self.tree_config.expect("tree_config is not set"),
+            payer: self.payer.expect("payer is not set"),
+            authority: self.authority,
+            merkle_tree: self.merkle_tree.expect("merkle_tree is not set"),
+            core_collection: self.core_collection,
+            log_wrapper: self.log_wrapper.unwrap_or(solana_program::pubkey!(
+                "mnoopTCrg4p8ry25e4bcWA9XZjbNjMTfgYVGGEdRsf3"
+            )),
+            compression_program: self.compression_program.unwrap_or(solana_program::pubkey!(
+                "mcmt6YrQEMKw8Mw43FmpRLmf7BqRnFMKmAcbxE3xkAW"
+            )),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+        };
+        let args = BatchUpdateMetadataV2InstructionArgs {
+            root: self.root.clone().expect("root is not set"),
+            leaves: self.leaves.clone().expect("leaves is not set"),
+            update_args: self.update_args.clone().expect("update_args is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `batch_update_metadata_v2` CPI accounts.
+pub struct BatchUpdateMetadataV2CpiAccounts<'a, 'b> {
+    pub tree_config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Either collection authority or tree owner/delegate, depending
+    /// on whether the items are in a verified collection.  Defaults to `payer`
+    pub authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub merkle_tree: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub core_collection: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub log_wrapper: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub compression_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `batch_update_metadata_v2` CPI instruction.
+pub struct BatchUpdateMetadataV2Cpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub tree_config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Either collection authority or tree owner/delegate, depending
+    /// on whether the items are in a verified collection.  Defaults to `payer`
+    pub authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub merkle_tree: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub core_collection: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    pub log_wrapper: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub compression_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: BatchUpdateMetadataV2InstructionArgs,
+}
+
+impl<'a, 'b> BatchUpdateMetadataV2Cpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: BatchUpdateMetadataV2CpiAccounts<'a, 'b>,
+        args: BatchUpdateMetadataV2InstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            tree_config: accounts.tree_config,
+            payer: accounts.payer,
+            authority: accounts.authority,
+            merkle_tree: accounts.merkle_tree,
+            core_collection: accounts.core_collection,
+            log_wrapper: accounts.log_wrapper,
+            compression_program: accounts.compression_program,
+            system_program: accounts.system_program,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.tree_config.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.payer.key,
+            true,
+        ));
+        if let Some(authority) = self.authority {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *authority.key,
+                true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_BUBBLEGUM_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.merkle_tree.key,
+            false,
+        ));
+        if let Some(core_collection) = self.core_collection {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *core_collection.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_BUBBLEGUM_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.log_wrapper.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.compression_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_writable: remaining_account.1,
+                is_signer: remaining_account.2,
+            })
+        });
+        let mut data = borsh::to_vec(&(BatchUpdateMetadataV2InstructionData::new())).unwrap();
+        let mut args = borsh::to_vec(&self.__args).unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::MPL_BUBBLEGUM_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(8 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.tree_config.clone());
+        account_infos.push(self.payer.clone());
+        if let Some(authority) = self.authority {
+            account_infos.push(authority.clone());
+        }
+        account_infos.push(self.merkle_tree.clone());
+        if let Some(core_collection) = self.core_collection {
+            account_infos.push(core_collection.clone());
+        }
+        account_infos.push(self.log_wrapper.clone());
+        account_infos.push(self.compression_program.clone());
+        account_infos.push(self.system_program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `BatchUpdateMetadataV2` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` tree_config
+///   1. `[writable, signer]` payer
+///   2. `[signer, optional]` authority
+///   3. `[writable]` merkle_tree
+///   4. `[optional]` core_collection
+///   5. `[]` log_wrapper
+///   6. `[]` compression_program
+///   7. `[]` system_program
+pub struct BatchUpdateMetadataV2CpiBuilder<'a, 'b> {
+    instruction: Box<BatchUpdateMetadataV2CpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> BatchUpdateMetadataV2CpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(BatchUpdateMetadataV2CpiBuilderInstruction {
+            __program: program,
+            tree_config: None,
+            payer: None,
+            authority: None,
+            merkle_tree: None,
+            core_collection: None,
+            log_wrapper: None,
+            compression_program: None,
+            system_program: None,
+            root: None,
+            leaves: None,
+            update_args: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    #[inline(always)]
+    pub fn tree_config(
+        &mut self,
+        tree_config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.tree_config = Some(tree_config);
+        self
+    }
+    #[inline(always)]
+    pub fn payer(&mut self, payer: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.payer = Some(payer);
+        self
+    }
+    /// `[optional account]`
+    /// Either collection authority or tree owner/delegate, depending
+    /// on whether the items are in a verified collection.  Defaults to `payer`
+    #[inline(always)]
+    pub fn authority(
+        &mut self,
+        authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.authority = authority;
+        self
+    }
+    #[inline(always)]
+    pub fn merkle_tree(
+        &mut self,
+        merkle_tree: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.merkle_tree = Some(merkle_tree);
+        self
+    }
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn core_collection(
+        &mut self,
+        core_collection: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.core_collection = core_collection;
+        self
+    }
+    #[inline(always)]
+    pub fn log_wrapper(
+        &mut self,
+        log_wrapper: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.log_wrapper = Some(log_wrapper);
+        self
+    }
+    #[inline(always)]
+    pub fn compression_program(
+        &mut self,
+        compression_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.compression_program = Some(compression_program);
+        self
+    }
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn root(&mut self, root: [u8; 32]) -> &mut Self {
+        self.instruction.root = Some(root);
+        self
+    }
+    #[inline(always)]
+    pub fn leaves(&mut self, leaves: Vec<LeafMetadataUpdate>) -> &mut Self {
+        self.instruction.leaves = Some(leaves);
+        self
+    }
+    #[inline(always)]
+    pub fn update_args(&mut self, update_args: UpdateArgs) -> &mut Self {
+        self.instruction.update_args = Some(update_args);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = BatchUpdateMetadataV2InstructionArgs {
+            root: self.instruction.root.clone().expect("root is not set"),
+            leaves: self.instruction.leaves.clone().expect("leaves is not set"),
+            update_args: self
+                .instruction
+                .update_args
+                .clone()
+                .expect("update_args is not set"),
+        };
+        let instruction = BatchUpdateMetadataV2Cpi {
+            __program: self.instruction.__program,
+
+            tree_config: self
+                .instruction
+                .tree_config
+                .expect("tree_config is not set"),
+
+            payer: self.instruction.payer.expect("payer is not set"),
+
+            authority: self.instruction.authority,
+
+            merkle_tree: self
+                .instruction
+                .merkle_tree
+                .expect("merkle_tree is not set"),
+
+            core_collection: self.instruction.core_collection,
+
+            log_wrapper: self
+                .instruction
+                .log_wrapper
+                .expect("log_wrapper is not set"),
+
+            compression_program: self
+                .instruction
+                .compression_program
+                .expect("compression_program is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct BatchUpdateMetadataV2CpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    tree_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    merkle_tree: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    core_collection: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    log_wrapper: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    compression_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    root: Option<[u8; 32]>,
+    leaves: Option<Vec<LeafMetadataUpdate>>,
+    update_args: Option<UpdateArgs>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -16,7 +16,7 @@ pub struct BurnV2 {
 
     pub payer: solana_program::pubkey::Pubkey,
     /// Optional authority, defaults to `payer`.  Must be either
-    /// the leaf owner or collection collection permanent burn delegate.
+    /// the leaf owner or collection or tree permanent burn delegate.
     pub authority: Option<solana_program::pubkey::Pubkey>,
 
     pub leaf_owner: solana_program::pubkey::Pubkey,
@@ -223,7 +223,7 @@ impl BurnV2Builder {
     }
     /// `[optional account]`
     /// Optional authority, defaults to `payer`.  Must be either
-    /// the leaf owner or collection collection permanent burn delegate.
+    /// the leaf owner or collection or tree permanent burn delegate.
     #[inline(always)]
     pub fn authority(&mut self, authority: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
         self.authority = authority;
@@ -396,7 +396,7 @@ pub struct BurnV2CpiAccounts<'a, 'b> {
 
     pub payer: &'b solana_program::account_info::AccountInfo<'a>,
     /// Optional authority, defaults to `payer`.  Must be either
-    /// the leaf owner or collection collection permanent burn delegate.
+    /// the leaf owner or collection or tree permanent burn delegate.
     pub authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 
     pub leaf_owner: &'b solana_program::account_info::AccountInfo<'a>,
@@ -427,7 +427,7 @@ pub struct BurnV2Cpi<'a, 'b> {
 
     pub payer: &'b solana_program::account_info::AccountInfo<'a>,
     /// Optional authority, defaults to `payer`.  Must be either
-    /// the leaf owner or collection collection permanent burn delegate.
+    /// the leaf owner or collection or tree permanent burn delegate.
     pub authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 
     pub leaf_owner: &'b solana_program::account_info::AccountInfo<'a>,
@@ -696,7 +696,7 @@ impl<'a, 'b> BurnV2CpiBuilder<'a, 'b> {
     }
     /// `[optional account]`
     /// Optional authority, defaults to `payer`.  Must be either
-    /// the leaf owner or collection collection permanent burn delegate.
+    /// the leaf owner or collection or tree permanent burn delegate.
     #[inline(always)]
     pub fn authority(
         &mut self,
@@ -13,7 +13,7 @@ use borsh::{BorshDeserialize, BorshSerialize};
 /// Accounts.
 pub struct CloseTreeV2 {
     pub tree_config: solana_program::pubkey::Pubkey,
-    /// Tree creator or delegate.
+    /// Tree creator or delegate, or a scoped delegate with the close role.
     pub authority: solana_program::pubkey::Pubkey,
 
     pub merkle_tree: solana_program::pubkey::Pubkey,
@@ -123,7 +123,7 @@ impl CloseTreeV2Builder {
         self.tree_config = Some(tree_config);
         self
     }
-    /// Tree creator or delegate.
+    /// Tree creator or delegate, or a scoped delegate with the close role.
     #[inline(always)]
     pub fn authority(&mut self, authority: solana_program::pubkey::Pubkey) -> &mut Self {
         self.authority = Some(authority);
@@ -205,7 +205,7 @@ impl CloseTreeV2Builder {
 /// `close_tree_v2` CPI accounts.
 pub struct CloseTreeV2CpiAccounts<'a, 'b> {
     pub tree_config: &'b solana_program::account_info::AccountInfo<'a>,
-    /// Tree creator or delegate.
+    /// Tree creator or delegate, or a scoped delegate with the close role.
     pub authority: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub merkle_tree: &'b solana_program::account_info::AccountInfo<'a>,
@@ -226,7 +226,7 @@ pub struct CloseTreeV2Cpi<'a, 'b> {
     pub __program: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub tree_config: &'b solana_program::account_info::AccountInfo<'a>,
-    /// Tree creator or delegate.
+    /// Tree creator or delegate, or a scoped delegate with the close role.
     pub authority: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub merkle_tree: &'b solana_program::account_info::AccountInfo<'a>,
@@ -392,7 +392,7 @@ impl<'a, 'b> CloseTreeV2CpiBuilder<'a, 'b> {
         self.instruction.tree_config = Some(tree_config);
         self
     }
-    /// Tree creator or delegate.
+    /// Tree creator or delegate, or a scoped delegate with the close role.
     #[inline(always)]
     pub fn authority(
         &mut self,
@@ -15,6 +15,8 @@ pub struct CollectV2 {
     pub tree_config: solana_program::pubkey::Pubkey,
 
     pub destination: solana_program::pubkey::Pubkey,
+
+    pub fee_config: solana_program::pubkey::Pubkey,
 }
 
 impl CollectV2 {
@@ -26,7 +28,7 @@ impl CollectV2 {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(2 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.tree_config,
             false,
@@ -35,6 +37,10 @@ impl CollectV2 {
             self.destination,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.fee_config,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let data = borsh::to_vec(&(CollectV2InstructionData::new())).unwrap();
 
@@ -66,10 +72,12 @@ impl CollectV2InstructionData {
 ///
 ///   0. `[writable]` tree_config
 ///   1. `[writable, optional]` destination (default to `2dgJVPC5fjLTBTmMvKDRig9JJUGK2Fgwr3EHShFxckhv`)
+///   2. `[optional]` fee_config (default to `CZ6RYvLgRnpxwZtAgJ2ozqYxYF4sYmxQikZpMQCZ1ZKE`)
 #[derive(Default)]
 pub struct CollectV2Builder {
     tree_config: Option<solana_program::pubkey::Pubkey>,
     destination: Option<solana_program::pubkey::Pubkey>,
+    fee_config: Option<solana_program::pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -88,6 +96,12 @@ impl CollectV2Builder {
         self.destination = Some(destination);
         self
     }
+    /// `[optional account, default to 'CZ6RYvLgRnpxwZtAgJ2ozqYxYF4sYmxQikZpMQCZ1ZKE']`
+    #[inline(always)]
+    pub fn fee_config(&mut self, fee_config: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.fee_config = Some(fee_config);
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -113,6 +127,9 @@ impl CollectV2Builder {
             destination: self.destination.unwrap_or(solana_program::pubkey!(
                 "2dgJVPC5fjLTBTmMvKDRig9JJUGK2Fgwr3EHShFxckhv"
             )),
+            fee_config: self.fee_config.unwrap_or(solana_program::pubkey!(
+                "CZ6RYvLgRnpxwZtAgJ2ozqYxYF4sYmxQikZpMQCZ1ZKE"
+            )),
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -124,6 +141,8 @@ pub struct CollectV2CpiAccounts<'a, 'b> {
     pub tree_config: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub destination: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub fee_config: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 /// `collect_v2` CPI instruction.
@@ -134,6 +153,8 @@ pub struct CollectV2Cpi<'a, 'b> {
     pub tree_config: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub destination: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub fee_config: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 impl<'a, 'b> CollectV2Cpi<'a, 'b> {
@@ -145,6 +166,7 @@ impl<'a, 'b> CollectV2Cpi<'a, 'b> {
             __program: program,
             tree_config: accounts.tree_config,
             destination: accounts.destination,
+            fee_config: accounts.fee_config,
         }
     }
     #[inline(always)]
@@ -180,7 +202,7 @@ impl<'a, 'b> CollectV2Cpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(2 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.tree_config.key,
             false,
@@ -189,6 +211,10 @@ impl<'a, 'b> CollectV2Cpi<'a, 'b> {
             *self.destination.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.fee_config.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -203,10 +229,11 @@ impl<'a, 'b> CollectV2Cpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(2 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(3 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.tree_config.clone());
         account_infos.push(self.destination.clone());
+        account_infos.push(self.fee_config.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -225,6 +252,7 @@ impl<'a, 'b> CollectV2Cpi<'a, 'b> {
 ///
 ///   0. `[writable]` tree_config
 ///   1. `[writable]` destination
+///   2. `[]` fee_config
 pub struct CollectV2CpiBuilder<'a, 'b> {
     instruction: Box<CollectV2CpiBuilderInstruction<'a, 'b>>,
 }
@@ -235,6 +263,7 @@ impl<'a, 'b> CollectV2CpiBuilder<'a, 'b> {
             __program: program,
             tree_config: None,
             destination: None,
+            fee_config: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -255,6 +284,14 @@ impl<'a, 'b> CollectV2CpiBuilder<'a, 'b> {
         self.instruction.destination = Some(destination);
         self
     }
+    #[inline(always)]
+    pub fn fee_config(
+        &mut self,
+        fee_config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.fee_config = Some(fee_config);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -308,6 +345,8 @@ impl<'a, 'b> CollectV2CpiBuilder<'a, 'b> {
                 .instruction
                 .destination
                 .expect("destination is not set"),
+
+            fee_config: self.instruction.fee_config.expect("fee_config is not set"),
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -320,6 +359,7 @@ struct CollectV2CpiBuilderInstruction<'a, 'b> {
     __program: &'b solana_program::account_info::AccountInfo<'a>,
     tree_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    fee_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
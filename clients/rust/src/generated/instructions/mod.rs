@@ -5,72 +5,136 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+pub(crate) mod r#accept_tree_creator;
+pub(crate) mod r#add_tree_extension;
+pub(crate) mod r#add_tree_to_group;
+pub(crate) mod r#batch_update_metadata_v2;
 pub(crate) mod r#burn;
 pub(crate) mod r#burn_v2;
 pub(crate) mod r#cancel_redeem;
+pub(crate) mod r#close_tree;
+pub(crate) mod r#close_tree_delegate_record;
 pub(crate) mod r#close_tree_v2;
 pub(crate) mod r#collect_v2;
+pub(crate) mod r#create_master_edition_v2;
 pub(crate) mod r#create_tree_config;
 pub(crate) mod r#create_tree_config_v2;
+pub(crate) mod r#create_tree_group;
 pub(crate) mod r#decompress_v1;
 pub(crate) mod r#delegate;
 pub(crate) mod r#delegate_and_freeze_v2;
 pub(crate) mod r#delegate_v2;
 pub(crate) mod r#freeze_v2;
+pub(crate) mod r#merge_v2;
+pub(crate) mod r#mint_fungible_v2;
 pub(crate) mod r#mint_to_collection_v1;
+pub(crate) mod r#mint_to_group_v2;
+pub(crate) mod r#mint_usable_v2;
 pub(crate) mod r#mint_v1;
 pub(crate) mod r#mint_v2;
+pub(crate) mod r#print_edition_v2;
+pub(crate) mod r#recover_non_transferable_v2;
 pub(crate) mod r#redeem;
+pub(crate) mod r#revoke_v2;
 pub(crate) mod r#set_and_verify_collection;
+pub(crate) mod r#set_base_uri_v2;
 pub(crate) mod r#set_collection_v2;
 pub(crate) mod r#set_decompressible_state;
+pub(crate) mod r#set_fee_config;
 pub(crate) mod r#set_non_transferable_v2;
+pub(crate) mod r#set_tree_creator_fee;
 pub(crate) mod r#set_tree_delegate;
+pub(crate) mod r#set_tree_delegate_record;
+pub(crate) mod r#set_tree_fee_exempt;
+pub(crate) mod r#set_tree_paused;
+pub(crate) mod r#set_tree_plugins;
+pub(crate) mod r#split_v2;
+pub(crate) mod r#stake_v2;
 pub(crate) mod r#thaw_and_revoke_v2;
 pub(crate) mod r#thaw_v2;
 pub(crate) mod r#transfer;
 pub(crate) mod r#transfer_v2;
+pub(crate) mod r#unstake_v2;
 pub(crate) mod r#unverify_collection;
 pub(crate) mod r#unverify_creator;
 pub(crate) mod r#unverify_creator_v2;
 pub(crate) mod r#update_asset_data_v2;
+pub(crate) mod r#update_creator_v2;
 pub(crate) mod r#update_metadata;
 pub(crate) mod r#update_metadata_v2;
+pub(crate) mod r#update_tree_config;
+pub(crate) mod r#utilize_v1;
+pub(crate) mod r#utilize_v2;
 pub(crate) mod r#verify_collection;
 pub(crate) mod r#verify_creator;
 pub(crate) mod r#verify_creator_v2;
+pub(crate) mod r#verify_creators_v2;
+pub(crate) mod r#verify_leaf_v2;
+pub(crate) mod r#withdraw_creator_fees;
 
+pub use self::r#accept_tree_creator::*;
+pub use self::r#add_tree_extension::*;
+pub use self::r#add_tree_to_group::*;
+pub use self::r#batch_update_metadata_v2::*;
 pub use self::r#burn::*;
 pub use self::r#burn_v2::*;
 pub use self::r#cancel_redeem::*;
+pub use self::r#close_tree::*;
+pub use self::r#close_tree_delegate_record::*;
 pub use self::r#close_tree_v2::*;
 pub use self::r#collect_v2::*;
+pub use self::r#create_master_edition_v2::*;
 pub use self::r#create_tree_config::*;
 pub use self::r#create_tree_config_v2::*;
+pub use self::r#create_tree_group::*;
 pub use self::r#decompress_v1::*;
 pub use self::r#delegate::*;
 pub use self::r#delegate_and_freeze_v2::*;
 pub use self::r#delegate_v2::*;
 pub use self::r#freeze_v2::*;
+pub use self::r#merge_v2::*;
+pub use self::r#mint_fungible_v2::*;
 pub use self::r#mint_to_collection_v1::*;
+pub use self::r#mint_to_group_v2::*;
+pub use self::r#mint_usable_v2::*;
 pub use self::r#mint_v1::*;
 pub use self::r#mint_v2::*;
+pub use self::r#print_edition_v2::*;
+pub use self::r#recover_non_transferable_v2::*;
 pub use self::r#redeem::*;
+pub use self::r#revoke_v2::*;
 pub use self::r#set_and_verify_collection::*;
+pub use self::r#set_base_uri_v2::*;
 pub use self::r#set_collection_v2::*;
 pub use self::r#set_decompressible_state::*;
+pub use self::r#set_fee_config::*;
 pub use self::r#set_non_transferable_v2::*;
+pub use self::r#set_tree_creator_fee::*;
 pub use self::r#set_tree_delegate::*;
+pub use self::r#set_tree_delegate_record::*;
+pub use self::r#set_tree_fee_exempt::*;
+pub use self::r#set_tree_paused::*;
+pub use self::r#set_tree_plugins::*;
+pub use self::r#split_v2::*;
+pub use self::r#stake_v2::*;
 pub use self::r#thaw_and_revoke_v2::*;
 pub use self::r#thaw_v2::*;
 pub use self::r#transfer::*;
 pub use self::r#transfer_v2::*;
+pub use self::r#unstake_v2::*;
 pub use self::r#unverify_collection::*;
 pub use self::r#unverify_creator::*;
 pub use self::r#unverify_creator_v2::*;
 pub use self::r#update_asset_data_v2::*;
+pub use self::r#update_creator_v2::*;
 pub use self::r#update_metadata::*;
 pub use self::r#update_metadata_v2::*;
+pub use self::r#update_tree_config::*;
+pub use self::r#utilize_v1::*;
+pub use self::r#utilize_v2::*;
 pub use self::r#verify_collection::*;
 pub use self::r#verify_creator::*;
 pub use self::r#verify_creator_v2::*;
+pub use self::r#verify_creators_v2::*;
+pub use self::r#verify_leaf_v2::*;
+pub use self::r#withdraw_creator_fees::*;
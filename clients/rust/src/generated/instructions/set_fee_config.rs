@@ -0,0 +1,538 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+/// Accounts.
+pub struct SetFeeConfig {
+    pub fee_config: solana_program::pubkey::Pubkey,
+
+    pub payer: solana_program::pubkey::Pubkey,
+    /// Upgrade authority of the Bubblegum program.
+    pub authority: solana_program::pubkey::Pubkey,
+
+    pub program_data: solana_program::pubkey::Pubkey,
+
+    pub system_program: solana_program::pubkey::Pubkey,
+}
+
+impl SetFeeConfig {
+    pub fn instruction(
+        &self,
+        args: SetFeeConfigInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: SetFeeConfigInstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.fee_config,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.payer, true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.authority,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.program_data,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = borsh::to_vec(&(SetFeeConfigInstructionData::new())).unwrap();
+        let mut args = borsh::to_vec(&args).unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::MPL_BUBBLEGUM_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct SetFeeConfigInstructionData {
+    discriminator: [u8; 8],
+}
+
+impl SetFeeConfigInstructionData {
+    pub fn new() -> Self {
+        Self {
+            discriminator: [221, 222, 52, 206, 114, 198, 64, 91],
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SetFeeConfigInstructionArgs {
+    pub fee_recipient: Pubkey,
+    pub mint_v2_fee_lamports: u64,
+    pub transfer_v2_fee_lamports: u64,
+}
+
+/// Instruction builder for `SetFeeConfig`.
+///
+/// ### Accounts:
+///
+///   0. `[writable, optional]` fee_config (default to `CZ6RYvLgRnpxwZtAgJ2ozqYxYF4sYmxQikZpMQCZ1ZKE`)
+///   1. `[writable, signer]` payer
+///   2. `[signer]` authority
+///   3. `[optional]` program_data (default to `BuBmqo7ehiQf5svTpw54air9bveqqFZQV9BjX277rqm7`)
+///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
+#[derive(Default)]
+pub struct SetFeeConfigBuilder {
+    fee_config: Option<solana_program::pubkey::Pubkey>,
+    payer: Option<solana_program::pubkey::Pubkey>,
+    authority: Option<solana_program::pubkey::Pubkey>,
+    program_data: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    fee_recipient: Option<Pubkey>,
+    mint_v2_fee_lamports: Option<u64>,
+    transfer_v2_fee_lamports: Option<u64>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl SetFeeConfigBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// `[optional account, default to 'CZ6RYvLgRnpxwZtAgJ2ozqYxYF4sYmxQikZpMQCZ1ZKE']`
+    #[inline(always)]
+    pub fn fee_config(&mut self, fee_config: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.fee_config = Some(fee_config);
+        self
+    }
+    #[inline(always)]
+    pub fn payer(&mut self, payer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    /// Upgrade authority of the Bubblegum program.
+    #[inline(always)]
+    pub fn authority(&mut self, authority: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+    /// `[optional account, default to 'BuBmqo7ehiQf5svTpw54air9bveqqFZQV9BjX277rqm7']`
+    #[inline(always)]
+    pub fn program_data(&mut self, program_data: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.program_data = Some(program_data);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn fee_recipient(&mut self, fee_recipient: Pubkey) -> &mut Self {
+        self.fee_recipient = Some(fee_recipient);
+        self
+    }
+    #[inline(always)]
+    pub fn mint_v2_fee_lamports(&mut self, mint_v2_fee_lamports: u64) -> &mut Self {
+        self.mint_v2_fee_lamports = Some(mint_v2_fee_lamports);
+        self
+    }
+    #[inline(always)]
+    pub fn transfer_v2_fee_lamports(&mut self, transfer_v2_fee_lamports: u64) -> &mut Self {
+        self.transfer_v2_fee_lamports = Some(transfer_v2_fee_lamports);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = SetFeeConfig {
+            fee_config: self.fee_config.unwrap_or(solana_program::pubkey!(
+                "CZ6RYvLgRnpxwZtAgJ2ozqYxYF4sYmxQikZpMQCZ1ZKE"
+            )),
+            payer: self.payer.expect("payer is not set"),
+            authority: self.authority.expect("authority is not set"),
+            program_data: self.program_data.unwrap_or(solana_program::pubkey!(
+                "BuBmqo7ehiQf5svTpw54air9bveqqFZQV9BjX277rqm7"
+            )),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+        };
+        let args = SetFeeConfigInstructionArgs {
+            fee_recipient: self
+                .fee_recipient
+                .clone()
+                .expect("fee_recipient is not set"),
+            mint_v2_fee_lamports: self
+                .mint_v2_fee_lamports
+                .clone()
+                .expect("mint_v2_fee_lamports is not set"),
+            transfer_v2_fee_lamports: self
+                .transfer_v2_fee_lamports
+                .clone()
+                .expect("transfer_v2_fee_lamports is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `set_fee_config` CPI accounts.
+pub struct SetFeeConfigCpiAccounts<'a, 'b> {
+    pub fee_config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Upgrade authority of the Bubblegum program.
+    pub authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub program_data: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `set_fee_config` CPI instruction.
+pub struct SetFeeConfigCpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub fee_config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Upgrade authority of the Bubblegum program.
+    pub authority: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub program_data: &'b solana_program::account_info::AccountInfo<'a>,
+
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: SetFeeConfigInstructionArgs,
+}
+
+impl<'a, 'b> SetFeeConfigCpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: SetFeeConfigCpiAccounts<'a, 'b>,
+        args: SetFeeConfigInstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            fee_config: accounts.fee_config,
+            payer: accounts.payer,
+            authority: accounts.authority,
+            program_data: accounts.program_data,
+            system_program: accounts.system_program,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.fee_config.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.payer.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.authority.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.program_data.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_writable: remaining_account.1,
+                is_signer: remaining_account.2,
+            })
+        });
+        let mut data = borsh::to_vec(&(SetFeeConfigInstructionData::new())).unwrap();
+        let mut args = borsh::to_vec(&self.__args).unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::MPL_BUBBLEGUM_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(5 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.fee_config.clone());
+        account_infos.push(self.payer.clone());
+        account_infos.push(self.authority.clone());
+        account_infos.push(self.program_data.clone());
+        account_infos.push(self.system_program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `SetFeeConfig` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` fee_config
+///   1. `[writable, signer]` payer
+///   2. `[signer]` authority
+///   3. `[]` program_data
+///   4. `[]` system_program
+pub struct SetFeeConfigCpiBuilder<'a, 'b> {
+    instruction: Box<SetFeeConfigCpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> SetFeeConfigCpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(SetFeeConfigCpiBuilderInstruction {
+            __program: program,
+            fee_config: None,
+            payer: None,
+            authority: None,
+            program_data: None,
+            system_program: None,
+            fee_recipient: None,
+            mint_v2_fee_lamports: None,
+            transfer_v2_fee_lamports: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    #[inline(always)]
+    pub fn fee_config(
+        &mut self,
+        fee_config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.fee_config = Some(fee_config);
+        self
+    }
+    #[inline(always)]
+    pub fn payer(&mut self, payer: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.payer = Some(payer);
+        self
+    }
+    /// Upgrade authority of the Bubblegum program.
+    #[inline(always)]
+    pub fn authority(
+        &mut self,
+        authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.authority = Some(authority);
+        self
+    }
+    #[inline(always)]
+    pub fn program_data(
+        &mut self,
+        program_data: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.program_data = Some(program_data);
+        self
+    }
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn fee_recipient(&mut self, fee_recipient: Pubkey) -> &mut Self {
+        self.instruction.fee_recipient = Some(fee_recipient);
+        self
+    }
+    #[inline(always)]
+    pub fn mint_v2_fee_lamports(&mut self, mint_v2_fee_lamports: u64) -> &mut Self {
+        self.instruction.mint_v2_fee_lamports = Some(mint_v2_fee_lamports);
+        self
+    }
+    #[inline(always)]
+    pub fn transfer_v2_fee_lamports(&mut self, transfer_v2_fee_lamports: u64) -> &mut Self {
+        self.instruction.transfer_v2_fee_lamports = Some(transfer_v2_fee_lamports);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = SetFeeConfigInstructionArgs {
+            fee_recipient: self
+                .instruction
+                .fee_recipient
+                .clone()
+                .expect("fee_recipient is not set"),
+            mint_v2_fee_lamports: self
+                .instruction
+                .mint_v2_fee_lamports
+                .clone()
+                .expect("mint_v2_fee_lamports is not set"),
+            transfer_v2_fee_lamports: self
+                .instruction
+                .transfer_v2_fee_lamports
+                .clone()
+                .expect("transfer_v2_fee_lamports is not set"),
+        };
+        let instruction = SetFeeConfigCpi {
+            __program: self.instruction.__program,
+
+            fee_config: self.instruction.fee_config.expect("fee_config is not set"),
+
+            payer: self.instruction.payer.expect("payer is not set"),
+
+            authority: self.instruction.authority.expect("authority is not set"),
+
+            program_data: self
+                .instruction
+                .program_data
+                .expect("program_data is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct SetFeeConfigCpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    fee_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    program_data: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    fee_recipient: Option<Pubkey>,
+    mint_v2_fee_lamports: Option<u64>,
+    transfer_v2_fee_lamports: Option<u64>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -16,7 +16,7 @@ pub struct ThawV2 {
 
     pub payer: solana_program::pubkey::Pubkey,
     /// Optional authority, defaults to `payer`.  Must be either
-    /// the leaf delegate or collection permanent freeze delegate.
+    /// the leaf delegate or collection or tree permanent freeze delegate.
     pub authority: Option<solana_program::pubkey::Pubkey>,
 
     pub leaf_owner: solana_program::pubkey::Pubkey,
@@ -193,7 +193,7 @@ impl ThawV2Builder {
     }
     /// `[optional account]`
     /// Optional authority, defaults to `payer`.  Must be either
-    /// the leaf delegate or collection permanent freeze delegate.
+    /// the leaf delegate or collection or tree permanent freeze delegate.
     #[inline(always)]
     pub fn authority(&mut self, authority: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
         self.authority = authority;
@@ -339,7 +339,7 @@ pub struct ThawV2CpiAccounts<'a, 'b> {
 
     pub payer: &'b solana_program::account_info::AccountInfo<'a>,
     /// Optional authority, defaults to `payer`.  Must be either
-    /// the leaf delegate or collection permanent freeze delegate.
+    /// the leaf delegate or collection or tree permanent freeze delegate.
     pub authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 
     pub leaf_owner: &'b solana_program::account_info::AccountInfo<'a>,
@@ -366,7 +366,7 @@ pub struct ThawV2Cpi<'a, 'b> {
 
     pub payer: &'b solana_program::account_info::AccountInfo<'a>,
     /// Optional authority, defaults to `payer`.  Must be either
-    /// the leaf delegate or collection permanent freeze delegate.
+    /// the leaf delegate or collection or tree permanent freeze delegate.
     pub authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 
     pub leaf_owner: &'b solana_program::account_info::AccountInfo<'a>,
@@ -597,7 +597,7 @@ impl<'a, 'b> ThawV2CpiBuilder<'a, 'b> {
     }
     /// `[optional account]`
     /// Optional authority, defaults to `payer`.  Must be either
-    /// the leaf delegate or collection permanent freeze delegate.
+    /// the leaf delegate or collection or tree permanent freeze delegate.
     #[inline(always)]
     pub fn authority(
         &mut self,
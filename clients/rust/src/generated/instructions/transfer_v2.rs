@@ -16,7 +16,7 @@ pub struct TransferV2 {
 
     pub payer: solana_program::pubkey::Pubkey,
     /// Optional authority, defaults to `payer`.  Must be either
-    /// the leaf owner or collection permanent transfer delegate.
+    /// the leaf owner or collection or tree permanent transfer delegate.
     pub authority: Option<solana_program::pubkey::Pubkey>,
 
     pub leaf_owner: solana_program::pubkey::Pubkey,
@@ -208,7 +208,7 @@ impl TransferV2Builder {
     }
     /// `[optional account]`
     /// Optional authority, defaults to `payer`.  Must be either
-    /// the leaf owner or collection permanent transfer delegate.
+    /// the leaf owner or collection or tree permanent transfer delegate.
     #[inline(always)]
     pub fn authority(&mut self, authority: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
         self.authority = authority;
@@ -365,7 +365,7 @@ pub struct TransferV2CpiAccounts<'a, 'b> {
 
     pub payer: &'b solana_program::account_info::AccountInfo<'a>,
     /// Optional authority, defaults to `payer`.  Must be either
-    /// the leaf owner or collection permanent transfer delegate.
+    /// the leaf owner or collection or tree permanent transfer delegate.
     pub authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 
     pub leaf_owner: &'b solana_program::account_info::AccountInfo<'a>,
@@ -394,7 +394,7 @@ pub struct TransferV2Cpi<'a, 'b> {
 
     pub payer: &'b solana_program::account_info::AccountInfo<'a>,
     /// Optional authority, defaults to `payer`.  Must be either
-    /// the leaf owner or collection permanent transfer delegate.
+    /// the leaf owner or collection or tree permanent transfer delegate.
     pub authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 
     pub leaf_owner: &'b solana_program::account_info::AccountInfo<'a>,
@@ -644,7 +644,7 @@ impl<'a, 'b> TransferV2CpiBuilder<'a, 'b> {
     }
     /// `[optional account]`
     /// Optional authority, defaults to `payer`.  Must be either
-    /// the leaf owner or collection permanent transfer delegate.
+    /// the leaf owner or collection or tree permanent transfer delegate.
     #[inline(always)]
     pub fn authority(
         &mut self,
@@ -18,4 +18,7 @@ use num_derive::FromPrimitive;
 pub enum BubblegumEventType {
     Uninitialized,
     LeafSchemaEvent,
+    TreeConfigEvent,
+    PrintEditionEvent,
+    AssetEvent,
 }
@@ -22,7 +22,7 @@ pub struct MetadataArgsV2 {
     pub name: String,
     /// The symbol for the asset
     pub symbol: String,
-    /// URI pointing to JSON representing the asset
+    /// URI pointing to JSON representing the asset, or its suffix for trees with a base URI
     pub uri: String,
     /// Royalty basis points that goes to creators in secondary sales (0-10000)
     pub seller_fee_basis_points: u16,
@@ -30,7 +30,7 @@ pub struct MetadataArgsV2 {
     pub primary_sale_happened: bool,
     /// Whether or not the data struct is mutable, default is not
     pub is_mutable: bool,
-    /// Token standard.  Currently only `NonFungible` is allowed.
+    /// Token standard.  Only `NonFungible` is allowed, or `FungibleAsset` with `mint_fungible_v2`.
     pub token_standard: Option<TokenStandard>,
     /// Creator array
     pub creators: Vec<Creator>,
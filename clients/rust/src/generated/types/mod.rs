@@ -6,31 +6,49 @@
 //!
 
 pub(crate) mod r#asset_data_schema;
+pub(crate) mod r#asset_update;
 pub(crate) mod r#bubblegum_event_type;
 pub(crate) mod r#collection;
 pub(crate) mod r#creator;
 pub(crate) mod r#decompressible_state;
+pub(crate) mod r#leaf_metadata_update;
 pub(crate) mod r#leaf_schema;
+pub(crate) mod r#leaf_update;
 pub(crate) mod r#metadata_args;
 pub(crate) mod r#metadata_args_v2;
 pub(crate) mod r#token_program_version;
 pub(crate) mod r#token_standard;
+pub(crate) mod r#tree_config_update;
+pub(crate) mod r#tree_counters;
+pub(crate) mod r#tree_extension_type;
+pub(crate) mod r#tree_rule_set;
+pub(crate) mod r#tree_settings;
 pub(crate) mod r#update_args;
+pub(crate) mod r#uri_template;
 pub(crate) mod r#use_method;
 pub(crate) mod r#uses;
 pub(crate) mod r#version;
 
 pub use self::r#asset_data_schema::*;
+pub use self::r#asset_update::*;
 pub use self::r#bubblegum_event_type::*;
 pub use self::r#collection::*;
 pub use self::r#creator::*;
 pub use self::r#decompressible_state::*;
+pub use self::r#leaf_metadata_update::*;
 pub use self::r#leaf_schema::*;
+pub use self::r#leaf_update::*;
 pub use self::r#metadata_args::*;
 pub use self::r#metadata_args_v2::*;
 pub use self::r#token_program_version::*;
 pub use self::r#token_standard::*;
+pub use self::r#tree_config_update::*;
+pub use self::r#tree_counters::*;
+pub use self::r#tree_extension_type::*;
+pub use self::r#tree_rule_set::*;
+pub use self::r#tree_settings::*;
 pub use self::r#update_args::*;
+pub use self::r#uri_template::*;
 pub use self::r#use_method::*;
 pub use self::r#uses::*;
 pub use self::r#version::*;
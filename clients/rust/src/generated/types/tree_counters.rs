@@ -0,0 +1,28 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TreeCounters {
+    pub num_burned: u64,
+    /// Leaves redeemed for a voucher that was neither cancelled nor decompressed yet.  Always zero
+    /// until V2 trees support redeeming.
+    pub num_redeemed: u64,
+    /// Always zero until V2 trees support decompression.
+    pub num_decompressed: u64,
+    /// Leaves currently frozen, at the asset level or the permanent level.
+    pub num_frozen: u64,
+    /// Leaves currently non-transferable.
+    pub num_non_transferable: u64,
+}
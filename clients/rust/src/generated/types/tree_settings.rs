@@ -0,0 +1,27 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TreeSettings {
+    /// Blocks minting, transferring, delegating and burning while set.
+    pub is_paused: bool,
+    /// Whether a `TreePlugins` account applies to uncollected assets in the tree.
+    pub has_plugins: bool,
+    /// Exempts the tree from the protocol fees in `FeeConfig`.
+    pub is_fee_exempt: bool,
+    /// Whether mints into a public tree by anyone other than the tree creator or delegates pay the
+    /// creator fee in `TreeCreatorFees`.
+    pub has_creator_fee: bool,
+}
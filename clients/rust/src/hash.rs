@@ -2,7 +2,10 @@ use borsh::to_vec;
 use solana_program::{keccak, pubkey::Pubkey};
 use std::io::Result;
 
-use crate::{traits::MetadataArgsCommon, types::Creator};
+use crate::{
+    traits::MetadataArgsCommon,
+    types::{Creator, Uses},
+};
 
 /// Computes the hash of the creators.
 ///
@@ -63,3 +66,27 @@ pub const DEFAULT_ASSET_DATA_HASH: [u8; 32] = [
     197, 210, 70, 1, 134, 247, 35, 60, 146, 126, 125, 178, 220, 199, 3, 192, 229, 0, 182, 83, 202,
     130, 39, 59, 123, 250, 216, 4, 93, 133, 164, 112,
 ];
+
+/// Computes the asset data hash of a print of a master edition.
+///
+/// The hash is computed as the keccak256 hash of the `edition` prefix, the master edition address
+/// and the little-endian edition number.
+pub fn hash_edition(master_edition: &Pubkey, edition: u64) -> [u8; 32] {
+    keccak::hashv(&[b"edition", master_edition.as_ref(), &edition.to_le_bytes()]).to_bytes()
+}
+
+/// Computes the asset data hash of a fungible asset leaf.
+///
+/// The hash is computed as the keccak256 hash of the `amount` prefix, the class id and the
+/// little-endian amount held by the leaf.  The class id is the asset id of the leaf minted with
+/// `mint_fungible_v2`, shared by every leaf split off from it.
+pub fn hash_amount(class_id: &Pubkey, amount: u64) -> [u8; 32] {
+    keccak::hashv(&[b"amount", class_id.as_ref(), &amount.to_le_bytes()]).to_bytes()
+}
+
+/// Computes the asset data hash of a usable asset leaf minted with `mint_usable_v2`.
+///
+/// The hash is computed as the keccak256 hash of the `uses` prefix and the uses bytes.
+pub fn hash_uses(uses: &Uses) -> Result<[u8; 32]> {
+    Ok(keccak::hashv(&[b"uses", to_vec(uses)?.as_slice()]).to_bytes())
+}
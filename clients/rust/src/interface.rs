@@ -0,0 +1,97 @@
+//! Helpers for programs that CPI into Bubblegum.
+//!
+//! This module only builds on `solana-program`, so it can be used by integrating programs
+//! regardless of their framework. It complements the generated `*CpiBuilder` types with PDA
+//! helpers, proof accounts and decoding of the data returned by Bubblegum.
+
+use solana_program::{
+    account_info::AccountInfo, instruction::AccountMeta, program::get_return_data, pubkey::Pubkey,
+};
+
+use crate::{
+    accounts::StakeRecord,
+    instructions::{
+        BurnV2CpiBuilder, DelegateAndFreezeV2CpiBuilder, DelegateV2CpiBuilder, FreezeV2CpiBuilder,
+        RecoverNonTransferableV2CpiBuilder, RevokeV2CpiBuilder, SetCollectionV2CpiBuilder,
+        SetNonTransferableV2CpiBuilder, StakeV2CpiBuilder, ThawAndRevokeV2CpiBuilder,
+        ThawV2CpiBuilder, TransferV2CpiBuilder, UnstakeV2CpiBuilder, UnverifyCreatorV2CpiBuilder,
+        UpdateAssetDataV2CpiBuilder, UpdateMetadataV2CpiBuilder, VerifyCreatorV2CpiBuilder,
+        VerifyLeafV2CpiBuilder,
+    },
+    types::LeafUpdate,
+};
+
+/// Seed prefix of the PDA that signs mpl-core CPIs on behalf of Bubblegum.
+pub const MPL_CORE_CPI_SIGNER_PREFIX: &[u8] = b"mpl_core_cpi_signer";
+
+/// Finds the PDA that Bubblegum uses to sign mpl-core CPIs, such as collection updates.
+pub fn find_mpl_core_cpi_signer_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[MPL_CORE_CPI_SIGNER_PREFIX], &crate::ID)
+}
+
+/// Finds the stake record PDA of the leaf with the given nonce, which is its leaf delegate while
+/// staked.
+pub fn find_stake_record_pda(merkle_tree: &Pubkey, nonce: u64) -> (Pubkey, u8) {
+    StakeRecord::find_pda(merkle_tree, nonce)
+}
+
+/// Converts proof nodes into the read-only remaining accounts expected by Bubblegum.
+pub fn proof_account_metas(proof: &[[u8; 32]]) -> Vec<AccountMeta> {
+    proof
+        .iter()
+        .map(|node| AccountMeta::new_readonly(Pubkey::new_from_array(*node), false))
+        .collect()
+}
+
+/// Reads the `LeafUpdate` set as return data by the last Bubblegum CPI, if any.
+pub fn get_leaf_update() -> Option<LeafUpdate> {
+    let (program_id, data) = get_return_data()?;
+
+    if program_id != crate::ID {
+        return None;
+    }
+
+    LeafUpdate::from_return_data(&data).ok()
+}
+
+/// Adds the proof of a leaf to a CPI builder, as read-only remaining accounts.
+pub trait WithProof<'a, 'b> {
+    fn add_proof(&mut self, proof: &'b [AccountInfo<'a>]) -> &mut Self;
+}
+
+macro_rules! impl_with_proof {
+    ($($builder:ident),* $(,)?) => {
+        $(
+            impl<'a, 'b> WithProof<'a, 'b> for $builder<'a, 'b> {
+                fn add_proof(&mut self, proof: &'b [AccountInfo<'a>]) -> &mut Self {
+                    let accounts = proof
+                        .iter()
+                        .map(|account| (account, false, false))
+                        .collect::<Vec<_>>();
+                    self.add_remaining_accounts(&accounts)
+                }
+            }
+        )*
+    };
+}
+
+impl_with_proof!(
+    BurnV2CpiBuilder,
+    DelegateAndFreezeV2CpiBuilder,
+    DelegateV2CpiBuilder,
+    FreezeV2CpiBuilder,
+    RecoverNonTransferableV2CpiBuilder,
+    RevokeV2CpiBuilder,
+    SetCollectionV2CpiBuilder,
+    SetNonTransferableV2CpiBuilder,
+    StakeV2CpiBuilder,
+    ThawAndRevokeV2CpiBuilder,
+    ThawV2CpiBuilder,
+    TransferV2CpiBuilder,
+    UnstakeV2CpiBuilder,
+    UnverifyCreatorV2CpiBuilder,
+    UpdateAssetDataV2CpiBuilder,
+    UpdateMetadataV2CpiBuilder,
+    VerifyCreatorV2CpiBuilder,
+    VerifyLeafV2CpiBuilder,
+);
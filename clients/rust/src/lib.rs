@@ -1,14 +1,17 @@
 use borsh::{BorshDeserialize, BorshSerialize};
-use modular_bitfield::{bitfield, specifiers::B5};
-use types::{BubblegumEventType, LeafSchema, Version};
+use modular_bitfield::{bitfield, specifiers::B4};
+use solana_program::pubkey::Pubkey;
+use types::{AssetUpdate, BubblegumEventType, LeafSchema, TreeConfigUpdate, Version};
 
 mod generated;
 pub mod hash;
+pub mod interface;
 mod traits;
 pub mod utils;
 
 pub use generated::programs::MPL_BUBBLEGUM_ID as ID;
 pub use generated::*;
+pub use traits::{TreeExtension, TREE_EXTENSION_HEADER_SIZE, URI_TEMPLATE_INDEX_PLACEHOLDER};
 
 pub enum InstructionName {
     Unknown,
@@ -114,6 +117,101 @@ impl LeafSchemaEvent {
     }
 }
 
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct TreeConfigEvent {
+    pub event_type: BubblegumEventType,
+    pub version: Version,
+    pub merkle_tree: Pubkey,
+    pub update: TreeConfigUpdate,
+}
+
+impl TreeConfigEvent {
+    pub fn new(version: Version, merkle_tree: Pubkey, update: TreeConfigUpdate) -> Self {
+        Self {
+            event_type: BubblegumEventType::TreeConfigEvent,
+            version,
+            merkle_tree,
+            update,
+        }
+    }
+}
+
+/// Emitted by `print_edition_v2` after the leaf schema event of the print.
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct PrintEditionEvent {
+    pub event_type: BubblegumEventType,
+    pub version: Version,
+    pub master_edition: Pubkey,
+    pub asset_id: Pubkey,
+    pub edition: u64,
+}
+
+impl PrintEditionEvent {
+    pub fn new(version: Version, master_edition: Pubkey, asset_id: Pubkey, edition: u64) -> Self {
+        Self {
+            event_type: BubblegumEventType::PrintEditionEvent,
+            version,
+            master_edition,
+            asset_id,
+            edition,
+        }
+    }
+}
+
+/// Emitted by V2 instructions after the leaf schema event, describing what happened to the asset.
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct AssetEvent {
+    pub event_type: BubblegumEventType,
+    pub version: Version,
+    pub asset_id: Pubkey,
+    pub update: AssetUpdate,
+}
+
+impl AssetEvent {
+    pub fn new(version: Version, asset_id: Pubkey, update: AssetUpdate) -> Self {
+        Self {
+            event_type: BubblegumEventType::AssetEvent,
+            version,
+            asset_id,
+            update,
+        }
+    }
+}
+
+/// Any event logged by Bubblegum as application data through the noop program.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum BubblegumEvent {
+    LeafSchema(LeafSchemaEvent),
+    TreeConfig(TreeConfigEvent),
+    PrintEdition(PrintEditionEvent),
+    Asset(AssetEvent),
+}
+
+impl BubblegumEvent {
+    /// Decodes the application data of an event, using its leading event type.
+    pub fn decode(data: &[u8]) -> Result<Self, std::io::Error> {
+        let event_type = BubblegumEventType::deserialize(&mut &data[..])?;
+        let data = &mut &data[..];
+
+        match event_type {
+            BubblegumEventType::Uninitialized => Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "Uninitialized event",
+            )),
+            BubblegumEventType::LeafSchemaEvent => {
+                LeafSchemaEvent::deserialize(data).map(Self::LeafSchema)
+            }
+            BubblegumEventType::TreeConfigEvent => {
+                TreeConfigEvent::deserialize(data).map(Self::TreeConfig)
+            }
+            BubblegumEventType::PrintEditionEvent => {
+                PrintEditionEvent::deserialize(data).map(Self::PrintEdition)
+            }
+            BubblegumEventType::AssetEvent => AssetEvent::deserialize(data).map(Self::Asset),
+        }
+    }
+}
+
 /// Bitfield representation of asset flags.
 #[bitfield(bits = 8)]
 #[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
@@ -124,9 +222,23 @@ pub struct Flags {
     pub permanent_lvl_frozen: bool,
     /// Set to permanently non-transferable (soulbound).
     pub non_transferable: bool,
+    /// Permanently revoked by the collection or tree authority, without burning the asset.
+    pub revoked: bool,
     /// Unused flags for future asset-level usage.
-    pub empty_bits: B5,
+    pub empty_bits: B4,
 }
 
 /// Default flags for `LeafSchemaV2`.
 pub const DEFAULT_FLAGS: u8 = 0;
+
+/// Roles that can be granted to a scoped tree delegate, combined into the
+/// `TreeDelegateRecord::roles` bitmask.
+#[repr(u8)]
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub enum TreeDelegateRole {
+    Mint = 1 << 0,
+    UpdateMetadata = 1 << 1,
+    UpdateAssetData = 1 << 2,
+    Close = 1 << 3,
+    Revoke = 1 << 4,
+}
@@ -2,9 +2,14 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{keccak, pubkey::Pubkey};
 
-use crate::types::{
-    Collection, Creator, LeafSchema, MetadataArgs, MetadataArgsV2, TokenProgramVersion,
-    TokenStandard, UpdateArgs, Version,
+use crate::{
+    accounts::{FeeConfig, StakeRecord, TreeConfig},
+    types::{
+        Collection, Creator, LeafSchema, LeafUpdate, MetadataArgs, MetadataArgsV2,
+        TokenProgramVersion, TokenStandard, TreeCounters, TreeExtensionType, TreeSettings,
+        UpdateArgs, UriTemplate, Version,
+    },
+    Flags,
 };
 
 // LeafSchema
@@ -128,6 +133,12 @@ impl LeafSchema {
             LeafSchema::V2 { flags, .. } => *flags,
         }
     }
+
+    /// Whether the asset was revoked with `revoke_v2`, which blocks its transfer, delegation and
+    /// thaw.  V1 leaves cannot be revoked.
+    pub fn is_revoked(&self) -> bool {
+        Flags::from_bytes([self.flags()]).revoked()
+    }
 }
 
 // TODO where is this used
@@ -162,6 +173,22 @@ impl Default for Version {
     }
 }
 
+// LeafUpdate
+
+impl LeafUpdate {
+    /// Decodes the return data of an instruction that replaces a leaf, as found in the
+    /// transaction metadata.
+    ///
+    /// The runtime strips trailing zero bytes from return data, so these are restored before
+    /// deserializing.
+    pub fn from_return_data(data: &[u8]) -> Result<Self, std::io::Error> {
+        let mut padded = data.to_vec();
+        padded.resize(data.len() + std::mem::size_of::<Self>(), 0);
+
+        Self::deserialize(&mut padded.as_slice())
+    }
+}
+
 /// MetadataArgs
 /// Differences:
 /// `edition_nonce` not present in V2, default to `None`
@@ -206,6 +233,150 @@ impl Default for UpdateArgs {
     }
 }
 
+// FeeConfig
+
+/// Fees charged when the `FeeConfig` account is not passed to an instruction.
+impl Default for FeeConfig {
+    fn default() -> Self {
+        Self {
+            discriminator: [143, 52, 146, 187, 219, 123, 76, 155],
+            fee_recipient: solana_program::pubkey!("2dgJVPC5fjLTBTmMvKDRig9JJUGK2Fgwr3EHShFxckhv"),
+            mint_v2_fee_lamports: 90000,
+            transfer_v2_fee_lamports: 6000,
+        }
+    }
+}
+
+impl FeeConfig {
+    /// Fee charged by `mint_v2` and `mint_to_group_v2` on the given tree.
+    pub fn mint_v2_fee(&self, settings: &TreeSettings) -> u64 {
+        if settings.is_fee_exempt {
+            0
+        } else {
+            self.mint_v2_fee_lamports
+        }
+    }
+
+    /// Fee charged by `transfer_v2` on the given tree.
+    pub fn transfer_v2_fee(&self, settings: &TreeSettings) -> u64 {
+        if settings.is_fee_exempt {
+            0
+        } else {
+            self.transfer_v2_fee_lamports
+        }
+    }
+}
+
+// StakeRecord
+
+impl StakeRecord {
+    /// Prefix of the stake record PDA, followed by the merkle tree and the little-endian nonce.
+    pub const PREFIX: &'static [u8] = b"stake_record";
+
+    pub fn find_pda(merkle_tree: &Pubkey, nonce: u64) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[Self::PREFIX, merkle_tree.as_ref(), &nonce.to_le_bytes()],
+            &crate::ID,
+        )
+    }
+
+    /// Seconds the asset has been staked for at the given unix timestamp.
+    pub fn staked_duration(&self, unix_timestamp: i64) -> i64 {
+        unix_timestamp.saturating_sub(self.staked_at).max(0)
+    }
+}
+
+// TreeConfig extensions
+
+/// Size of the type and length header in front of each tree config extension.
+pub const TREE_EXTENSION_HEADER_SIZE: usize = 2 + 2;
+
+/// Value stored in a tree config extension.
+pub trait TreeExtension: BorshDeserialize {
+    const TYPE: TreeExtensionType;
+    const LEN: usize;
+}
+
+impl TreeExtension for TreeCounters {
+    const TYPE: TreeExtensionType = TreeExtensionType::Counters;
+    const LEN: usize = 8 + 8 + 8 + 8 + 8;
+}
+
+impl TreeExtension for TreeSettings {
+    const TYPE: TreeExtensionType = TreeExtensionType::Settings;
+    const LEN: usize = 1 + 1 + 1 + 1;
+}
+
+impl TreeExtension for UriTemplate {
+    const TYPE: TreeExtensionType = TreeExtensionType::UriTemplate;
+    // Borsh string of up to `MAX_URI_LENGTH` bytes and the frozen flag.
+    const LEN: usize = 4 + 200 + 1;
+}
+
+impl TreeConfig {
+    /// Reads an extension from the data of a tree config account, or `None` if the tree does
+    /// not have it.
+    pub fn extension<T: TreeExtension>(data: &[u8]) -> Result<Option<T>, std::io::Error> {
+        let mut offset = TreeConfig::LEN;
+        while offset + TREE_EXTENSION_HEADER_SIZE <= data.len() {
+            let extension_type = u16::from_le_bytes([data[offset], data[offset + 1]]);
+            if extension_type == TreeExtensionType::Uninitialized as u16 {
+                break;
+            }
+
+            let len = u16::from_le_bytes([data[offset + 2], data[offset + 3]]) as usize;
+            let value = data
+                .get(offset + TREE_EXTENSION_HEADER_SIZE..offset + TREE_EXTENSION_HEADER_SIZE + len)
+                .ok_or_else(|| {
+                    std::io::Error::new(std::io::ErrorKind::InvalidData, "Invalid tree extension")
+                })?;
+
+            if extension_type == T::TYPE as u16 {
+                return T::deserialize(&mut &value[..]).map(Some);
+            }
+
+            offset += TREE_EXTENSION_HEADER_SIZE + len;
+        }
+
+        Ok(None)
+    }
+}
+
+impl TreeCounters {
+    /// Number of minted leaves that are still in the tree.
+    pub fn num_live(&self, num_minted: u64) -> u64 {
+        num_minted
+            .saturating_sub(self.num_burned)
+            .saturating_sub(self.num_redeemed)
+            .saturating_sub(self.num_decompressed)
+    }
+}
+
+/// Placeholder of a `UriTemplate` base URI replaced by the leaf nonce.
+pub const URI_TEMPLATE_INDEX_PLACEHOLDER: &str = "{index}";
+
+impl UriTemplate {
+    /// Full URI of the leaf with the given nonce, storing `uri` in its metadata.
+    pub fn resolve(&self, uri: &str, nonce: u64) -> String {
+        let mut resolved = self
+            .base_uri
+            .replace(URI_TEMPLATE_INDEX_PLACEHOLDER, &nonce.to_string());
+        resolved.push_str(uri);
+        resolved
+    }
+}
+
+impl MetadataArgsV2 {
+    /// Full URI of the leaf with the given nonce, resolved against the base URI of its tree if
+    /// the tree has a `UriTemplate` extension.
+    pub fn resolved_uri(&self, uri_template: Option<&UriTemplate>, nonce: u64) -> String {
+        match uri_template {
+            Some(uri_template) => uri_template.resolve(&self.uri, nonce),
+            None => self.uri.clone(),
+        }
+    }
+}
+
 pub trait MetadataArgsCommon: BorshSerialize + BorshDeserialize {
     fn version(&self) -> Version;
 
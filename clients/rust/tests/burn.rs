@@ -2,7 +2,10 @@
 pub mod setup;
 pub use setup::*;
 
-use mpl_bubblegum::types::{Creator, MetadataArgsV2, TokenStandard};
+use mpl_bubblegum::{
+    accounts::TreeConfig,
+    types::{Creator, MetadataArgsV2, TokenStandard, TreeCounters},
+};
 use solana_program_test::tokio;
 use solana_sdk::signature::Keypair;
 use solana_sdk::signature::Signer;
@@ -84,5 +87,16 @@ mod burn {
         // And the merkle tree root is updated.
 
         tree_manager.assert_root(&mut context).await;
+
+        // And the burn is counted by the tree config.
+
+        let (tree_config, _) = TreeConfig::find_pda(&tree_manager.tree.pubkey());
+        let tree_config_account = get_account(&mut context, &tree_config).await;
+        let counters = TreeConfig::extension::<TreeCounters>(&tree_config_account.data)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(counters.num_burned, 1);
+        assert_eq!(counters.num_live(tree_manager.minted()), 9);
     }
 }
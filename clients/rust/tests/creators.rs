@@ -0,0 +1,235 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+pub use setup::*;
+
+use mpl_bubblegum::errors::MplBubblegumError;
+use mpl_bubblegum::hash::hash_creators;
+use mpl_bubblegum::types::{Creator, MetadataArgsV2, TokenStandard};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::tokio;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signature::Signer;
+
+mod creators {
+
+    use super::*;
+
+    fn metadata(creator: Pubkey, verified: bool) -> MetadataArgsV2 {
+        MetadataArgsV2 {
+            name: String::from("cNFT"),
+            symbol: String::from("cNFT"),
+            uri: String::from("https://c.nft"),
+            seller_fee_basis_points: 500,
+            primary_sale_happened: true,
+            is_mutable: true,
+            token_standard: Some(TokenStandard::NonFungible),
+            creators: vec![Creator {
+                address: creator,
+                share: 100,
+                verified,
+            }],
+            collection: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn update_creator_moves_verified_entry() {
+        let mut program_test = create_program_test();
+        program_test.set_compute_max_units(400_000);
+        let mut context = program_test.start_with_context().await;
+
+        // Given a minted cNFT with the payer as a verified creator.
+
+        let mut tree_manager = TreeManager::<5, 8>::default();
+        tree_manager.create_v2(&mut context).await.unwrap();
+
+        let owner = Keypair::new();
+        let current_metadata = metadata(context.payer.pubkey(), true);
+
+        let asset = tree_manager
+            .mint_v2(&mut context, owner.pubkey(), current_metadata.clone())
+            .await
+            .unwrap();
+
+        // When the creator hands its entry over to a new address.
+
+        let new_creator = Keypair::new();
+        let creator = context.payer.insecure_clone();
+
+        let updated = tree_manager
+            .update_creator_v2(
+                &mut context,
+                &creator,
+                &asset,
+                current_metadata,
+                &new_creator,
+            )
+            .await
+            .unwrap();
+
+        // Then the new creator keeps the share and stays verified, since it signed.
+
+        let expected_creators = vec![Creator {
+            address: new_creator.pubkey(),
+            share: 100,
+            verified: true,
+        }];
+        assert_eq!(updated.creator_hash(), hash_creators(&expected_creators));
+
+        tree_manager.assert_root(&mut context).await;
+
+        // And can hand the entry over again.
+
+        let next_creator = Keypair::new();
+        let updated = tree_manager
+            .update_creator_v2(
+                &mut context,
+                &new_creator,
+                &updated,
+                metadata(new_creator.pubkey(), true),
+                &next_creator,
+            )
+            .await
+            .unwrap();
+
+        let expected_creators = vec![Creator {
+            address: next_creator.pubkey(),
+            share: 100,
+            verified: true,
+        }];
+        assert_eq!(updated.creator_hash(), hash_creators(&expected_creators));
+
+        tree_manager.assert_root(&mut context).await;
+    }
+
+    #[tokio::test]
+    async fn update_creator_requires_verified_creator() {
+        let mut program_test = create_program_test();
+        program_test.set_compute_max_units(400_000);
+        let mut context = program_test.start_with_context().await;
+
+        // Given a minted cNFT with an unverified creator.
+
+        let mut tree_manager = TreeManager::<5, 8>::default();
+        tree_manager.create_v2(&mut context).await.unwrap();
+
+        let owner = Keypair::new();
+        let creator = Keypair::new();
+        let current_metadata = metadata(creator.pubkey(), false);
+
+        let asset = tree_manager
+            .mint_v2(&mut context, owner.pubkey(), current_metadata.clone())
+            .await
+            .unwrap();
+
+        // When the unverified creator hands its entry over to another address.
+
+        let error = tree_manager
+            .update_creator_v2(
+                &mut context,
+                &creator,
+                &asset,
+                current_metadata,
+                &Keypair::new(),
+            )
+            .await
+            .unwrap_err();
+
+        // Then it fails and the cNFT is unchanged.
+
+        assert_custom_instruction_error!(0, error, MplBubblegumError::CreatorDidNotVerify);
+
+        tree_manager.assert_root(&mut context).await;
+    }
+
+    #[tokio::test]
+    async fn verify_creators_in_one_update() {
+        let mut program_test = create_program_test();
+        program_test.set_compute_max_units(400_000);
+        let mut context = program_test.start_with_context().await;
+
+        // Given a minted cNFT with two unverified creators.
+
+        let mut tree_manager = TreeManager::<5, 8>::default();
+        tree_manager.create_v2(&mut context).await.unwrap();
+
+        let owner = Keypair::new();
+        let second_creator = Keypair::new();
+        let mut current_metadata = metadata(context.payer.pubkey(), false);
+        current_metadata.creators = vec![
+            Creator {
+                address: context.payer.pubkey(),
+                share: 50,
+                verified: false,
+            },
+            Creator {
+                address: second_creator.pubkey(),
+                share: 50,
+                verified: false,
+            },
+        ];
+
+        let asset = tree_manager
+            .mint_v2(&mut context, owner.pubkey(), current_metadata.clone())
+            .await
+            .unwrap();
+
+        // When both creators sign a single verification.
+
+        let verified = tree_manager
+            .verify_creators_v2(&mut context, &[&second_creator], &asset, current_metadata)
+            .await
+            .unwrap();
+
+        // Then both creators are verified.
+
+        let expected_creators = vec![
+            Creator {
+                address: context.payer.pubkey(),
+                share: 50,
+                verified: true,
+            },
+            Creator {
+                address: second_creator.pubkey(),
+                share: 50,
+                verified: true,
+            },
+        ];
+        assert_eq!(verified.creator_hash(), hash_creators(&expected_creators));
+
+        tree_manager.assert_root(&mut context).await;
+    }
+
+    #[tokio::test]
+    async fn verify_creators_requires_signing_creator() {
+        let mut program_test = create_program_test();
+        program_test.set_compute_max_units(400_000);
+        let mut context = program_test.start_with_context().await;
+
+        // Given a minted cNFT with an unverified creator other than the payer.
+
+        let mut tree_manager = TreeManager::<5, 8>::default();
+        tree_manager.create_v2(&mut context).await.unwrap();
+
+        let owner = Keypair::new();
+        let current_metadata = metadata(Keypair::new().pubkey(), false);
+
+        let asset = tree_manager
+            .mint_v2(&mut context, owner.pubkey(), current_metadata.clone())
+            .await
+            .unwrap();
+
+        // When only signers that are not creators verify it.
+
+        let error = tree_manager
+            .verify_creators_v2(&mut context, &[&Keypair::new()], &asset, current_metadata)
+            .await
+            .unwrap_err();
+
+        // Then it fails and the cNFT is unchanged.
+
+        assert_custom_instruction_error!(0, error, MplBubblegumError::CreatorNotFound);
+
+        tree_manager.assert_root(&mut context).await;
+    }
+}
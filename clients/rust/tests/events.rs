@@ -0,0 +1,104 @@
+use borsh::to_vec;
+use mpl_bubblegum::{
+    types::{AssetUpdate, BubblegumEventType, LeafSchema, Version},
+    AssetEvent, BubblegumEvent, LeafSchemaEvent, DEFAULT_FLAGS,
+};
+use solana_program::pubkey::Pubkey;
+
+mod events {
+
+    use super::*;
+
+    #[test]
+    fn decode_leaf_schema_event() {
+        // Given a leaf schema event as logged by the program.
+
+        let schema = LeafSchema::V2 {
+            id: Pubkey::new_unique(),
+            owner: Pubkey::new_unique(),
+            delegate: Pubkey::new_unique(),
+            nonce: 7,
+            data_hash: [1; 32],
+            creator_hash: [2; 32],
+            collection_hash: [3; 32],
+            asset_data_hash: [4; 32],
+            flags: DEFAULT_FLAGS,
+        };
+        let event = LeafSchemaEvent::new(Version::V2, schema.clone(), schema.hash());
+        let data = to_vec(&event).unwrap();
+
+        // When decoding it.
+
+        let decoded = BubblegumEvent::decode(&data).unwrap();
+
+        // Then it is the same leaf schema event.
+
+        assert_eq!(decoded, BubblegumEvent::LeafSchema(event));
+    }
+
+    #[test]
+    fn decode_asset_event() {
+        // Given asset events as logged by the program.
+
+        let authority = Pubkey::new_unique();
+        let updates = vec![
+            AssetUpdate::BurnedV2 { authority },
+            AssetUpdate::DelegatedV2 {
+                authority,
+                previous_delegate: authority,
+                new_delegate: Pubkey::new_unique(),
+            },
+            AssetUpdate::CollectionSetV2 {
+                authority,
+                collection: Some(Pubkey::new_unique()),
+            },
+            AssetUpdate::CreatorsVerifiedV2 {
+                creators: vec![authority, Pubkey::new_unique()],
+                verified: true,
+            },
+            AssetUpdate::SplitV2 {
+                authority,
+                split_asset_id: Pubkey::new_unique(),
+                new_owner: Pubkey::new_unique(),
+                split_amount: 30,
+            },
+            AssetUpdate::MergedV2 {
+                authority,
+                merged_asset_id: Pubkey::new_unique(),
+                merged_amount: 70,
+            },
+            AssetUpdate::CreatorUpdatedV2 {
+                previous_creator: authority,
+                new_creator: Pubkey::new_unique(),
+            },
+        ];
+
+        for update in updates {
+            let event = AssetEvent::new(Version::V2, Pubkey::new_unique(), update);
+            let data = to_vec(&event).unwrap();
+
+            // When decoding them.
+
+            let decoded = BubblegumEvent::decode(&data).unwrap();
+
+            // Then they are the same asset events.
+
+            assert_eq!(decoded, BubblegumEvent::Asset(event));
+        }
+    }
+
+    #[test]
+    fn decode_uninitialized_event() {
+        // Given data with an uninitialized event type.
+
+        let data = to_vec(&BubblegumEventType::Uninitialized).unwrap();
+
+        // When decoding it.
+
+        let result = BubblegumEvent::decode(&data);
+
+        // Then it fails.
+
+        assert!(result.is_err());
+    }
+}
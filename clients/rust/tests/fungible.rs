@@ -0,0 +1,180 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+pub use setup::*;
+
+use mpl_bubblegum::errors::MplBubblegumError;
+use mpl_bubblegum::hash::hash_amount;
+use mpl_bubblegum::types::{Creator, MetadataArgsV2, TokenStandard};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::tokio;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signature::Signer;
+
+mod fungible {
+
+    use super::*;
+
+    fn metadata(creator: Pubkey) -> MetadataArgsV2 {
+        MetadataArgsV2 {
+            name: String::from("Gold"),
+            symbol: String::from("GLD"),
+            uri: String::from("https://c.nft"),
+            seller_fee_basis_points: 0,
+            primary_sale_happened: true,
+            is_mutable: true,
+            token_standard: Some(TokenStandard::FungibleAsset),
+            creators: vec![Creator {
+                address: creator,
+                share: 100,
+                verified: false,
+            }],
+            collection: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn split_and_merge() {
+        let mut program_test = create_program_test();
+        program_test.set_compute_max_units(400_000);
+        let mut context = program_test.start_with_context().await;
+
+        // Given a fungible asset leaf holding 100 units.
+
+        let mut tree_manager = TreeManager::<5, 8>::default();
+        tree_manager.create_v2(&mut context).await.unwrap();
+
+        let owner = Keypair::new();
+        let asset = tree_manager
+            .mint_fungible_v2(&mut context, owner.pubkey(), metadata(owner.pubkey()), 100)
+            .await
+            .unwrap();
+        let class_id = asset.id();
+
+        // When the owner splits 30 units off to itself.
+
+        let (asset, split) = tree_manager
+            .split_v2(
+                &mut context,
+                &owner,
+                &asset,
+                class_id,
+                100,
+                30,
+                owner.pubkey(),
+            )
+            .await
+            .unwrap();
+
+        // Then both leaves hold their part of the amount in the same class.
+
+        assert_eq!(asset.asset_data_hash(), hash_amount(&class_id, 70));
+        assert_eq!(split.asset_data_hash(), hash_amount(&class_id, 30));
+        assert_eq!(split.owner(), owner.pubkey());
+        tree_manager.assert_root(&mut context).await;
+
+        // And merging them back removes the split off leaf and restores the amount.
+
+        let merged = tree_manager
+            .merge_v2(&mut context, &owner, &asset, 70, &split, 30, class_id)
+            .await
+            .unwrap();
+
+        assert_eq!(merged.id(), class_id);
+        assert_eq!(merged.asset_data_hash(), hash_amount(&class_id, 100));
+        tree_manager.assert_root(&mut context).await;
+    }
+
+    #[tokio::test]
+    async fn split_to_new_owner() {
+        let mut program_test = create_program_test();
+        program_test.set_compute_max_units(400_000);
+        let mut context = program_test.start_with_context().await;
+
+        // Given a fungible asset leaf holding 100 units.
+
+        let mut tree_manager = TreeManager::<5, 8>::default();
+        tree_manager.create_v2(&mut context).await.unwrap();
+
+        let owner = Keypair::new();
+        let asset = tree_manager
+            .mint_fungible_v2(&mut context, owner.pubkey(), metadata(owner.pubkey()), 100)
+            .await
+            .unwrap();
+        let class_id = asset.id();
+
+        // When the owner splits 40 units off to another owner.
+
+        let new_owner = Keypair::new();
+        let (asset, split) = tree_manager
+            .split_v2(
+                &mut context,
+                &owner,
+                &asset,
+                class_id,
+                100,
+                40,
+                new_owner.pubkey(),
+            )
+            .await
+            .unwrap();
+
+        // Then the new owner holds a leaf of 40 units of the same class.
+
+        assert_eq!(asset.owner(), owner.pubkey());
+        assert_eq!(split.owner(), new_owner.pubkey());
+        assert_eq!(split.delegate(), new_owner.pubkey());
+        assert_eq!(split.asset_data_hash(), hash_amount(&class_id, 40));
+        tree_manager.assert_root(&mut context).await;
+
+        // And the owner cannot split off its whole remaining amount.
+
+        let error = tree_manager
+            .split_v2(
+                &mut context,
+                &owner,
+                &asset,
+                class_id,
+                60,
+                60,
+                new_owner.pubkey(),
+            )
+            .await
+            .unwrap_err();
+
+        assert_custom_instruction_error!(0, error, MplBubblegumError::InvalidAmount);
+        tree_manager.assert_root(&mut context).await;
+    }
+
+    #[tokio::test]
+    async fn cannot_merge_leaves_of_different_classes() {
+        let mut program_test = create_program_test();
+        program_test.set_compute_max_units(400_000);
+        let mut context = program_test.start_with_context().await;
+
+        // Given two fungible asset leaves minted separately with the same metadata.
+
+        let mut tree_manager = TreeManager::<5, 8>::default();
+        tree_manager.create_v2(&mut context).await.unwrap();
+
+        let owner = Keypair::new();
+        let asset = tree_manager
+            .mint_fungible_v2(&mut context, owner.pubkey(), metadata(owner.pubkey()), 100)
+            .await
+            .unwrap();
+        let other = tree_manager
+            .mint_fungible_v2(&mut context, owner.pubkey(), metadata(owner.pubkey()), 5)
+            .await
+            .unwrap();
+
+        // When the owner merges the second leaf into the first one.
+
+        let result = tree_manager
+            .merge_v2(&mut context, &owner, &asset, 100, &other, 5, asset.id())
+            .await;
+
+        // Then it fails since the leaves belong to different classes.
+
+        assert!(result.is_err());
+        tree_manager.assert_root(&mut context).await;
+    }
+}
@@ -0,0 +1,209 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+pub use setup::*;
+
+use mpl_bubblegum::{
+    accounts::{TreeConfig, Voucher},
+    interface::proof_account_metas,
+    types::{Creator, LeafUpdate, MetadataArgsV2, TokenStandard},
+};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+use solana_program_test::{processor, tokio};
+use solana_sdk::signature::Keypair;
+use solana_sdk::signature::Signer;
+use solana_sdk::transaction::Transaction;
+
+/// Example program holding cNFTs in a vault PDA, which it releases to a recipient by CPI into
+/// Bubblegum through the `interface` helpers.
+mod vault {
+    use borsh::{BorshDeserialize, BorshSerialize};
+    use mpl_bubblegum::{
+        instructions::TransferV2CpiBuilder,
+        interface::{get_leaf_update, WithProof},
+    };
+    use solana_program::{
+        account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+        pubkey::Pubkey,
+    };
+
+    pub const ID: Pubkey = Pubkey::new_from_array([7; 32]);
+
+    pub const VAULT_PREFIX: &[u8] = b"vault";
+
+    #[derive(BorshSerialize, BorshDeserialize)]
+    pub struct ReleaseArgs {
+        pub root: [u8; 32],
+        pub data_hash: [u8; 32],
+        pub creator_hash: [u8; 32],
+        pub nonce: u64,
+    }
+
+    pub fn find_vault_pda() -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[VAULT_PREFIX], &ID)
+    }
+
+    /// Accounts: bubblegum program, tree config, payer, vault, recipient, merkle tree, log
+    /// wrapper, compression program and system program, followed by the proof.
+    pub fn process_instruction(
+        _program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        data: &[u8],
+    ) -> ProgramResult {
+        let [bubblegum, tree_config, payer, vault, recipient, merkle_tree, log_wrapper, compression_program, system_program, proof @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        let args =
+            ReleaseArgs::try_from_slice(data).map_err(|_| ProgramError::InvalidInstructionData)?;
+        let (_, bump) = find_vault_pda();
+
+        TransferV2CpiBuilder::new(bubblegum)
+            .tree_config(tree_config)
+            .payer(payer)
+            .authority(Some(vault))
+            .leaf_owner(vault)
+            .new_leaf_owner(recipient)
+            .merkle_tree(merkle_tree)
+            .log_wrapper(log_wrapper)
+            .compression_program(compression_program)
+            .system_program(system_program)
+            .root(args.root)
+            .data_hash(args.data_hash)
+            .creator_hash(args.creator_hash)
+            .nonce(args.nonce)
+            .index(args.nonce as u32)
+            .add_proof(proof)
+            .invoke_signed(&[&[VAULT_PREFIX, &[bump]]])?;
+
+        // The updated leaf is returned by Bubblegum.
+        let update = get_leaf_update().ok_or(ProgramError::InvalidAccountData)?;
+        if update.leaf.owner() != *recipient.key {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(())
+    }
+}
+
+mod interface {
+
+    use super::*;
+
+    #[test]
+    fn find_voucher_pda() {
+        let merkle_tree = Pubkey::new_unique();
+
+        // The program seeds the voucher with the little-endian nonce.
+
+        assert_eq!(
+            Voucher::find_pda(&merkle_tree, 42u64.to_le_bytes()),
+            Pubkey::find_program_address(
+                &[b"voucher", merkle_tree.as_ref(), &42u64.to_le_bytes()],
+                &mpl_bubblegum::ID,
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn release_asset_from_vault_program() {
+        let mut program_test = create_program_test();
+        program_test.add_program("vault", vault::ID, processor!(vault::process_instruction));
+        program_test.set_compute_max_units(400_000);
+        let mut context = program_test.start_with_context().await;
+
+        // Given a new merkle tree.
+
+        let mut tree_manager = TreeManager::<5, 8>::default();
+        tree_manager.create_v2(&mut context).await.unwrap();
+
+        // And a cNFT owned by the vault of the example program.
+
+        let (vault, _) = vault::find_vault_pda();
+
+        let metadata = MetadataArgsV2 {
+            name: String::from("cNFT"),
+            symbol: String::from("cNFT"),
+            uri: String::from("https://c.nft"),
+            seller_fee_basis_points: 500,
+            primary_sale_happened: true,
+            is_mutable: true,
+            token_standard: Some(TokenStandard::NonFungible),
+            creators: vec![Creator {
+                address: context.payer.pubkey(),
+                share: 100,
+                verified: false,
+            }],
+            collection: None,
+        };
+
+        let asset = tree_manager
+            .mint_v2(&mut context, vault, metadata)
+            .await
+            .unwrap();
+
+        // When the example program releases the cNFT to a recipient.
+
+        let recipient = Keypair::new().pubkey();
+        let (tree_config, _) = TreeConfig::find_pda(&tree_manager.tree.pubkey());
+
+        let args = vault::ReleaseArgs {
+            root: tree_manager.root(),
+            data_hash: asset.data_hash(),
+            creator_hash: asset.creator_hash(),
+            nonce: asset.nonce(),
+        };
+
+        let mut accounts = vec![
+            AccountMeta::new_readonly(mpl_bubblegum::ID, false),
+            AccountMeta::new(tree_config, false),
+            AccountMeta::new(context.payer.pubkey(), true),
+            AccountMeta::new_readonly(vault, false),
+            AccountMeta::new_readonly(recipient, false),
+            AccountMeta::new(tree_manager.tree.pubkey(), false),
+            AccountMeta::new_readonly(mpl_noop::ID, false),
+            AccountMeta::new_readonly(mpl_account_compression::ID, false),
+            AccountMeta::new_readonly(
+                solana_program::pubkey!("11111111111111111111111111111111"),
+                false,
+            ),
+        ];
+        accounts.extend(proof_account_metas(
+            &tree_manager.get_proof(asset.nonce() as u32),
+        ));
+
+        let release_ix = Instruction {
+            program_id: vault::ID,
+            accounts,
+            data: borsh::to_vec(&args).unwrap(),
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[release_ix],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+
+        let result = context
+            .banks_client
+            .process_transaction_with_metadata(tx)
+            .await
+            .unwrap();
+        result.result.unwrap();
+
+        // Then the cNFT is owned by the recipient.
+
+        let return_data = result.metadata.unwrap().return_data.unwrap();
+        assert_eq!(return_data.program_id, mpl_bubblegum::ID);
+
+        let update = LeafUpdate::from_return_data(&return_data.data).unwrap();
+        assert_eq!(update.leaf.id(), asset.id());
+        assert_eq!(update.leaf.owner(), recipient);
+        assert_eq!(update.index, asset.nonce() as u32);
+    }
+}
@@ -0,0 +1,126 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+pub use setup::*;
+
+use mpl_bubblegum::errors::MplBubblegumError;
+use mpl_bubblegum::hash::hash_metadata;
+use mpl_bubblegum::types::{Creator, MetadataArgsV2, TokenStandard, UpdateArgs};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::tokio;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signature::Signer;
+
+mod metadata {
+
+    use super::*;
+
+    fn metadata(creator: Pubkey, name: &str) -> MetadataArgsV2 {
+        MetadataArgsV2 {
+            name: String::from(name),
+            symbol: String::from("cNFT"),
+            uri: String::from("https://old.host/c.nft"),
+            seller_fee_basis_points: 500,
+            primary_sale_happened: true,
+            is_mutable: true,
+            token_standard: Some(TokenStandard::NonFungible),
+            creators: vec![Creator {
+                address: creator,
+                share: 100,
+                verified: false,
+            }],
+            collection: None,
+        }
+    }
+
+    fn update_uri() -> UpdateArgs {
+        UpdateArgs {
+            name: None,
+            symbol: None,
+            uri: Some(String::from("https://new.host/c.nft")),
+            creators: None,
+            seller_fee_basis_points: None,
+            primary_sale_happened: None,
+            is_mutable: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn batch_update_metadata() {
+        let mut program_test = create_program_test();
+        program_test.set_compute_max_units(400_000);
+        let mut context = program_test.start_with_context().await;
+
+        // Given two cNFTs with different metadata in a tree created by the payer.
+
+        let mut tree_manager = TreeManager::<5, 8>::default();
+        tree_manager.create_v2(&mut context).await.unwrap();
+
+        let owner = Keypair::new();
+        let mut assets = Vec::new();
+
+        for name in ["First", "Second"] {
+            let metadata = metadata(context.payer.pubkey(), name);
+            let asset = tree_manager
+                .mint_v2(&mut context, owner.pubkey(), metadata.clone())
+                .await
+                .unwrap();
+            assets.push((asset, metadata));
+        }
+
+        // When the tree creator moves both URIs to a new host in one call.
+
+        let authority = context.payer.insecure_clone();
+
+        let updated = tree_manager
+            .batch_update_metadata_v2(&mut context, &authority, &assets, update_uri())
+            .await
+            .unwrap();
+
+        // Then both cNFTs keep their own metadata with the new URI.
+
+        for ((_, current_metadata), leaf) in assets.iter().zip(updated.iter()) {
+            let mut expected_metadata = current_metadata.clone();
+            expected_metadata.uri = String::from("https://new.host/c.nft");
+            assert_eq!(leaf.data_hash(), hash_metadata(&expected_metadata).unwrap());
+        }
+
+        tree_manager.assert_root(&mut context).await;
+    }
+
+    #[tokio::test]
+    async fn batch_update_metadata_with_invalid_authority() {
+        let mut program_test = create_program_test();
+        program_test.set_compute_max_units(400_000);
+        let mut context = program_test.start_with_context().await;
+
+        // Given two cNFTs in a tree created by the payer.
+
+        let mut tree_manager = TreeManager::<5, 8>::default();
+        tree_manager.create_v2(&mut context).await.unwrap();
+
+        let owner = Keypair::new();
+        let mut assets = Vec::new();
+
+        for name in ["First", "Second"] {
+            let metadata = metadata(context.payer.pubkey(), name);
+            let asset = tree_manager
+                .mint_v2(&mut context, owner.pubkey(), metadata.clone())
+                .await
+                .unwrap();
+            assets.push((asset, metadata));
+        }
+
+        // When an authority other than the tree creator updates them.
+
+        let error = tree_manager
+            .batch_update_metadata_v2(&mut context, &Keypair::new(), &assets, update_uri())
+            .await
+            .unwrap_err();
+
+        // Then it fails and the cNFTs are unchanged.
+
+        assert_custom_instruction_error!(0, error, MplBubblegumError::TreeAuthorityIncorrect);
+
+        tree_manager.assert_root(&mut context).await;
+    }
+}
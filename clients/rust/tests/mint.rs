@@ -3,13 +3,24 @@ pub mod setup;
 pub use setup::*;
 
 use mpl_bubblegum::{
+    accounts::{TreeConfig, TreeCreatorFees},
+    errors::MplBubblegumError,
     hash::{hash_creators, hash_metadata},
-    types::{Creator, MetadataArgs, MetadataArgsV2, TokenProgramVersion, TokenStandard},
+    instructions::{
+        AddTreeExtensionBuilder, CloseTreeV2Builder, MintV2Builder, SetBaseUriV2Builder,
+        SetTreeCreatorFeeBuilder, UpdateTreeConfigBuilder, WithdrawCreatorFeesBuilder,
+    },
+    types::{
+        Creator, MetadataArgs, MetadataArgsV2, TokenProgramVersion, TokenStandard,
+        TreeExtensionType, UriTemplate,
+    },
     utils::get_asset_id,
 };
+use solana_program::instruction::AccountMeta;
 use solana_program_test::tokio;
 use solana_sdk::signature::Keypair;
 use solana_sdk::signature::Signer;
+use solana_sdk::transaction::Transaction;
 
 mod mint {
 
@@ -278,3 +289,363 @@ async fn mint_v2_multiple_asset() {
 
     tree_manager.assert_root(&mut context).await;
 }
+
+#[tokio::test]
+async fn mint_v2_asset_with_base_uri() {
+    let mut program_test = create_program_test();
+    program_test.set_compute_max_units(400_000);
+    let mut context = program_test.start_with_context().await;
+
+    // Given a new merkle tree with a base URI.
+
+    let mut tree_manager = TreeManager::<5, 8>::default();
+    tree_manager.create_v2(&mut context).await.unwrap();
+
+    let (tree_config, _) = TreeConfig::find_pda(&tree_manager.tree.pubkey());
+
+    let add_extension_ix = AddTreeExtensionBuilder::new()
+        .tree_config(tree_config)
+        .payer(context.payer.pubkey())
+        .tree_creator(context.payer.pubkey())
+        .merkle_tree(tree_manager.tree.pubkey())
+        .extension_type(TreeExtensionType::UriTemplate)
+        .instruction();
+
+    let set_base_uri_ix = SetBaseUriV2Builder::new()
+        .tree_config(tree_config)
+        .payer(context.payer.pubkey())
+        .merkle_tree(tree_manager.tree.pubkey())
+        .base_uri(String::from("https://c.nft/{index}.json"))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[add_extension_ix, set_base_uri_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When minting a new cNFT without a URI.
+
+    let owner = Keypair::new();
+
+    let metadata = MetadataArgsV2 {
+        name: String::from("cNFT"),
+        symbol: String::from("cNFT"),
+        uri: String::new(),
+        seller_fee_basis_points: 500,
+        primary_sale_happened: true,
+        is_mutable: true,
+        token_standard: Some(TokenStandard::NonFungible),
+        creators: vec![Creator {
+            address: context.payer.pubkey(),
+            share: 100,
+            verified: false,
+        }],
+        collection: None,
+    };
+
+    let leaf = tree_manager
+        .mint_v2(&mut context, owner.pubkey(), metadata.clone())
+        .await
+        .unwrap();
+
+    // Then its URI resolves against the base URI of the tree.
+
+    let tree_config_account = get_account(&mut context, &tree_config).await;
+    let uri_template = TreeConfig::extension::<UriTemplate>(&tree_config_account.data)
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(
+        metadata.resolved_uri(Some(&uri_template), leaf.nonce()),
+        "https://c.nft/0.json"
+    );
+
+    // And the merkle tree root is updated.
+
+    tree_manager.assert_root(&mut context).await;
+}
+
+#[tokio::test]
+async fn mint_v2_immutable_asset_freezes_base_uri() {
+    let mut program_test = create_program_test();
+    program_test.set_compute_max_units(400_000);
+    let mut context = program_test.start_with_context().await;
+
+    // Given a new merkle tree with a base URI.
+
+    let mut tree_manager = TreeManager::<5, 8>::default();
+    tree_manager.create_v2(&mut context).await.unwrap();
+
+    let (tree_config, _) = TreeConfig::find_pda(&tree_manager.tree.pubkey());
+
+    let add_extension_ix = AddTreeExtensionBuilder::new()
+        .tree_config(tree_config)
+        .payer(context.payer.pubkey())
+        .tree_creator(context.payer.pubkey())
+        .merkle_tree(tree_manager.tree.pubkey())
+        .extension_type(TreeExtensionType::UriTemplate)
+        .instruction();
+
+    let set_base_uri_ix = SetBaseUriV2Builder::new()
+        .tree_config(tree_config)
+        .payer(context.payer.pubkey())
+        .merkle_tree(tree_manager.tree.pubkey())
+        .base_uri(String::from("https://c.nft/{index}.json"))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[add_extension_ix, set_base_uri_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When minting an immutable cNFT.
+
+    let owner = Keypair::new();
+
+    let metadata = MetadataArgsV2 {
+        name: String::from("cNFT"),
+        symbol: String::from("cNFT"),
+        uri: String::new(),
+        seller_fee_basis_points: 500,
+        primary_sale_happened: true,
+        is_mutable: false,
+        token_standard: Some(TokenStandard::NonFungible),
+        creators: vec![Creator {
+            address: context.payer.pubkey(),
+            share: 100,
+            verified: false,
+        }],
+        collection: None,
+    };
+
+    tree_manager
+        .mint_v2(&mut context, owner.pubkey(), metadata)
+        .await
+        .unwrap();
+
+    // Then the base URI of the tree is frozen.
+
+    let tree_config_account = get_account(&mut context, &tree_config).await;
+    let uri_template = TreeConfig::extension::<UriTemplate>(&tree_config_account.data)
+        .unwrap()
+        .unwrap();
+
+    assert!(uri_template.is_frozen);
+
+    // And it can no longer be changed.
+
+    let set_base_uri_ix = SetBaseUriV2Builder::new()
+        .tree_config(tree_config)
+        .payer(context.payer.pubkey())
+        .merkle_tree(tree_manager.tree.pubkey())
+        .base_uri(String::from("https://other.host/{index}.json"))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[set_base_uri_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplBubblegumError::BaseUriFrozen);
+}
+
+#[tokio::test]
+async fn mint_v2_creator_fee() {
+    let mut program_test = create_program_test();
+    program_test.set_compute_max_units(400_000);
+    let mut context = program_test.start_with_context().await;
+
+    // Given a new public merkle tree with a creator fee.
+
+    let mut tree_manager = TreeManager::<5, 8>::default();
+    tree_manager.create_v2(&mut context).await.unwrap();
+
+    let (tree_config, _) = TreeConfig::find_pda(&tree_manager.tree.pubkey());
+    let (tree_creator_fees, _) = TreeCreatorFees::find_pda(&tree_manager.tree.pubkey());
+    let creator_fee_lamports = 10_000;
+
+    let update_tree_config_ix = UpdateTreeConfigBuilder::new()
+        .tree_config(tree_config)
+        .payer(context.payer.pubkey())
+        .tree_creator(context.payer.pubkey())
+        .merkle_tree(tree_manager.tree.pubkey())
+        .log_wrapper(mpl_noop::ID)
+        .is_public(Some(true))
+        .instruction();
+
+    let set_creator_fee_ix = SetTreeCreatorFeeBuilder::new()
+        .tree_config(tree_config)
+        .payer(context.payer.pubkey())
+        .tree_creator(context.payer.pubkey())
+        .merkle_tree(tree_manager.tree.pubkey())
+        .tree_creator_fees(tree_creator_fees)
+        .creator_fee_lamports(creator_fee_lamports)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[update_tree_config_ix, set_creator_fee_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // And a third-party minter.
+
+    let minter = Keypair::new();
+    airdrop(&mut context, &minter.pubkey(), 1_000_000_000).await;
+
+    let metadata = MetadataArgsV2 {
+        name: String::from("cNFT"),
+        symbol: String::from("cNFT"),
+        uri: String::from("https://c.nft"),
+        seller_fee_basis_points: 500,
+        primary_sale_happened: true,
+        is_mutable: true,
+        token_standard: Some(TokenStandard::NonFungible),
+        creators: vec![],
+        collection: None,
+    };
+
+    let mut mint_ix = MintV2Builder::new();
+    mint_ix
+        .tree_config(tree_config)
+        .payer(minter.pubkey())
+        .leaf_owner(minter.pubkey())
+        .merkle_tree(tree_manager.tree.pubkey())
+        .metadata(metadata);
+
+    // When minting without the creator fees account, the mint fails.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[mint_ix.instruction()],
+        Some(&minter.pubkey()),
+        &[&minter],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_custom_instruction_error!(0, error, MplBubblegumError::MissingTreeCreatorFeesAccount);
+
+    // When minting with the creator fees account ahead of the remaining accounts.
+
+    let balance = get_account(&mut context, &tree_creator_fees).await.lamports;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[mint_ix
+            .add_remaining_account(AccountMeta::new(tree_creator_fees, false))
+            .instruction()],
+        Some(&minter.pubkey()),
+        &[&minter],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the creator fee is paid into the creator fees account.
+
+    let new_balance = get_account(&mut context, &tree_creator_fees).await.lamports;
+    assert_eq!(new_balance - balance, creator_fee_lamports);
+}
+
+#[tokio::test]
+async fn withdraw_creator_fees_after_close_tree() {
+    let mut program_test = create_program_test();
+    program_test.set_compute_max_units(400_000);
+    let mut context = program_test.start_with_context().await;
+
+    // Given a new merkle tree with a creator fee.
+
+    let mut tree_manager = TreeManager::<5, 8>::default();
+    tree_manager.create_v2(&mut context).await.unwrap();
+
+    let (tree_config, _) = TreeConfig::find_pda(&tree_manager.tree.pubkey());
+    let (tree_creator_fees, _) = TreeCreatorFees::find_pda(&tree_manager.tree.pubkey());
+
+    let set_creator_fee_ix = SetTreeCreatorFeeBuilder::new()
+        .tree_config(tree_config)
+        .payer(context.payer.pubkey())
+        .tree_creator(context.payer.pubkey())
+        .merkle_tree(tree_manager.tree.pubkey())
+        .tree_creator_fees(tree_creator_fees)
+        .creator_fee_lamports(10_000)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[set_creator_fee_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // And fees held by the creator fees account.
+
+    airdrop(&mut context, &tree_creator_fees, 50_000).await;
+    let balance = get_account(&mut context, &tree_creator_fees).await.lamports;
+
+    // When the tree is closed.
+
+    let close_tree_ix = CloseTreeV2Builder::new()
+        .tree_config(tree_config)
+        .authority(context.payer.pubkey())
+        .merkle_tree(tree_manager.tree.pubkey())
+        .recipient(context.payer.pubkey())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[close_tree_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    assert!(find_account(&mut context, &tree_config).await.is_none());
+
+    // Then the tree creator can still withdraw the fees.
+
+    let destination = Keypair::new().pubkey();
+
+    let withdraw_ix = WithdrawCreatorFeesBuilder::new()
+        .tree_config(tree_config)
+        .tree_creator(context.payer.pubkey())
+        .merkle_tree(tree_manager.tree.pubkey())
+        .tree_creator_fees(tree_creator_fees)
+        .destination(destination)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[withdraw_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // And the creator fees account is closed into the destination.
+
+    assert!(find_account(&mut context, &tree_creator_fees)
+        .await
+        .is_none());
+    assert_eq!(
+        get_account(&mut context, &destination).await.lamports,
+        balance
+    );
+}
@@ -0,0 +1,92 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+pub use setup::*;
+
+use mpl_bubblegum::errors::MplBubblegumError;
+use mpl_bubblegum::types::{Creator, MetadataArgsV2, TokenStandard};
+use solana_program_test::tokio;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signature::Signer;
+
+mod plugins {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn permanent_freeze_delegate_kept_while_leaves_frozen() {
+        let mut program_test = create_program_test();
+        program_test.set_compute_max_units(400_000);
+        let mut context = program_test.start_with_context().await;
+
+        // Given a new merkle tree with a permanent freeze delegate.
+
+        let mut tree_manager = TreeManager::<5, 8>::default();
+        tree_manager.create_v2(&mut context).await.unwrap();
+
+        let freeze_delegate = Keypair::new();
+        tree_manager
+            .set_tree_plugins(&mut context, Some(freeze_delegate.pubkey()))
+            .await
+            .unwrap();
+
+        // And a minted cNFT frozen by the permanent freeze delegate.
+
+        let owner = Keypair::new();
+
+        let metadata = MetadataArgsV2 {
+            name: String::from("cNFT"),
+            symbol: String::from("cNFT"),
+            uri: String::from("https://c.nft"),
+            seller_fee_basis_points: 500,
+            primary_sale_happened: true,
+            is_mutable: true,
+            token_standard: Some(TokenStandard::NonFungible),
+            creators: vec![Creator {
+                address: context.payer.pubkey(),
+                share: 100,
+                verified: false,
+            }],
+            collection: None,
+        };
+
+        let asset = tree_manager
+            .mint_v2(&mut context, owner.pubkey(), metadata)
+            .await
+            .unwrap();
+
+        let frozen = tree_manager
+            .permanent_freeze_v2(&mut context, &freeze_delegate, &asset, true)
+            .await
+            .unwrap();
+
+        tree_manager.assert_root(&mut context).await;
+
+        // When the tree creator removes the permanent freeze delegate.
+
+        let error = tree_manager
+            .set_tree_plugins(&mut context, None)
+            .await
+            .unwrap_err();
+
+        // Then it fails while the cNFT is frozen.
+
+        assert_custom_instruction_error!(0, error, MplBubblegumError::TreeHasFrozenLeaves);
+
+        // And succeeds once the cNFT is thawed.
+
+        tree_manager
+            .permanent_freeze_v2(&mut context, &freeze_delegate, &frozen, false)
+            .await
+            .unwrap();
+
+        tree_manager.assert_root(&mut context).await;
+
+        // A new blockhash so the retry is not a duplicate of the failed transaction.
+        context.last_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+        tree_manager
+            .set_tree_plugins(&mut context, None)
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,131 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+pub use setup::*;
+
+use mpl_bubblegum::errors::MplBubblegumError;
+use mpl_bubblegum::types::{Creator, LeafSchema, MetadataArgsV2, TokenStandard};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::signature::Keypair;
+use solana_sdk::signature::Signer;
+
+mod recover {
+
+    use super::*;
+
+    /// Mints a cNFT in a new collection and sets it non-transferable with the collection
+    /// permanent freeze delegate.
+    async fn mint_non_transferable(
+        context: &mut ProgramTestContext,
+        tree_manager: &mut TreeManager<5, 8>,
+        owner: Pubkey,
+    ) -> (LeafSchema, Pubkey) {
+        let freeze_delegate = Keypair::new();
+        let collection = create_core_collection(context, Some(freeze_delegate.pubkey())).await;
+
+        let metadata = MetadataArgsV2 {
+            name: String::from("cNFT"),
+            symbol: String::from("cNFT"),
+            uri: String::from("https://c.nft"),
+            seller_fee_basis_points: 500,
+            primary_sale_happened: true,
+            is_mutable: true,
+            token_standard: Some(TokenStandard::NonFungible),
+            creators: vec![Creator {
+                address: context.payer.pubkey(),
+                share: 100,
+                verified: false,
+            }],
+            collection: Some(collection),
+        };
+
+        let asset = tree_manager
+            .mint_v2(context, owner, metadata)
+            .await
+            .unwrap();
+
+        let asset = tree_manager
+            .set_non_transferable_v2(context, &freeze_delegate, &asset, collection)
+            .await
+            .unwrap();
+
+        (asset, collection)
+    }
+
+    #[tokio::test]
+    async fn recover_non_transferable_as_collection_authority() {
+        let mut program_test = create_program_test();
+        program_test.set_compute_max_units(400_000);
+        let mut context = program_test.start_with_context().await;
+
+        // Given a non-transferable cNFT in a collection owned by the payer.
+
+        let mut tree_manager = TreeManager::<5, 8>::default();
+        tree_manager.create_v2(&mut context).await.unwrap();
+
+        let owner = Keypair::new();
+        let (asset, collection) =
+            mint_non_transferable(&mut context, &mut tree_manager, owner.pubkey()).await;
+
+        // When the collection update authority recovers the cNFT to a new owner.
+
+        let new_owner = Keypair::new();
+        let authority = context.payer.insecure_clone();
+
+        let recovered = tree_manager
+            .recover_non_transferable_v2(
+                &mut context,
+                &authority,
+                &asset,
+                collection,
+                new_owner.pubkey(),
+            )
+            .await
+            .unwrap();
+
+        // Then the cNFT belongs to the new owner and is still non-transferable.
+
+        assert_eq!(recovered.owner(), new_owner.pubkey());
+        assert_eq!(recovered.delegate(), new_owner.pubkey());
+        assert_eq!(recovered.flags(), asset.flags());
+
+        tree_manager.assert_root(&mut context).await;
+    }
+
+    #[tokio::test]
+    async fn recover_non_transferable_with_invalid_authority() {
+        let mut program_test = create_program_test();
+        program_test.set_compute_max_units(400_000);
+        let mut context = program_test.start_with_context().await;
+
+        // Given a non-transferable cNFT in a collection owned by the payer.
+
+        let mut tree_manager = TreeManager::<5, 8>::default();
+        tree_manager.create_v2(&mut context).await.unwrap();
+
+        let owner = Keypair::new();
+        let (asset, collection) =
+            mint_non_transferable(&mut context, &mut tree_manager, owner.pubkey()).await;
+
+        // When an authority other than the collection update authority recovers the cNFT.
+
+        let attacker = Keypair::new();
+
+        let error = tree_manager
+            .recover_non_transferable_v2(
+                &mut context,
+                &attacker,
+                &asset,
+                collection,
+                attacker.pubkey(),
+            )
+            .await
+            .unwrap_err();
+
+        // Then it fails and the cNFT is unchanged.
+
+        assert_custom_instruction_error!(0, error, MplBubblegumError::InvalidAuthority);
+
+        tree_manager.assert_root(&mut context).await;
+    }
+}
@@ -0,0 +1,158 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+pub use setup::*;
+
+use mpl_bubblegum::errors::MplBubblegumError;
+use mpl_bubblegum::types::{Creator, MetadataArgsV2, TokenStandard};
+use solana_program_test::tokio;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signature::Signer;
+
+mod revoke {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn revoke_asset() {
+        let mut program_test = create_program_test();
+        program_test.set_compute_max_units(400_000);
+        let mut context = program_test.start_with_context().await;
+
+        // Given a new merkle tree.
+
+        let mut tree_manager = TreeManager::<5, 8>::default();
+        tree_manager.create_v2(&mut context).await.unwrap();
+
+        // And a minted cNFT.
+
+        let owner = Keypair::new();
+
+        let metadata = MetadataArgsV2 {
+            name: String::from("cNFT"),
+            symbol: String::from("cNFT"),
+            uri: String::from("https://c.nft"),
+            seller_fee_basis_points: 500,
+            primary_sale_happened: true,
+            is_mutable: true,
+            token_standard: Some(TokenStandard::NonFungible),
+            creators: vec![Creator {
+                address: context.payer.pubkey(),
+                share: 100,
+                verified: false,
+            }],
+            collection: None,
+        };
+
+        let asset = tree_manager
+            .mint_v2(&mut context, owner.pubkey(), metadata)
+            .await
+            .unwrap();
+
+        assert!(!asset.is_revoked());
+
+        // When the tree creator revokes the cNFT.
+
+        let revoked = tree_manager.revoke_v2(&mut context, &asset).await.unwrap();
+
+        // Then the cNFT is revoked but stays with its owner.
+
+        assert!(revoked.is_revoked());
+        assert_eq!(revoked.owner(), owner.pubkey());
+        tree_manager.assert_root(&mut context).await;
+
+        // And it cannot be transferred.
+
+        let receiver = Keypair::new().pubkey();
+        let error = tree_manager
+            .transfer_v2(&mut context, &owner, receiver, &revoked)
+            .await
+            .unwrap_err();
+
+        assert_custom_instruction_error!(0, error, MplBubblegumError::AssetIsRevoked);
+
+        // Nor used to prove ownership.
+
+        let error = tree_manager
+            .verify_leaf_v2(&mut context, &revoked, false)
+            .await
+            .unwrap_err();
+
+        assert_custom_instruction_error!(0, error, MplBubblegumError::AssetIsRevoked);
+
+        // But the owner can still burn it.
+
+        tree_manager
+            .burn_v2(&mut context, &owner, &revoked)
+            .await
+            .unwrap();
+
+        tree_manager.assert_root(&mut context).await;
+    }
+
+    #[tokio::test]
+    async fn cannot_revoke_staked_asset() {
+        let mut program_test = create_program_test();
+        program_test.set_compute_max_units(400_000);
+        let mut context = program_test.start_with_context().await;
+
+        // Given a new merkle tree.
+
+        let mut tree_manager = TreeManager::<5, 8>::default();
+        tree_manager.create_v2(&mut context).await.unwrap();
+
+        // And a staked cNFT.
+
+        let owner = Keypair::new();
+
+        let metadata = MetadataArgsV2 {
+            name: String::from("cNFT"),
+            symbol: String::from("cNFT"),
+            uri: String::from("https://c.nft"),
+            seller_fee_basis_points: 500,
+            primary_sale_happened: true,
+            is_mutable: true,
+            token_standard: Some(TokenStandard::NonFungible),
+            creators: vec![Creator {
+                address: context.payer.pubkey(),
+                share: 100,
+                verified: false,
+            }],
+            collection: None,
+        };
+
+        let asset = tree_manager
+            .mint_v2(&mut context, owner.pubkey(), metadata)
+            .await
+            .unwrap();
+
+        airdrop(&mut context, &owner.pubkey(), 1_000_000_000).await;
+
+        let staking_authority = Keypair::new();
+        let staked = tree_manager
+            .stake_v2(&mut context, &owner, &staking_authority, &asset)
+            .await
+            .unwrap();
+
+        // When the tree creator revokes the cNFT.
+
+        let error = tree_manager
+            .revoke_v2(&mut context, &staked)
+            .await
+            .unwrap_err();
+
+        // Then it fails, since a revoked cNFT could never be unstaked.
+
+        assert_custom_instruction_error!(0, error, MplBubblegumError::AssetIsStaked);
+        tree_manager.assert_root(&mut context).await;
+
+        // And the staking authority can still unstake it.
+
+        let unstaked = tree_manager
+            .unstake_v2(&mut context, &staking_authority, &staked)
+            .await
+            .unwrap();
+
+        assert_eq!(unstaked.owner(), owner.pubkey());
+        tree_manager.assert_root(&mut context).await;
+    }
+}
@@ -1,9 +1,14 @@
 mod tree_manager;
 pub use tree_manager::*;
 
+use mpl_core::{
+    instructions::CreateCollectionV2Builder,
+    types::{BubblegumV2, PermanentFreezeDelegate, Plugin, PluginAuthority, PluginAuthorityPair},
+};
 use solana_program::pubkey::Pubkey;
 use solana_program_test::{ProgramTest, ProgramTestContext};
-use solana_sdk::account::Account;
+use solana_sdk::{account::Account, signature::Keypair, signer::Signer, transaction::Transaction};
+use solana_system_interface::instruction as system_instruction;
 use std::str::FromStr;
 
 /// Asserts that a given error is a custom instruction error.
@@ -67,3 +72,59 @@ pub async fn get_account(context: &mut ProgramTestContext, pubkey: &Pubkey) -> A
         .unwrap()
         .expect("account not found")
 }
+
+/// Transfers lamports from the payer to a given pubkey.
+pub async fn airdrop(context: &mut ProgramTestContext, receiver: &Pubkey, amount: u64) {
+    let tx = Transaction::new_signed_with_payer(
+        &[system_instruction::transfer(
+            &context.payer.pubkey(),
+            receiver,
+            amount,
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+/// Creates an mpl-core collection with the Bubblegum V2 plugin, owned by the payer, and an
+/// optional permanent freeze delegate.
+pub async fn create_core_collection(
+    context: &mut ProgramTestContext,
+    permanent_freeze_delegate: Option<Pubkey>,
+) -> Pubkey {
+    let collection = Keypair::new();
+
+    let mut plugins = vec![PluginAuthorityPair {
+        plugin: Plugin::BubblegumV2(BubblegumV2 {}),
+        authority: None,
+    }];
+    if let Some(address) = permanent_freeze_delegate {
+        plugins.push(PluginAuthorityPair {
+            plugin: Plugin::PermanentFreezeDelegate(PermanentFreezeDelegate { frozen: false }),
+            authority: Some(PluginAuthority::Address { address }),
+        });
+    }
+
+    let ix = CreateCollectionV2Builder::new()
+        .collection(collection.pubkey())
+        .update_authority(Some(context.payer.pubkey()))
+        .payer(context.payer.pubkey())
+        .name(String::from("Collection"))
+        .uri(String::from("https://c.nft/collection.json"))
+        .plugins(plugins)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &collection],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    collection.pubkey()
+}
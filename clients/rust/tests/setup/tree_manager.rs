@@ -1,11 +1,23 @@
 use mpl_bubblegum::{
-    accounts::TreeConfig,
-    hash::{hash_creators, hash_metadata, DEFAULT_ASSET_DATA_HASH, DEFAULT_COLLECTION_HASH},
+    accounts::{StakeRecord, TreeConfig, TreePlugins},
+    hash::{
+        hash_amount, hash_collection_option, hash_creators, hash_metadata, hash_uses,
+        DEFAULT_ASSET_DATA_HASH,
+    },
     instructions::{
-        BurnV2Builder, CreateTreeConfigBuilder, CreateTreeConfigV2Builder, MintV1Builder,
-        MintV2Builder, TransferBuilder, TransferV2Builder,
+        BatchUpdateMetadataV2Builder, BurnV2Builder, CreateTreeConfigBuilder,
+        CreateTreeConfigV2Builder, DelegateV2Builder, FreezeV2Builder, MergeV2Builder,
+        MintFungibleV2Builder, MintUsableV2Builder, MintV1Builder, MintV2Builder,
+        RecoverNonTransferableV2Builder, RevokeV2Builder, SetNonTransferableV2Builder,
+        SetTreePluginsBuilder, SplitV2Builder, StakeV2Builder, ThawV2Builder, TransferBuilder,
+        TransferV2Builder, UnstakeV2Builder, UpdateCreatorV2Builder, UtilizeV2Builder,
+        VerifyCreatorsV2Builder, VerifyLeafV2Builder,
+    },
+    interface::find_mpl_core_cpi_signer_pda,
+    types::{
+        LeafMetadataUpdate, LeafSchema, LeafUpdate, MetadataArgs, MetadataArgsV2, TreeRuleSet,
+        UpdateArgs, UseMethod, Uses,
     },
-    types::{LeafSchema, MetadataArgs, MetadataArgsV2},
     utils::get_asset_id,
     DEFAULT_FLAGS,
 };
@@ -13,7 +25,10 @@ use mpl_bubblegum::{
 // We import from mpl-account-compression to avoid pulling in the spl-account-compression crate
 // which may not be updated to the same Solana SDK version.
 use mpl_account_compression::{state::CONCURRENT_MERKLE_TREE_HEADER_SIZE_V1, ConcurrentMerkleTree};
-use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
 use solana_program_test::{BanksClientError, ProgramTestContext};
 use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
 use solana_system_interface::instruction as system_instruction;
@@ -53,6 +68,10 @@ impl<const MAX_DEPTH: usize, const MAX_BUFFER_SIZE: usize> TreeManager<MAX_DEPTH
         self.minted
     }
 
+    pub fn root(&self) -> Node {
+        self.proof_tree.root
+    }
+
     pub fn get_proof(&self, index: u32) -> Vec<Node> {
         self.proof_tree.get_proof_of_leaf(index as usize)
     }
@@ -199,6 +218,8 @@ impl<const MAX_DEPTH: usize, const MAX_BUFFER_SIZE: usize> TreeManager<MAX_DEPTH
             .leaf_owner(owner)
             .leaf_delegate(Some(owner))
             .merkle_tree(self.tree.pubkey())
+            .core_collection(args.collection)
+            .mpl_core_cpi_signer(args.collection.map(|_| find_mpl_core_cpi_signer_pda().0))
             .metadata(args.clone())
             .instruction();
 
@@ -209,7 +230,11 @@ impl<const MAX_DEPTH: usize, const MAX_BUFFER_SIZE: usize> TreeManager<MAX_DEPTH
             context.last_blockhash,
         );
 
-        context.banks_client.process_transaction(tx).await?;
+        let result = context
+            .banks_client
+            .process_transaction_with_metadata(tx)
+            .await?;
+        result.result.map_err(BanksClientError::TransactionError)?;
 
         // on success, we store the newly-created leaf in the proof tree
         // and increment the number of minted assets
@@ -225,11 +250,124 @@ impl<const MAX_DEPTH: usize, const MAX_BUFFER_SIZE: usize> TreeManager<MAX_DEPTH
             nonce: self.minted,
             data_hash,
             creator_hash,
-            collection_hash: DEFAULT_COLLECTION_HASH,
+            collection_hash: hash_collection_option(args.collection).unwrap(),
             asset_data_hash: DEFAULT_ASSET_DATA_HASH,
             flags: DEFAULT_FLAGS,
         };
 
+        // the program returns the same leaf, at the index of its nonce
+
+        let return_data = result
+            .metadata
+            .and_then(|metadata| metadata.return_data)
+            .expect("missing return data");
+        let update = LeafUpdate::from_return_data(&return_data.data).unwrap();
+        assert_eq!(update.leaf, leaf);
+        assert_eq!(update.index as u64, self.minted);
+
+        self.proof_tree.add_leaf(leaf.hash(), self.minted as usize);
+        self.minted += 1;
+
+        Ok(leaf)
+    }
+
+    pub async fn mint_usable_v2(
+        &mut self,
+        context: &mut ProgramTestContext,
+        owner: Pubkey,
+        args: MetadataArgsV2,
+        uses: Uses,
+    ) -> Result<LeafSchema, BanksClientError> {
+        let (tree_config, _) = TreeConfig::find_pda(&self.tree.pubkey());
+
+        let mint_ix = MintUsableV2Builder::new()
+            .tree_config(tree_config)
+            .payer(context.payer.pubkey())
+            .tree_creator_or_delegate(Some(context.payer.pubkey()))
+            .leaf_owner(owner)
+            .leaf_delegate(Some(owner))
+            .merkle_tree(self.tree.pubkey())
+            .metadata(args.clone())
+            .uses(uses.clone())
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[mint_ix],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await?;
+
+        // on success, we store the newly-created leaf in the proof tree
+        // and increment the number of minted assets
+
+        let leaf = LeafSchema::V2 {
+            id: get_asset_id(&self.tree.pubkey(), self.minted),
+            owner,
+            delegate: owner,
+            nonce: self.minted,
+            data_hash: hash_metadata(&args).unwrap(),
+            creator_hash: hash_creators(&args.creators),
+            collection_hash: hash_collection_option(args.collection).unwrap(),
+            asset_data_hash: hash_uses(&uses).unwrap(),
+            flags: DEFAULT_FLAGS,
+        };
+
+        self.proof_tree.add_leaf(leaf.hash(), self.minted as usize);
+        self.minted += 1;
+
+        Ok(leaf)
+    }
+
+    pub async fn mint_fungible_v2(
+        &mut self,
+        context: &mut ProgramTestContext,
+        owner: Pubkey,
+        args: MetadataArgsV2,
+        amount: u64,
+    ) -> Result<LeafSchema, BanksClientError> {
+        let (tree_config, _) = TreeConfig::find_pda(&self.tree.pubkey());
+
+        let mint_ix = MintFungibleV2Builder::new()
+            .tree_config(tree_config)
+            .payer(context.payer.pubkey())
+            .tree_creator_or_delegate(Some(context.payer.pubkey()))
+            .leaf_owner(owner)
+            .leaf_delegate(Some(owner))
+            .merkle_tree(self.tree.pubkey())
+            .metadata(args.clone())
+            .amount(amount)
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[mint_ix],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await?;
+
+        // on success, we store the newly-created leaf in the proof tree
+        // and increment the number of minted assets; the leaf starts a
+        // class identified by its asset id
+
+        let asset_id = get_asset_id(&self.tree.pubkey(), self.minted);
+
+        let leaf = LeafSchema::V2 {
+            id: asset_id,
+            owner,
+            delegate: owner,
+            nonce: self.minted,
+            data_hash: hash_metadata(&args).unwrap(),
+            creator_hash: hash_creators(&args.creators),
+            collection_hash: hash_collection_option(args.collection).unwrap(),
+            asset_data_hash: hash_amount(&asset_id, amount),
+            flags: DEFAULT_FLAGS,
+        };
+
         self.proof_tree.add_leaf(leaf.hash(), self.minted as usize);
         self.minted += 1;
 
@@ -292,6 +430,19 @@ impl<const MAX_DEPTH: usize, const MAX_BUFFER_SIZE: usize> TreeManager<MAX_DEPTH
         owner: &Keypair,
         receiver: Pubkey,
         asset: &LeafSchema,
+    ) -> Result<LeafSchema, BanksClientError> {
+        self.transfer_v2_with_optional_accounts(context, owner, receiver, asset, &[])
+            .await
+    }
+
+    /// Transfers an asset, passing `optional_accounts` (e.g. the fee config) ahead of the proof.
+    pub async fn transfer_v2_with_optional_accounts(
+        &mut self,
+        context: &mut ProgramTestContext,
+        owner: &Keypair,
+        receiver: Pubkey,
+        asset: &LeafSchema,
+        optional_accounts: &[AccountMeta],
     ) -> Result<LeafSchema, BanksClientError> {
         let (tree_config, _) = TreeConfig::find_pda(&self.tree.pubkey());
         let proof = self.build_proof(asset.nonce() as u32);
@@ -307,8 +458,10 @@ impl<const MAX_DEPTH: usize, const MAX_BUFFER_SIZE: usize> TreeManager<MAX_DEPTH
             .root(self.proof_tree.root)
             .data_hash(asset.data_hash())
             .creator_hash(asset.creator_hash())
+            .flags(asset.flags())
             .nonce(asset.nonce())
             .index(asset.nonce() as u32)
+            .add_remaining_accounts(optional_accounts)
             .add_remaining_accounts(&proof)
             .instruction();
 
@@ -319,9 +472,14 @@ impl<const MAX_DEPTH: usize, const MAX_BUFFER_SIZE: usize> TreeManager<MAX_DEPTH
             context.last_blockhash,
         );
 
-        context.banks_client.process_transaction(tx).await?;
+        let result = context
+            .banks_client
+            .process_transaction_with_metadata(tx)
+            .await?;
+        result.result.map_err(BanksClientError::TransactionError)?;
 
-        // on success, we update the leaf in the proof tree
+        // on success, we check the leaf returned by the program against the expected
+        // one and update it in the proof tree
 
         let leaf = LeafSchema::V2 {
             id: asset.id(),
@@ -335,12 +493,219 @@ impl<const MAX_DEPTH: usize, const MAX_BUFFER_SIZE: usize> TreeManager<MAX_DEPTH
             flags: asset.flags(),
         };
 
+        let return_data = result
+            .metadata
+            .and_then(|metadata| metadata.return_data)
+            .expect("missing return data");
+        let update = LeafUpdate::from_return_data(&return_data.data).unwrap();
+
+        assert_eq!(update.leaf, leaf);
+        assert_eq!(update.index, asset.nonce() as u32);
+
         self.proof_tree
             .add_leaf(leaf.hash(), asset.nonce() as usize);
 
         Ok(leaf)
     }
 
+    /// Approves `new_delegate` as the leaf delegate of an asset.
+    pub async fn delegate_v2(
+        &mut self,
+        context: &mut ProgramTestContext,
+        owner: &Keypair,
+        new_delegate: Pubkey,
+        asset: &LeafSchema,
+    ) -> Result<LeafSchema, BanksClientError> {
+        let (tree_config, _) = TreeConfig::find_pda(&self.tree.pubkey());
+        let proof = self.build_proof(asset.nonce() as u32);
+
+        let ix = DelegateV2Builder::new()
+            .tree_config(tree_config)
+            .payer(context.payer.pubkey())
+            .leaf_owner(Some(owner.pubkey()))
+            .previous_leaf_delegate(Some(asset.delegate()))
+            .new_leaf_delegate(new_delegate)
+            .merkle_tree(self.tree.pubkey())
+            .root(self.proof_tree.root)
+            .data_hash(asset.data_hash())
+            .creator_hash(asset.creator_hash())
+            .collection_hash(asset.collection_hash())
+            .asset_data_hash(asset.asset_data_hash())
+            .flags(asset.flags())
+            .nonce(asset.nonce())
+            .index(asset.nonce() as u32)
+            .add_remaining_accounts(&proof)
+            .instruction();
+
+        self.process_leaf_update(context, ix, owner).await
+    }
+
+    /// Stakes an asset with `staking_authority` as the authority that must sign its unstake.
+    pub async fn stake_v2(
+        &mut self,
+        context: &mut ProgramTestContext,
+        owner: &Keypair,
+        staking_authority: &Keypair,
+        asset: &LeafSchema,
+    ) -> Result<LeafSchema, BanksClientError> {
+        let (tree_config, _) = TreeConfig::find_pda(&self.tree.pubkey());
+        let (stake_record, _) = StakeRecord::find_pda(&self.tree.pubkey(), asset.nonce());
+        let proof = self.build_proof(asset.nonce() as u32);
+
+        let ix = StakeV2Builder::new()
+            .tree_config(tree_config)
+            .leaf_owner(owner.pubkey())
+            .staking_authority(staking_authority.pubkey())
+            .merkle_tree(self.tree.pubkey())
+            .stake_record(stake_record)
+            .root(self.proof_tree.root)
+            .data_hash(asset.data_hash())
+            .creator_hash(asset.creator_hash())
+            .collection_hash(asset.collection_hash())
+            .asset_data_hash(asset.asset_data_hash())
+            .flags(asset.flags())
+            .nonce(asset.nonce())
+            .index(asset.nonce() as u32)
+            .add_remaining_accounts(&proof)
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&context.payer.pubkey()),
+            &[owner, staking_authority, &context.payer],
+            context.last_blockhash,
+        );
+
+        let result = context
+            .banks_client
+            .process_transaction_with_metadata(tx)
+            .await?;
+        result.result.map_err(BanksClientError::TransactionError)?;
+
+        // on success, we update the leaf in the proof tree with the one returned
+        // by the program
+
+        let return_data = result
+            .metadata
+            .and_then(|metadata| metadata.return_data)
+            .expect("missing return data");
+        let update = LeafUpdate::from_return_data(&return_data.data).unwrap();
+
+        self.proof_tree
+            .add_leaf(update.leaf.hash(), update.index as usize);
+
+        Ok(update.leaf)
+    }
+
+    /// Unstakes an asset, signed by `staking_authority`.  The rent of the stake record goes back
+    /// to the owner of the asset.
+    pub async fn unstake_v2(
+        &mut self,
+        context: &mut ProgramTestContext,
+        staking_authority: &Keypair,
+        asset: &LeafSchema,
+    ) -> Result<LeafSchema, BanksClientError> {
+        let (tree_config, _) = TreeConfig::find_pda(&self.tree.pubkey());
+        let (stake_record, _) = StakeRecord::find_pda(&self.tree.pubkey(), asset.nonce());
+        let proof = self.build_proof(asset.nonce() as u32);
+
+        let ix = UnstakeV2Builder::new()
+            .tree_config(tree_config)
+            .leaf_owner(asset.owner())
+            .staking_authority(staking_authority.pubkey())
+            .merkle_tree(self.tree.pubkey())
+            .stake_record(stake_record)
+            .root(self.proof_tree.root)
+            .data_hash(asset.data_hash())
+            .creator_hash(asset.creator_hash())
+            .collection_hash(asset.collection_hash())
+            .asset_data_hash(asset.asset_data_hash())
+            .flags(asset.flags())
+            .nonce(asset.nonce())
+            .index(asset.nonce() as u32)
+            .add_remaining_accounts(&proof)
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&context.payer.pubkey()),
+            &[staking_authority, &context.payer],
+            context.last_blockhash,
+        );
+
+        let result = context
+            .banks_client
+            .process_transaction_with_metadata(tx)
+            .await?;
+        result.result.map_err(BanksClientError::TransactionError)?;
+
+        // on success, we update the leaf in the proof tree with the one returned
+        // by the program
+
+        let return_data = result
+            .metadata
+            .and_then(|metadata| metadata.return_data)
+            .expect("missing return data");
+        let update = LeafUpdate::from_return_data(&return_data.data).unwrap();
+
+        self.proof_tree
+            .add_leaf(update.leaf.hash(), update.index as usize);
+
+        Ok(update.leaf)
+    }
+
+    pub async fn revoke_v2(
+        &mut self,
+        context: &mut ProgramTestContext,
+        asset: &LeafSchema,
+    ) -> Result<LeafSchema, BanksClientError> {
+        let (tree_config, _) = TreeConfig::find_pda(&self.tree.pubkey());
+        let proof = self.build_proof(asset.nonce() as u32);
+
+        let ix = RevokeV2Builder::new()
+            .tree_config(tree_config)
+            .payer(context.payer.pubkey())
+            .leaf_owner(asset.owner())
+            .leaf_delegate(Some(asset.delegate()))
+            .merkle_tree(self.tree.pubkey())
+            .root(self.proof_tree.root)
+            .data_hash(asset.data_hash())
+            .creator_hash(asset.creator_hash())
+            .asset_data_hash(asset.asset_data_hash())
+            .flags(asset.flags())
+            .nonce(asset.nonce())
+            .index(asset.nonce() as u32)
+            .add_remaining_accounts(&proof)
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+
+        let result = context
+            .banks_client
+            .process_transaction_with_metadata(tx)
+            .await?;
+        result.result.map_err(BanksClientError::TransactionError)?;
+
+        // on success, we update the leaf in the proof tree with the one returned
+        // by the program
+
+        let return_data = result
+            .metadata
+            .and_then(|metadata| metadata.return_data)
+            .expect("missing return data");
+        let update = LeafUpdate::from_return_data(&return_data.data).unwrap();
+
+        self.proof_tree
+            .add_leaf(update.leaf.hash(), update.index as usize);
+
+        Ok(update.leaf)
+    }
+
     pub async fn burn_v2(
         &mut self,
         context: &mut ProgramTestContext,
@@ -360,6 +725,7 @@ impl<const MAX_DEPTH: usize, const MAX_BUFFER_SIZE: usize> TreeManager<MAX_DEPTH
             .root(self.proof_tree.root)
             .data_hash(asset.data_hash())
             .creator_hash(asset.creator_hash())
+            .flags(asset.flags())
             .nonce(asset.nonce())
             .index(asset.nonce() as u32)
             .add_remaining_accounts(&proof)
@@ -382,6 +748,739 @@ impl<const MAX_DEPTH: usize, const MAX_BUFFER_SIZE: usize> TreeManager<MAX_DEPTH
         Ok(())
     }
 
+    /// Verifies that an asset is in the tree and returns its asset id.
+    pub async fn verify_leaf_v2(
+        &self,
+        context: &mut ProgramTestContext,
+        asset: &LeafSchema,
+        require_not_frozen: bool,
+    ) -> Result<Pubkey, BanksClientError> {
+        let (tree_config, _) = TreeConfig::find_pda(&self.tree.pubkey());
+        let proof = self.build_proof(asset.nonce() as u32);
+
+        let ix = VerifyLeafV2Builder::new()
+            .tree_config(tree_config)
+            .leaf_owner(asset.owner())
+            .leaf_delegate(Some(asset.delegate()))
+            .merkle_tree(self.tree.pubkey())
+            .root(self.proof_tree.root)
+            .data_hash(asset.data_hash())
+            .creator_hash(asset.creator_hash())
+            .collection_hash(asset.collection_hash())
+            .asset_data_hash(asset.asset_data_hash())
+            .flags(asset.flags())
+            .nonce(asset.nonce())
+            .index(asset.nonce() as u32)
+            .require_not_frozen(require_not_frozen)
+            .add_remaining_accounts(&proof)
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+
+        let result = context
+            .banks_client
+            .process_transaction_with_metadata(tx)
+            .await?;
+        result.result.map_err(BanksClientError::TransactionError)?;
+
+        // on success, the program returns the asset id; trailing zeros are
+        // trimmed from the return data
+
+        let mut return_data = result
+            .metadata
+            .and_then(|metadata| metadata.return_data)
+            .expect("missing return data")
+            .data;
+        return_data.resize(32, 0);
+
+        Ok(Pubkey::try_from(return_data.as_slice()).unwrap())
+    }
+
+    pub async fn set_tree_plugins(
+        &mut self,
+        context: &mut ProgramTestContext,
+        permanent_freeze_delegate: Option<Pubkey>,
+    ) -> Result<(), BanksClientError> {
+        let (tree_config, _) = TreeConfig::find_pda(&self.tree.pubkey());
+        let (tree_plugins, _) = TreePlugins::find_pda(&self.tree.pubkey());
+
+        let ix = SetTreePluginsBuilder::new()
+            .tree_config(tree_config)
+            .payer(context.payer.pubkey())
+            .tree_creator(context.payer.pubkey())
+            .merkle_tree(self.tree.pubkey())
+            .tree_plugins(tree_plugins)
+            .royalties_rule_set(TreeRuleSet::None)
+            .permanent_freeze_delegate(permanent_freeze_delegate)
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    /// Sets the tree-level plugins with only a permanent transfer delegate.
+    pub async fn set_tree_permanent_transfer_delegate(
+        &mut self,
+        context: &mut ProgramTestContext,
+        permanent_transfer_delegate: Pubkey,
+    ) -> Result<(), BanksClientError> {
+        let (tree_config, _) = TreeConfig::find_pda(&self.tree.pubkey());
+        let (tree_plugins, _) = TreePlugins::find_pda(&self.tree.pubkey());
+
+        let ix = SetTreePluginsBuilder::new()
+            .tree_config(tree_config)
+            .payer(context.payer.pubkey())
+            .tree_creator(context.payer.pubkey())
+            .merkle_tree(self.tree.pubkey())
+            .tree_plugins(tree_plugins)
+            .royalties_rule_set(TreeRuleSet::None)
+            .permanent_freeze_delegate(None)
+            .permanent_transfer_delegate(Some(permanent_transfer_delegate))
+            .permanent_burn_delegate(None)
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    /// Transfers an asset as the tree permanent transfer delegate, passing the tree plugins
+    /// account ahead of the proof.
+    pub async fn force_transfer_v2(
+        &mut self,
+        context: &mut ProgramTestContext,
+        delegate: &Keypair,
+        receiver: Pubkey,
+        asset: &LeafSchema,
+    ) -> Result<LeafSchema, BanksClientError> {
+        let (tree_config, _) = TreeConfig::find_pda(&self.tree.pubkey());
+        let (tree_plugins, _) = TreePlugins::find_pda(&self.tree.pubkey());
+        let proof = self.build_proof(asset.nonce() as u32);
+
+        let ix = TransferV2Builder::new()
+            .tree_config(tree_config)
+            .payer(context.payer.pubkey())
+            .authority(Some(delegate.pubkey()))
+            .leaf_owner(asset.owner())
+            .leaf_delegate(Some(asset.delegate()))
+            .new_leaf_owner(receiver)
+            .merkle_tree(self.tree.pubkey())
+            .root(self.proof_tree.root)
+            .data_hash(asset.data_hash())
+            .creator_hash(asset.creator_hash())
+            .asset_data_hash(asset.asset_data_hash())
+            .flags(asset.flags())
+            .nonce(asset.nonce())
+            .index(asset.nonce() as u32)
+            .add_remaining_account(AccountMeta::new_readonly(tree_plugins, false))
+            .add_remaining_accounts(&proof)
+            .instruction();
+
+        self.process_leaf_update(context, ix, delegate).await
+    }
+
+    /// Freezes (or thaws) an asset as the tree permanent freeze delegate, passing the tree
+    /// plugins account ahead of the proof.
+    pub async fn permanent_freeze_v2(
+        &mut self,
+        context: &mut ProgramTestContext,
+        delegate: &Keypair,
+        asset: &LeafSchema,
+        frozen: bool,
+    ) -> Result<LeafSchema, BanksClientError> {
+        let (tree_config, _) = TreeConfig::find_pda(&self.tree.pubkey());
+        let (tree_plugins, _) = TreePlugins::find_pda(&self.tree.pubkey());
+        let proof = self.build_proof(asset.nonce() as u32);
+
+        let ix = if frozen {
+            FreezeV2Builder::new()
+                .tree_config(tree_config)
+                .payer(context.payer.pubkey())
+                .authority(Some(delegate.pubkey()))
+                .leaf_owner(asset.owner())
+                .leaf_delegate(asset.delegate())
+                .merkle_tree(self.tree.pubkey())
+                .root(self.proof_tree.root)
+                .data_hash(asset.data_hash())
+                .creator_hash(asset.creator_hash())
+                .asset_data_hash(asset.asset_data_hash())
+                .flags(asset.flags())
+                .nonce(asset.nonce())
+                .index(asset.nonce() as u32)
+                .add_remaining_account(AccountMeta::new_readonly(tree_plugins, false))
+                .add_remaining_accounts(&proof)
+                .instruction()
+        } else {
+            ThawV2Builder::new()
+                .tree_config(tree_config)
+                .payer(context.payer.pubkey())
+                .authority(Some(delegate.pubkey()))
+                .leaf_owner(asset.owner())
+                .leaf_delegate(asset.delegate())
+                .merkle_tree(self.tree.pubkey())
+                .root(self.proof_tree.root)
+                .data_hash(asset.data_hash())
+                .creator_hash(asset.creator_hash())
+                .asset_data_hash(asset.asset_data_hash())
+                .flags(asset.flags())
+                .nonce(asset.nonce())
+                .index(asset.nonce() as u32)
+                .add_remaining_account(AccountMeta::new_readonly(tree_plugins, false))
+                .add_remaining_accounts(&proof)
+                .instruction()
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&context.payer.pubkey()),
+            &[delegate, &context.payer],
+            context.last_blockhash,
+        );
+
+        let result = context
+            .banks_client
+            .process_transaction_with_metadata(tx)
+            .await?;
+        result.result.map_err(BanksClientError::TransactionError)?;
+
+        // on success, we update the leaf in the proof tree with the one returned
+        // by the program
+
+        let return_data = result
+            .metadata
+            .and_then(|metadata| metadata.return_data)
+            .expect("missing return data");
+        let update = LeafUpdate::from_return_data(&return_data.data).unwrap();
+
+        self.proof_tree
+            .add_leaf(update.leaf.hash(), update.index as usize);
+
+        Ok(update.leaf)
+    }
+
+    /// Sets the non-transferable flag of a collection asset as the permanent freeze delegate of
+    /// the collection.
+    pub async fn set_non_transferable_v2(
+        &mut self,
+        context: &mut ProgramTestContext,
+        authority: &Keypair,
+        asset: &LeafSchema,
+        core_collection: Pubkey,
+    ) -> Result<LeafSchema, BanksClientError> {
+        let (tree_config, _) = TreeConfig::find_pda(&self.tree.pubkey());
+        let proof = self.build_proof(asset.nonce() as u32);
+
+        let ix = SetNonTransferableV2Builder::new()
+            .tree_config(tree_config)
+            .payer(context.payer.pubkey())
+            .authority(Some(authority.pubkey()))
+            .leaf_owner(asset.owner())
+            .leaf_delegate(Some(asset.delegate()))
+            .merkle_tree(self.tree.pubkey())
+            .core_collection(core_collection)
+            .root(self.proof_tree.root)
+            .data_hash(asset.data_hash())
+            .creator_hash(asset.creator_hash())
+            .asset_data_hash(asset.asset_data_hash())
+            .flags(asset.flags())
+            .nonce(asset.nonce())
+            .index(asset.nonce() as u32)
+            .add_remaining_accounts(&proof)
+            .instruction();
+
+        self.process_leaf_update(context, ix, authority).await
+    }
+
+    pub async fn recover_non_transferable_v2(
+        &mut self,
+        context: &mut ProgramTestContext,
+        authority: &Keypair,
+        asset: &LeafSchema,
+        core_collection: Pubkey,
+        new_leaf_owner: Pubkey,
+    ) -> Result<LeafSchema, BanksClientError> {
+        let (tree_config, _) = TreeConfig::find_pda(&self.tree.pubkey());
+        let proof = self.build_proof(asset.nonce() as u32);
+
+        let ix = RecoverNonTransferableV2Builder::new()
+            .tree_config(tree_config)
+            .payer(context.payer.pubkey())
+            .authority(Some(authority.pubkey()))
+            .leaf_owner(asset.owner())
+            .leaf_delegate(Some(asset.delegate()))
+            .new_leaf_owner(new_leaf_owner)
+            .merkle_tree(self.tree.pubkey())
+            .core_collection(core_collection)
+            .root(self.proof_tree.root)
+            .data_hash(asset.data_hash())
+            .creator_hash(asset.creator_hash())
+            .asset_data_hash(asset.asset_data_hash())
+            .flags(asset.flags())
+            .nonce(asset.nonce())
+            .index(asset.nonce() as u32)
+            .reason(String::from("Lost wallet"))
+            .add_remaining_accounts(&proof)
+            .instruction();
+
+        self.process_leaf_update(context, ix, authority).await
+    }
+
+    /// Spends uses of an asset minted with `mint_usable_v2`, returning `None` if the asset was
+    /// burned after its last use.  `authority` is the leaf owner or delegate.
+    pub async fn utilize_v2(
+        &mut self,
+        context: &mut ProgramTestContext,
+        authority: &Keypair,
+        asset: &LeafSchema,
+        uses: Uses,
+        number_of_uses: u64,
+    ) -> Result<Option<LeafSchema>, BanksClientError> {
+        let (tree_config, _) = TreeConfig::find_pda(&self.tree.pubkey());
+        let proof = self.build_proof(asset.nonce() as u32);
+        let burned = uses.use_method == UseMethod::Burn && uses.remaining == number_of_uses;
+
+        let ix = UtilizeV2Builder::new()
+            .tree_config(tree_config)
+            .payer(context.payer.pubkey())
+            .authority(Some(authority.pubkey()))
+            .leaf_owner(asset.owner())
+            .leaf_delegate(Some(asset.delegate()))
+            .merkle_tree(self.tree.pubkey())
+            .root(self.proof_tree.root)
+            .data_hash(asset.data_hash())
+            .creator_hash(asset.creator_hash())
+            .flags(asset.flags())
+            .nonce(asset.nonce())
+            .index(asset.nonce() as u32)
+            .uses(uses)
+            .number_of_uses(number_of_uses)
+            .add_remaining_accounts(&proof)
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&context.payer.pubkey()),
+            &[authority, &context.payer],
+            context.last_blockhash,
+        );
+
+        let result = context
+            .banks_client
+            .process_transaction_with_metadata(tx)
+            .await?;
+        result.result.map_err(BanksClientError::TransactionError)?;
+
+        let return_data = result
+            .metadata
+            .and_then(|metadata| metadata.return_data)
+            .expect("missing return data");
+        let update = LeafUpdate::from_return_data(&return_data.data).unwrap();
+
+        // a leaf with `UseMethod::Burn` is returned with its last use spent, but it is no
+        // longer in the tree
+
+        if burned {
+            self.proof_tree
+                .add_leaf(Node::default(), update.index as usize);
+            Ok(None)
+        } else {
+            self.proof_tree
+                .add_leaf(update.leaf.hash(), update.index as usize);
+            Ok(Some(update.leaf))
+        }
+    }
+
+    /// Verifies every creator in `creators` with a single leaf update.  The creators sign the
+    /// transaction and are passed ahead of the proof.
+    pub async fn verify_creators_v2(
+        &mut self,
+        context: &mut ProgramTestContext,
+        creators: &[&Keypair],
+        asset: &LeafSchema,
+        metadata: MetadataArgsV2,
+    ) -> Result<LeafSchema, BanksClientError> {
+        let (tree_config, _) = TreeConfig::find_pda(&self.tree.pubkey());
+        let proof = self.build_proof(asset.nonce() as u32);
+
+        let signers: Vec<AccountMeta> = creators
+            .iter()
+            .map(|creator| AccountMeta::new_readonly(creator.pubkey(), true))
+            .collect();
+
+        let ix = VerifyCreatorsV2Builder::new()
+            .tree_config(tree_config)
+            .payer(context.payer.pubkey())
+            .leaf_owner(asset.owner())
+            .leaf_delegate(Some(asset.delegate()))
+            .merkle_tree(self.tree.pubkey())
+            .root(self.proof_tree.root)
+            .asset_data_hash(asset.asset_data_hash())
+            .flags(asset.flags())
+            .nonce(asset.nonce())
+            .index(asset.nonce() as u32)
+            .metadata(metadata)
+            .add_remaining_accounts(&signers)
+            .add_remaining_accounts(&proof)
+            .instruction();
+
+        self.process_leaf_update_with_signers(context, ix, creators)
+            .await
+    }
+
+    /// Replaces the address of `creator` in the metadata of an asset with `new_creator`.
+    pub async fn update_creator_v2(
+        &mut self,
+        context: &mut ProgramTestContext,
+        creator: &Keypair,
+        asset: &LeafSchema,
+        current_metadata: MetadataArgsV2,
+        new_creator: &Keypair,
+    ) -> Result<LeafSchema, BanksClientError> {
+        let (tree_config, _) = TreeConfig::find_pda(&self.tree.pubkey());
+        let proof = self.build_proof(asset.nonce() as u32);
+
+        let ix = UpdateCreatorV2Builder::new()
+            .tree_config(tree_config)
+            .payer(context.payer.pubkey())
+            .creator(Some(creator.pubkey()))
+            .new_creator(new_creator.pubkey())
+            .leaf_owner(asset.owner())
+            .leaf_delegate(Some(asset.delegate()))
+            .merkle_tree(self.tree.pubkey())
+            .root(self.proof_tree.root)
+            .asset_data_hash(asset.asset_data_hash())
+            .flags(asset.flags())
+            .nonce(asset.nonce())
+            .index(asset.nonce() as u32)
+            .current_metadata(current_metadata)
+            .add_remaining_accounts(&proof)
+            .instruction();
+
+        self.process_leaf_update_with_signers(context, ix, &[creator, new_creator])
+            .await
+    }
+
+    /// Applies `update_args` to the metadata of every asset in `assets`, passing one proof per
+    /// asset, and returns the updated leaves.
+    pub async fn batch_update_metadata_v2(
+        &mut self,
+        context: &mut ProgramTestContext,
+        authority: &Keypair,
+        assets: &[(LeafSchema, MetadataArgsV2)],
+        update_args: UpdateArgs,
+    ) -> Result<Vec<LeafSchema>, BanksClientError> {
+        let (tree_config, _) = TreeConfig::find_pda(&self.tree.pubkey());
+
+        let leaves = assets
+            .iter()
+            .map(|(asset, metadata)| LeafMetadataUpdate {
+                leaf_owner: asset.owner(),
+                leaf_delegate: Some(asset.delegate()),
+                asset_data_hash: Some(asset.asset_data_hash()),
+                flags: Some(asset.flags()),
+                nonce: asset.nonce(),
+                index: asset.nonce() as u32,
+                current_metadata: metadata.clone(),
+            })
+            .collect();
+
+        // the proofs in the same order as the leaves
+
+        let proofs: Vec<AccountMeta> = assets
+            .iter()
+            .flat_map(|(asset, _)| self.build_proof(asset.nonce() as u32))
+            .collect();
+
+        let ix = BatchUpdateMetadataV2Builder::new()
+            .tree_config(tree_config)
+            .payer(context.payer.pubkey())
+            .authority(Some(authority.pubkey()))
+            .merkle_tree(self.tree.pubkey())
+            .root(self.proof_tree.root)
+            .leaves(leaves)
+            .update_args(update_args.clone())
+            .add_remaining_accounts(&proofs)
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&context.payer.pubkey()),
+            &[authority, &context.payer],
+            context.last_blockhash,
+        );
+
+        let result = context
+            .banks_client
+            .process_transaction_with_metadata(tx)
+            .await?;
+        result.result.map_err(BanksClientError::TransactionError)?;
+
+        // on success, we update the leaves in the proof tree
+
+        let mut updated = Vec::with_capacity(assets.len());
+
+        for (asset, metadata) in assets {
+            let mut metadata = metadata.clone();
+            if let Some(name) = &update_args.name {
+                metadata.name = name.clone();
+            }
+            if let Some(symbol) = &update_args.symbol {
+                metadata.symbol = symbol.clone();
+            }
+            if let Some(uri) = &update_args.uri {
+                metadata.uri = uri.clone();
+            }
+            if let Some(creators) = &update_args.creators {
+                metadata.creators = creators.clone();
+            }
+            if let Some(seller_fee_basis_points) = update_args.seller_fee_basis_points {
+                metadata.seller_fee_basis_points = seller_fee_basis_points;
+            }
+            if let Some(primary_sale_happened) = update_args.primary_sale_happened {
+                metadata.primary_sale_happened = primary_sale_happened;
+            }
+            if let Some(is_mutable) = update_args.is_mutable {
+                metadata.is_mutable = is_mutable;
+            }
+
+            let leaf = LeafSchema::V2 {
+                id: asset.id(),
+                owner: asset.owner(),
+                delegate: asset.delegate(),
+                nonce: asset.nonce(),
+                data_hash: hash_metadata(&metadata).unwrap(),
+                creator_hash: hash_creators(&metadata.creators),
+                collection_hash: asset.collection_hash(),
+                asset_data_hash: asset.asset_data_hash(),
+                flags: asset.flags(),
+            };
+
+            self.proof_tree
+                .add_leaf(leaf.hash(), asset.nonce() as usize);
+            updated.push(leaf);
+        }
+
+        // the program returns the update of the first leaf
+
+        let return_data = result
+            .metadata
+            .and_then(|metadata| metadata.return_data)
+            .expect("missing return data");
+        let update = LeafUpdate::from_return_data(&return_data.data).unwrap();
+
+        assert_eq!(update.leaf, updated[0]);
+        assert_eq!(update.index, updated[0].nonce() as u32);
+
+        Ok(updated)
+    }
+
+    /// Splits `split_amount` off a fungible asset leaf holding `amount` into a new leaf owned by
+    /// `new_owner`, returning the remaining leaf and the split off leaf.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn split_v2(
+        &mut self,
+        context: &mut ProgramTestContext,
+        owner: &Keypair,
+        asset: &LeafSchema,
+        class_id: Pubkey,
+        amount: u64,
+        split_amount: u64,
+        new_owner: Pubkey,
+    ) -> Result<(LeafSchema, LeafSchema), BanksClientError> {
+        let (tree_config, _) = TreeConfig::find_pda(&self.tree.pubkey());
+        let proof = self.build_proof(asset.nonce() as u32);
+
+        let split_ix = SplitV2Builder::new()
+            .tree_config(tree_config)
+            .payer(context.payer.pubkey())
+            .authority(Some(owner.pubkey()))
+            .leaf_owner(owner.pubkey())
+            .leaf_delegate(Some(asset.delegate()))
+            .new_leaf_owner(new_owner)
+            .merkle_tree(self.tree.pubkey())
+            .root(self.proof_tree.root)
+            .data_hash(asset.data_hash())
+            .creator_hash(asset.creator_hash())
+            .flags(asset.flags())
+            .nonce(asset.nonce())
+            .index(asset.nonce() as u32)
+            .class_id(class_id)
+            .amount(amount)
+            .split_amount(split_amount)
+            .add_remaining_accounts(&proof)
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[split_ix],
+            Some(&context.payer.pubkey()),
+            &[owner, &context.payer],
+            context.last_blockhash,
+        );
+
+        let result = context
+            .banks_client
+            .process_transaction_with_metadata(tx)
+            .await?;
+        result.result.map_err(BanksClientError::TransactionError)?;
+
+        // on success, we update the leaf in the proof tree and append
+        // the split off leaf, which has no delegate
+
+        let leaf = LeafSchema::V2 {
+            id: asset.id(),
+            owner: asset.owner(),
+            delegate: asset.delegate(),
+            nonce: asset.nonce(),
+            data_hash: asset.data_hash(),
+            creator_hash: asset.creator_hash(),
+            collection_hash: asset.collection_hash(),
+            asset_data_hash: hash_amount(&class_id, amount - split_amount),
+            flags: asset.flags(),
+        };
+        let return_data = result
+            .metadata
+            .and_then(|metadata| metadata.return_data)
+            .expect("missing return data");
+        let update = LeafUpdate::from_return_data(&return_data.data).unwrap();
+
+        assert_eq!(update.leaf, leaf);
+        assert_eq!(update.index, asset.nonce() as u32);
+
+        self.proof_tree
+            .add_leaf(leaf.hash(), asset.nonce() as usize);
+
+        let split_leaf = LeafSchema::V2 {
+            id: get_asset_id(&self.tree.pubkey(), self.minted),
+            owner: new_owner,
+            delegate: new_owner,
+            nonce: self.minted,
+            data_hash: asset.data_hash(),
+            creator_hash: asset.creator_hash(),
+            collection_hash: asset.collection_hash(),
+            asset_data_hash: hash_amount(&class_id, split_amount),
+            flags: asset.flags(),
+        };
+        self.proof_tree
+            .add_leaf(split_leaf.hash(), self.minted as usize);
+        self.minted += 1;
+
+        Ok((leaf, split_leaf))
+    }
+
+    /// Merges the fungible asset leaf `other` holding `other_amount` into `asset` holding
+    /// `amount`, returning the merged leaf.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn merge_v2(
+        &mut self,
+        context: &mut ProgramTestContext,
+        owner: &Keypair,
+        asset: &LeafSchema,
+        amount: u64,
+        other: &LeafSchema,
+        other_amount: u64,
+        class_id: Pubkey,
+    ) -> Result<LeafSchema, BanksClientError> {
+        let (tree_config, _) = TreeConfig::find_pda(&self.tree.pubkey());
+
+        // the proof of `asset` followed by the proof of `other`
+
+        let mut proof = self.build_proof(asset.nonce() as u32);
+        proof.extend(self.build_proof(other.nonce() as u32));
+
+        let ix = MergeV2Builder::new()
+            .tree_config(tree_config)
+            .payer(context.payer.pubkey())
+            .authority(Some(owner.pubkey()))
+            .leaf_owner(owner.pubkey())
+            .leaf_delegate(Some(asset.delegate()))
+            .merkle_tree(self.tree.pubkey())
+            .root(self.proof_tree.root)
+            .data_hash(asset.data_hash())
+            .creator_hash(asset.creator_hash())
+            .flags(asset.flags())
+            .nonce(asset.nonce())
+            .index(asset.nonce() as u32)
+            .class_id(class_id)
+            .amount(amount)
+            .other_nonce(other.nonce())
+            .other_index(other.nonce() as u32)
+            .other_amount(other_amount)
+            .add_remaining_accounts(&proof)
+            .instruction();
+
+        let leaf = self.process_leaf_update(context, ix, owner).await?;
+
+        // the merged leaf is removed from the tree
+
+        self.proof_tree
+            .add_leaf(Node::default(), other.nonce() as usize);
+
+        Ok(leaf)
+    }
+
+    /// Sends an instruction that updates a leaf, signed by `authority` and the payer, and stores
+    /// the updated leaf returned by the program in the proof tree.
+    async fn process_leaf_update(
+        &mut self,
+        context: &mut ProgramTestContext,
+        ix: Instruction,
+        authority: &Keypair,
+    ) -> Result<LeafSchema, BanksClientError> {
+        self.process_leaf_update_with_signers(context, ix, &[authority])
+            .await
+    }
+
+    /// Same as `process_leaf_update`, signed by every keypair in `signers` and the payer.
+    async fn process_leaf_update_with_signers(
+        &mut self,
+        context: &mut ProgramTestContext,
+        ix: Instruction,
+        signers: &[&Keypair],
+    ) -> Result<LeafSchema, BanksClientError> {
+        let mut signers = signers.to_vec();
+        signers.push(&context.payer);
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&context.payer.pubkey()),
+            signers.as_slice(),
+            context.last_blockhash,
+        );
+
+        let result = context
+            .banks_client
+            .process_transaction_with_metadata(tx)
+            .await?;
+        result.result.map_err(BanksClientError::TransactionError)?;
+
+        let return_data = result
+            .metadata
+            .and_then(|metadata| metadata.return_data)
+            .expect("missing return data");
+        let update = LeafUpdate::from_return_data(&return_data.data).unwrap();
+
+        self.proof_tree
+            .add_leaf(update.leaf.hash(), update.index as usize);
+
+        Ok(update.leaf)
+    }
+
     pub async fn assert_root(&self, context: &mut ProgramTestContext) {
         let mut tree_account = get_account(context, &self.tree.pubkey()).await;
         let merkle_tree = tree_account.data.as_mut_slice();
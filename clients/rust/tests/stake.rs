@@ -0,0 +1,210 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+pub use setup::*;
+
+use mpl_bubblegum::{
+    accounts::StakeRecord,
+    errors::MplBubblegumError,
+    types::{Creator, MetadataArgsV2, TokenStandard},
+    Flags,
+};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::tokio;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signature::Signer;
+
+mod stake {
+
+    use super::*;
+
+    fn metadata(creator: Pubkey) -> MetadataArgsV2 {
+        MetadataArgsV2 {
+            name: String::from("cNFT"),
+            symbol: String::from("cNFT"),
+            uri: String::from("https://c.nft"),
+            seller_fee_basis_points: 500,
+            primary_sale_happened: true,
+            is_mutable: true,
+            token_standard: Some(TokenStandard::NonFungible),
+            creators: vec![Creator {
+                address: creator,
+                share: 100,
+                verified: false,
+            }],
+            collection: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn stake_and_unstake_asset() {
+        let mut program_test = create_program_test();
+        program_test.set_compute_max_units(400_000);
+        let mut context = program_test.start_with_context().await;
+
+        // Given a new merkle tree.
+
+        let mut tree_manager = TreeManager::<5, 8>::default();
+        tree_manager.create_v2(&mut context).await.unwrap();
+
+        // And a minted cNFT.
+
+        let owner = Keypair::new();
+        let metadata = metadata(context.payer.pubkey());
+
+        let asset = tree_manager
+            .mint_v2(&mut context, owner.pubkey(), metadata)
+            .await
+            .unwrap();
+
+        // When staking the cNFT.
+
+        let (stake_record, _) = StakeRecord::find_pda(&tree_manager.tree.pubkey(), asset.nonce());
+        let staking_authority = Keypair::new();
+
+        // The owner pays for the stake record.
+        airdrop(&mut context, &owner.pubkey(), 1_000_000_000).await;
+
+        let staked = tree_manager
+            .stake_v2(&mut context, &owner, &staking_authority, &asset)
+            .await
+            .unwrap();
+
+        // Then the cNFT stays with its owner, frozen and delegated to the stake record.
+
+        assert_eq!(staked.owner(), owner.pubkey());
+        assert_eq!(staked.delegate(), stake_record);
+        assert!(Flags::from_bytes([staked.flags()]).asset_lvl_frozen());
+
+        let record_account = get_account(&mut context, &stake_record).await;
+        let record = StakeRecord::from_bytes(&record_account.data).unwrap();
+        assert_eq!(record.merkle_tree, tree_manager.tree.pubkey());
+        assert_eq!(record.owner, owner.pubkey());
+        assert_eq!(record.authority, staking_authority.pubkey());
+        assert_eq!(record.nonce, asset.nonce());
+
+        tree_manager.assert_root(&mut context).await;
+
+        // And when the staking authority unstakes the cNFT.
+
+        let unstaked = tree_manager
+            .unstake_v2(&mut context, &staking_authority, &staked)
+            .await
+            .unwrap();
+
+        // Then the cNFT is thawed, its delegate is revoked and the stake record is closed.
+
+        assert_eq!(unstaked.owner(), owner.pubkey());
+        assert_eq!(unstaked.delegate(), owner.pubkey());
+        assert_eq!(unstaked.flags(), asset.flags());
+        assert!(find_account(&mut context, &stake_record).await.is_none());
+
+        tree_manager.assert_root(&mut context).await;
+    }
+
+    #[tokio::test]
+    async fn unstake_requires_staking_authority() {
+        let mut program_test = create_program_test();
+        program_test.set_compute_max_units(400_000);
+        let mut context = program_test.start_with_context().await;
+
+        // Given a staked cNFT.
+
+        let mut tree_manager = TreeManager::<5, 8>::default();
+        tree_manager.create_v2(&mut context).await.unwrap();
+
+        let owner = Keypair::new();
+        let asset = tree_manager
+            .mint_v2(
+                &mut context,
+                owner.pubkey(),
+                metadata(context.payer.pubkey()),
+            )
+            .await
+            .unwrap();
+
+        airdrop(&mut context, &owner.pubkey(), 1_000_000_000).await;
+
+        let staked = tree_manager
+            .stake_v2(&mut context, &owner, &Keypair::new(), &asset)
+            .await
+            .unwrap();
+
+        // When the owner unstakes the cNFT on its own.
+
+        let error = tree_manager
+            .unstake_v2(&mut context, &owner, &staked)
+            .await
+            .unwrap_err();
+
+        // Then it fails, since only the staking authority can unstake.
+
+        assert_custom_instruction_error!(0, error, MplBubblegumError::InvalidAuthority);
+        tree_manager.assert_root(&mut context).await;
+    }
+
+    #[tokio::test]
+    async fn permanent_transfer_delegate_cannot_move_staked_asset() {
+        let mut program_test = create_program_test();
+        program_test.set_compute_max_units(400_000);
+        let mut context = program_test.start_with_context().await;
+
+        // Given a tree with a permanent transfer delegate.
+
+        let mut tree_manager = TreeManager::<5, 8>::default();
+        tree_manager.create_v2(&mut context).await.unwrap();
+
+        let transfer_delegate = Keypair::new();
+        tree_manager
+            .set_tree_permanent_transfer_delegate(&mut context, transfer_delegate.pubkey())
+            .await
+            .unwrap();
+
+        // And a staked cNFT.
+
+        let owner = Keypair::new();
+        let asset = tree_manager
+            .mint_v2(
+                &mut context,
+                owner.pubkey(),
+                metadata(context.payer.pubkey()),
+            )
+            .await
+            .unwrap();
+
+        airdrop(&mut context, &owner.pubkey(), 1_000_000_000).await;
+
+        let staking_authority = Keypair::new();
+        let staked = tree_manager
+            .stake_v2(&mut context, &owner, &staking_authority, &asset)
+            .await
+            .unwrap();
+
+        // When the permanent transfer delegate moves the cNFT.
+
+        let receiver = Keypair::new().pubkey();
+        let error = tree_manager
+            .force_transfer_v2(&mut context, &transfer_delegate, receiver, &staked)
+            .await
+            .unwrap_err();
+
+        // Then it fails, since the stake record could then never be closed.
+
+        assert_custom_instruction_error!(0, error, MplBubblegumError::AssetIsStaked);
+        tree_manager.assert_root(&mut context).await;
+
+        // And it can move the cNFT once unstaked.
+
+        let unstaked = tree_manager
+            .unstake_v2(&mut context, &staking_authority, &staked)
+            .await
+            .unwrap();
+
+        let transferred = tree_manager
+            .force_transfer_v2(&mut context, &transfer_delegate, receiver, &unstaked)
+            .await
+            .unwrap();
+
+        assert_eq!(transferred.owner(), receiver);
+        tree_manager.assert_root(&mut context).await;
+    }
+}
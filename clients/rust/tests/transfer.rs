@@ -2,10 +2,13 @@
 pub mod setup;
 pub use setup::*;
 
-use mpl_bubblegum::types::{
-    Creator, MetadataArgs, MetadataArgsV2, TokenProgramVersion, TokenStandard,
+use mpl_bubblegum::{
+    accounts::{FeeConfig, TreeConfig},
+    types::{Creator, MetadataArgs, MetadataArgsV2, TokenProgramVersion, TokenStandard},
 };
+use solana_program::instruction::AccountMeta;
 use solana_program_test::tokio;
+use solana_sdk::account::AccountSharedData;
 use solana_sdk::signature::Keypair;
 use solana_sdk::signature::Signer;
 
@@ -143,4 +146,96 @@ mod transfer {
 
         tree_manager.assert_root(&mut context).await;
     }
+
+    #[tokio::test]
+    async fn transfer_v2_fees() {
+        let mut program_test = create_program_test();
+        program_test.set_compute_max_units(400_000);
+        let mut context = program_test.start_with_context().await;
+
+        // Given a new merkle tree.
+
+        let mut tree_manager = TreeManager::<5, 8>::default();
+        tree_manager.create_v2(&mut context).await.unwrap();
+
+        // And a minted cNFT.
+
+        let owner = Keypair::new();
+
+        let metadata = MetadataArgsV2 {
+            name: String::from("cNFT"),
+            symbol: String::from("cNFT"),
+            uri: String::from("https://c.nft"),
+            seller_fee_basis_points: 500,
+            primary_sale_happened: true,
+            is_mutable: true,
+            token_standard: Some(TokenStandard::NonFungible),
+            creators: vec![Creator {
+                address: context.payer.pubkey(),
+                share: 100,
+                verified: false,
+            }],
+            collection: None,
+        };
+
+        let asset = tree_manager
+            .mint_v2(&mut context, owner.pubkey(), metadata)
+            .await
+            .unwrap();
+
+        let (tree_config, _) = TreeConfig::find_pda(&tree_manager.tree.pubkey());
+        let balance = get_account(&mut context, &tree_config).await.lamports;
+
+        // When transferring the cNFT with the original account layout, without a fee config.
+
+        let receiver = Keypair::new();
+        let asset = tree_manager
+            .transfer_v2(&mut context, &owner, receiver.pubkey(), &asset)
+            .await
+            .unwrap();
+
+        // Then the default fee is paid into the tree config.
+
+        let default_fee = FeeConfig::default().transfer_v2_fee_lamports;
+        let new_balance = get_account(&mut context, &tree_config).await.lamports;
+        assert_eq!(new_balance - balance, default_fee);
+
+        // And given a fee config with a lower transfer fee.
+
+        let fee_config = FeeConfig {
+            transfer_v2_fee_lamports: default_fee / 2,
+            ..FeeConfig::default()
+        };
+        let data = borsh::to_vec(&fee_config).unwrap();
+        let rent = context.banks_client.get_rent().await.unwrap();
+        let mut account = AccountSharedData::new(
+            rent.minimum_balance(data.len()),
+            data.len(),
+            &mpl_bubblegum::ID,
+        );
+        account.set_data_from_slice(&data);
+        let (fee_config_pda, _) = FeeConfig::find_pda();
+        context.set_account(&fee_config_pda, &account);
+
+        // When transferring the cNFT with the fee config ahead of the proof.
+
+        tree_manager
+            .transfer_v2_with_optional_accounts(
+                &mut context,
+                &receiver,
+                owner.pubkey(),
+                &asset,
+                &[AccountMeta::new_readonly(fee_config_pda, false)],
+            )
+            .await
+            .unwrap();
+
+        // Then the configured fee is paid instead.
+
+        let balance = new_balance;
+        let new_balance = get_account(&mut context, &tree_config).await.lamports;
+        assert_eq!(new_balance - balance, fee_config.transfer_v2_fee_lamports);
+
+        tree_manager.assert_root(&mut context).await;
+    }
 }
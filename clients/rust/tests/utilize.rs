@@ -0,0 +1,181 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+pub use setup::*;
+
+use mpl_bubblegum::errors::MplBubblegumError;
+use mpl_bubblegum::hash::hash_uses;
+use mpl_bubblegum::types::{Creator, MetadataArgsV2, TokenStandard, UseMethod, Uses};
+use solana_program_test::tokio;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signature::Signer;
+
+mod utilize {
+
+    use super::*;
+
+    fn metadata(creator: &Keypair) -> MetadataArgsV2 {
+        MetadataArgsV2 {
+            name: String::from("Ticket"),
+            symbol: String::from("TKT"),
+            uri: String::from("https://c.nft"),
+            seller_fee_basis_points: 500,
+            primary_sale_happened: true,
+            is_mutable: false,
+            token_standard: Some(TokenStandard::NonFungible),
+            creators: vec![Creator {
+                address: creator.pubkey(),
+                share: 100,
+                verified: false,
+            }],
+            collection: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn utilize_burns_asset_after_last_use() {
+        let mut program_test = create_program_test();
+        program_test.set_compute_max_units(400_000);
+        let mut context = program_test.start_with_context().await;
+
+        // Given a usable cNFT with two uses that burns on its last use.
+
+        let mut tree_manager = TreeManager::<5, 8>::default();
+        tree_manager.create_v2(&mut context).await.unwrap();
+
+        let owner = Keypair::new();
+        let uses = Uses {
+            use_method: UseMethod::Burn,
+            remaining: 2,
+            total: 2,
+        };
+
+        let asset = tree_manager
+            .mint_usable_v2(&mut context, owner.pubkey(), metadata(&owner), uses.clone())
+            .await
+            .unwrap();
+
+        // When the owner spends one use.
+
+        let used = tree_manager
+            .utilize_v2(&mut context, &owner, &asset, uses, 1)
+            .await
+            .unwrap()
+            .unwrap();
+
+        // Then the cNFT has one use remaining.
+
+        let uses = Uses {
+            use_method: UseMethod::Burn,
+            remaining: 1,
+            total: 2,
+        };
+        assert_eq!(used.asset_data_hash(), hash_uses(&uses).unwrap());
+        tree_manager.assert_root(&mut context).await;
+
+        // And is burned once the owner spends the last one.
+
+        let burned = tree_manager
+            .utilize_v2(&mut context, &owner, &used, uses, 1)
+            .await
+            .unwrap();
+
+        assert!(burned.is_none());
+        tree_manager.assert_root(&mut context).await;
+    }
+
+    #[tokio::test]
+    async fn utilize_requires_leaf_owner_or_delegate() {
+        let mut program_test = create_program_test();
+        program_test.set_compute_max_units(400_000);
+        let mut context = program_test.start_with_context().await;
+
+        // Given a usable cNFT.
+
+        let mut tree_manager = TreeManager::<5, 8>::default();
+        tree_manager.create_v2(&mut context).await.unwrap();
+
+        let owner = Keypair::new();
+        let uses = Uses {
+            use_method: UseMethod::Multiple,
+            remaining: 5,
+            total: 5,
+        };
+
+        let asset = tree_manager
+            .mint_usable_v2(&mut context, owner.pubkey(), metadata(&owner), uses.clone())
+            .await
+            .unwrap();
+
+        // When someone other than the owner or delegate spends a use.
+
+        let error = tree_manager
+            .utilize_v2(&mut context, &Keypair::new(), &asset, uses.clone(), 1)
+            .await
+            .unwrap_err();
+
+        // Then it fails.
+
+        assert_custom_instruction_error!(0, error, MplBubblegumError::InvalidAuthority);
+
+        // And the owner cannot spend more uses than remaining.
+
+        let error = tree_manager
+            .utilize_v2(&mut context, &owner, &asset, uses, 6)
+            .await
+            .unwrap_err();
+
+        assert_custom_instruction_error!(0, error, MplBubblegumError::NotEnoughUses);
+
+        tree_manager.assert_root(&mut context).await;
+    }
+
+    #[tokio::test]
+    async fn leaf_delegate_can_utilize() {
+        let mut program_test = create_program_test();
+        program_test.set_compute_max_units(400_000);
+        let mut context = program_test.start_with_context().await;
+
+        // Given a usable cNFT with a leaf delegate.
+
+        let mut tree_manager = TreeManager::<5, 8>::default();
+        tree_manager.create_v2(&mut context).await.unwrap();
+
+        let owner = Keypair::new();
+        let uses = Uses {
+            use_method: UseMethod::Multiple,
+            remaining: 5,
+            total: 5,
+        };
+
+        let asset = tree_manager
+            .mint_usable_v2(&mut context, owner.pubkey(), metadata(&owner), uses.clone())
+            .await
+            .unwrap();
+
+        let delegate = Keypair::new();
+        let delegated = tree_manager
+            .delegate_v2(&mut context, &owner, delegate.pubkey(), &asset)
+            .await
+            .unwrap();
+
+        // When the delegate spends a use as the approved use authority.
+
+        let used = tree_manager
+            .utilize_v2(&mut context, &delegate, &delegated, uses, 1)
+            .await
+            .unwrap()
+            .unwrap();
+
+        // Then the cNFT has one use less and keeps its owner and delegate.
+
+        let uses = Uses {
+            use_method: UseMethod::Multiple,
+            remaining: 4,
+            total: 5,
+        };
+        assert_eq!(used.asset_data_hash(), hash_uses(&uses).unwrap());
+        assert_eq!(used.owner(), owner.pubkey());
+        assert_eq!(used.delegate(), delegate.pubkey());
+        tree_manager.assert_root(&mut context).await;
+    }
+}
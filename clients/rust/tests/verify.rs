@@ -0,0 +1,152 @@
+#![cfg(feature = "test-sbf")]
+pub mod setup;
+pub use setup::*;
+
+use mpl_bubblegum::errors::MplBubblegumError;
+use mpl_bubblegum::types::{Creator, LeafSchema, MetadataArgsV2, TokenStandard};
+use solana_program_test::tokio;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signature::Signer;
+
+mod verify {
+
+    use super::*;
+
+    fn metadata(creator: &Keypair) -> MetadataArgsV2 {
+        MetadataArgsV2 {
+            name: String::from("cNFT"),
+            symbol: String::from("cNFT"),
+            uri: String::from("https://c.nft"),
+            seller_fee_basis_points: 500,
+            primary_sale_happened: true,
+            is_mutable: true,
+            token_standard: Some(TokenStandard::NonFungible),
+            creators: vec![Creator {
+                address: creator.pubkey(),
+                share: 100,
+                verified: false,
+            }],
+            collection: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn verify_leaf_returns_asset_id() {
+        let mut program_test = create_program_test();
+        program_test.set_compute_max_units(400_000);
+        let mut context = program_test.start_with_context().await;
+
+        // Given a minted cNFT.
+
+        let mut tree_manager = TreeManager::<5, 8>::default();
+        tree_manager.create_v2(&mut context).await.unwrap();
+
+        let owner = Keypair::new();
+        let asset = tree_manager
+            .mint_v2(&mut context, owner.pubkey(), metadata(&owner))
+            .await
+            .unwrap();
+
+        // When verifying the leaf.
+
+        let asset_id = tree_manager
+            .verify_leaf_v2(&mut context, &asset, true)
+            .await
+            .unwrap();
+
+        // Then it returns the asset id and leaves the tree unchanged.
+
+        assert_eq!(asset_id, asset.id());
+        tree_manager.assert_root(&mut context).await;
+    }
+
+    #[tokio::test]
+    async fn verify_leaf_with_invalid_owner() {
+        let mut program_test = create_program_test();
+        program_test.set_compute_max_units(400_000);
+        let mut context = program_test.start_with_context().await;
+
+        // Given a minted cNFT.
+
+        let mut tree_manager = TreeManager::<5, 8>::default();
+        tree_manager.create_v2(&mut context).await.unwrap();
+
+        let owner = Keypair::new();
+        let asset = tree_manager
+            .mint_v2(&mut context, owner.pubkey(), metadata(&owner))
+            .await
+            .unwrap();
+
+        // When verifying the leaf with another owner.
+
+        let attacker = Keypair::new();
+        let forged = LeafSchema::V2 {
+            id: asset.id(),
+            owner: attacker.pubkey(),
+            delegate: attacker.pubkey(),
+            nonce: asset.nonce(),
+            data_hash: asset.data_hash(),
+            creator_hash: asset.creator_hash(),
+            collection_hash: asset.collection_hash(),
+            asset_data_hash: asset.asset_data_hash(),
+            flags: asset.flags(),
+        };
+
+        let result = tree_manager
+            .verify_leaf_v2(&mut context, &forged, false)
+            .await;
+
+        // Then it fails.
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn verify_leaf_requiring_not_frozen() {
+        let mut program_test = create_program_test();
+        program_test.set_compute_max_units(400_000);
+        let mut context = program_test.start_with_context().await;
+
+        // Given a cNFT frozen by the permanent freeze delegate of the tree.
+
+        let mut tree_manager = TreeManager::<5, 8>::default();
+        tree_manager.create_v2(&mut context).await.unwrap();
+
+        let freeze_delegate = Keypair::new();
+        tree_manager
+            .set_tree_plugins(&mut context, Some(freeze_delegate.pubkey()))
+            .await
+            .unwrap();
+
+        let owner = Keypair::new();
+        let asset = tree_manager
+            .mint_v2(&mut context, owner.pubkey(), metadata(&owner))
+            .await
+            .unwrap();
+
+        let frozen = tree_manager
+            .permanent_freeze_v2(&mut context, &freeze_delegate, &asset, true)
+            .await
+            .unwrap();
+
+        // When verifying the leaf requiring it not to be frozen.
+
+        let error = tree_manager
+            .verify_leaf_v2(&mut context, &frozen, true)
+            .await
+            .unwrap_err();
+
+        // Then it fails.
+
+        assert_custom_instruction_error!(0, error, MplBubblegumError::AssetIsFrozen);
+
+        // But it still verifies when frozen assets are accepted.
+
+        let asset_id = tree_manager
+            .verify_leaf_v2(&mut context, &frozen, false)
+            .await
+            .unwrap();
+
+        assert_eq!(asset_id, asset.id());
+    }
+}
@@ -75,6 +75,57 @@ kinobi.update(
         k.variablePdaSeedNode("nonce", k.numberTypeNode("u64")),
       ],
     },
+    treeCreatorProposal: {
+      seeds: [
+        k.constantPdaSeedNodeFromString("tree_creator_proposal"),
+        k.variablePdaSeedNode("merkleTree", k.publicKeyTypeNode()),
+      ],
+      size: 40,
+    },
+    treeDelegateRecord: {
+      seeds: [
+        k.constantPdaSeedNodeFromString("tree_delegate_record"),
+        k.variablePdaSeedNode("merkleTree", k.publicKeyTypeNode()),
+        k.variablePdaSeedNode("delegate", k.publicKeyTypeNode()),
+      ],
+      size: 105,
+    },
+    feeConfig: {
+      seeds: [k.constantPdaSeedNodeFromString("fee_config")],
+      size: 56,
+    },
+    treeCreatorFees: {
+      seeds: [
+        k.constantPdaSeedNodeFromString("tree_creator_fees"),
+        k.variablePdaSeedNode("merkleTree", k.publicKeyTypeNode()),
+      ],
+      size: 80,
+    },
+    treePlugins: {
+      seeds: [
+        k.constantPdaSeedNodeFromString("tree_plugins"),
+        k.variablePdaSeedNode("merkleTree", k.publicKeyTypeNode()),
+      ],
+      size: 368,
+    },
+    treeGroup: {
+      seeds: [
+        k.constantPdaSeedNodeFromString("tree_group"),
+        k.variablePdaSeedNode("authority", k.publicKeyTypeNode()),
+        k.variablePdaSeedNode("groupSeed", k.publicKeyTypeNode()),
+      ],
+    },
+    masterEdition: {
+      seeds: [
+        k.constantPdaSeedNodeFromString("master_edition"),
+        k.variablePdaSeedNode("authority", k.publicKeyTypeNode()),
+        k.variablePdaSeedNode("editionSeed", k.publicKeyTypeNode()),
+      ],
+      size: 121,
+    },
+    stakeRecord: {
+      size: 120,
+    },
   })
 );
 
@@ -176,6 +227,7 @@ for (let ix of deprecatedTmIxes) {
 const v1Ixs = [
   "burn",
   "cancel_redeem",
+  "closeTree",
   "compress",
   "create_tree",
   "decompressV1",
@@ -188,6 +240,7 @@ const v1Ixs = [
   "unverifyCollection",
   "unverifyCreator",
   "updateMetadata",
+  "utilizeV1",
   "verifyCollection",
   "verifyCreator",
 ];
@@ -218,22 +271,38 @@ for (let ix of v1Ixs) {
 // Use mpl-noop and mpl-account-compression as defaults for all
 // V2 instructions.
 const v2Ixs = [
+  "batchUpdateMetadataV2",
   "burnV2",
   "closeTreeV2",
   "createTreeV2",
   "delegateAndFreezeV2",
   "delegateV2",
   "freezeV2",
+  "mergeV2",
+  "mintFungibleV2",
+  "mintToGroupV2",
+  "mintUsableV2",
   "mintV2",
+  "printEditionV2",
+  "recoverNonTransferableV2",
+  "revokeV2",
+  "setBaseUriV2",
   "setCollectionV2",
   "setNonTransferableV2",
+  "splitV2",
+  "stakeV2",
   "thawAndRevokeV2",
   "thawV2",
   "transferV2",
+  "unstakeV2",
   "unverifyCreatorV2",
   "updateAssetDataV2",
+  "updateCreatorV2",
   "updateMetadataV2",
+  "utilizeV2",
   "verifyCreatorV2",
+  "verifyCreatorsV2",
+  "verifyLeafV2",
 ];
 let v2IxUpdaters = [];
 for (let ix of v2Ixs) {
@@ -310,6 +379,16 @@ kinobi.update(
       ignoreIfOptional: true,
       defaultValue: k.pdaValueNode("treeConfig"),
     },
+    {
+      account: "feeConfig",
+      ignoreIfOptional: true,
+      defaultValue: k.publicKeyValueNode("CZ6RYvLgRnpxwZtAgJ2ozqYxYF4sYmxQikZpMQCZ1ZKE"),
+    },
+    {
+      account: "programData",
+      ignoreIfOptional: true,
+      defaultValue: k.publicKeyValueNode("BuBmqo7ehiQf5svTpw54air9bveqqFZQV9BjX277rqm7"),
+    },
     {
       account: "bubblegumSigner",
       ignoreIfOptional: true,
@@ -407,6 +486,12 @@ kinobi.update(
         leafDelegate: { isSigner: "either" },
       },
     },
+    utilizeV1: {
+      accounts: {
+        leafOwner: { isSigner: "either" },
+        leafDelegate: { isSigner: "either" },
+      },
+    },
     redeem: {
       accounts: {
         voucher: {
@@ -498,7 +583,7 @@ kinobi.update(
       name: "createTreeConfigV2",
       byteDeltas: [
         k.instructionByteDeltaNode(
-          k.numberValueNode(96) // TreeConfig account size
+          k.numberValueNode(140) // TreeConfig account size with the counters extension
         ),
       ],
     },
@@ -522,6 +607,28 @@ kinobi.update(
         flags: { defaultValue: k.noneValueNode() }
       }
     },
+    mergeV2: {
+      arguments: {
+        flags: { defaultValue: k.noneValueNode() },
+      }
+    },
+    mintFungibleV2: {
+      arguments: {
+        metadataArgs: { name: "metadata" },
+      },
+    },
+    mintToGroupV2: {
+      arguments: {
+        metadataArgs: { name: "metadata" },
+        assetData: { defaultValue: k.noneValueNode() },
+        assetDataSchema: { defaultValue: k.noneValueNode() }
+      },
+    },
+    mintUsableV2: {
+      arguments: {
+        metadataArgs: { name: "metadata" },
+      },
+    },
     mintV2: {
       arguments: {
         metadataArgs: { name: "metadata" },
@@ -529,6 +636,23 @@ kinobi.update(
         assetDataSchema: { defaultValue: k.noneValueNode() }
       },
     },
+    printEditionV2: {
+      arguments: {
+        metadataArgs: { name: "metadata" },
+      },
+    },
+    recoverNonTransferableV2: {
+      arguments: {
+        assetDataHash: { defaultValue: k.noneValueNode() },
+        flags: { defaultValue: k.noneValueNode() },
+      }
+    },
+    revokeV2: {
+      arguments: {
+        assetDataHash: { defaultValue: k.noneValueNode() },
+        flags: { defaultValue: k.noneValueNode() },
+      }
+    },
     setCollectionV2: {
       arguments: {
         assetDataHash: { defaultValue: k.noneValueNode() },
@@ -541,6 +665,18 @@ kinobi.update(
         flags: { defaultValue: k.noneValueNode() },
       }
     },
+    splitV2: {
+      arguments: {
+        flags: { defaultValue: k.noneValueNode() },
+      }
+    },
+    stakeV2: {
+      arguments: {
+        collectionHash: { defaultValue: k.noneValueNode() },
+        assetDataHash: { defaultValue: k.noneValueNode() },
+        flags: { defaultValue: k.noneValueNode() }
+      }
+    },
     thawAndRevokeV2: {
       arguments: {
         collectionHash: { defaultValue: k.noneValueNode() },
@@ -560,6 +696,13 @@ kinobi.update(
         flags: { defaultValue: k.noneValueNode() },
       }
     },
+    unstakeV2: {
+      arguments: {
+        collectionHash: { defaultValue: k.noneValueNode() },
+        assetDataHash: { defaultValue: k.noneValueNode() },
+        flags: { defaultValue: k.noneValueNode() }
+      }
+    },
     unverifyCreatorV2: {
       arguments: {
         assetDataHash: { defaultValue: k.noneValueNode() },
@@ -574,17 +717,41 @@ kinobi.update(
         newAssetDataSchema: { defaultValue: k.noneValueNode() }
       }
     },
+    updateCreatorV2: {
+      arguments: {
+        assetDataHash: { defaultValue: k.noneValueNode() },
+        flags: { defaultValue: k.noneValueNode() },
+      }
+    },
     updateMetadataV2: {
       arguments: {
         assetDataHash: { defaultValue: k.noneValueNode() },
         flags: { defaultValue: k.noneValueNode() },
       }
     },
+    utilizeV2: {
+      arguments: {
+        flags: { defaultValue: k.noneValueNode() },
+      }
+    },
     verifyCreatorV2: {
       arguments: {
         assetDataHash: { defaultValue: k.noneValueNode() },
         flags: { defaultValue: k.noneValueNode() },
       }
+    },
+    verifyCreatorsV2: {
+      arguments: {
+        assetDataHash: { defaultValue: k.noneValueNode() },
+        flags: { defaultValue: k.noneValueNode() },
+      }
+    },
+    verifyLeafV2: {
+      arguments: {
+        collectionHash: { defaultValue: k.noneValueNode() },
+        assetDataHash: { defaultValue: k.noneValueNode() },
+        flags: { defaultValue: k.noneValueNode() },
+      }
     }
   })
 );
@@ -696,11 +863,15 @@ kinobi.update(
           "delegateAndFreezeV2",
           "delegateV2",
           "freezeV2",
+          "recoverNonTransferableV2",
+          "revokeV2",
           "setCollectionV2",
           "setNonTransferableV2",
+          "stakeV2",
           "thawAndRevokeV2",
           "thawV2",
           "transferV2",
+          "unstakeV2",
           "unverifyCreatorV2",
           "updateAssetDataV2",
           "updateMetadataV2",
@@ -768,6 +939,20 @@ kinobi.accept(
   })
 );
 
+// The Rust renderer stringifies number seeds, so the voucher nonce is seeded with its
+// little-endian bytes instead, matching the program.
+kinobi.update(
+  k.updateAccountsVisitor({
+    voucher: {
+      seeds: [
+        k.constantPdaSeedNodeFromString("voucher"),
+        k.variablePdaSeedNode("merkleTree", k.publicKeyTypeNode()),
+        k.variablePdaSeedNode("nonce", k.bytesTypeNode(k.fixedSizeNode(8))),
+      ],
+    },
+  })
+);
+
 // Render Rust.
 const crateDir = path.join(clientDir, "rust");
 const rustDir = path.join(clientDir, "rust", "src", "generated");
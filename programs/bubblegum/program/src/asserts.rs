@@ -1,13 +1,19 @@
 use crate::{
     error::BubblegumError,
-    state::metaplex_adapter::{MetadataArgsCommon, TokenStandard as MetadataTokenStandard},
+    state::{
+        leaf_schema::Version,
+        metaplex_adapter::{MetadataArgsCommon, TokenStandard as MetadataTokenStandard},
+        TreeSettings,
+    },
     utils::cmp_pubkeys,
 };
 use anchor_lang::prelude::*;
+use mpl_account_compression::Noop as MplNoop;
 use mpl_token_metadata::{
     accounts::{CollectionAuthorityRecord, Metadata, MetadataDelegateRecord},
     types::{Collection, MetadataDelegateRole, TokenStandard},
 };
+use spl_account_compression::Noop as SplNoop;
 
 /// Assert that the provided MetadataArgs are compatible with MPL `Data`
 pub fn assert_metadata_is_mpl_compatible<T: MetadataArgsCommon>(metadata: &T) -> Result<()> {
@@ -89,6 +95,29 @@ pub fn assert_derivation(
     }
 }
 
+/// Assert that the tree is not paused by its creator.
+pub fn assert_tree_not_paused(tree_authority: &AccountInfo) -> Result<()> {
+    if TreeSettings::load(tree_authority)?.is_paused {
+        return Err(BubblegumError::TreePaused.into());
+    }
+
+    Ok(())
+}
+
+/// Assert that the log wrapper is the noop program used by trees of the given version.
+pub fn assert_log_wrapper(version: Version, log_wrapper: &Pubkey) -> Result<()> {
+    let expected = match version {
+        Version::V1 => SplNoop::id(),
+        Version::V2 => MplNoop::id(),
+    };
+
+    if !cmp_pubkeys(log_wrapper, &expected) {
+        return Err(BubblegumError::InvalidLogWrapper.into());
+    }
+
+    Ok(())
+}
+
 pub fn assert_owned_by(account: &AccountInfo, owner: &Pubkey) -> Result<()> {
     if !cmp_pubkeys(account.owner, owner) {
         //todo add better errors
@@ -197,10 +226,13 @@ pub fn assert_collection_membership(
 }
 
 /// Assert that the provided MetadataArgs contains info about Token Standard
-/// and ensures that it's NonFungible
-pub fn assert_metadata_token_standard<T: MetadataArgsCommon>(metadata: &T) -> Result<()> {
+/// and ensures that it's the one expected by the mint instruction
+pub fn assert_metadata_token_standard<T: MetadataArgsCommon>(
+    metadata: &T,
+    token_standard: MetadataTokenStandard,
+) -> Result<()> {
     match metadata.token_standard() {
-        Some(MetadataTokenStandard::NonFungible) => Ok(()),
+        Some(standard) if *standard == token_standard => Ok(()),
         _ => Err(BubblegumError::InvalidTokenStandard.into()),
     }
 }
@@ -118,6 +118,66 @@ pub enum BubblegumError {
     MissingMplCoreCpiSignerAccount,
     #[msg("Asset is not frozen")]
     AssetIsNotFrozen,
+    #[msg("Total mint capacity can only be lowered, and not below the number minted")]
+    InvalidTotalMintCapacity,
+    #[msg("Missing tree creator proposal account")]
+    MissingTreeCreatorProposalAccount,
+    #[msg("Invalid tree delegate roles")]
+    InvalidTreeDelegateRoles,
+    #[msg("Tree is paused")]
+    TreePaused,
+    #[msg("Tree group cannot hold more trees")]
+    TreeGroupFull,
+    #[msg("Tree is already in the tree group")]
+    TreeAlreadyInGroup,
+    #[msg("Tree is not available for minting in the tree group")]
+    TreeNotInGroup,
+    #[msg("Permanent tree plugins can only be added before minting")]
+    TreePluginsLocked,
+    #[msg("Too many programs in tree rule set")]
+    TreeRuleSetTooLong,
+    #[msg("Missing tree plugins account")]
+    MissingTreePluginsAccount,
+    #[msg("Tree has frozen leaves")]
+    TreeHasFrozenLeaves,
+    #[msg("Fee exceeds the maximum fee")]
+    FeeExceedsMaximum,
+    #[msg("Missing tree creator fees account")]
+    MissingTreeCreatorFeesAccount,
+    #[msg("Tree still contains assets")]
+    TreeNotEmpty,
+    #[msg("Invalid tree extension")]
+    InvalidTreeExtension,
+    #[msg("Tree extension already exists")]
+    TreeExtensionAlreadyExists,
+    #[msg("Tree extension can only be added before the first mint")]
+    TreeExtensionRequiresEmptyTree,
+    #[msg("Master edition has reached its max supply")]
+    MasterEditionSupplyExhausted,
+    #[msg("Metadata does not match the master edition")]
+    EditionMetadataMismatch,
+    #[msg("Asset has no uses")]
+    Unusable,
+    #[msg("Not enough uses remaining")]
+    NotEnoughUses,
+    #[msg("Uses must have remaining uses, no more than the total, and a total of one if single")]
+    InvalidUses,
+    #[msg("Invalid amount")]
+    InvalidAmount,
+    #[msg("Leaf cannot be merged with itself")]
+    CannotMergeLeafWithItself,
+    #[msg("Batch must contain leaves and the same number of proof accounts for each leaf")]
+    InvalidBatch,
+    #[msg("Base URI is frozen since the tree has immutable or collection leaves")]
+    BaseUriFrozen,
+    #[msg("Asset is staked")]
+    AssetIsStaked,
+    #[msg("Only non-transferable assets can be recovered")]
+    AssetIsTransferable,
+    #[msg("Recovery reason is too long")]
+    RecoveryReasonTooLong,
+    #[msg("Asset is revoked")]
+    AssetIsRevoked,
 }
 
 // Converts certain Token Metadata errors into Bubblegum equivalents
@@ -12,9 +12,10 @@ pub mod utils;
 
 use processor::*;
 use state::{
-    leaf_schema::LeafSchema,
-    metaplex_adapter::{MetadataArgs, MetadataArgsV2, UpdateArgs},
-    AssetDataSchema, DecompressibleState,
+    leaf_schema::{LeafSchema, LeafUpdate},
+    metaplex_adapter::{LeafMetadataUpdate, MetadataArgs, MetadataArgsV2, UpdateArgs, Uses},
+    tree_extension::TreeExtensionType,
+    AssetDataSchema, DecompressibleState, TreeRuleSet,
 };
 
 declare_id!("BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY");
@@ -70,6 +71,38 @@ pub enum InstructionName {
     UpdateMetadataV2,
     VerifyCreatorV2,
     CloseTreeV2,
+    CloseTree,
+    UpdateTreeConfig,
+    AcceptTreeCreator,
+    SetTreeDelegateRecord,
+    CloseTreeDelegateRecord,
+    SetTreePaused,
+    CreateTreeGroup,
+    AddTreeToGroup,
+    MintToGroupV2,
+    SetTreePlugins,
+    SetFeeConfig,
+    SetTreeFeeExempt,
+    SetTreeCreatorFee,
+    WithdrawCreatorFees,
+    AddTreeExtension,
+    CreateMasterEditionV2,
+    PrintEditionV2,
+    UtilizeV1,
+    MintUsableV2,
+    UtilizeV2,
+    MintFungibleV2,
+    SplitV2,
+    MergeV2,
+    VerifyCreatorsV2,
+    BatchUpdateMetadataV2,
+    SetBaseUriV2,
+    UpdateCreatorV2,
+    VerifyLeafV2,
+    StakeV2,
+    UnstakeV2,
+    RecoverNonTransferableV2,
+    RevokeV2,
 }
 
 pub fn get_instruction_type(full_bytes: &[u8]) -> InstructionName {
@@ -115,6 +148,38 @@ pub fn get_instruction_type(full_bytes: &[u8]) -> InstructionName {
         [43, 103, 89, 42, 121, 242, 62, 72] => InstructionName::UpdateMetadataV2,
         [85, 138, 140, 42, 22, 241, 118, 102] => InstructionName::VerifyCreatorV2,
         [45, 172, 6, 94, 28, 90, 157, 70] => InstructionName::CloseTreeV2,
+        [9, 124, 164, 131, 238, 218, 148, 212] => InstructionName::CloseTree,
+        [75, 1, 47, 108, 102, 26, 239, 207] => InstructionName::UpdateTreeConfig,
+        [106, 65, 242, 169, 157, 90, 154, 67] => InstructionName::AcceptTreeCreator,
+        [145, 41, 224, 38, 100, 66, 250, 182] => InstructionName::SetTreeDelegateRecord,
+        [215, 161, 41, 143, 89, 92, 138, 150] => InstructionName::CloseTreeDelegateRecord,
+        [55, 169, 41, 72, 70, 115, 25, 208] => InstructionName::SetTreePaused,
+        [136, 220, 135, 210, 201, 1, 66, 85] => InstructionName::CreateTreeGroup,
+        [96, 100, 174, 138, 209, 59, 133, 10] => InstructionName::AddTreeToGroup,
+        [2, 28, 150, 57, 64, 19, 146, 236] => InstructionName::MintToGroupV2,
+        [183, 37, 252, 10, 131, 163, 233, 7] => InstructionName::SetTreePlugins,
+        [221, 222, 52, 206, 114, 198, 64, 91] => InstructionName::SetFeeConfig,
+        [87, 237, 144, 246, 204, 218, 27, 192] => InstructionName::SetTreeFeeExempt,
+        [223, 14, 232, 229, 118, 137, 253, 198] => InstructionName::SetTreeCreatorFee,
+        [8, 30, 213, 18, 121, 105, 129, 222] => InstructionName::WithdrawCreatorFees,
+        [255, 207, 116, 51, 26, 93, 109, 28] => InstructionName::AddTreeExtension,
+        [189, 116, 24, 32, 114, 188, 147, 33] => InstructionName::CreateMasterEditionV2,
+        [132, 4, 142, 14, 243, 87, 113, 157] => InstructionName::PrintEditionV2,
+        [123, 251, 135, 20, 124, 105, 198, 124] => InstructionName::UtilizeV1,
+        [25, 64, 198, 4, 31, 115, 154, 70] => InstructionName::MintUsableV2,
+        [39, 131, 75, 205, 122, 30, 143, 117] => InstructionName::UtilizeV2,
+        [246, 68, 49, 115, 136, 72, 3, 255] => InstructionName::MintFungibleV2,
+        [75, 85, 149, 182, 115, 201, 8, 40] => InstructionName::SplitV2,
+        [152, 61, 247, 211, 114, 135, 166, 47] => InstructionName::MergeV2,
+        [179, 80, 249, 195, 100, 226, 101, 14] => InstructionName::VerifyCreatorsV2,
+        [216, 104, 210, 61, 210, 190, 0, 132] => InstructionName::BatchUpdateMetadataV2,
+        [35, 115, 98, 35, 1, 187, 251, 246] => InstructionName::SetBaseUriV2,
+        [136, 105, 105, 120, 87, 81, 148, 47] => InstructionName::UpdateCreatorV2,
+        [69, 197, 64, 216, 112, 212, 255, 168] => InstructionName::VerifyLeafV2,
+        [138, 220, 129, 193, 15, 244, 74, 53] => InstructionName::StakeV2,
+        [88, 53, 113, 37, 217, 40, 248, 41] => InstructionName::UnstakeV2,
+        [48, 227, 123, 92, 95, 55, 241, 55] => InstructionName::RecoverNonTransferableV2,
+        [180, 208, 8, 46, 191, 114, 137, 5] => InstructionName::RevokeV2,
         _ => InstructionName::Unknown,
     }
 }
@@ -123,6 +188,37 @@ pub fn get_instruction_type(full_bytes: &[u8]) -> InstructionName {
 pub mod bubblegum {
     use super::*;
 
+    /// Accepts a pending tree creator hand-off proposed via `update_tree_config`.  The tree delegate
+    /// is reset to the new tree creator.
+    pub fn accept_tree_creator(ctx: Context<AcceptTreeCreator>) -> Result<()> {
+        processor::accept_tree_creator(ctx)
+    }
+
+    /// Adds an extension to the tree config, reallocating the account if needed.
+    pub fn add_tree_extension(
+        ctx: Context<AddTreeExtension>,
+        extension_type: TreeExtensionType,
+    ) -> Result<()> {
+        processor::add_tree_extension(ctx, extension_type)
+    }
+
+    /// Appends a V2 tree created by the group authority to a tree group.
+    pub fn add_tree_to_group(ctx: Context<AddTreeToGroup>) -> Result<()> {
+        processor::add_tree_to_group(ctx)
+    }
+
+    /// Applies the same metadata update to several `LeafSchema` V2 leaf nodes of one tree and
+    /// collection.  The authority is validated once, and each leaf needs its own proof, passed in
+    /// the order of `leaves` as remaining accounts.  Returns the update of the first leaf.
+    pub fn batch_update_metadata_v2<'info>(
+        ctx: Context<'_, '_, '_, 'info, BatchUpdateMetadataV2<'info>>,
+        root: [u8; 32],
+        leaves: Vec<LeafMetadataUpdate>,
+        update_args: UpdateArgs,
+    ) -> Result<LeafUpdate> {
+        processor::batch_update_metadata_v2(ctx, root, leaves, update_args)
+    }
+
     /// Burns a leaf node from the tree.
     pub fn burn<'info>(
         ctx: Context<'_, '_, '_, 'info, Burn<'info>>,
@@ -166,6 +262,16 @@ pub mod bubblegum {
         processor::cancel_redeem(ctx, root)
     }
 
+    /// Closes an empty V1 tree and its config PDA to reclaim rent.
+    pub fn close_tree(ctx: Context<CloseTree>) -> Result<()> {
+        processor::close_tree(ctx)
+    }
+
+    /// Closes a scoped tree delegate record, revoking all of its roles.
+    pub fn close_tree_delegate_record(ctx: Context<CloseTreeDelegateRecord>) -> Result<()> {
+        processor::close_tree_delegate_record(ctx)
+    }
+
     /// Closes an empty tree and its config PDA to reclaim rent.
     pub fn close_tree_v2(ctx: Context<CloseTreeV2>) -> Result<()> {
         processor::close_tree_v2(ctx)
@@ -181,6 +287,17 @@ pub mod bubblegum {
         processor::compress(ctx)
     }
 
+    /// Creates a master edition whose numbered prints are minted into V2 trees with
+    /// `print_edition_v2`, up to `max_supply` prints if set.
+    pub fn create_master_edition_v2(
+        ctx: Context<CreateMasterEditionV2>,
+        edition_seed: Pubkey,
+        metadata: MetadataArgsV2,
+        max_supply: Option<u64>,
+    ) -> Result<()> {
+        processor::create_master_edition_v2(ctx, edition_seed, metadata, max_supply)
+    }
+
     /// Creates a new tree.
     pub fn create_tree(
         ctx: Context<CreateTree>,
@@ -191,6 +308,16 @@ pub mod bubblegum {
         processor::create_tree(ctx, max_depth, max_buffer_size, public)
     }
 
+    /// Creates a tree group, an ordered set of up to `max_trees` V2 trees minted into one after
+    /// the other with `mint_to_group_v2`.
+    pub fn create_tree_group(
+        ctx: Context<CreateTreeGroup>,
+        group_seed: Pubkey,
+        max_trees: u8,
+    ) -> Result<()> {
+        processor::create_tree_group(ctx, group_seed, max_trees)
+    }
+
     /// Creates a new tree for use with `LeafSchema` V2 leaf nodes.  See `mint_v2` for more details
     /// on the new functionality.
     pub fn create_tree_v2(
@@ -230,7 +357,7 @@ pub mod bubblegum {
         flags: Option<u8>,
         nonce: u64,
         index: u32,
-    ) -> Result<()> {
+    ) -> Result<LeafUpdate> {
         processor::delegate_and_freeze_v2(
             ctx,
             root,
@@ -255,7 +382,7 @@ pub mod bubblegum {
         flags: Option<u8>,
         nonce: u64,
         index: u32,
-    ) -> Result<()> {
+    ) -> Result<LeafUpdate> {
         processor::delegate_v2(
             ctx,
             root,
@@ -279,7 +406,7 @@ pub mod bubblegum {
         flags: Option<u8>,
         nonce: u64,
         index: u32,
-    ) -> Result<()> {
+    ) -> Result<LeafUpdate> {
         processor::freeze_v2(
             ctx,
             root,
@@ -292,6 +419,52 @@ pub mod bubblegum {
         )
     }
 
+    /// Merges the fungible asset leaf at `other_index` into the leaf at `index`, adding up their
+    /// amounts.  Both leaves must share the same class, metadata, creators, collection, flags,
+    /// owner and delegate.  The remaining accounts are the proof of the leaf at `index` followed by
+    /// the proof of the leaf at `other_index`, both of the same length.
+    pub fn merge_v2<'info>(
+        ctx: Context<'_, '_, '_, 'info, MergeV2<'info>>,
+        root: [u8; 32],
+        data_hash: [u8; 32],
+        creator_hash: [u8; 32],
+        flags: Option<u8>,
+        nonce: u64,
+        index: u32,
+        class_id: Pubkey,
+        amount: u64,
+        other_nonce: u64,
+        other_index: u32,
+        other_amount: u64,
+    ) -> Result<LeafUpdate> {
+        processor::merge_v2(
+            ctx,
+            root,
+            data_hash,
+            creator_hash,
+            flags,
+            nonce,
+            index,
+            class_id,
+            amount,
+            other_nonce,
+            other_index,
+            other_amount,
+        )
+    }
+
+    /// Mints a fungible asset leaf holding `amount` units, like `mint_v2` but with
+    /// `TokenStandard::FungibleAsset` metadata.  The leaf starts a new class whose id is its asset
+    /// id, and the asset data hash of the leaf commits to that class id and the amount.  Only
+    /// leaves split off from the same mint can be merged.
+    pub fn mint_fungible_v2(
+        ctx: Context<MintV2>,
+        metadata_args: MetadataArgsV2,
+        amount: u64,
+    ) -> Result<LeafUpdate> {
+        processor::mint_fungible_v2(ctx, metadata_args, amount)
+    }
+
     /// Mints a new asset and adds it to a Token Metadata collection.
     pub fn mint_to_collection_v1(
         ctx: Context<MintToCollectionV1>,
@@ -300,6 +473,28 @@ pub mod bubblegum {
         processor::mint_to_collection_v1(ctx, metadata_args)
     }
 
+    /// Mints a new asset like `mint_v2`, into the current tree of a tree group.  The group moves
+    /// on to its next tree once the current one is full, and only the group authority can mint
+    /// into a later tree.
+    pub fn mint_to_group_v2(
+        ctx: Context<MintToGroupV2>,
+        metadata_args: MetadataArgsV2,
+        asset_data: Option<Vec<u8>>,
+        asset_data_schema: Option<AssetDataSchema>,
+    ) -> Result<LeafUpdate> {
+        processor::mint_to_group_v2(ctx, metadata_args, asset_data, asset_data_schema)
+    }
+
+    /// Mints a usable asset like `mint_v2`, with `uses` committed to by the asset data hash of the
+    /// leaf since V2 metadata has no uses.  Uses are spent with `utilize_v2`.
+    pub fn mint_usable_v2(
+        ctx: Context<MintV2>,
+        metadata_args: MetadataArgsV2,
+        uses: Uses,
+    ) -> Result<LeafUpdate> {
+        processor::mint_usable_v2(ctx, metadata_args, uses)
+    }
+
     /// Mints a new asset.
     pub fn mint_v1(ctx: Context<MintV1>, message: MetadataArgs) -> Result<LeafSchema> {
         processor::mint_v1(ctx, message)
@@ -327,10 +522,47 @@ pub mod bubblegum {
         metadata_args: MetadataArgsV2,
         asset_data: Option<Vec<u8>>,
         asset_data_schema: Option<AssetDataSchema>,
-    ) -> Result<LeafSchema> {
+    ) -> Result<LeafUpdate> {
         processor::mint_v2(ctx, metadata_args, asset_data, asset_data_schema)
     }
 
+    /// Mints the next numbered print of a master edition like `mint_v2`.  The metadata must match
+    /// the master edition, and the asset data hash of the leaf commits to the master edition and
+    /// the edition number.
+    pub fn print_edition_v2(
+        ctx: Context<PrintEditionV2>,
+        metadata_args: MetadataArgsV2,
+    ) -> Result<LeafUpdate> {
+        processor::print_edition_v2(ctx, metadata_args)
+    }
+
+    /// Moves a non-transferable `LeafSchema` V2 leaf node to a new owner.  Only the mpl-core
+    /// collection update authority can recover an asset, and the reason is recorded in an asset
+    /// event.  The leaf stays non-transferable.
+    pub fn recover_non_transferable_v2<'info>(
+        ctx: Context<'_, '_, '_, 'info, RecoverNonTransferableV2<'info>>,
+        root: [u8; 32],
+        data_hash: [u8; 32],
+        creator_hash: [u8; 32],
+        asset_data_hash: Option<[u8; 32]>,
+        flags: Option<u8>,
+        nonce: u64,
+        index: u32,
+        reason: String,
+    ) -> Result<LeafUpdate> {
+        processor::recover_non_transferable_v2(
+            ctx,
+            root,
+            data_hash,
+            creator_hash,
+            asset_data_hash,
+            flags,
+            nonce,
+            index,
+            reason,
+        )
+    }
+
     /// Redeems a vouches.
     ///
     /// Once a vouch is redeemed, the corresponding leaf node is removed from the tree.
@@ -345,6 +577,30 @@ pub mod bubblegum {
         processor::redeem(ctx, root, data_hash, creator_hash, nonce, index)
     }
 
+    /// Permanently sets the revoked flag on a `LeafSchema` V2 leaf node, which blocks its
+    /// transfer, delegation and thaw while keeping it in the tree.  The owner can still burn it.
+    pub fn revoke_v2<'info>(
+        ctx: Context<'_, '_, '_, 'info, RevokeV2<'info>>,
+        root: [u8; 32],
+        data_hash: [u8; 32],
+        creator_hash: [u8; 32],
+        asset_data_hash: Option<[u8; 32]>,
+        flags: Option<u8>,
+        nonce: u64,
+        index: u32,
+    ) -> Result<LeafUpdate> {
+        processor::revoke_v2(
+            ctx,
+            root,
+            data_hash,
+            creator_hash,
+            asset_data_hash,
+            flags,
+            nonce,
+            index,
+        )
+    }
+
     /// Sets and verifies a collection to a leaf node
     pub fn set_and_verify_collection<'info>(
         ctx: Context<'_, '_, '_, 'info, CollectionVerification<'info>>,
@@ -368,6 +624,14 @@ pub mod bubblegum {
         )
     }
 
+    /// Sets the base URI that the URIs stored in the leaves of a tree with a `UriTemplate`
+    /// extension are appended to.  Leaf data hashes commit to the stored URIs, so this changes the
+    /// resolved URI of every leaf at once.  Fails once the tree has an immutable leaf or a leaf in
+    /// a collection.
+    pub fn set_base_uri_v2(ctx: Context<SetBaseUriV2>, base_uri: String) -> Result<()> {
+        processor::set_base_uri_v2(ctx, base_uri)
+    }
+
     /// Sets and collection to a `LeafSchema` V2 leaf node
     pub fn set_collection_v2<'info>(
         ctx: Context<'_, '_, '_, 'info, SetCollectionV2<'info>>,
@@ -377,7 +641,7 @@ pub mod bubblegum {
         nonce: u64,
         index: u32,
         message: MetadataArgsV2,
-    ) -> Result<()> {
+    ) -> Result<LeafUpdate> {
         processor::set_collection_v2(ctx, root, asset_data_hash, flags, nonce, index, message)
     }
 
@@ -394,6 +658,21 @@ pub mod bubblegum {
         processor::set_decompressible_state(ctx, decompressable_state)
     }
 
+    /// Sets the protocol fee schedule.  Only callable by the program upgrade authority.
+    pub fn set_fee_config(
+        ctx: Context<SetFeeConfig>,
+        fee_recipient: Pubkey,
+        mint_v2_fee_lamports: u64,
+        transfer_v2_fee_lamports: u64,
+    ) -> Result<()> {
+        processor::set_fee_config(
+            ctx,
+            fee_recipient,
+            mint_v2_fee_lamports,
+            transfer_v2_fee_lamports,
+        )
+    }
+
     /// Sets the `decompressible_state` of a tree.
     pub fn set_decompressible_state(
         ctx: Context<SetDecompressibleState>,
@@ -413,7 +692,7 @@ pub mod bubblegum {
         flags: Option<u8>,
         nonce: u64,
         index: u32,
-    ) -> Result<()> {
+    ) -> Result<LeafUpdate> {
         processor::set_non_transferable_v2(
             ctx,
             root,
@@ -426,11 +705,111 @@ pub mod bubblegum {
         )
     }
 
+    /// Sets the creator fee paid by third-party mints into a public tree.  A fee of zero disables
+    /// it.
+    pub fn set_tree_creator_fee(
+        ctx: Context<SetTreeCreatorFee>,
+        creator_fee_lamports: u64,
+    ) -> Result<()> {
+        processor::set_tree_creator_fee(ctx, creator_fee_lamports)
+    }
+
     /// Sets a delegate for a tree.
     pub fn set_tree_delegate(ctx: Context<SetTreeDelegate>) -> Result<()> {
         processor::set_tree_delegate(ctx)
     }
 
+    /// Sets a scoped delegate for a tree, limited to the given roles bitmask.  The delegate passes
+    /// the record ahead of any proof in the remaining accounts of the instructions it uses it for.
+    pub fn set_tree_delegate_record(ctx: Context<SetTreeDelegateRecord>, roles: u8) -> Result<()> {
+        processor::set_tree_delegate_record(ctx, roles)
+    }
+
+    /// Exempts a tree from the protocol fees, or removes the exemption.  Only callable by the
+    /// program upgrade authority.
+    pub fn set_tree_fee_exempt(ctx: Context<SetTreeFeeExempt>, is_fee_exempt: bool) -> Result<()> {
+        processor::set_tree_fee_exempt(ctx, is_fee_exempt)
+    }
+
+    /// Pauses or unpauses minting, transferring, delegating and burning on a tree.
+    pub fn set_tree_paused(ctx: Context<SetTreePaused>, is_paused: bool) -> Result<()> {
+        processor::set_tree_paused(ctx, is_paused)
+    }
+
+    /// Sets the tree-level plugins that apply to `LeafSchema` V2 assets without a collection.
+    /// Permanent delegates can only be added before the first mint.
+    pub fn set_tree_plugins(
+        ctx: Context<SetTreePlugins>,
+        royalties_rule_set: TreeRuleSet,
+        permanent_freeze_delegate: Option<Pubkey>,
+        permanent_transfer_delegate: Option<Pubkey>,
+        permanent_burn_delegate: Option<Pubkey>,
+    ) -> Result<()> {
+        processor::set_tree_plugins(
+            ctx,
+            royalties_rule_set,
+            permanent_freeze_delegate,
+            permanent_transfer_delegate,
+            permanent_burn_delegate,
+        )
+    }
+
+    /// Splits `split_amount` units off a fungible asset leaf into a new leaf owned by
+    /// `new_leaf_owner`.  Splitting off to another owner is a partial transfer, validated and
+    /// charged like `transfer_v2`.
+    pub fn split_v2<'info>(
+        ctx: Context<'_, '_, '_, 'info, SplitV2<'info>>,
+        root: [u8; 32],
+        data_hash: [u8; 32],
+        creator_hash: [u8; 32],
+        flags: Option<u8>,
+        nonce: u64,
+        index: u32,
+        class_id: Pubkey,
+        amount: u64,
+        split_amount: u64,
+    ) -> Result<LeafUpdate> {
+        processor::split_v2(
+            ctx,
+            root,
+            data_hash,
+            creator_hash,
+            flags,
+            nonce,
+            index,
+            class_id,
+            amount,
+            split_amount,
+        )
+    }
+
+    /// Stakes a `LeafSchema` V2 leaf node in place like `delegate_and_freeze_v2`: a `StakeRecord`
+    /// PDA recording the stake time and the staking authority becomes the leaf delegate and
+    /// freezes the asset, which stays in the wallet of its owner.
+    pub fn stake_v2<'info>(
+        ctx: Context<'_, '_, '_, 'info, StakeV2<'info>>,
+        root: [u8; 32],
+        data_hash: [u8; 32],
+        creator_hash: [u8; 32],
+        collection_hash: Option<[u8; 32]>,
+        asset_data_hash: Option<[u8; 32]>,
+        flags: Option<u8>,
+        nonce: u64,
+        index: u32,
+    ) -> Result<LeafUpdate> {
+        processor::stake_v2(
+            ctx,
+            root,
+            data_hash,
+            creator_hash,
+            collection_hash,
+            asset_data_hash,
+            flags,
+            nonce,
+            index,
+        )
+    }
+
     /// Thaws a previously frozen `LeafSchema` V2 leaf node, and revoke the leaf delegate.
     pub fn thaw_and_revoke_v2<'info>(
         ctx: Context<'_, '_, '_, 'info, ThawAndRevokeV2<'info>>,
@@ -442,7 +821,7 @@ pub mod bubblegum {
         flags: Option<u8>,
         nonce: u64,
         index: u32,
-    ) -> Result<()> {
+    ) -> Result<LeafUpdate> {
         processor::thaw_and_revoke_v2(
             ctx,
             root,
@@ -466,7 +845,7 @@ pub mod bubblegum {
         flags: Option<u8>,
         nonce: u64,
         index: u32,
-    ) -> Result<()> {
+    ) -> Result<LeafUpdate> {
         processor::thaw_v2(
             ctx,
             root,
@@ -501,7 +880,7 @@ pub mod bubblegum {
         flags: Option<u8>,
         nonce: u64,
         index: u32,
-    ) -> Result<()> {
+    ) -> Result<LeafUpdate> {
         processor::transfer_v2(
             ctx,
             root,
@@ -527,6 +906,32 @@ pub mod bubblegum {
         processor::unverify_collection(ctx, root, data_hash, creator_hash, nonce, index, message)
     }
 
+    /// Unstakes a `LeafSchema` V2 leaf node staked with `stake_v2` like `thaw_and_revoke_v2`,
+    /// signed by the staking authority.  The `StakeRecord` delegate is revoked and closed.
+    pub fn unstake_v2<'info>(
+        ctx: Context<'_, '_, '_, 'info, UnstakeV2<'info>>,
+        root: [u8; 32],
+        data_hash: [u8; 32],
+        creator_hash: [u8; 32],
+        collection_hash: Option<[u8; 32]>,
+        asset_data_hash: Option<[u8; 32]>,
+        flags: Option<u8>,
+        nonce: u64,
+        index: u32,
+    ) -> Result<LeafUpdate> {
+        processor::unstake_v2(
+            ctx,
+            root,
+            data_hash,
+            creator_hash,
+            collection_hash,
+            asset_data_hash,
+            flags,
+            nonce,
+            index,
+        )
+    }
+
     /// Unverifies a creator from a leaf node.
     pub fn unverify_creator<'info>(
         ctx: Context<'_, '_, '_, 'info, CreatorVerification<'info>>,
@@ -549,7 +954,7 @@ pub mod bubblegum {
         nonce: u64,
         index: u32,
         message: MetadataArgsV2,
-    ) -> Result<()> {
+    ) -> Result<LeafUpdate> {
         processor::unverify_creator_v2(ctx, root, asset_data_hash, flags, nonce, index, message)
     }
 
@@ -565,7 +970,7 @@ pub mod bubblegum {
         index: u32,
         new_asset_data: Option<Vec<u8>>,
         new_asset_data_schema: Option<AssetDataSchema>,
-    ) -> Result<()> {
+    ) -> Result<LeafUpdate> {
         processor::update_asset_data_v2(
             ctx,
             root,
@@ -580,6 +985,29 @@ pub mod bubblegum {
         )
     }
 
+    /// Replaces the address of a verified creator of a `LeafSchema` V2 leaf node, for example after
+    /// a key rotation.  Signed by the creator itself and the new address, without the update
+    /// authority.  The share and the verified flag move to the new address.
+    pub fn update_creator_v2<'info>(
+        ctx: Context<'_, '_, '_, 'info, UpdateCreatorV2<'info>>,
+        root: [u8; 32],
+        asset_data_hash: Option<[u8; 32]>,
+        flags: Option<u8>,
+        nonce: u64,
+        index: u32,
+        current_metadata: MetadataArgsV2,
+    ) -> Result<LeafUpdate> {
+        processor::update_creator_v2(
+            ctx,
+            root,
+            asset_data_hash,
+            flags,
+            nonce,
+            index,
+            current_metadata,
+        )
+    }
+
     /// Updates metadata for a leaf node.
     pub fn update_metadata<'info>(
         ctx: Context<'_, '_, '_, 'info, UpdateMetadata<'info>>,
@@ -602,7 +1030,7 @@ pub mod bubblegum {
         index: u32,
         current_metadata: MetadataArgsV2,
         update_args: UpdateArgs,
-    ) -> Result<()> {
+    ) -> Result<LeafUpdate> {
         processor::update_metadata_v2(
             ctx,
             root,
@@ -615,6 +1043,57 @@ pub mod bubblegum {
         )
     }
 
+    /// Updates the config of a tree: lowers the total mint capacity, toggles public minting,
+    /// and/or proposes a new tree creator.
+    pub fn update_tree_config(
+        ctx: Context<UpdateTreeConfig>,
+        total_mint_capacity: Option<u64>,
+        is_public: Option<bool>,
+        proposed_tree_creator: Option<Pubkey>,
+    ) -> Result<()> {
+        processor::update_tree_config(ctx, total_mint_capacity, is_public, proposed_tree_creator)
+    }
+
+    /// Spends `number_of_uses` uses of a leaf node, signed by the leaf owner or delegate.  A leaf
+    /// with `UseMethod::Burn` is burned once it has no uses remaining.
+    pub fn utilize_v1<'info>(
+        ctx: Context<'_, '_, '_, 'info, Utilize<'info>>,
+        root: [u8; 32],
+        nonce: u64,
+        index: u32,
+        current_metadata: MetadataArgs,
+        number_of_uses: u64,
+    ) -> Result<()> {
+        processor::utilize_v1(ctx, root, nonce, index, current_metadata, number_of_uses)
+    }
+
+    /// Spends `number_of_uses` uses of a usable `LeafSchema` V2 leaf node minted with
+    /// `mint_usable_v2`, signed by the leaf owner or delegate.  Returns the updated leaf; a leaf
+    /// with `UseMethod::Burn` is burned after its last use and returned with no uses remaining.
+    pub fn utilize_v2<'info>(
+        ctx: Context<'_, '_, '_, 'info, UtilizeV2<'info>>,
+        root: [u8; 32],
+        data_hash: [u8; 32],
+        creator_hash: [u8; 32],
+        flags: Option<u8>,
+        nonce: u64,
+        index: u32,
+        uses: Uses,
+        number_of_uses: u64,
+    ) -> Result<LeafUpdate> {
+        processor::utilize_v2(
+            ctx,
+            root,
+            data_hash,
+            creator_hash,
+            flags,
+            nonce,
+            index,
+            uses,
+            number_of_uses,
+        )
+    }
+
     /// Verifies a collection for a leaf node.
     pub fn verify_collection<'info>(
         ctx: Context<'_, '_, '_, 'info, CollectionVerification<'info>>,
@@ -650,7 +1129,57 @@ pub mod bubblegum {
         nonce: u64,
         index: u32,
         message: MetadataArgsV2,
-    ) -> Result<()> {
+    ) -> Result<LeafUpdate> {
         processor::verify_creator_v2(ctx, root, asset_data_hash, flags, nonce, index, message)
     }
+
+    /// Verifies every signing creator of a `LeafSchema` V2 leaf node in a single leaf update.  The
+    /// payer, the optional creator and any signers passed as leading remaining accounts, before the
+    /// proof, count as signing creators.
+    pub fn verify_creators_v2<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreatorVerificationV2<'info>>,
+        root: [u8; 32],
+        asset_data_hash: Option<[u8; 32]>,
+        flags: Option<u8>,
+        nonce: u64,
+        index: u32,
+        message: MetadataArgsV2,
+    ) -> Result<LeafUpdate> {
+        processor::verify_creators_v2(ctx, root, asset_data_hash, flags, nonce, index, message)
+    }
+
+    /// Verifies that a `LeafSchema` V2 leaf node is in the tree without modifying it, and returns
+    /// the asset id.  Revoked assets are rejected.  Passing the mpl-core collection asserts the
+    /// asset belongs to it, and `require_not_frozen` asserts the asset is not frozen.
+    pub fn verify_leaf_v2<'info>(
+        ctx: Context<'_, '_, '_, 'info, VerifyLeafV2<'info>>,
+        root: [u8; 32],
+        data_hash: [u8; 32],
+        creator_hash: [u8; 32],
+        collection_hash: Option<[u8; 32]>,
+        asset_data_hash: Option<[u8; 32]>,
+        flags: Option<u8>,
+        nonce: u64,
+        index: u32,
+        require_not_frozen: bool,
+    ) -> Result<Pubkey> {
+        processor::verify_leaf_v2(
+            ctx,
+            root,
+            data_hash,
+            creator_hash,
+            collection_hash,
+            asset_data_hash,
+            flags,
+            nonce,
+            index,
+            require_not_frozen,
+        )
+    }
+
+    /// Withdraws the creator fees collected by a tree to a destination chosen by the tree creator
+    /// that set the fee, closing the creator fees account once the tree is closed.
+    pub fn withdraw_creator_fees(ctx: Context<WithdrawCreatorFees>) -> Result<()> {
+        processor::withdraw_creator_fees(ctx)
+    }
 }
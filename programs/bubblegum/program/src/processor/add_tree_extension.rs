@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::BubblegumError,
+    state::{
+        leaf_schema::Version,
+        tree_extension::{add_extension, TreeExtensionType},
+        TreeConfig, TreeCounters, TreeSettings, UriTemplate,
+    },
+};
+
+#[derive(Accounts)]
+pub struct AddTreeExtension<'info> {
+    #[account(
+        mut,
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+        has_one = tree_creator
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub tree_creator: Signer<'info>,
+    /// CHECK: Used to derive `tree_authority`
+    pub merkle_tree: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn add_tree_extension(
+    ctx: Context<AddTreeExtension>,
+    extension_type: TreeExtensionType,
+) -> Result<()> {
+    let tree_authority = ctx.accounts.tree_authority.to_account_info();
+    let payer = ctx.accounts.payer.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+
+    match extension_type {
+        TreeExtensionType::Uninitialized => Err(BubblegumError::InvalidTreeExtension.into()),
+        TreeExtensionType::Counters => {
+            // Counters are only kept by the V2 instructions.
+            require!(
+                ctx.accounts.tree_authority.version == Version::V2,
+                BubblegumError::UnsupportedSchemaVersion
+            );
+
+            // Counters start at zero, so they are only accurate if added before the first mint.
+            require!(
+                ctx.accounts.tree_authority.num_minted == 0,
+                BubblegumError::TreeExtensionRequiresEmptyTree
+            );
+
+            add_extension::<TreeCounters>(&tree_authority, &payer, &system_program)
+        }
+        TreeExtensionType::Settings => {
+            add_extension::<TreeSettings>(&tree_authority, &payer, &system_program)
+        }
+        TreeExtensionType::UriTemplate => {
+            // Base URIs are only resolved for `MetadataArgsV2` leaves.
+            require!(
+                ctx.accounts.tree_authority.version == Version::V2,
+                BubblegumError::UnsupportedSchemaVersion
+            );
+
+            // Leaves minted before the template would have their full URI prefixed by the base.
+            require!(
+                ctx.accounts.tree_authority.num_minted == 0,
+                BubblegumError::TreeExtensionRequiresEmptyTree
+            );
+
+            add_extension::<UriTemplate>(&tree_authority, &payer, &system_program)
+        }
+    }
+}
@@ -7,11 +7,13 @@ use mpl_core::{
 use spl_account_compression::{program::SplAccountCompression, Node, Noop as SplNoop};
 
 use crate::{
+    asserts::assert_tree_not_paused,
     error::BubblegumError,
+    processor::{asset_validate_not_staked, emit_asset_event, OptionalAccounts},
     state::{
         leaf_schema::{LeafSchema, Version},
         metaplex_anchor::MplCore,
-        TreeConfig, MPL_CORE_CPI_SIGNER_PREFIX,
+        AssetUpdate, TreeConfig, TreeCounters, MPL_CORE_CPI_SIGNER_PREFIX,
     },
     traits::{MplCorePluginValidation, ValidationResult},
     utils::{
@@ -53,6 +55,8 @@ pub(crate) fn burn_v1<'info>(
         BubblegumError::UnsupportedSchemaVersion
     );
 
+    assert_tree_not_paused(&ctx.accounts.tree_authority.to_account_info())?;
+
     let owner = ctx.accounts.leaf_owner.to_account_info();
     let delegate = ctx.accounts.leaf_delegate.to_account_info();
 
@@ -102,7 +106,7 @@ pub struct BurnV2<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     /// Optional authority, defaults to `payer`.  Must be either
-    /// the leaf owner or collection collection permanent burn delegate.
+    /// the leaf owner or collection or tree permanent burn delegate.
     pub authority: Option<Signer<'info>>,
     /// CHECK: This account is neither written to nor read from
     pub leaf_owner: UncheckedAccount<'info>,
@@ -142,6 +146,11 @@ pub(crate) fn burn_v2<'info>(
         BubblegumError::UnsupportedSchemaVersion
     );
 
+    let optional =
+        OptionalAccounts::parse(&ctx.accounts.merkle_tree.key(), ctx.remaining_accounts)?;
+
+    assert_tree_not_paused(&ctx.accounts.tree_authority.to_account_info())?;
+
     let authority = ctx
         .accounts
         .authority
@@ -178,6 +187,9 @@ pub(crate) fn burn_v2<'info>(
         let core_collection_data = &core_collection.data.borrow()[..];
         let collection = MplCoreCollection::from_bytes(core_collection_data)?;
         mpl_core_collection_validate_burn(&collection, authority, leaf_owner)?
+    } else if let Some(tree_plugins) = &optional.tree_plugins {
+        // Uncollected assets use the tree-level plugins instead.
+        tree_plugins.validate_burn(authority)
     } else {
         ValidationResult::Abstain
     };
@@ -195,6 +207,10 @@ pub(crate) fn burn_v2<'info>(
         // Ensure asset is not frozen.  Note this is skipped if the permanent burn delegate force
         // approved.
         asset_validate_non_frozen(flags)?;
+    } else {
+        // The permanent burn delegate cannot burn a staked asset, since its stake record could
+        // then never be closed.
+        asset_validate_not_staked(ctx.accounts.leaf_delegate.as_deref())?;
     }
 
     let collection_hash = hash_collection_option(
@@ -223,6 +239,16 @@ pub(crate) fn burn_v2<'info>(
 
     let new_leaf = Node::default();
 
+    emit_asset_event(
+        asset_id,
+        AssetUpdate::BurnedV2 { authority },
+        &ctx.accounts.log_wrapper,
+    )?;
+
+    TreeCounters::update(&ctx.accounts.tree_authority.to_account_info(), |counters| {
+        counters.record_burn(flags)
+    })?;
+
     replace_leaf(
         Version::V2,
         &merkle_tree.key(),
@@ -231,7 +257,7 @@ pub(crate) fn burn_v2<'info>(
         &ctx.accounts.tree_authority.to_account_info(),
         &ctx.accounts.merkle_tree.to_account_info(),
         &ctx.accounts.log_wrapper.to_account_info(),
-        ctx.remaining_accounts,
+        optional.remaining_accounts,
         root,
         previous_leaf.to_node(),
         new_leaf,
@@ -1,9 +1,77 @@
 use crate::{
     error::BubblegumError,
-    state::{leaf_schema::Version, TreeConfig},
+    processor::OptionalAccounts,
+    state::{
+        leaf_schema::Version, tree_extension::get_extension, TreeConfig, TreeCounters,
+        TreeDelegateRecord, TreeDelegateRole,
+    },
 };
 use anchor_lang::prelude::*;
 use mpl_account_compression::{program::MplAccountCompression, Noop as MplNoop};
+use spl_account_compression::{program::SplAccountCompression, Noop as SplNoop};
+
+#[derive(Accounts)]
+pub struct CloseTree<'info> {
+    #[account(
+        mut,
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    /// Tree creator or delegate, or a scoped delegate with the close role.
+    pub authority: Signer<'info>,
+    /// CHECK: This account is modified in the downstream program.
+    #[account(mut, owner = spl_account_compression::ID)]
+    pub merkle_tree: UncheckedAccount<'info>,
+    /// Recipient for reclaimed lamports (tree + config PDA). Must be the creator
+    /// or the delegate.
+    /// CHECK: This account is validated in the instruction.
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+    pub compression_program: Program<'info, SplAccountCompression>,
+    pub log_wrapper: Program<'info, SplNoop>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn close_tree(ctx: Context<CloseTree>) -> Result<()> {
+    // Only V1 trees (created via `create_tree`) are supported.
+    require!(
+        ctx.accounts.tree_authority.version == Version::V1,
+        BubblegumError::UnsupportedSchemaVersion
+    );
+
+    let optional =
+        OptionalAccounts::parse(&ctx.accounts.merkle_tree.key(), ctx.remaining_accounts)?;
+    assert_can_close_tree(
+        &ctx.accounts.tree_authority,
+        &ctx.accounts.merkle_tree.key(),
+        &ctx.accounts.authority.key(),
+        optional.tree_delegate_record.as_ref(),
+        &ctx.accounts.recipient.key(),
+    )?;
+
+    // Close the empty tree via CPI using the tree authority PDA as the signer.
+    let merkle_tree = ctx.accounts.merkle_tree.to_account_info();
+    let seed = merkle_tree.key();
+    let seeds = &[seed.as_ref(), &[ctx.bumps.tree_authority]];
+    let authority_pda_signer = &[&seeds[..]];
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.compression_program.to_account_info(),
+        spl_account_compression::cpi::accounts::CloseTree {
+            merkle_tree: ctx.accounts.merkle_tree.to_account_info(),
+            authority: ctx.accounts.tree_authority.to_account_info(),
+            recipient: ctx.accounts.recipient.to_account_info(),
+        },
+        authority_pda_signer,
+    );
+    spl_account_compression::cpi::close_empty_tree(cpi_ctx)?;
+
+    // Close the tree config PDA to reclaim its rent.
+    ctx.accounts
+        .tree_authority
+        .close(ctx.accounts.recipient.to_account_info())
+}
 
 #[derive(Accounts)]
 pub struct CloseTreeV2<'info> {
@@ -13,7 +81,7 @@ pub struct CloseTreeV2<'info> {
         bump,
     )]
     pub tree_authority: Account<'info, TreeConfig>,
-    /// Tree creator or delegate.
+    /// Tree creator or delegate, or a scoped delegate with the close role.
     pub authority: Signer<'info>,
     /// CHECK: This account is modified in the downstream program.
     #[account(mut, owner = mpl_account_compression::ID)]
@@ -35,20 +103,25 @@ pub(crate) fn close_tree_v2(ctx: Context<CloseTreeV2>) -> Result<()> {
         BubblegumError::UnsupportedSchemaVersion
     );
 
-    // Only the creator or delegate can trigger closure.
-    let authority = ctx.accounts.authority.key();
-    require!(
-        authority == ctx.accounts.tree_authority.tree_creator
-            || authority == ctx.accounts.tree_authority.tree_delegate,
-        BubblegumError::InvalidAuthority
-    );
+    let optional =
+        OptionalAccounts::parse(&ctx.accounts.merkle_tree.key(), ctx.remaining_accounts)?;
+    assert_can_close_tree(
+        &ctx.accounts.tree_authority,
+        &ctx.accounts.merkle_tree.key(),
+        &ctx.accounts.authority.key(),
+        optional.tree_delegate_record.as_ref(),
+        &ctx.accounts.recipient.key(),
+    )?;
 
-    // Recipient must be the creator or delegate as well.
-    require!(
-        ctx.accounts.recipient.key() == ctx.accounts.tree_authority.tree_creator
-            || ctx.accounts.recipient.key() == ctx.accounts.tree_authority.tree_delegate,
-        BubblegumError::PublicKeyMismatch
-    );
+    // Fail early with a clear error when the counters show that leaves are still in the tree.
+    if let Some(counters) =
+        get_extension::<TreeCounters>(&ctx.accounts.tree_authority.to_account_info())?
+    {
+        require!(
+            counters.num_live(ctx.accounts.tree_authority.num_minted) == 0,
+            BubblegumError::TreeNotEmpty
+        );
+    }
 
     // Close the empty tree via CPI using the tree authority PDA as the signer.
     let merkle_tree = ctx.accounts.merkle_tree.to_account_info();
@@ -72,3 +145,32 @@ pub(crate) fn close_tree_v2(ctx: Context<CloseTreeV2>) -> Result<()> {
         .tree_authority
         .close(ctx.accounts.recipient.to_account_info())
 }
+
+/// Authority and recipient checks shared by `close_tree` and `close_tree_v2`.
+fn assert_can_close_tree(
+    tree_authority: &TreeConfig,
+    merkle_tree: &Pubkey,
+    authority: &Pubkey,
+    tree_delegate_record: Option<&TreeDelegateRecord>,
+    recipient: &Pubkey,
+) -> Result<()> {
+    // Only the creator or delegate (including a scoped delegate with the close role) can trigger
+    // closure.
+    require!(
+        tree_authority.is_authorized(
+            merkle_tree,
+            authority,
+            tree_delegate_record,
+            TreeDelegateRole::Close,
+        ),
+        BubblegumError::InvalidAuthority
+    );
+
+    // Recipient must be the creator or delegate as well.
+    require!(
+        *recipient == tree_authority.tree_creator || *recipient == tree_authority.tree_delegate,
+        BubblegumError::PublicKeyMismatch
+    );
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{TreeConfig, TreeDelegateRecord, TREE_DELEGATE_RECORD_PREFIX};
+
+#[derive(Accounts)]
+pub struct CloseTreeDelegateRecord<'info> {
+    #[account(
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+        has_one = tree_creator
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    #[account(mut)]
+    pub tree_creator: Signer<'info>,
+    /// CHECK: this account is neither read from or written to
+    pub delegate: UncheckedAccount<'info>,
+    /// CHECK: Used to derive `tree_authority`
+    pub merkle_tree: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = tree_creator,
+        seeds = [
+            TREE_DELEGATE_RECORD_PREFIX.as_ref(),
+            merkle_tree.key().as_ref(),
+            delegate.key().as_ref()
+        ],
+        bump,
+    )]
+    pub tree_delegate_record: Account<'info, TreeDelegateRecord>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn close_tree_delegate_record(_ctx: Context<CloseTreeDelegateRecord>) -> Result<()> {
+    // The record is closed by the `close` constraint.
+    Ok(())
+}
@@ -2,16 +2,27 @@ use anchor_lang::prelude::*;
 
 use crate::{
     error::BubblegumError,
-    state::{collect::COLLECT_RECIPIENT, leaf_schema::Version, TreeConfig, TREE_AUTHORITY_SIZE},
+    state::{
+        collect::{FeeConfig, DEFAULT_FEE_RECIPIENT, FEE_CONFIG_PREFIX},
+        leaf_schema::Version,
+        TreeConfig,
+    },
 };
 
 #[derive(Accounts)]
 pub struct CollectV2<'info> {
     #[account(mut)]
     pub tree_authority: Account<'info, TreeConfig>,
-    /// CHECK: Hardcoded recipient with no data
-    #[account(mut, address = COLLECT_RECIPIENT)]
+    /// CHECK: Fee recipient, checked in the instruction against the fee config, or the default
+    /// recipient while the fee config is not initialized
+    #[account(mut)]
     destination: UncheckedAccount<'info>,
+    /// CHECK: Fee config PDA, which may not be initialized
+    #[account(
+        seeds = [FEE_CONFIG_PREFIX.as_ref()],
+        bump,
+    )]
+    pub fee_config: UncheckedAccount<'info>,
 }
 
 pub(crate) fn collect_v2<'info>(ctx: Context<'_, '_, '_, 'info, CollectV2<'info>>) -> Result<()> {
@@ -21,8 +32,20 @@ pub(crate) fn collect_v2<'info>(ctx: Context<'_, '_, '_, 'info, CollectV2<'info>
         BubblegumError::UnsupportedSchemaVersion
     );
 
-    let rent_amount = Rent::get()?.minimum_balance(TREE_AUTHORITY_SIZE);
+    // Fees are swept to the default recipient until the fee config is initialized.
+    let fee_config = ctx.accounts.fee_config.to_account_info();
+    let fee_recipient = if fee_config.data_is_empty() {
+        DEFAULT_FEE_RECIPIENT
+    } else {
+        FeeConfig::load(&fee_config)?.fee_recipient
+    };
+    require!(
+        ctx.accounts.destination.key() == fee_recipient,
+        BubblegumError::PublicKeyMismatch
+    );
+
     let source = ctx.accounts.tree_authority.to_account_info();
+    let rent_amount = Rent::get()?.minimum_balance(source.data_len());
     let destination = ctx.accounts.destination.to_account_info();
 
     let fee_amount = source
@@ -12,7 +12,10 @@ use std::cell::Ref;
 
 use crate::{
     error::BubblegumError,
-    state::{DecompressibleState, TreeConfig, TREE_AUTHORITY_SIZE},
+    state::{
+        tree_extension::{add_extension, TreeExtension, TREE_EXTENSION_HEADER_SIZE},
+        DecompressibleState, TreeConfig, TreeCounters, TREE_AUTHORITY_SIZE,
+    },
 };
 
 pub const MAX_ACC_PROOFS_SIZE: u32 = 17;
@@ -86,7 +89,7 @@ pub struct CreateTreeV2<'info> {
         init,
         seeds = [merkle_tree.key().as_ref()],
         payer = payer,
-        space = TREE_AUTHORITY_SIZE,
+        space = TREE_AUTHORITY_SIZE + TREE_EXTENSION_HEADER_SIZE + TreeCounters::LEN,
         bump,
     )]
     pub tree_authority: Account<'info, TreeConfig>,
@@ -138,6 +141,12 @@ pub(crate) fn create_tree_v2(
         version: crate::state::leaf_schema::Version::V2,
     });
 
+    add_extension::<TreeCounters>(
+        &authority.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
     let authority_pda_signer = &[&seeds[..]];
     let cpi_ctx = CpiContext::new_with_signer(
         ctx.accounts.compression_program.to_account_info(),
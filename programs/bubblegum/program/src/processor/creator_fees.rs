@@ -0,0 +1,153 @@
+use anchor_lang::prelude::*;
+use solana_program::{program::invoke, system_instruction};
+
+use crate::{
+    error::BubblegumError,
+    processor::OptionalAccounts,
+    state::{
+        collect::{TreeCreatorFees, TREE_CREATOR_FEES_PREFIX, TREE_CREATOR_FEES_SIZE},
+        leaf_schema::Version,
+        TreeConfig, TreeDelegateRole, TreeSettings,
+    },
+};
+
+#[derive(Accounts)]
+pub struct SetTreeCreatorFee<'info> {
+    #[account(
+        mut,
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+        has_one = tree_creator
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub tree_creator: Signer<'info>,
+    /// CHECK: Used to derive `tree_authority`
+    pub merkle_tree: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = TREE_CREATOR_FEES_SIZE,
+        seeds = [TREE_CREATOR_FEES_PREFIX.as_ref(), merkle_tree.key().as_ref()],
+        bump,
+    )]
+    pub tree_creator_fees: Account<'info, TreeCreatorFees>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn set_tree_creator_fee(
+    ctx: Context<SetTreeCreatorFee>,
+    creator_fee_lamports: u64,
+) -> Result<()> {
+    let tree_authority = &mut ctx.accounts.tree_authority;
+
+    // The creator fee is charged by the V2 mint instructions only.
+    require!(
+        tree_authority.version == Version::V2,
+        BubblegumError::UnsupportedSchemaVersion
+    );
+
+    let tree_creator_fees = &mut ctx.accounts.tree_creator_fees;
+    tree_creator_fees.merkle_tree = ctx.accounts.merkle_tree.key();
+    tree_creator_fees.tree_creator = ctx.accounts.tree_creator.key();
+    tree_creator_fees.creator_fee_lamports = creator_fee_lamports;
+
+    TreeSettings::update(
+        &tree_authority.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        |settings| settings.has_creator_fee = creator_fee_lamports > 0,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawCreatorFees<'info> {
+    /// CHECK: Only checked for existence, since the tree may have been closed
+    #[account(
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+    )]
+    pub tree_authority: UncheckedAccount<'info>,
+    pub tree_creator: Signer<'info>,
+    /// CHECK: Used to derive `tree_authority`
+    pub merkle_tree: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [TREE_CREATOR_FEES_PREFIX.as_ref(), merkle_tree.key().as_ref()],
+        bump,
+        has_one = tree_creator
+    )]
+    pub tree_creator_fees: Account<'info, TreeCreatorFees>,
+    /// CHECK: Receives the fees, chosen by the tree creator
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+}
+
+pub(crate) fn withdraw_creator_fees(ctx: Context<WithdrawCreatorFees>) -> Result<()> {
+    // Once the tree is closed no more fees can be charged, so close the account with them.
+    if ctx.accounts.tree_authority.data_is_empty() {
+        return ctx
+            .accounts
+            .tree_creator_fees
+            .close(ctx.accounts.destination.to_account_info());
+    }
+
+    let rent_amount = Rent::get()?.minimum_balance(TREE_CREATOR_FEES_SIZE);
+    let source = ctx.accounts.tree_creator_fees.to_account_info();
+    let destination = ctx.accounts.destination.to_account_info();
+
+    let fee_amount = source
+        .lamports()
+        .checked_sub(rent_amount)
+        .ok_or(BubblegumError::NumericalOverflowError)?;
+
+    **destination.try_borrow_mut_lamports()? = destination
+        .lamports()
+        .checked_add(fee_amount)
+        .ok_or(BubblegumError::NumericalOverflowError)?;
+
+    **source.try_borrow_mut_lamports()? = rent_amount;
+
+    Ok(())
+}
+
+/// Charges the creator fee on a mint into a public tree by `minter`, unless it is allowed to mint
+/// into the tree anyway.  The creator fees account is read from the optional accounts.
+pub(crate) fn pay_creator_fee<'info>(
+    tree_authority: &Account<'info, TreeConfig>,
+    merkle_tree: &Pubkey,
+    minter: &Pubkey,
+    optional: &OptionalAccounts<'_, 'info>,
+    payer: &AccountInfo<'info>,
+) -> Result<()> {
+    if !tree_authority.is_public
+        || !TreeSettings::load(&tree_authority.to_account_info())?.has_creator_fee
+        || tree_authority.is_authorized(
+            merkle_tree,
+            minter,
+            optional.tree_delegate_record.as_ref(),
+            TreeDelegateRole::Mint,
+        )
+    {
+        return Ok(());
+    }
+
+    let (tree_creator_fees, account) = optional
+        .tree_creator_fees
+        .as_ref()
+        .ok_or(BubblegumError::MissingTreeCreatorFeesAccount)?;
+
+    invoke(
+        &system_instruction::transfer(
+            payer.key,
+            account.key,
+            tree_creator_fees.creator_fee_lamports,
+        ),
+        &[payer.clone(), (*account).clone()],
+    )?;
+
+    Ok(())
+}
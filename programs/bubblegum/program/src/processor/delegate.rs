@@ -3,10 +3,12 @@ use mpl_account_compression::{program::MplAccountCompression, Noop as MplNoop};
 use spl_account_compression::{program::SplAccountCompression, Noop as SplNoop};
 
 use crate::{
+    asserts::assert_tree_not_paused,
     error::BubblegumError,
+    processor::{asset_validate_not_revoked, emit_asset_event},
     state::{
-        leaf_schema::{LeafSchema, Version},
-        TreeConfig,
+        leaf_schema::{LeafSchema, LeafUpdate, Version},
+        AssetUpdate, TreeConfig,
     },
     utils::{
         get_asset_id, replace_leaf, Flags, DEFAULT_ASSET_DATA_HASH, DEFAULT_COLLECTION_HASH,
@@ -49,6 +51,8 @@ pub(crate) fn delegate<'info>(
         BubblegumError::UnsupportedSchemaVersion
     );
 
+    assert_tree_not_paused(&ctx.accounts.tree_authority.to_account_info())?;
+
     let merkle_tree = ctx.accounts.merkle_tree.to_account_info();
     let owner = ctx.accounts.leaf_owner.key();
     let previous_delegate = ctx.accounts.previous_leaf_delegate.key();
@@ -128,13 +132,15 @@ pub(crate) fn delegate_v2<'info>(
     flags: Option<u8>,
     nonce: u64,
     index: u32,
-) -> Result<()> {
+) -> Result<LeafUpdate> {
     // V2 instructions only work with V2 trees.
     require!(
         ctx.accounts.tree_authority.version == Version::V2,
         BubblegumError::UnsupportedSchemaVersion
     );
 
+    assert_tree_not_paused(&ctx.accounts.tree_authority.to_account_info())?;
+
     // Ensure asset is not frozen.
     let raw_flags = flags.unwrap_or(DEFAULT_FLAGS);
     let flags = Flags::from_bytes([raw_flags]);
@@ -190,6 +196,16 @@ pub(crate) fn delegate_v2<'info>(
         &ctx.accounts.log_wrapper,
     )?;
 
+    emit_asset_event(
+        asset_id,
+        AssetUpdate::DelegatedV2 {
+            authority: leaf_owner,
+            previous_delegate: previous_leaf_delegate,
+            new_delegate: ctx.accounts.new_leaf_delegate.key(),
+        },
+        &ctx.accounts.log_wrapper,
+    )?;
+
     replace_leaf(
         Version::V2,
         &merkle_tree.key(),
@@ -203,7 +219,9 @@ pub(crate) fn delegate_v2<'info>(
         previous_leaf.to_node(),
         new_leaf.to_node(),
         index,
-    )
+    )?;
+
+    LeafUpdate::new(new_leaf, index, &ctx.accounts.merkle_tree.to_account_info())
 }
 
 pub(crate) fn asset_validate_delegate(flags: Flags) -> Result<()> {
@@ -211,5 +229,5 @@ pub(crate) fn asset_validate_delegate(flags: Flags) -> Result<()> {
         return Err(BubblegumError::AssetIsFrozen.into());
     }
 
-    Ok(())
+    asset_validate_not_revoked(flags)
 }
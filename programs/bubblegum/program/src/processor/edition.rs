@@ -0,0 +1,162 @@
+use anchor_lang::prelude::*;
+use mpl_account_compression::{program::MplAccountCompression, Noop as MplNoop};
+
+use crate::{
+    error::BubblegumError,
+    processor::{mint::process_mint_v2, MintV2, MintV2Bumps},
+    state::{
+        leaf_schema::{LeafUpdate, Version},
+        metaplex_adapter::{MetadataArgsV2, TokenStandard},
+        metaplex_anchor::MplCore,
+        MasterEdition, PrintEditionEvent, TreeConfig, MASTER_EDITION_PREFIX, MASTER_EDITION_SIZE,
+        MPL_CORE_CPI_SIGNER_PREFIX,
+    },
+    utils::{hash_edition, hash_metadata, wrap_application_data_v1},
+};
+
+#[derive(Accounts)]
+#[instruction(edition_seed: Pubkey)]
+pub struct CreateMasterEditionV2<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = MASTER_EDITION_SIZE,
+        seeds = [MASTER_EDITION_PREFIX.as_ref(), authority.key().as_ref(), edition_seed.as_ref()],
+        bump,
+    )]
+    pub master_edition: Account<'info, MasterEdition>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn create_master_edition_v2(
+    ctx: Context<CreateMasterEditionV2>,
+    edition_seed: Pubkey,
+    metadata: MetadataArgsV2,
+    max_supply: Option<u64>,
+) -> Result<()> {
+    ctx.accounts.master_edition.set_inner(MasterEdition {
+        authority: ctx.accounts.authority.key(),
+        edition_seed,
+        data_hash: hash_metadata(&metadata)?,
+        max_supply,
+        supply: 0,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct PrintEditionV2<'info> {
+    #[account(
+        mut,
+        seeds = [
+            MASTER_EDITION_PREFIX.as_ref(),
+            master_edition.authority.as_ref(),
+            master_edition.edition_seed.as_ref(),
+        ],
+        bump,
+        constraint = master_edition.authority == edition_authority.key()
+            @ BubblegumError::InvalidAuthority,
+    )]
+    pub master_edition: Account<'info, MasterEdition>,
+    pub edition_authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// Optional tree delegate, defaults to `payer`
+    pub tree_delegate: Option<Signer<'info>>,
+    /// Optional collection authority, defaults to `tree_delegate`
+    pub collection_authority: Option<Signer<'info>>,
+    /// CHECK: This account is neither written to nor read from
+    pub leaf_owner: UncheckedAccount<'info>,
+    /// CHECK: This account is neither written to nor read from
+    pub leaf_delegate: Option<UncheckedAccount<'info>>,
+    /// CHECK: This account is modified in the downstream program
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+    /// CHECK: This account is checked in the instruction
+    #[account(mut, owner = mpl_core_program.key())]
+    pub core_collection: Option<UncheckedAccount<'info>>,
+    /// CHECK: This is just used as a signing PDA.
+    #[account(
+        seeds = [MPL_CORE_CPI_SIGNER_PREFIX.as_ref()],
+        bump,
+    )]
+    pub mpl_core_cpi_signer: Option<UncheckedAccount<'info>>,
+    pub log_wrapper: Program<'info, MplNoop>,
+    pub compression_program: Program<'info, MplAccountCompression>,
+    pub mpl_core_program: Program<'info, MplCore>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn print_edition_v2(
+    ctx: Context<PrintEditionV2>,
+    metadata_args: MetadataArgsV2,
+) -> Result<LeafUpdate> {
+    let master_edition = &mut ctx.accounts.master_edition;
+    require!(
+        master_edition.contains_supply(),
+        BubblegumError::MasterEditionSupplyExhausted
+    );
+
+    // Prints are exact copies of the master edition metadata.
+    require!(
+        hash_metadata(&metadata_args)? == master_edition.data_hash,
+        BubblegumError::EditionMetadataMismatch
+    );
+
+    master_edition.supply = master_edition
+        .supply
+        .checked_add(1)
+        .ok_or(BubblegumError::NumericalOverflowError)?;
+    let edition = master_edition.supply;
+    let master_edition_key = master_edition.key();
+
+    let mut accounts = MintV2 {
+        tree_authority: ctx.accounts.tree_authority.clone(),
+        payer: ctx.accounts.payer.clone(),
+        tree_delegate: ctx.accounts.tree_delegate.clone(),
+        collection_authority: ctx.accounts.collection_authority.clone(),
+        leaf_owner: ctx.accounts.leaf_owner.clone(),
+        leaf_delegate: ctx.accounts.leaf_delegate.clone(),
+        merkle_tree: ctx.accounts.merkle_tree.clone(),
+        core_collection: ctx.accounts.core_collection.clone(),
+        mpl_core_cpi_signer: ctx.accounts.mpl_core_cpi_signer.clone(),
+        log_wrapper: ctx.accounts.log_wrapper.clone(),
+        compression_program: ctx.accounts.compression_program.clone(),
+        mpl_core_program: ctx.accounts.mpl_core_program.clone(),
+        system_program: ctx.accounts.system_program.clone(),
+    };
+    let bumps = MintV2Bumps {
+        tree_authority: ctx.bumps.tree_authority,
+        mpl_core_cpi_signer: ctx.bumps.mpl_core_cpi_signer,
+    };
+
+    // The edition authority signs every print, so it is the only creator that can be verified on
+    // the prints, as on any other mint.
+    let update = process_mint_v2(
+        Context::new(ctx.program_id, &mut accounts, ctx.remaining_accounts, bumps),
+        metadata_args,
+        TokenStandard::NonFungible,
+        hash_edition(&master_edition_key, edition),
+        &[ctx.accounts.edition_authority.key()],
+    )?;
+
+    // The mint count was incremented on the copy of the tree config.
+    ctx.accounts
+        .tree_authority
+        .set_inner(accounts.tree_authority.into_inner());
+
+    let event = PrintEditionEvent::new(Version::V2, master_edition_key, update.leaf.id(), edition);
+    wrap_application_data_v1(Version::V2, event.try_to_vec()?, &ctx.accounts.log_wrapper)?;
+
+    Ok(update)
+}
@@ -0,0 +1,95 @@
+use anchor_lang::{prelude::*, solana_program::bpf_loader_upgradeable};
+
+use crate::{
+    error::BubblegumError,
+    state::{
+        collect::{
+            FeeConfig, FEE_CONFIG_PREFIX, FEE_CONFIG_SIZE, MAX_MINT_V2_FEE_LAMPORTS,
+            MAX_TRANSFER_V2_FEE_LAMPORTS,
+        },
+        TreeConfig, TreeSettings,
+    },
+};
+
+#[derive(Accounts)]
+pub struct SetFeeConfig<'info> {
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = FEE_CONFIG_SIZE,
+        seeds = [FEE_CONFIG_PREFIX.as_ref()],
+        bump,
+    )]
+    pub fee_config: Account<'info, FeeConfig>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// Upgrade authority of the Bubblegum program.
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [crate::id().as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable::ID,
+        constraint = program_data.upgrade_authority_address == Some(authority.key())
+            @ BubblegumError::InvalidAuthority,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn set_fee_config(
+    ctx: Context<SetFeeConfig>,
+    fee_recipient: Pubkey,
+    mint_v2_fee_lamports: u64,
+    transfer_v2_fee_lamports: u64,
+) -> Result<()> {
+    require!(
+        mint_v2_fee_lamports <= MAX_MINT_V2_FEE_LAMPORTS
+            && transfer_v2_fee_lamports <= MAX_TRANSFER_V2_FEE_LAMPORTS,
+        BubblegumError::FeeExceedsMaximum
+    );
+
+    ctx.accounts.fee_config.set_inner(FeeConfig {
+        fee_recipient,
+        mint_v2_fee_lamports,
+        transfer_v2_fee_lamports,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetTreeFeeExempt<'info> {
+    #[account(
+        mut,
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    /// CHECK: Used to derive `tree_authority`
+    pub merkle_tree: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// Upgrade authority of the Bubblegum program.
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [crate::id().as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable::ID,
+        constraint = program_data.upgrade_authority_address == Some(authority.key())
+            @ BubblegumError::InvalidAuthority,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn set_tree_fee_exempt(
+    ctx: Context<SetTreeFeeExempt>,
+    is_fee_exempt: bool,
+) -> Result<()> {
+    TreeSettings::update(
+        &ctx.accounts.tree_authority.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        |settings| settings.is_fee_exempt = is_fee_exempt,
+    )
+}
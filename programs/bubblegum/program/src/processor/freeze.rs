@@ -4,9 +4,10 @@ use mpl_core::Collection;
 
 use crate::{
     error::BubblegumError,
+    processor::{asset_validate_not_revoked, emit_asset_event, OptionalAccounts},
     state::{
-        leaf_schema::{LeafSchema, Version},
-        TreeConfig,
+        leaf_schema::{LeafSchema, LeafUpdate, Version},
+        AssetUpdate, TreeConfig, TreeCounters,
     },
     traits::{MplCorePluginValidation, ValidationResult},
     utils::{
@@ -26,7 +27,7 @@ pub struct FreezeV2<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     /// Optional authority, defaults to `payer`.  Must be either
-    /// the leaf delegate or collection permanent freeze delegate.
+    /// the leaf delegate or collection or tree permanent freeze delegate.
     pub authority: Option<Signer<'info>>,
     /// CHECK: This account is neither written to nor read from
     pub leaf_owner: UncheckedAccount<'info>,
@@ -51,7 +52,7 @@ pub(crate) fn freeze_v2<'info>(
     flags: Option<u8>,
     nonce: u64,
     index: u32,
-) -> Result<()> {
+) -> Result<LeafUpdate> {
     process_freeze(
         ctx,
         root,
@@ -75,13 +76,16 @@ pub(crate) fn process_freeze<'info>(
     nonce: u64,
     index: u32,
     frozen: bool,
-) -> Result<()> {
+) -> Result<LeafUpdate> {
     // V2 instructions only work with V2 trees.
     require!(
         ctx.accounts.tree_authority.version == Version::V2,
         BubblegumError::UnsupportedSchemaVersion
     );
 
+    let optional =
+        OptionalAccounts::parse(&ctx.accounts.merkle_tree.key(), ctx.remaining_accounts)?;
+
     let authority = ctx
         .accounts
         .authority
@@ -106,14 +110,23 @@ pub(crate) fn process_freeze<'info>(
         } else {
             ValidationResult::Abstain
         }
+    } else if let Some(tree_plugins) = &optional.tree_plugins {
+        // Uncollected assets use the tree-level plugins instead.
+        tree_plugins.validate_freeze(authority)
     } else {
         ValidationResult::Abstain
     };
 
     // Additional checks and set correct freeze flag.
     let flags = flags.unwrap_or(DEFAULT_FLAGS);
+
+    // Revoked assets cannot be thawed.
+    if !frozen {
+        asset_validate_not_revoked(Flags::from_bytes([flags]))?;
+    }
+
     let updated_flags = if validation_result == ValidationResult::ForceApproved {
-        // Authority by permanent-level authority on the collection.
+        // Authority by permanent-level authority on the collection or tree.
         set_permanent_lvl_freeze_flag(flags, frozen)
     } else {
         // If freeze not not approved by a collection plugin, then the leaf delegate can freeze
@@ -166,6 +179,27 @@ pub(crate) fn process_freeze<'info>(
         &ctx.accounts.log_wrapper,
     )?;
 
+    let permanent = validation_result == ValidationResult::ForceApproved;
+    let update = if frozen {
+        AssetUpdate::FrozenV2 {
+            authority,
+            permanent,
+        }
+    } else {
+        AssetUpdate::ThawedV2 {
+            authority,
+            permanent,
+        }
+    };
+    emit_asset_event(asset_id, update, &ctx.accounts.log_wrapper)?;
+
+    TreeCounters::update(&ctx.accounts.tree_authority.to_account_info(), |counters| {
+        counters.record_flags_change(
+            Flags::from_bytes([flags]),
+            Flags::from_bytes([updated_flags]),
+        )
+    })?;
+
     replace_leaf(
         Version::V2,
         &merkle_tree.key(),
@@ -174,12 +208,14 @@ pub(crate) fn process_freeze<'info>(
         &ctx.accounts.tree_authority.to_account_info(),
         &ctx.accounts.merkle_tree.to_account_info(),
         &ctx.accounts.log_wrapper.to_account_info(),
-        ctx.remaining_accounts,
+        optional.remaining_accounts,
         root,
         previous_leaf.to_node(),
         new_leaf.to_node(),
         index,
-    )
+    )?;
+
+    LeafUpdate::new(new_leaf, index, &ctx.accounts.merkle_tree.to_account_info())
 }
 
 fn set_permanent_lvl_freeze_flag(flags: u8, frozen: bool) -> u8 {
@@ -0,0 +1,473 @@
+use anchor_lang::prelude::*;
+use mpl_account_compression::{program::MplAccountCompression, Noop as MplNoop};
+use mpl_core::Collection;
+use solana_program::{program::invoke, system_instruction};
+use spl_account_compression::Node;
+
+use crate::{
+    asserts::assert_tree_not_paused,
+    error::BubblegumError,
+    processor::{
+        asset_validate_non_frozen, asset_validate_transferable, emit_asset_event,
+        mint::process_mint_v2, mpl_core_collection_validate_transfer, MintV2, OptionalAccounts,
+    },
+    state::{
+        leaf_schema::{LeafSchema, LeafUpdate, Version},
+        metaplex_adapter::{MetadataArgsV2, TokenStandard},
+        AssetUpdate, TreeConfig, TreeCounters, TreeSettings,
+    },
+    utils::{
+        append_leaf, get_asset_id, hash_amount, hash_collection_option, replace_leaf, Flags,
+        DEFAULT_FLAGS,
+    },
+};
+
+pub(crate) fn mint_fungible_v2(
+    ctx: Context<MintV2>,
+    metadata_args: MetadataArgsV2,
+    amount: u64,
+) -> Result<LeafUpdate> {
+    require!(amount > 0, BubblegumError::InvalidAmount);
+
+    // The leaf being minted starts a new class, identified by its asset id.
+    let class_id = get_asset_id(
+        &ctx.accounts.merkle_tree.key(),
+        ctx.accounts.tree_authority.num_minted,
+    );
+
+    process_mint_v2(
+        ctx,
+        metadata_args,
+        TokenStandard::FungibleAsset,
+        hash_amount(&class_id, amount),
+        &[],
+    )
+}
+
+#[derive(Accounts)]
+pub struct SplitV2<'info> {
+    #[account(
+        mut,
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// Optional authority, defaults to `payer`.  Must be the leaf owner.
+    pub authority: Option<Signer<'info>>,
+    /// CHECK: This account is neither written to nor read from
+    pub leaf_owner: UncheckedAccount<'info>,
+    /// CHECK: This account is neither written to nor read from
+    /// Defaults to `leaf_owner`
+    pub leaf_delegate: Option<UncheckedAccount<'info>>,
+    /// Owner of the leaf split off, which is a partial transfer when it is not `leaf_owner`.
+    /// CHECK: This account is neither written to nor read from.
+    pub new_leaf_owner: UncheckedAccount<'info>,
+    /// CHECK: This account is modified in the downstream program
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+    /// CHECK: This account is checked in the instruction
+    pub core_collection: Option<UncheckedAccount<'info>>,
+    pub log_wrapper: Program<'info, MplNoop>,
+    pub compression_program: Program<'info, MplAccountCompression>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn split_v2<'info>(
+    ctx: Context<'_, '_, '_, 'info, SplitV2<'info>>,
+    root: [u8; 32],
+    data_hash: [u8; 32],
+    creator_hash: [u8; 32],
+    flags: Option<u8>,
+    nonce: u64,
+    index: u32,
+    class_id: Pubkey,
+    amount: u64,
+    split_amount: u64,
+) -> Result<LeafUpdate> {
+    // V2 instructions only work with V2 trees.
+    require!(
+        ctx.accounts.tree_authority.version == Version::V2,
+        BubblegumError::UnsupportedSchemaVersion
+    );
+
+    let optional =
+        OptionalAccounts::parse(&ctx.accounts.merkle_tree.key(), ctx.remaining_accounts)?;
+
+    assert_tree_not_paused(&ctx.accounts.tree_authority.to_account_info())?;
+
+    // Both leaves must keep a non-zero amount.
+    require!(
+        split_amount > 0 && split_amount < amount,
+        BubblegumError::InvalidAmount
+    );
+
+    let authority = ctx
+        .accounts
+        .authority
+        .as_ref()
+        .map(|account| account.key())
+        .unwrap_or(ctx.accounts.payer.key());
+
+    let leaf_owner = ctx.accounts.leaf_owner.key();
+    let leaf_delegate = ctx
+        .accounts
+        .leaf_delegate
+        .as_ref()
+        .map(|account| account.key())
+        .unwrap_or(leaf_owner);
+
+    require!(authority == leaf_owner, BubblegumError::InvalidAuthority);
+
+    let raw_flags = flags.unwrap_or(DEFAULT_FLAGS);
+    let flags = Flags::from_bytes([raw_flags]);
+    asset_validate_non_frozen(flags)?;
+
+    let new_leaf_owner = ctx.accounts.new_leaf_owner.key();
+
+    // Splitting off to another owner is a transfer of that amount.
+    if new_leaf_owner != leaf_owner {
+        asset_validate_transferable(flags)?;
+
+        if let Some(core_collection) = &ctx.accounts.core_collection {
+            require!(
+                *core_collection.owner == mpl_core::ID,
+                BubblegumError::IncorrectOwner
+            );
+
+            let core_collection_data = &core_collection.data.borrow()[..];
+            let collection = Collection::from_bytes(core_collection_data)?;
+            mpl_core_collection_validate_transfer(
+                &collection,
+                authority,
+                &ctx.accounts.leaf_owner,
+                &ctx.accounts.new_leaf_owner,
+            )?;
+        } else if TreeSettings::load(&ctx.accounts.tree_authority.to_account_info())?.has_plugins {
+            // Uncollected assets use the tree-level plugins instead.
+            let tree_plugins = optional
+                .tree_plugins
+                .as_ref()
+                .ok_or(BubblegumError::MissingTreePluginsAccount)?;
+
+            tree_plugins.validate_transfer(
+                authority,
+                &ctx.accounts.leaf_owner,
+                &ctx.accounts.new_leaf_owner,
+            )?;
+        }
+
+        let settings = TreeSettings::load(&ctx.accounts.tree_authority.to_account_info())?;
+        let fee = optional
+            .fee_config
+            .unwrap_or_default()
+            .transfer_v2_fee(&settings);
+        if fee > 0 {
+            invoke(
+                &system_instruction::transfer(
+                    ctx.accounts.payer.key,
+                    &ctx.accounts.tree_authority.key(),
+                    fee,
+                ),
+                &[
+                    ctx.accounts.payer.to_account_info(),
+                    ctx.accounts.tree_authority.to_account_info(),
+                ],
+            )?;
+        }
+    }
+
+    // The split off amount is a new leaf.
+    let tree_authority = &mut ctx.accounts.tree_authority;
+    if !tree_authority.contains_mint_capacity(1) {
+        return Err(BubblegumError::InsufficientMintCapacity.into());
+    }
+
+    let merkle_tree = &ctx.accounts.merkle_tree;
+    let collection_hash = hash_collection_option(
+        ctx.accounts
+            .core_collection
+            .as_ref()
+            .map(|account| *account.key),
+    )?;
+    let asset_id = get_asset_id(&merkle_tree.key(), nonce);
+
+    let previous_leaf = LeafSchema::new_v2(
+        asset_id,
+        leaf_owner,
+        leaf_delegate,
+        nonce,
+        data_hash,
+        creator_hash,
+        collection_hash,
+        hash_amount(&class_id, amount),
+        raw_flags,
+    );
+
+    let new_leaf = LeafSchema::new_v2(
+        asset_id,
+        leaf_owner,
+        leaf_delegate,
+        nonce,
+        data_hash,
+        creator_hash,
+        collection_hash,
+        hash_amount(&class_id, amount - split_amount),
+        raw_flags,
+    );
+
+    // New leaves are instantiated with no delegate.
+    let split_nonce = tree_authority.num_minted;
+    let split_leaf = LeafSchema::new_v2(
+        get_asset_id(&merkle_tree.key(), split_nonce),
+        new_leaf_owner,
+        new_leaf_owner,
+        split_nonce,
+        data_hash,
+        creator_hash,
+        collection_hash,
+        hash_amount(&class_id, split_amount),
+        raw_flags,
+    );
+
+    crate::utils::wrap_application_data_v1(
+        Version::V2,
+        new_leaf.to_event().try_to_vec()?,
+        &ctx.accounts.log_wrapper,
+    )?;
+
+    crate::utils::wrap_application_data_v1(
+        Version::V2,
+        split_leaf.to_event().try_to_vec()?,
+        &ctx.accounts.log_wrapper,
+    )?;
+
+    emit_asset_event(
+        asset_id,
+        AssetUpdate::SplitV2 {
+            authority,
+            split_asset_id: split_leaf.id(),
+            new_owner: new_leaf_owner,
+            split_amount,
+        },
+        &ctx.accounts.log_wrapper,
+    )?;
+
+    TreeCounters::update(&tree_authority.to_account_info(), |counters| {
+        counters.record_flags_change(Flags::new(), flags)
+    })?;
+
+    replace_leaf(
+        Version::V2,
+        &merkle_tree.key(),
+        ctx.bumps.tree_authority,
+        &ctx.accounts.compression_program.to_account_info(),
+        &tree_authority.to_account_info(),
+        &merkle_tree.to_account_info(),
+        &ctx.accounts.log_wrapper.to_account_info(),
+        optional.remaining_accounts,
+        root,
+        previous_leaf.to_node(),
+        new_leaf.to_node(),
+        index,
+    )?;
+
+    append_leaf(
+        Version::V2,
+        &merkle_tree.key(),
+        ctx.bumps.tree_authority,
+        &ctx.accounts.compression_program.to_account_info(),
+        &tree_authority.to_account_info(),
+        &merkle_tree.to_account_info(),
+        &ctx.accounts.log_wrapper.to_account_info(),
+        split_leaf.to_node(),
+    )?;
+
+    tree_authority.increment_mint_count();
+
+    LeafUpdate::new(new_leaf, index, &merkle_tree.to_account_info())
+}
+
+#[derive(Accounts)]
+pub struct MergeV2<'info> {
+    #[account(
+        mut,
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// Optional authority, defaults to `payer`.  Must be the owner of both leaves.
+    pub authority: Option<Signer<'info>>,
+    /// CHECK: This account is neither written to nor read from
+    pub leaf_owner: UncheckedAccount<'info>,
+    /// Delegate of both leaves.
+    /// CHECK: This account is neither written to nor read from
+    /// Defaults to `leaf_owner`
+    pub leaf_delegate: Option<UncheckedAccount<'info>>,
+    /// CHECK: This account is modified in the downstream program
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+    /// CHECK: This account is only used to derive the collection hash
+    pub core_collection: Option<UncheckedAccount<'info>>,
+    pub log_wrapper: Program<'info, MplNoop>,
+    pub compression_program: Program<'info, MplAccountCompression>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn merge_v2<'info>(
+    ctx: Context<'_, '_, '_, 'info, MergeV2<'info>>,
+    root: [u8; 32],
+    data_hash: [u8; 32],
+    creator_hash: [u8; 32],
+    flags: Option<u8>,
+    nonce: u64,
+    index: u32,
+    class_id: Pubkey,
+    amount: u64,
+    other_nonce: u64,
+    other_index: u32,
+    other_amount: u64,
+) -> Result<LeafUpdate> {
+    // V2 instructions only work with V2 trees.
+    require!(
+        ctx.accounts.tree_authority.version == Version::V2,
+        BubblegumError::UnsupportedSchemaVersion
+    );
+
+    assert_tree_not_paused(&ctx.accounts.tree_authority.to_account_info())?;
+
+    require!(
+        index != other_index,
+        BubblegumError::CannotMergeLeafWithItself
+    );
+
+    let authority = ctx
+        .accounts
+        .authority
+        .as_ref()
+        .map(|account| account.key())
+        .unwrap_or(ctx.accounts.payer.key());
+
+    let leaf_owner = ctx.accounts.leaf_owner.key();
+    let leaf_delegate = ctx
+        .accounts
+        .leaf_delegate
+        .as_ref()
+        .map(|account| account.key())
+        .unwrap_or(leaf_owner);
+
+    require!(authority == leaf_owner, BubblegumError::InvalidAuthority);
+
+    let raw_flags = flags.unwrap_or(DEFAULT_FLAGS);
+    let flags = Flags::from_bytes([raw_flags]);
+    asset_validate_non_frozen(flags)?;
+
+    let merged_amount = amount
+        .checked_add(other_amount)
+        .ok_or(BubblegumError::NumericalOverflowError)?;
+
+    let merkle_tree = &ctx.accounts.merkle_tree;
+    let collection_hash = hash_collection_option(
+        ctx.accounts
+            .core_collection
+            .as_ref()
+            .map(|account| *account.key),
+    )?;
+    let asset_id = get_asset_id(&merkle_tree.key(), nonce);
+
+    let previous_leaf = LeafSchema::new_v2(
+        asset_id,
+        leaf_owner,
+        leaf_delegate,
+        nonce,
+        data_hash,
+        creator_hash,
+        collection_hash,
+        hash_amount(&class_id, amount),
+        raw_flags,
+    );
+
+    let other_leaf = LeafSchema::new_v2(
+        get_asset_id(&merkle_tree.key(), other_nonce),
+        leaf_owner,
+        leaf_delegate,
+        other_nonce,
+        data_hash,
+        creator_hash,
+        collection_hash,
+        hash_amount(&class_id, other_amount),
+        raw_flags,
+    );
+
+    let new_leaf = LeafSchema::new_v2(
+        asset_id,
+        leaf_owner,
+        leaf_delegate,
+        nonce,
+        data_hash,
+        creator_hash,
+        collection_hash,
+        hash_amount(&class_id, merged_amount),
+        raw_flags,
+    );
+
+    crate::utils::wrap_application_data_v1(
+        Version::V2,
+        new_leaf.to_event().try_to_vec()?,
+        &ctx.accounts.log_wrapper,
+    )?;
+
+    emit_asset_event(
+        asset_id,
+        AssetUpdate::MergedV2 {
+            authority,
+            merged_asset_id: other_leaf.id(),
+            merged_amount: other_amount,
+        },
+        &ctx.accounts.log_wrapper,
+    )?;
+
+    // The merged leaf is removed from the tree like a burned one.
+    TreeCounters::update(&ctx.accounts.tree_authority.to_account_info(), |counters| {
+        counters.record_burn(flags)
+    })?;
+
+    let (proof, other_proof) = ctx
+        .remaining_accounts
+        .split_at(ctx.remaining_accounts.len() / 2);
+
+    replace_leaf(
+        Version::V2,
+        &merkle_tree.key(),
+        ctx.bumps.tree_authority,
+        &ctx.accounts.compression_program.to_account_info(),
+        &ctx.accounts.tree_authority.to_account_info(),
+        &merkle_tree.to_account_info(),
+        &ctx.accounts.log_wrapper.to_account_info(),
+        other_proof,
+        root,
+        other_leaf.to_node(),
+        Node::default(),
+        other_index,
+    )?;
+
+    replace_leaf(
+        Version::V2,
+        &merkle_tree.key(),
+        ctx.bumps.tree_authority,
+        &ctx.accounts.compression_program.to_account_info(),
+        &ctx.accounts.tree_authority.to_account_info(),
+        &merkle_tree.to_account_info(),
+        &ctx.accounts.log_wrapper.to_account_info(),
+        proof,
+        root,
+        previous_leaf.to_node(),
+        new_leaf.to_node(),
+        index,
+    )?;
+
+    LeafUpdate::new(new_leaf, index, &merkle_tree.to_account_info())
+}
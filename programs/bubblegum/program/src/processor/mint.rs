@@ -6,15 +6,20 @@ use spl_account_compression::{program::SplAccountCompression, Noop as SplNoop};
 use std::collections::HashSet;
 
 use crate::{
-    asserts::{assert_metadata_is_mpl_compatible, assert_metadata_token_standard},
+    asserts::{
+        assert_metadata_is_mpl_compatible, assert_metadata_token_standard, assert_tree_not_paused,
+    },
     error::BubblegumError,
-    processor::process_collection_verification_mpl_core_only,
+    processor::{
+        emit_asset_event, pay_creator_fee, process_collection_verification_mpl_core_only,
+        OptionalAccounts,
+    },
     state::{
-        collect::MINT_V2_FEE_LAMPORTS,
-        leaf_schema::{LeafSchema, Version},
-        metaplex_adapter::{MetadataArgs, MetadataArgsCommon, MetadataArgsV2},
+        leaf_schema::{LeafSchema, LeafUpdate, Version},
+        metaplex_adapter::{MetadataArgs, MetadataArgsCommon, MetadataArgsV2, TokenStandard},
         metaplex_anchor::MplCore,
-        AssetDataSchema, TreeConfig, MPL_CORE_CPI_SIGNER_PREFIX,
+        AssetDataSchema, AssetUpdate, TreeConfig, TreeDelegateRole, TreeSettings, UriTemplate,
+        MPL_CORE_CPI_SIGNER_PREFIX,
     },
     utils::{
         append_leaf, get_asset_id, hash_collection_option, DEFAULT_ASSET_DATA_HASH, DEFAULT_FLAGS,
@@ -55,10 +60,19 @@ pub(crate) fn mint_v1(ctx: Context<MintV1>, message: MetadataArgs) -> Result<Lea
         BubblegumError::UnsupportedSchemaVersion
     );
 
+    let optional =
+        OptionalAccounts::parse(&ctx.accounts.merkle_tree.key(), ctx.remaining_accounts)?;
+
+    assert_tree_not_paused(&authority.to_account_info())?;
+
     if !authority.is_public {
         require!(
-            incoming_tree_delegate == authority.tree_creator
-                || incoming_tree_delegate == authority.tree_delegate,
+            authority.is_authorized(
+                &merkle_tree.key(),
+                &incoming_tree_delegate,
+                optional.tree_delegate_record.as_ref(),
+                TreeDelegateRole::Mint,
+            ),
             BubblegumError::TreeAuthorityIncorrect,
         );
     }
@@ -93,6 +107,8 @@ pub(crate) fn mint_v1(ctx: Context<MintV1>, message: MetadataArgs) -> Result<Lea
         &ctx.accounts.log_wrapper,
         &ctx.accounts.compression_program,
         false,
+        TokenStandard::NonFungible,
+        DEFAULT_ASSET_DATA_HASH,
     )?;
 
     authority.increment_mint_count();
@@ -141,11 +157,30 @@ pub(crate) fn mint_v2(
     metadata_args: MetadataArgsV2,
     asset_data: Option<Vec<u8>>,
     asset_data_schema: Option<AssetDataSchema>,
-) -> Result<LeafSchema> {
+) -> Result<LeafUpdate> {
     if asset_data.is_some() || asset_data_schema.is_some() {
         return Err(BubblegumError::NotAvailable.into());
     }
 
+    process_mint_v2(
+        ctx,
+        metadata_args,
+        TokenStandard::NonFungible,
+        DEFAULT_ASSET_DATA_HASH,
+        &[],
+    )
+}
+
+/// Mints a leaf with the accounts of `mint_v2`, after checking that `tree_delegate` can mint into
+/// the tree, and charges the mint fees.  `metadata_signers` are signers outside of the `mint_v2`
+/// accounts that can also verify creators.
+pub(crate) fn process_mint_v2(
+    ctx: Context<MintV2>,
+    metadata_args: MetadataArgsV2,
+    token_standard: TokenStandard,
+    asset_data_hash: [u8; 32],
+    metadata_signers: &[Pubkey],
+) -> Result<LeafUpdate> {
     let tree_delegate = ctx
         .accounts
         .tree_delegate
@@ -160,10 +195,19 @@ pub(crate) fn mint_v2(
         BubblegumError::UnsupportedSchemaVersion
     );
 
+    let optional =
+        OptionalAccounts::parse(&ctx.accounts.merkle_tree.key(), ctx.remaining_accounts)?;
+
+    assert_tree_not_paused(&tree_authority.to_account_info())?;
+
     if !tree_authority.is_public {
         require!(
-            tree_delegate == tree_authority.tree_creator
-                || tree_delegate == tree_authority.tree_delegate,
+            tree_authority.is_authorized(
+                &ctx.accounts.merkle_tree.key(),
+                &tree_delegate,
+                optional.tree_delegate_record.as_ref(),
+                TreeDelegateRole::Mint,
+            ),
             BubblegumError::TreeAuthorityIncorrect,
         );
     }
@@ -177,6 +221,7 @@ pub(crate) fn mint_v2(
     let mut metadata_auth = HashSet::<Pubkey>::new();
     metadata_auth.insert(ctx.accounts.payer.key());
     metadata_auth.insert(tree_delegate);
+    metadata_auth.extend(metadata_signers);
 
     // If there are any remaining accounts that are also signers, they can also be used for
     // creator validation.
@@ -222,7 +267,7 @@ pub(crate) fn mint_v2(
     }
 
     let leaf = process_mint(
-        metadata_args,
+        metadata_args.clone(),
         &ctx.accounts.leaf_owner,
         ctx.accounts.leaf_delegate.as_deref(),
         metadata_auth,
@@ -232,23 +277,57 @@ pub(crate) fn mint_v2(
         &ctx.accounts.log_wrapper,
         &ctx.accounts.compression_program,
         true,
+        token_standard,
+        asset_data_hash,
     )?;
 
     tree_authority.increment_mint_count();
 
-    invoke(
-        &system_instruction::transfer(
-            ctx.accounts.payer.key,
-            &ctx.accounts.tree_authority.key(),
-            MINT_V2_FEE_LAMPORTS,
-        ),
-        &[
-            ctx.accounts.payer.to_account_info(),
-            ctx.accounts.tree_authority.to_account_info(),
-        ],
+    UriTemplate::freeze_for_leaf(
+        &ctx.accounts.tree_authority.to_account_info(),
+        metadata_args.is_mutable,
+        metadata_args.collection.is_some(),
     )?;
 
-    Ok(leaf)
+    emit_asset_event(
+        leaf.id(),
+        AssetUpdate::MintedV2 {
+            authority: tree_delegate,
+            leaf_owner: ctx.accounts.leaf_owner.key(),
+            metadata: metadata_args,
+        },
+        &ctx.accounts.log_wrapper,
+    )?;
+
+    let settings = TreeSettings::load(&ctx.accounts.tree_authority.to_account_info())?;
+    let fee = optional
+        .fee_config
+        .unwrap_or_default()
+        .mint_v2_fee(&settings);
+    if fee > 0 {
+        invoke(
+            &system_instruction::transfer(
+                ctx.accounts.payer.key,
+                &ctx.accounts.tree_authority.key(),
+                fee,
+            ),
+            &[
+                ctx.accounts.payer.to_account_info(),
+                ctx.accounts.tree_authority.to_account_info(),
+            ],
+        )?;
+    }
+
+    pay_creator_fee(
+        &ctx.accounts.tree_authority,
+        &ctx.accounts.merkle_tree.key(),
+        &tree_delegate,
+        &optional,
+        &ctx.accounts.payer.to_account_info(),
+    )?;
+
+    let index = leaf.nonce() as u32;
+    LeafUpdate::new(leaf, index, &ctx.accounts.merkle_tree.to_account_info())
 }
 
 pub(crate) fn process_mint<'info, T: MetadataArgsCommon>(
@@ -262,6 +341,8 @@ pub(crate) fn process_mint<'info, T: MetadataArgsCommon>(
     wrapper: &AccountInfo<'info>,
     compression_program: &AccountInfo<'info>,
     allow_verified_collection: bool,
+    token_standard: TokenStandard,
+    asset_data_hash: [u8; 32],
 ) -> Result<LeafSchema> {
     assert_metadata_is_mpl_compatible(&message)?;
 
@@ -269,7 +350,7 @@ pub(crate) fn process_mint<'info, T: MetadataArgsCommon>(
         return Err(BubblegumError::CollectionCannotBeVerifiedInThisInstruction.into());
     }
 
-    assert_metadata_token_standard(&message)?;
+    assert_metadata_token_standard(&message, token_standard)?;
 
     // @dev: seller_fee_basis points is encoded twice so that it can be passed to marketplace
     // instructions, without passing the entire, un-hashed MetadataArgs struct
@@ -327,7 +408,7 @@ pub(crate) fn process_mint<'info, T: MetadataArgsCommon>(
                 data_hash.to_bytes(),
                 creator_hash.to_bytes(),
                 collection_hash,
-                DEFAULT_ASSET_DATA_HASH,
+                asset_data_hash,
                 DEFAULT_FLAGS,
             )
         }
@@ -4,14 +4,16 @@ use anchor_lang::prelude::*;
 use spl_account_compression::{program::SplAccountCompression, Noop};
 
 use crate::{
+    asserts::assert_tree_not_paused,
     error::BubblegumError,
-    processor::{mint::process_mint, process_collection_verification_mpl_only},
+    processor::{mint::process_mint, process_collection_verification_mpl_only, OptionalAccounts},
     state::{
         leaf_schema::{LeafSchema, Version},
-        metaplex_adapter::MetadataArgs,
+        metaplex_adapter::{MetadataArgs, TokenStandard},
         metaplex_anchor::TokenMetadata,
-        TreeConfig,
+        TreeConfig, TreeDelegateRole,
     },
+    utils::DEFAULT_ASSET_DATA_HASH,
 };
 
 #[derive(Accounts)]
@@ -61,6 +63,11 @@ pub(crate) fn mint_to_collection_v1(
         BubblegumError::UnsupportedSchemaVersion
     );
 
+    let optional =
+        OptionalAccounts::parse(&ctx.accounts.merkle_tree.key(), ctx.remaining_accounts)?;
+
+    assert_tree_not_paused(&ctx.accounts.tree_authority.to_account_info())?;
+
     let mut message = metadata_args;
     let payer = ctx.accounts.payer.key();
     let incoming_tree_delegate = ctx.accounts.tree_delegate.key();
@@ -78,8 +85,12 @@ pub(crate) fn mint_to_collection_v1(
 
     if !authority.is_public {
         require!(
-            incoming_tree_delegate == authority.tree_creator
-                || incoming_tree_delegate == authority.tree_delegate,
+            authority.is_authorized(
+                &merkle_tree.key(),
+                &incoming_tree_delegate,
+                optional.tree_delegate_record.as_ref(),
+                TreeDelegateRole::Mint,
+            ),
             BubblegumError::TreeAuthorityIncorrect,
         );
     }
@@ -130,6 +141,8 @@ pub(crate) fn mint_to_collection_v1(
         &ctx.accounts.log_wrapper,
         &ctx.accounts.compression_program,
         true,
+        TokenStandard::NonFungible,
+        DEFAULT_ASSET_DATA_HASH,
     )?;
 
     authority.increment_mint_count();
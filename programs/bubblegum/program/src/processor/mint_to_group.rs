@@ -0,0 +1,142 @@
+use anchor_lang::prelude::*;
+use mpl_account_compression::{program::MplAccountCompression, Noop as MplNoop};
+
+use crate::{
+    error::BubblegumError,
+    processor::{mint::process_mint_v2, MintV2, MintV2Bumps},
+    state::{
+        leaf_schema::LeafUpdate,
+        metaplex_adapter::{MetadataArgsV2, TokenStandard},
+        metaplex_anchor::MplCore,
+        AssetDataSchema, TreeConfig, TreeGroup, MPL_CORE_CPI_SIGNER_PREFIX, TREE_GROUP_PREFIX,
+    },
+    utils::DEFAULT_ASSET_DATA_HASH,
+};
+
+#[derive(Accounts)]
+pub struct MintToGroupV2<'info> {
+    #[account(
+        mut,
+        seeds = [
+            TREE_GROUP_PREFIX.as_ref(),
+            tree_group.authority.as_ref(),
+            tree_group.group_seed.as_ref(),
+        ],
+        bump,
+    )]
+    pub tree_group: Account<'info, TreeGroup>,
+    #[account(
+        mut,
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// Optional tree delegate, defaults to `payer`
+    pub tree_delegate: Option<Signer<'info>>,
+    /// Optional collection authority, defaults to `tree_delegate`
+    pub collection_authority: Option<Signer<'info>>,
+    /// CHECK: This account is neither written to nor read from
+    pub leaf_owner: UncheckedAccount<'info>,
+    /// CHECK: This account is neither written to nor read from
+    pub leaf_delegate: Option<UncheckedAccount<'info>>,
+    /// The group's current tree, or a later one in the group if the current tree was filled
+    /// outside of the group and `tree_delegate` is the group authority.
+    /// CHECK: This account is modified in the downstream program
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+    /// CHECK: This account is checked in the instruction
+    #[account(mut, owner = mpl_core_program.key())]
+    pub core_collection: Option<UncheckedAccount<'info>>,
+    /// CHECK: This is just used as a signing PDA.
+    #[account(
+        seeds = [MPL_CORE_CPI_SIGNER_PREFIX.as_ref()],
+        bump,
+    )]
+    pub mpl_core_cpi_signer: Option<UncheckedAccount<'info>>,
+    pub log_wrapper: Program<'info, MplNoop>,
+    pub compression_program: Program<'info, MplAccountCompression>,
+    pub mpl_core_program: Program<'info, MplCore>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn mint_to_group_v2(
+    ctx: Context<MintToGroupV2>,
+    metadata_args: MetadataArgsV2,
+    asset_data: Option<Vec<u8>>,
+    asset_data_schema: Option<AssetDataSchema>,
+) -> Result<LeafUpdate> {
+    if asset_data.is_some() || asset_data_schema.is_some() {
+        return Err(BubblegumError::NotAvailable.into());
+    }
+
+    let merkle_tree = ctx.accounts.merkle_tree.key();
+    let tree_delegate = ctx
+        .accounts
+        .tree_delegate
+        .as_ref()
+        .map(|account| account.key())
+        .unwrap_or(ctx.accounts.payer.key());
+    let tree_group = &mut ctx.accounts.tree_group;
+
+    // Once the last tree is full the group is out of capacity until more trees are added.
+    let current_tree_index = tree_group.current_tree_index as usize;
+    if tree_group.current_tree().is_none() {
+        return Err(BubblegumError::InsufficientMintCapacity.into());
+    }
+
+    // Trees before the current one are full.  Only the group authority can target a later tree
+    // directly, which moves the group past any tree that was filled by minting to it outside of
+    // the group, so that other minters cannot skip trees that still have capacity.
+    let tree_index = tree_group
+        .trees
+        .iter()
+        .position(|tree| *tree == merkle_tree)
+        .filter(|index| {
+            *index == current_tree_index
+                || (*index > current_tree_index && tree_delegate == tree_group.authority)
+        })
+        .ok_or(BubblegumError::TreeNotInGroup)?;
+    tree_group.current_tree_index = tree_index as u32;
+
+    let mut accounts = MintV2 {
+        tree_authority: ctx.accounts.tree_authority.clone(),
+        payer: ctx.accounts.payer.clone(),
+        tree_delegate: ctx.accounts.tree_delegate.clone(),
+        collection_authority: ctx.accounts.collection_authority.clone(),
+        leaf_owner: ctx.accounts.leaf_owner.clone(),
+        leaf_delegate: ctx.accounts.leaf_delegate.clone(),
+        merkle_tree: ctx.accounts.merkle_tree.clone(),
+        core_collection: ctx.accounts.core_collection.clone(),
+        mpl_core_cpi_signer: ctx.accounts.mpl_core_cpi_signer.clone(),
+        log_wrapper: ctx.accounts.log_wrapper.clone(),
+        compression_program: ctx.accounts.compression_program.clone(),
+        mpl_core_program: ctx.accounts.mpl_core_program.clone(),
+        system_program: ctx.accounts.system_program.clone(),
+    };
+    let bumps = MintV2Bumps {
+        tree_authority: ctx.bumps.tree_authority,
+        mpl_core_cpi_signer: ctx.bumps.mpl_core_cpi_signer,
+    };
+
+    let update = process_mint_v2(
+        Context::new(ctx.program_id, &mut accounts, ctx.remaining_accounts, bumps),
+        metadata_args,
+        TokenStandard::NonFungible,
+        DEFAULT_ASSET_DATA_HASH,
+        &[],
+    )?;
+
+    // The mint count was incremented on the copy of the tree config.
+    ctx.accounts
+        .tree_authority
+        .set_inner(accounts.tree_authority.into_inner());
+
+    // Roll over as soon as the tree is full so the next mint goes to the following tree.
+    if !ctx.accounts.tree_authority.contains_mint_capacity(1) {
+        ctx.accounts.tree_group.current_tree_index += 1;
+    }
+
+    Ok(update)
+}
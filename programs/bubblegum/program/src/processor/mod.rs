@@ -16,74 +16,124 @@ use crate::{
             MetadataArgsCommon, MetadataArgsV2,
         },
         metaplex_anchor::TokenMetadata,
-        MPL_CORE_CPI_SIGNER_PREFIX,
+        AssetEvent, AssetUpdate, MPL_CORE_CPI_SIGNER_PREFIX,
     },
     traits::{MplCorePluginValidation, ValidationResult},
     utils::{
-        get_asset_id, hash_creators, hash_metadata, replace_leaf, DEFAULT_ASSET_DATA_HASH,
-        DEFAULT_COLLECTION_HASH, DEFAULT_FLAGS,
+        get_asset_id, hash_creators, hash_metadata, replace_leaf, wrap_application_data_v1,
+        DEFAULT_ASSET_DATA_HASH, DEFAULT_COLLECTION_HASH, DEFAULT_FLAGS,
     },
 };
 
+mod add_tree_extension;
 mod burn;
 mod cancel_redeem;
 mod close_tree;
+mod close_tree_delegate_record;
 mod collect;
 mod compress;
 mod create_tree;
+mod creator_fees;
 mod decompress;
 mod delegate;
 mod delegate_and_freeze;
+mod edition;
+mod fee_config;
 mod freeze;
+mod fungible;
 mod mint;
 mod mint_to_collection;
+mod mint_to_group;
+mod optional_accounts;
+mod recover_non_transferable;
 mod redeem;
+mod revoke;
 mod set_and_verify_collection;
+mod set_base_uri;
 mod set_collection;
 mod set_decompressible_state;
 mod set_non_transferable;
 mod set_tree_delegate;
+mod set_tree_delegate_record;
+mod set_tree_paused;
+mod set_tree_plugins;
+mod stake;
 mod thaw;
 mod thaw_and_revoke;
 mod transfer;
+mod tree_group;
 mod unverify_collection;
 mod unverify_creator;
 mod update_asset_data;
+mod update_creator;
 mod update_metadata;
+mod update_tree_config;
+mod utilize;
 mod verify_collection;
 mod verify_creator;
+mod verify_leaf;
 
+pub(crate) use add_tree_extension::*;
 pub(crate) use burn::*;
 pub(crate) use cancel_redeem::*;
 pub(crate) use close_tree::*;
+pub(crate) use close_tree_delegate_record::*;
 pub(crate) use collect::*;
 pub(crate) use compress::*;
 pub(crate) use create_tree::*;
+pub(crate) use creator_fees::*;
 pub(crate) use decompress::*;
 pub(crate) use delegate::*;
 pub(crate) use delegate_and_freeze::*;
+pub(crate) use edition::*;
+pub(crate) use fee_config::*;
 pub(crate) use freeze::*;
+pub(crate) use fungible::*;
 pub(crate) use mint::*;
 pub(crate) use mint_to_collection::*;
+pub(crate) use mint_to_group::*;
+pub(crate) use optional_accounts::*;
+pub(crate) use recover_non_transferable::*;
 pub(crate) use redeem::*;
+pub(crate) use revoke::*;
 pub(crate) use set_and_verify_collection::*;
+pub(crate) use set_base_uri::*;
 pub(crate) use set_collection::*;
 pub(crate) use set_decompressible_state::*;
 pub(crate) use set_non_transferable::*;
 pub(crate) use set_tree_delegate::*;
+pub(crate) use set_tree_delegate_record::*;
+pub(crate) use set_tree_paused::*;
+pub(crate) use set_tree_plugins::*;
+pub(crate) use stake::*;
 pub(crate) use thaw::*;
 pub(crate) use thaw_and_revoke::*;
 pub(crate) use transfer::*;
+pub(crate) use tree_group::*;
 pub(crate) use unverify_collection::*;
 pub(crate) use unverify_creator::*;
 pub(crate) use update_asset_data::*;
+pub(crate) use update_creator::*;
 pub(crate) use update_metadata::*;
+pub(crate) use update_tree_config::*;
+pub(crate) use utilize::*;
 pub(crate) use verify_collection::*;
 pub(crate) use verify_creator::*;
+pub(crate) use verify_leaf::*;
+
+/// Logs an `AssetEvent` for a `LeafSchema` V2 leaf, after its leaf schema event.
+pub(crate) fn emit_asset_event(
+    asset_id: Pubkey,
+    update: AssetUpdate,
+    log_wrapper: &AccountInfo<'_>,
+) -> Result<()> {
+    let event = AssetEvent::new(Version::V2, asset_id, update);
+    wrap_application_data_v1(Version::V2, event.try_to_vec()?, log_wrapper)
+}
 
 fn process_creator_verification<T: MetadataArgsCommon>(
     merkle_tree: Pubkey,
-    creator: Pubkey,
+    creators: &[Pubkey],
     data_hash: [u8; 32],
     creator_hash: [u8; 32],
     nonce: u64,
@@ -100,8 +150,12 @@ fn process_creator_verification<T: MetadataArgsCommon>(
         return Err(BubblegumError::NoCreatorsPresent.into());
     }
 
-    // Creator must be in user-provided creator Vec.
-    if !message.creators().iter().any(|c| c.address == creator) {
+    // At least one creator must be in user-provided creator Vec.
+    if !message
+        .creators()
+        .iter()
+        .any(|c| creators.contains(&c.address))
+    {
         return Err(BubblegumError::CreatorNotFound.into());
     }
 
@@ -117,12 +171,12 @@ fn process_creator_verification<T: MetadataArgsCommon>(
         return Err(BubblegumError::DataHashMismatch.into());
     }
 
-    // Calculate new creator Vec with `verified` set to true for signing creator.
+    // Calculate new creator Vec with `verified` set for the signing creators.
     let updated_creator_vec = message
         .creators()
         .iter()
         .map(|c| {
-            let verified = if c.address == creator.key() {
+            let verified = if creators.contains(&c.address) {
                 verify
             } else {
                 c.verified
@@ -360,7 +414,8 @@ fn process_collection_verification<'info>(
         &ctx.accounts.tree_authority.to_account_info(),
         &ctx.accounts.merkle_tree.to_account_info(),
         &ctx.accounts.log_wrapper.to_account_info(),
-        ctx.remaining_accounts,
+        OptionalAccounts::parse(&ctx.accounts.merkle_tree.key(), ctx.remaining_accounts)?
+            .remaining_accounts,
         root,
         previous_leaf.to_node(),
         new_leaf.to_node(),
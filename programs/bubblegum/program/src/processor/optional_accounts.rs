@@ -0,0 +1,109 @@
+use anchor_lang::{prelude::*, Discriminator};
+
+use crate::{
+    error::BubblegumError,
+    state::{
+        collect::{FeeConfig, TreeCreatorFees},
+        TreeDelegateRecord, TreePlugins, TREE_PLUGINS_PREFIX,
+    },
+};
+
+/// Bubblegum accounts that an instruction optionally reads from the start of its remaining
+/// accounts, ahead of the proof or creator signers.  They are recognized by their owner and
+/// discriminator, which proof nodes and signers do not have, so callers that do not pass them keep
+/// the account layout they always had.
+pub(crate) struct OptionalAccounts<'a, 'info> {
+    /// Scoped delegate record, checked against the tree by `TreeConfig::is_authorized`.
+    pub tree_delegate_record: Option<TreeDelegateRecord>,
+    /// Tree-level plugins of the tree.
+    pub tree_plugins: Option<TreePlugins>,
+    /// Protocol fee schedule, the default fees apply when it is not passed.
+    pub fee_config: Option<FeeConfig>,
+    /// Creator fees of the tree and the account holding them, which receives the creator fee.
+    pub tree_creator_fees: Option<(TreeCreatorFees, &'a AccountInfo<'info>)>,
+    /// Remaining accounts after the optional ones.
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+}
+
+impl<'a, 'info> OptionalAccounts<'a, 'info> {
+    /// Reads the optional accounts of `merkle_tree` from the start of `remaining_accounts`,
+    /// stopping at the first account that is not one of them or repeats one already read.
+    pub fn parse(
+        merkle_tree: &Pubkey,
+        remaining_accounts: &'a [AccountInfo<'info>],
+    ) -> Result<Self> {
+        let mut optional = Self {
+            tree_delegate_record: None,
+            tree_plugins: None,
+            fee_config: None,
+            tree_creator_fees: None,
+            remaining_accounts,
+        };
+
+        while let Some((account, rest)) = optional.remaining_accounts.split_first() {
+            let discriminator = discriminator(account);
+            if discriminator == Some(TreeDelegateRecord::DISCRIMINATOR)
+                && optional.tree_delegate_record.is_none()
+            {
+                optional.tree_delegate_record = Some(TreeDelegateRecord::try_deserialize(
+                    &mut &account.try_borrow_data()?[..],
+                )?);
+            } else if discriminator == Some(TreePlugins::DISCRIMINATOR)
+                && optional.tree_plugins.is_none()
+            {
+                // The plugins account does not store its tree, so check its address instead.
+                let (tree_plugins, _) = Pubkey::find_program_address(
+                    &[TREE_PLUGINS_PREFIX.as_ref(), merkle_tree.as_ref()],
+                    &crate::id(),
+                );
+                require_keys_eq!(
+                    account.key(),
+                    tree_plugins,
+                    BubblegumError::PublicKeyMismatch
+                );
+
+                optional.tree_plugins = Some(TreePlugins::try_deserialize(
+                    &mut &account.try_borrow_data()?[..],
+                )?);
+            } else if discriminator == Some(FeeConfig::DISCRIMINATOR)
+                && optional.fee_config.is_none()
+            {
+                // `set_fee_config` only creates the fee config at its PDA, so there is no other
+                // account to check the address against.
+                optional.fee_config = Some(FeeConfig::try_deserialize(
+                    &mut &account.try_borrow_data()?[..],
+                )?);
+            } else if discriminator == Some(TreeCreatorFees::DISCRIMINATOR)
+                && optional.tree_creator_fees.is_none()
+            {
+                let tree_creator_fees =
+                    TreeCreatorFees::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+                require_keys_eq!(
+                    tree_creator_fees.merkle_tree,
+                    *merkle_tree,
+                    BubblegumError::PublicKeyMismatch
+                );
+
+                optional.tree_creator_fees = Some((tree_creator_fees, account));
+            } else {
+                break;
+            }
+
+            optional.remaining_accounts = rest;
+        }
+
+        Ok(optional)
+    }
+}
+
+/// Discriminator of an account owned by Bubblegum, or `None` for any other account.
+pub(crate) fn discriminator(account: &AccountInfo) -> Option<[u8; 8]> {
+    if *account.owner != crate::id() {
+        return None;
+    }
+
+    account
+        .try_borrow_data()
+        .ok()
+        .and_then(|data| data.get(..8).map(|bytes| bytes.try_into().unwrap()))
+}
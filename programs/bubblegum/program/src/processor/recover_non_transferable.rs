@@ -0,0 +1,200 @@
+use anchor_lang::prelude::*;
+use mpl_account_compression::{program::MplAccountCompression, Noop as MplNoop};
+use mpl_core::Collection;
+
+use crate::{
+    asserts::assert_tree_not_paused,
+    error::BubblegumError,
+    processor::{
+        asset_validate_not_revoked, asset_validate_not_staked, emit_asset_event, OptionalAccounts,
+    },
+    state::{
+        leaf_schema::{LeafSchema, LeafUpdate, Version},
+        AssetUpdate, TreeConfig, TreeCounters,
+    },
+    utils::{
+        get_asset_id, hash_collection_option, replace_leaf, Flags, DEFAULT_ASSET_DATA_HASH,
+        DEFAULT_FLAGS,
+    },
+};
+
+/// Maximum length of the reason recorded when recovering an asset.
+pub const MAX_RECOVERY_REASON_LEN: usize = 200;
+
+#[derive(Accounts)]
+pub struct RecoverNonTransferableV2<'info> {
+    #[account(
+        mut,
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// Collection update authority, defaults to `payer`
+    pub authority: Option<Signer<'info>>,
+    /// CHECK: This account is neither written to nor read from
+    pub leaf_owner: UncheckedAccount<'info>,
+    /// CHECK: This account is neither written to nor read from
+    /// Defaults to `leaf_owner`
+    pub leaf_delegate: Option<UncheckedAccount<'info>>,
+    /// CHECK: This account is neither written to nor read from
+    pub new_leaf_owner: UncheckedAccount<'info>,
+    /// CHECK: This account is modified in the downstream program
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+    /// CHECK: This account is checked in the instruction
+    pub core_collection: UncheckedAccount<'info>,
+    pub log_wrapper: Program<'info, MplNoop>,
+    pub compression_program: Program<'info, MplAccountCompression>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn recover_non_transferable_v2<'info>(
+    ctx: Context<'_, '_, '_, 'info, RecoverNonTransferableV2<'info>>,
+    root: [u8; 32],
+    data_hash: [u8; 32],
+    creator_hash: [u8; 32],
+    asset_data_hash: Option<[u8; 32]>,
+    flags: Option<u8>,
+    nonce: u64,
+    index: u32,
+    reason: String,
+) -> Result<LeafUpdate> {
+    // V2 instructions only work with V2 trees.
+    require!(
+        ctx.accounts.tree_authority.version == Version::V2,
+        BubblegumError::UnsupportedSchemaVersion
+    );
+
+    assert_tree_not_paused(&ctx.accounts.tree_authority.to_account_info())?;
+
+    require!(
+        reason.len() <= MAX_RECOVERY_REASON_LEN,
+        BubblegumError::RecoveryReasonTooLong
+    );
+
+    require!(
+        *ctx.accounts.core_collection.owner == mpl_core::ID,
+        BubblegumError::IncorrectOwner
+    );
+
+    let authority = ctx
+        .accounts
+        .authority
+        .as_ref()
+        .map(|account| account.key())
+        .unwrap_or(ctx.accounts.payer.key());
+
+    // Only the collection update authority can recover its assets, so that neither the tree
+    // creator nor its delegates can move assets of a collection they do not control.
+    let core_collection_data = &ctx.accounts.core_collection.data.borrow()[..];
+    let collection = Collection::from_bytes(core_collection_data)?;
+    require!(
+        collection.base.update_authority == authority,
+        BubblegumError::InvalidAuthority
+    );
+
+    let merkle_tree = &ctx.accounts.merkle_tree;
+    let optional =
+        OptionalAccounts::parse(&ctx.accounts.merkle_tree.key(), ctx.remaining_accounts)?;
+
+    // Only non-transferable assets can be recovered, transferable ones are moved by their owner.
+    let flags = flags.unwrap_or(DEFAULT_FLAGS);
+    require!(
+        Flags::from_bytes([flags]).non_transferable(),
+        BubblegumError::AssetIsTransferable
+    );
+    asset_validate_not_revoked(Flags::from_bytes([flags]))?;
+    asset_validate_not_staked(ctx.accounts.leaf_delegate.as_deref())?;
+    let updated_flags = recovered_flags(flags);
+
+    // Gather info for previous leaf and new leaf.
+    let asset_id = get_asset_id(&merkle_tree.key(), nonce);
+
+    let leaf_owner = ctx.accounts.leaf_owner.key();
+    let leaf_delegate = ctx
+        .accounts
+        .leaf_delegate
+        .as_ref()
+        .map(|account| account.key())
+        .unwrap_or(leaf_owner);
+    let new_leaf_owner = ctx.accounts.new_leaf_owner.key();
+
+    let collection_hash = hash_collection_option(Some(ctx.accounts.core_collection.key()))?;
+    let asset_data_hash = asset_data_hash.unwrap_or(DEFAULT_ASSET_DATA_HASH);
+
+    let previous_leaf = LeafSchema::new_v2(
+        asset_id,
+        leaf_owner,
+        leaf_delegate,
+        nonce,
+        data_hash,
+        creator_hash,
+        collection_hash,
+        asset_data_hash,
+        flags,
+    );
+
+    // New leaf is owned and delegated to the new owner, and stays non-transferable.
+    let new_leaf = LeafSchema::new_v2(
+        asset_id,
+        new_leaf_owner,
+        new_leaf_owner,
+        nonce,
+        data_hash,
+        creator_hash,
+        collection_hash,
+        asset_data_hash,
+        updated_flags,
+    );
+
+    crate::utils::wrap_application_data_v1(
+        Version::V2,
+        new_leaf.to_event().try_to_vec()?,
+        &ctx.accounts.log_wrapper,
+    )?;
+
+    emit_asset_event(
+        asset_id,
+        AssetUpdate::RecoveredV2 {
+            authority,
+            previous_owner: leaf_owner,
+            new_owner: new_leaf_owner,
+            reason,
+        },
+        &ctx.accounts.log_wrapper,
+    )?;
+
+    TreeCounters::update(&ctx.accounts.tree_authority.to_account_info(), |counters| {
+        counters.record_flags_change(
+            Flags::from_bytes([flags]),
+            Flags::from_bytes([updated_flags]),
+        )
+    })?;
+
+    replace_leaf(
+        Version::V2,
+        &merkle_tree.key(),
+        ctx.bumps.tree_authority,
+        &ctx.accounts.compression_program.to_account_info(),
+        &ctx.accounts.tree_authority.to_account_info(),
+        &ctx.accounts.merkle_tree.to_account_info(),
+        &ctx.accounts.log_wrapper.to_account_info(),
+        optional.remaining_accounts,
+        root,
+        previous_leaf.to_node(),
+        new_leaf.to_node(),
+        index,
+    )?;
+
+    LeafUpdate::new(new_leaf, index, &ctx.accounts.merkle_tree.to_account_info())
+}
+
+/// The owner-level freeze does not carry over to the new owner, while the permanent freeze and
+/// non-transferable flags set by the collection stay.
+fn recovered_flags(flags: u8) -> u8 {
+    let mut flags = Flags::from_bytes([flags]);
+    flags.set_asset_lvl_frozen(false);
+    flags.into_bytes()[0]
+}
@@ -0,0 +1,193 @@
+use anchor_lang::prelude::*;
+use mpl_account_compression::{program::MplAccountCompression, Noop as MplNoop};
+use mpl_core::Collection as MplCoreCollection;
+
+use crate::{
+    error::BubblegumError,
+    processor::{
+        asset_validate_not_revoked, asset_validate_not_staked, emit_asset_event,
+        mpl_core_collection_validate_update, OptionalAccounts,
+    },
+    state::{
+        leaf_schema::{LeafSchema, LeafUpdate, Version},
+        AssetUpdate, TreeConfig, TreeDelegateRole,
+    },
+    traits::ValidationResult,
+    utils::{
+        get_asset_id, hash_collection_option, replace_leaf, Flags, DEFAULT_ASSET_DATA_HASH,
+        DEFAULT_FLAGS,
+    },
+};
+
+#[derive(Accounts)]
+pub struct RevokeV2<'info> {
+    #[account(
+        mut,
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// Either collection authority or tree owner/delegate, depending on
+    /// whether the item is in a verified collection.  Defaults to `payer`
+    pub authority: Option<Signer<'info>>,
+    /// CHECK: This account is neither written to nor read from
+    pub leaf_owner: UncheckedAccount<'info>,
+    /// CHECK: This account is neither written to nor read from
+    /// Defaults to `leaf_owner`
+    pub leaf_delegate: Option<UncheckedAccount<'info>>,
+    /// CHECK: This account is modified in the downstream program
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+    /// CHECK: This account is checked in the instruction
+    pub core_collection: Option<UncheckedAccount<'info>>,
+    pub log_wrapper: Program<'info, MplNoop>,
+    pub compression_program: Program<'info, MplAccountCompression>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn revoke_v2<'info>(
+    ctx: Context<'_, '_, '_, 'info, RevokeV2<'info>>,
+    root: [u8; 32],
+    data_hash: [u8; 32],
+    creator_hash: [u8; 32],
+    asset_data_hash: Option<[u8; 32]>,
+    flags: Option<u8>,
+    nonce: u64,
+    index: u32,
+) -> Result<LeafUpdate> {
+    // V2 instructions only work with V2 trees.
+    require!(
+        ctx.accounts.tree_authority.version == Version::V2,
+        BubblegumError::UnsupportedSchemaVersion
+    );
+
+    let optional =
+        OptionalAccounts::parse(&ctx.accounts.merkle_tree.key(), ctx.remaining_accounts)?;
+
+    let authority = ctx
+        .accounts
+        .authority
+        .as_ref()
+        .map(|account| account.key())
+        .unwrap_or(ctx.accounts.payer.key());
+
+    if let Some(core_collection) = &ctx.accounts.core_collection {
+        require!(
+            *core_collection.owner == mpl_core::ID,
+            BubblegumError::IncorrectOwner
+        );
+
+        let core_collection_data = &core_collection.data.borrow()[..];
+        let collection: Box<MplCoreCollection> =
+            MplCoreCollection::from_bytes(core_collection_data)?;
+
+        // If there's a collection, the update authority or update delegate must be the
+        // authority.
+        if mpl_core_collection_validate_update(&collection, authority)?
+            != ValidationResult::Approved
+        {
+            return Err(BubblegumError::InvalidCollectionAuthority.into());
+        }
+    } else {
+        // No collection case.
+        require!(
+            ctx.accounts.tree_authority.is_authorized(
+                &ctx.accounts.merkle_tree.key(),
+                &authority,
+                optional.tree_delegate_record.as_ref(),
+                TreeDelegateRole::Revoke,
+            ),
+            BubblegumError::TreeAuthorityIncorrect,
+        );
+    }
+
+    // Set revoked flag, which cannot be cleared.
+    let flags = flags.unwrap_or(DEFAULT_FLAGS);
+    asset_validate_not_revoked(Flags::from_bytes([flags]))?;
+
+    // A revoked leaf can no longer be thawed, so revoking a staked asset would strand it.
+    asset_validate_not_staked(ctx.accounts.leaf_delegate.as_deref())?;
+    let updated_flags = set_revoked_flag(flags);
+
+    // Gather info for previous leaf and new leaf.
+    let merkle_tree = &ctx.accounts.merkle_tree;
+    let asset_id = get_asset_id(&merkle_tree.key(), nonce);
+
+    let leaf_owner = ctx.accounts.leaf_owner.key();
+    let leaf_delegate = ctx
+        .accounts
+        .leaf_delegate
+        .as_ref()
+        .map(|account| account.key())
+        .unwrap_or(leaf_owner);
+
+    let collection_hash = hash_collection_option(
+        ctx.accounts
+            .core_collection
+            .as_ref()
+            .map(|account| *account.key),
+    )?;
+    let asset_data_hash = asset_data_hash.unwrap_or(DEFAULT_ASSET_DATA_HASH);
+
+    let previous_leaf = LeafSchema::new_v2(
+        asset_id,
+        leaf_owner,
+        leaf_delegate,
+        nonce,
+        data_hash,
+        creator_hash,
+        collection_hash,
+        asset_data_hash,
+        flags,
+    );
+
+    // New leaf only has updated flags.
+    let new_leaf = LeafSchema::new_v2(
+        asset_id,
+        leaf_owner,
+        leaf_delegate,
+        nonce,
+        data_hash,
+        creator_hash,
+        collection_hash,
+        asset_data_hash,
+        updated_flags,
+    );
+
+    crate::utils::wrap_application_data_v1(
+        Version::V2,
+        new_leaf.to_event().try_to_vec()?,
+        &ctx.accounts.log_wrapper,
+    )?;
+
+    emit_asset_event(
+        asset_id,
+        AssetUpdate::RevokedV2 { authority },
+        &ctx.accounts.log_wrapper,
+    )?;
+
+    replace_leaf(
+        Version::V2,
+        &merkle_tree.key(),
+        ctx.bumps.tree_authority,
+        &ctx.accounts.compression_program.to_account_info(),
+        &ctx.accounts.tree_authority.to_account_info(),
+        &ctx.accounts.merkle_tree.to_account_info(),
+        &ctx.accounts.log_wrapper.to_account_info(),
+        optional.remaining_accounts,
+        root,
+        previous_leaf.to_node(),
+        new_leaf.to_node(),
+        index,
+    )?;
+
+    LeafUpdate::new(new_leaf, index, &ctx.accounts.merkle_tree.to_account_info())
+}
+
+fn set_revoked_flag(flags: u8) -> u8 {
+    let mut flags = Flags::from_bytes([flags]);
+    flags.set_revoked(true);
+    flags.into_bytes()[0]
+}
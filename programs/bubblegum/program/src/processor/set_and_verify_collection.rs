@@ -2,8 +2,11 @@ use anchor_lang::prelude::*;
 
 use crate::{
     error::BubblegumError,
-    processor::{process_collection_verification, verify_collection::CollectionVerification},
-    state::metaplex_adapter::MetadataArgs,
+    processor::{
+        process_collection_verification, verify_collection::CollectionVerification,
+        OptionalAccounts,
+    },
+    state::{metaplex_adapter::MetadataArgs, TreeDelegateRole},
 };
 
 pub(crate) fn set_and_verify_collection<'info>(
@@ -20,6 +23,8 @@ pub(crate) fn set_and_verify_collection<'info>(
     let tree_creator = ctx.accounts.tree_authority.tree_creator;
     let tree_delegate = ctx.accounts.tree_authority.tree_delegate;
     let collection_metadata = &ctx.accounts.collection_metadata;
+    let optional =
+        OptionalAccounts::parse(&ctx.accounts.merkle_tree.key(), ctx.remaining_accounts)?;
 
     // Require that either the tree authority signed this transaction, or the tree authority is
     // the collection update authority which means the leaf update is approved via proxy, when
@@ -30,8 +35,12 @@ pub(crate) fn set_and_verify_collection<'info>(
     // authority) to be different than the collection authority (actual or delegated).  The
     // token-metadata program required them to be the same.
     let tree_authority_signed = incoming_tree_delegate.is_signer
-        && (incoming_tree_delegate.key() == tree_creator
-            || incoming_tree_delegate.key() == tree_delegate);
+        && ctx.accounts.tree_authority.is_authorized(
+            &ctx.accounts.merkle_tree.key(),
+            &incoming_tree_delegate.key(),
+            optional.tree_delegate_record.as_ref(),
+            TreeDelegateRole::UpdateMetadata,
+        );
 
     let tree_authority_is_collection_update_authority = collection_metadata.update_authority
         == tree_creator
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    asserts::assert_log_wrapper,
+    error::BubblegumError,
+    processor::{emit_tree_config_event, OptionalAccounts},
+    state::{
+        leaf_schema::Version,
+        tree_extension::{get_extension, set_extension},
+        TreeConfig, TreeConfigUpdate, TreeDelegateRole, UriTemplate,
+    },
+};
+
+#[derive(Accounts)]
+pub struct SetBaseUriV2<'info> {
+    #[account(
+        mut,
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    pub payer: Signer<'info>,
+    /// Tree creator or delegate with the update metadata role, defaults to `payer`
+    pub authority: Option<Signer<'info>>,
+    /// CHECK: Used to derive `tree_authority`
+    pub merkle_tree: UncheckedAccount<'info>,
+    /// CHECK: Program is checked against the tree version in the instruction.
+    pub log_wrapper: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn set_base_uri_v2(ctx: Context<SetBaseUriV2>, base_uri: String) -> Result<()> {
+    let merkle_tree = ctx.accounts.merkle_tree.key();
+    let log_wrapper = &ctx.accounts.log_wrapper;
+    let tree_authority = &ctx.accounts.tree_authority;
+    assert_log_wrapper(tree_authority.version, &log_wrapper.key())?;
+
+    // V2 instructions only work with V2 trees.
+    require!(
+        tree_authority.version == Version::V2,
+        BubblegumError::UnsupportedSchemaVersion
+    );
+
+    let optional =
+        OptionalAccounts::parse(&ctx.accounts.merkle_tree.key(), ctx.remaining_accounts)?;
+
+    let authority = ctx
+        .accounts
+        .authority
+        .as_ref()
+        .map(|account| account.key())
+        .unwrap_or(ctx.accounts.payer.key());
+
+    require!(
+        tree_authority.is_authorized(
+            &merkle_tree,
+            &authority,
+            optional.tree_delegate_record.as_ref(),
+            TreeDelegateRole::UpdateMetadata,
+        ),
+        BubblegumError::TreeAuthorityIncorrect,
+    );
+
+    require!(
+        base_uri.len() <= mpl_token_metadata::MAX_URI_LENGTH,
+        BubblegumError::MetadataUriTooLong
+    );
+
+    let tree_authority_info = tree_authority.to_account_info();
+    let mut uri_template = get_extension::<UriTemplate>(&tree_authority_info)?
+        .ok_or(BubblegumError::InvalidTreeExtension)?;
+    require!(!uri_template.is_frozen, BubblegumError::BaseUriFrozen);
+    uri_template.base_uri = base_uri.clone();
+    set_extension(&tree_authority_info, &uri_template)?;
+
+    emit_tree_config_event(
+        tree_authority,
+        merkle_tree,
+        TreeConfigUpdate::BaseUri { base_uri },
+        log_wrapper,
+    )
+}
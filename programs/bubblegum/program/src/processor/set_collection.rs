@@ -4,12 +4,14 @@ use mpl_core::types::UpdateType;
 
 use crate::{
     error::BubblegumError,
-    processor::process_collection_verification_mpl_core_only,
+    processor::{
+        emit_asset_event, process_collection_verification_mpl_core_only, OptionalAccounts,
+    },
     state::{
-        leaf_schema::{LeafSchema, Version},
+        leaf_schema::{LeafSchema, LeafUpdate, Version},
         metaplex_adapter::MetadataArgsV2,
         metaplex_anchor::MplCore,
-        TreeConfig, MPL_CORE_CPI_SIGNER_PREFIX,
+        AssetUpdate, TreeConfig, TreeDelegateRole, UriTemplate, MPL_CORE_CPI_SIGNER_PREFIX,
     },
     utils::{
         get_asset_id, hash_collection_option, hash_creators, hash_metadata, replace_leaf,
@@ -68,13 +70,16 @@ pub(crate) fn set_collection_v2<'info>(
     nonce: u64,
     index: u32,
     message: MetadataArgsV2,
-) -> Result<()> {
+) -> Result<LeafUpdate> {
     // V2 instructions only work with V2 trees.
     require!(
         ctx.accounts.tree_authority.version == Version::V2,
         BubblegumError::UnsupportedSchemaVersion
     );
 
+    let optional =
+        OptionalAccounts::parse(&ctx.accounts.merkle_tree.key(), ctx.remaining_accounts)?;
+
     // Do not add to collection if already in collection, or remove from collection
     // if already not in a collection.
     match (message.collection, &ctx.accounts.new_core_collection) {
@@ -110,8 +115,12 @@ pub(crate) fn set_collection_v2<'info>(
     } else {
         // If there's no existing collection, the tree creator or tree delegate must sign.
         require!(
-            authority == ctx.accounts.tree_authority.tree_creator
-                || authority == ctx.accounts.tree_authority.tree_delegate,
+            ctx.accounts.tree_authority.is_authorized(
+                &ctx.accounts.merkle_tree.key(),
+                &authority,
+                optional.tree_delegate_record.as_ref(),
+                TreeDelegateRole::UpdateMetadata,
+            ),
             BubblegumError::TreeAuthorityIncorrect,
         );
     }
@@ -164,6 +173,12 @@ pub(crate) fn set_collection_v2<'info>(
 
     let updated_data_hash = hash_metadata(&updated_message)?;
 
+    UriTemplate::freeze_for_leaf(
+        &ctx.accounts.tree_authority.to_account_info(),
+        updated_message.is_mutable,
+        updated_message.collection.is_some(),
+    )?;
+
     let previous_collection_hash = hash_collection_option(
         ctx.accounts
             .core_collection
@@ -211,6 +226,15 @@ pub(crate) fn set_collection_v2<'info>(
         &ctx.accounts.log_wrapper,
     )?;
 
+    emit_asset_event(
+        asset_id,
+        AssetUpdate::CollectionSetV2 {
+            authority,
+            collection: updated_message.collection,
+        },
+        &ctx.accounts.log_wrapper,
+    )?;
+
     replace_leaf(
         Version::V2,
         &ctx.accounts.merkle_tree.key(),
@@ -219,10 +243,12 @@ pub(crate) fn set_collection_v2<'info>(
         &ctx.accounts.tree_authority.to_account_info(),
         &ctx.accounts.merkle_tree,
         &ctx.accounts.log_wrapper,
-        ctx.remaining_accounts,
+        optional.remaining_accounts,
         root,
         previous_leaf.to_node(),
         new_leaf.to_node(),
         index,
-    )
+    )?;
+
+    LeafUpdate::new(new_leaf, index, &ctx.accounts.merkle_tree)
 }
@@ -4,9 +4,10 @@ use mpl_core::Collection;
 
 use crate::{
     error::BubblegumError,
+    processor::emit_asset_event,
     state::{
-        leaf_schema::{LeafSchema, Version},
-        TreeConfig,
+        leaf_schema::{LeafSchema, LeafUpdate, Version},
+        AssetUpdate, TreeConfig, TreeCounters,
     },
     traits::{MplCorePluginValidation, ValidationResult},
     utils::{
@@ -52,7 +53,7 @@ pub(crate) fn set_non_transferable_v2<'info>(
     flags: Option<u8>,
     nonce: u64,
     index: u32,
-) -> Result<()> {
+) -> Result<LeafUpdate> {
     // V2 instructions only work with V2 trees.
     require!(
         ctx.accounts.tree_authority.version == Version::V2,
@@ -133,6 +134,19 @@ pub(crate) fn set_non_transferable_v2<'info>(
         &ctx.accounts.log_wrapper,
     )?;
 
+    emit_asset_event(
+        asset_id,
+        AssetUpdate::NonTransferableSetV2 { authority },
+        &ctx.accounts.log_wrapper,
+    )?;
+
+    TreeCounters::update(&ctx.accounts.tree_authority.to_account_info(), |counters| {
+        counters.record_flags_change(
+            Flags::from_bytes([flags]),
+            Flags::from_bytes([updated_flags]),
+        )
+    })?;
+
     replace_leaf(
         Version::V2,
         &merkle_tree.key(),
@@ -146,7 +160,9 @@ pub(crate) fn set_non_transferable_v2<'info>(
         previous_leaf.to_node(),
         new_leaf.to_node(),
         index,
-    )
+    )?;
+
+    LeafUpdate::new(new_leaf, index, &ctx.accounts.merkle_tree.to_account_info())
 }
 
 fn set_non_transferable_flag(flags: u8) -> u8 {
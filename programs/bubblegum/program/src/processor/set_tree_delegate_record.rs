@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::BubblegumError,
+    state::{
+        TreeConfig, TreeDelegateRecord, TreeDelegateRole, TREE_DELEGATE_RECORD_PREFIX,
+        TREE_DELEGATE_RECORD_SIZE,
+    },
+};
+
+#[derive(Accounts)]
+pub struct SetTreeDelegateRecord<'info> {
+    #[account(
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+        has_one = tree_creator
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub tree_creator: Signer<'info>,
+    /// CHECK: this account is neither read from or written to
+    pub delegate: UncheckedAccount<'info>,
+    /// CHECK: Used to derive `tree_authority`
+    pub merkle_tree: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = TREE_DELEGATE_RECORD_SIZE,
+        seeds = [
+            TREE_DELEGATE_RECORD_PREFIX.as_ref(),
+            merkle_tree.key().as_ref(),
+            delegate.key().as_ref()
+        ],
+        bump,
+    )]
+    pub tree_delegate_record: Account<'info, TreeDelegateRecord>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn set_tree_delegate_record(
+    ctx: Context<SetTreeDelegateRecord>,
+    roles: u8,
+) -> Result<()> {
+    // Removing all roles is done by closing the record.
+    require!(
+        roles != 0 && roles & !TreeDelegateRole::VALID == 0,
+        BubblegumError::InvalidTreeDelegateRoles
+    );
+
+    let tree_delegate_record = &mut ctx.accounts.tree_delegate_record;
+    tree_delegate_record.merkle_tree = ctx.accounts.merkle_tree.key();
+    tree_delegate_record.delegate = ctx.accounts.delegate.key();
+    tree_delegate_record.tree_creator = ctx.accounts.tree_creator.key();
+    tree_delegate_record.roles = roles;
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    asserts::assert_log_wrapper,
+    processor::emit_tree_config_event,
+    state::{TreeConfig, TreeConfigUpdate, TreeSettings},
+};
+
+#[derive(Accounts)]
+pub struct SetTreePaused<'info> {
+    #[account(
+        mut,
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+        has_one = tree_creator
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub tree_creator: Signer<'info>,
+    /// CHECK: Used to derive `tree_authority`
+    pub merkle_tree: UncheckedAccount<'info>,
+    /// CHECK: Program is checked against the tree version in the instruction.
+    pub log_wrapper: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn set_tree_paused(ctx: Context<SetTreePaused>, is_paused: bool) -> Result<()> {
+    let merkle_tree = ctx.accounts.merkle_tree.key();
+    let log_wrapper = &ctx.accounts.log_wrapper;
+    let tree_authority = &mut ctx.accounts.tree_authority;
+    assert_log_wrapper(tree_authority.version, &log_wrapper.key())?;
+
+    TreeSettings::update(
+        &tree_authority.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        |settings| settings.is_paused = is_paused,
+    )?;
+
+    emit_tree_config_event(
+        tree_authority,
+        merkle_tree,
+        TreeConfigUpdate::IsPaused { is_paused },
+        log_wrapper,
+    )
+}
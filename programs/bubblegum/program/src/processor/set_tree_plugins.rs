@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::BubblegumError,
+    state::{
+        leaf_schema::Version, tree_extension::get_extension, TreeConfig, TreeCounters, TreePlugins,
+        TreeRuleSet, TreeSettings, MAX_TREE_RULE_SET_PROGRAMS, TREE_PLUGINS_PREFIX,
+        TREE_PLUGINS_SIZE,
+    },
+};
+
+#[derive(Accounts)]
+pub struct SetTreePlugins<'info> {
+    #[account(
+        mut,
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+        has_one = tree_creator
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub tree_creator: Signer<'info>,
+    /// CHECK: Used to derive `tree_authority`
+    pub merkle_tree: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = TREE_PLUGINS_SIZE,
+        seeds = [TREE_PLUGINS_PREFIX.as_ref(), merkle_tree.key().as_ref()],
+        bump,
+    )]
+    pub tree_plugins: Account<'info, TreePlugins>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn set_tree_plugins(
+    ctx: Context<SetTreePlugins>,
+    royalties_rule_set: TreeRuleSet,
+    permanent_freeze_delegate: Option<Pubkey>,
+    permanent_transfer_delegate: Option<Pubkey>,
+    permanent_burn_delegate: Option<Pubkey>,
+) -> Result<()> {
+    let tree_authority = &mut ctx.accounts.tree_authority;
+
+    // Plugins only apply to `LeafSchema` V2 assets.
+    require!(
+        tree_authority.version == Version::V2,
+        BubblegumError::UnsupportedSchemaVersion
+    );
+
+    require!(
+        royalties_rule_set.len() <= MAX_TREE_RULE_SET_PROGRAMS,
+        BubblegumError::TreeRuleSetTooLong
+    );
+
+    // As with mpl-core permanent plugins, a permanent delegate can only be added before any asset
+    // exists, so that owners know about it when receiving an asset.  Removing one is allowed,
+    // except for a permanent freeze delegate while leaves are frozen, since it is the only one
+    // that can thaw permanently frozen leaves.
+    let tree_plugins = &mut ctx.accounts.tree_plugins;
+    if tree_authority.num_minted > 0 {
+        for (current, new) in [
+            (
+                tree_plugins.permanent_freeze_delegate,
+                permanent_freeze_delegate,
+            ),
+            (
+                tree_plugins.permanent_transfer_delegate,
+                permanent_transfer_delegate,
+            ),
+            (
+                tree_plugins.permanent_burn_delegate,
+                permanent_burn_delegate,
+            ),
+        ] {
+            require!(
+                new.is_none() || new == current,
+                BubblegumError::TreePluginsLocked
+            );
+        }
+
+        if tree_plugins.permanent_freeze_delegate.is_some() && permanent_freeze_delegate.is_none() {
+            // Trees without counters cannot tell whether leaves are frozen.
+            let has_frozen_leaves =
+                get_extension::<TreeCounters>(&tree_authority.to_account_info())?
+                    .map_or(true, |counters| counters.num_frozen > 0);
+            require!(!has_frozen_leaves, BubblegumError::TreeHasFrozenLeaves);
+        }
+    }
+
+    tree_plugins.set_inner(TreePlugins {
+        royalties_rule_set,
+        permanent_freeze_delegate,
+        permanent_transfer_delegate,
+        permanent_burn_delegate,
+    });
+
+    let has_plugins = !tree_plugins.is_empty();
+    TreeSettings::update(
+        &tree_authority.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        |settings| settings.has_plugins = has_plugins,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,190 @@
+use anchor_lang::{prelude::*, Discriminator};
+use mpl_account_compression::{program::MplAccountCompression, Noop as MplNoop};
+
+use crate::{
+    processor::{
+        discriminator, process_delegate_and_freeze_v2, process_thaw_and_revoke_v2, BubblegumError,
+    },
+    state::{
+        leaf_schema::LeafUpdate, StakeRecord, TreeConfig, STAKE_RECORD_PREFIX, STAKE_RECORD_SIZE,
+    },
+};
+
+#[derive(Accounts)]
+#[instruction(
+    _root: [u8; 32],
+    _data_hash: [u8; 32],
+    _creator_hash: [u8; 32],
+    _collection_hash: Option<[u8; 32]>,
+    _asset_data_hash: Option<[u8; 32]>,
+    _flags: Option<u8>,
+    nonce: u64,
+)]
+pub struct StakeV2<'info> {
+    #[account(
+        mut,
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    #[account(mut)]
+    pub leaf_owner: Signer<'info>,
+    /// Authority of the staking program, which must sign the unstake
+    pub staking_authority: Signer<'info>,
+    /// CHECK: This account is neither written to nor read from
+    /// Defaults to `leaf_owner`
+    pub previous_leaf_delegate: Option<UncheckedAccount<'info>>,
+    /// CHECK: This account is modified in the downstream program
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+    #[account(
+        init,
+        seeds = [
+            STAKE_RECORD_PREFIX.as_ref(),
+            merkle_tree.key().as_ref(),
+            &nonce.to_le_bytes(),
+        ],
+        payer = leaf_owner,
+        space = STAKE_RECORD_SIZE,
+        bump,
+    )]
+    pub stake_record: Account<'info, StakeRecord>,
+    pub log_wrapper: Program<'info, MplNoop>,
+    pub compression_program: Program<'info, MplAccountCompression>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(
+    _root: [u8; 32],
+    _data_hash: [u8; 32],
+    _creator_hash: [u8; 32],
+    _collection_hash: Option<[u8; 32]>,
+    _asset_data_hash: Option<[u8; 32]>,
+    _flags: Option<u8>,
+    nonce: u64,
+)]
+pub struct UnstakeV2<'info> {
+    #[account(
+        mut,
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    /// CHECK: This account is checked against the stake record, and receives its rent
+    #[account(mut)]
+    pub leaf_owner: UncheckedAccount<'info>,
+    pub staking_authority: Signer<'info>,
+    /// CHECK: This account is modified in the downstream program
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = leaf_owner,
+        seeds = [
+            STAKE_RECORD_PREFIX.as_ref(),
+            merkle_tree.key().as_ref(),
+            &nonce.to_le_bytes(),
+        ],
+        bump,
+        constraint = stake_record.owner == leaf_owner.key() @ BubblegumError::InvalidAuthority,
+        constraint = stake_record.authority == staking_authority.key()
+            @ BubblegumError::InvalidAuthority,
+    )]
+    pub stake_record: Account<'info, StakeRecord>,
+    pub log_wrapper: Program<'info, MplNoop>,
+    pub compression_program: Program<'info, MplAccountCompression>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn stake_v2<'info>(
+    ctx: Context<'_, '_, '_, 'info, StakeV2<'info>>,
+    root: [u8; 32],
+    data_hash: [u8; 32],
+    creator_hash: [u8; 32],
+    collection_hash: Option<[u8; 32]>,
+    asset_data_hash: Option<[u8; 32]>,
+    flags: Option<u8>,
+    nonce: u64,
+    index: u32,
+) -> Result<LeafUpdate> {
+    let leaf_owner = ctx.accounts.leaf_owner.key();
+    let previous_leaf_delegate = ctx
+        .accounts
+        .previous_leaf_delegate
+        .as_ref()
+        .map(|account| account.key())
+        .unwrap_or(leaf_owner);
+
+    ctx.accounts.stake_record.set_inner(StakeRecord {
+        merkle_tree: ctx.accounts.merkle_tree.key(),
+        owner: leaf_owner,
+        authority: ctx.accounts.staking_authority.key(),
+        nonce,
+        staked_at: Clock::get()?.unix_timestamp,
+    });
+
+    // The stake record becomes the leaf delegate, and the asset is frozen in place.
+    process_delegate_and_freeze_v2(
+        &ctx.accounts.tree_authority,
+        ctx.bumps.tree_authority,
+        &ctx.accounts.merkle_tree,
+        &ctx.accounts.log_wrapper,
+        &ctx.accounts.compression_program,
+        ctx.remaining_accounts,
+        leaf_owner,
+        previous_leaf_delegate,
+        ctx.accounts.stake_record.key(),
+        root,
+        data_hash,
+        creator_hash,
+        collection_hash,
+        asset_data_hash,
+        flags,
+        nonce,
+        index,
+    )
+}
+
+pub(crate) fn unstake_v2<'info>(
+    ctx: Context<'_, '_, '_, 'info, UnstakeV2<'info>>,
+    root: [u8; 32],
+    data_hash: [u8; 32],
+    creator_hash: [u8; 32],
+    collection_hash: Option<[u8; 32]>,
+    asset_data_hash: Option<[u8; 32]>,
+    flags: Option<u8>,
+    nonce: u64,
+    index: u32,
+) -> Result<LeafUpdate> {
+    // The stake record is the leaf delegate while the asset is staked, and acts as the signing
+    // delegate of a thaw and revoke once the staking authority signed.
+    process_thaw_and_revoke_v2(
+        &ctx.accounts.tree_authority,
+        ctx.bumps.tree_authority,
+        &ctx.accounts.merkle_tree,
+        &ctx.accounts.log_wrapper,
+        &ctx.accounts.compression_program,
+        ctx.remaining_accounts,
+        ctx.accounts.leaf_owner.key(),
+        ctx.accounts.stake_record.key(),
+        root,
+        data_hash,
+        creator_hash,
+        collection_hash,
+        asset_data_hash,
+        flags,
+        nonce,
+        index,
+    )
+}
+
+/// Ensures the leaf delegate is not a stake record, so that instructions that move or burn an
+/// asset without its delegate cannot leave a stake record behind that can never be closed.
+pub(crate) fn asset_validate_not_staked(leaf_delegate: Option<&AccountInfo>) -> Result<()> {
+    if leaf_delegate.and_then(discriminator) == Some(StakeRecord::DISCRIMINATOR) {
+        return Err(BubblegumError::AssetIsStaked.into());
+    }
+
+    Ok(())
+}
@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
 
-use crate::processor::{freeze::FreezeV2, process_freeze};
+use crate::{
+    processor::{freeze::FreezeV2, process_freeze},
+    state::leaf_schema::LeafUpdate,
+};
 
 pub(crate) fn thaw_v2<'info>(
     ctx: Context<'_, '_, '_, 'info, FreezeV2<'info>>,
@@ -11,7 +14,7 @@ pub(crate) fn thaw_v2<'info>(
     flags: Option<u8>,
     nonce: u64,
     index: u32,
-) -> Result<()> {
+) -> Result<LeafUpdate> {
     process_freeze(
         ctx,
         root,
@@ -2,10 +2,13 @@ use anchor_lang::prelude::*;
 use mpl_account_compression::{program::MplAccountCompression, Noop as MplNoop};
 
 use crate::{
-    processor::{freeze::set_asset_lvl_freeze_flag, BubblegumError},
+    processor::{
+        asset_validate_not_revoked, emit_asset_event, freeze::set_asset_lvl_freeze_flag,
+        BubblegumError,
+    },
     state::{
-        leaf_schema::{LeafSchema, Version},
-        TreeConfig,
+        leaf_schema::{LeafSchema, LeafUpdate, Version},
+        AssetUpdate, TreeConfig, TreeCounters,
     },
     utils::{
         get_asset_id, replace_leaf, Flags, DEFAULT_ASSET_DATA_HASH, DEFAULT_COLLECTION_HASH,
@@ -46,10 +49,57 @@ pub(crate) fn thaw_and_revoke_v2<'info>(
     flags: Option<u8>,
     nonce: u64,
     index: u32,
-) -> Result<()> {
+) -> Result<LeafUpdate> {
+    let leaf_delegate = ctx
+        .accounts
+        .leaf_delegate
+        .as_ref()
+        .map(|account| account.key())
+        .unwrap_or(ctx.accounts.payer.key());
+
+    process_thaw_and_revoke_v2(
+        &ctx.accounts.tree_authority,
+        ctx.bumps.tree_authority,
+        &ctx.accounts.merkle_tree,
+        &ctx.accounts.log_wrapper,
+        &ctx.accounts.compression_program,
+        ctx.remaining_accounts,
+        ctx.accounts.leaf_owner.key(),
+        leaf_delegate,
+        root,
+        data_hash,
+        creator_hash,
+        collection_hash,
+        asset_data_hash,
+        flags,
+        nonce,
+        index,
+    )
+}
+
+/// Thaws the leaf of `leaf_owner` frozen by `leaf_delegate` and revokes the delegate.  The
+/// accounts are validated by the caller, which is either `thaw_and_revoke_v2` or `unstake_v2`.
+pub(crate) fn process_thaw_and_revoke_v2<'info>(
+    tree_authority: &Account<'info, TreeConfig>,
+    tree_authority_bump: u8,
+    merkle_tree: &AccountInfo<'info>,
+    log_wrapper: &AccountInfo<'info>,
+    compression_program: &AccountInfo<'info>,
+    proof: &[AccountInfo<'info>],
+    leaf_owner: Pubkey,
+    leaf_delegate: Pubkey,
+    root: [u8; 32],
+    data_hash: [u8; 32],
+    creator_hash: [u8; 32],
+    collection_hash: Option<[u8; 32]>,
+    asset_data_hash: Option<[u8; 32]>,
+    flags: Option<u8>,
+    nonce: u64,
+    index: u32,
+) -> Result<LeafUpdate> {
     // V2 instructions only work with V2 trees.
     require!(
-        ctx.accounts.tree_authority.version == Version::V2,
+        tree_authority.version == Version::V2,
         BubblegumError::UnsupportedSchemaVersion
     );
 
@@ -61,19 +111,13 @@ pub(crate) fn thaw_and_revoke_v2<'info>(
         return Err(BubblegumError::AssetIsNotFrozen.into());
     }
 
+    asset_validate_not_revoked(flags)?;
+
     // Clear freeze flag.
     let updated_flags = set_asset_lvl_freeze_flag(raw_flags, false);
 
     // Gather info for previous leaf and new leaf.
-    let merkle_tree = &ctx.accounts.merkle_tree;
     let asset_id = get_asset_id(&merkle_tree.key(), nonce);
-    let leaf_owner = ctx.accounts.leaf_owner.key();
-    let leaf_delegate = ctx
-        .accounts
-        .leaf_delegate
-        .as_ref()
-        .map(|account| account.key())
-        .unwrap_or(ctx.accounts.payer.key());
 
     let collection_hash = collection_hash.unwrap_or(DEFAULT_COLLECTION_HASH);
     let asset_data_hash = asset_data_hash.unwrap_or(DEFAULT_ASSET_DATA_HASH);
@@ -106,21 +150,46 @@ pub(crate) fn thaw_and_revoke_v2<'info>(
     crate::utils::wrap_application_data_v1(
         Version::V2,
         new_leaf.to_event().try_to_vec()?,
-        &ctx.accounts.log_wrapper,
+        log_wrapper,
     )?;
 
+    emit_asset_event(
+        asset_id,
+        AssetUpdate::ThawedV2 {
+            authority: leaf_delegate,
+            permanent: false,
+        },
+        log_wrapper,
+    )?;
+
+    emit_asset_event(
+        asset_id,
+        AssetUpdate::DelegatedV2 {
+            authority: leaf_delegate,
+            previous_delegate: leaf_delegate,
+            new_delegate: leaf_owner,
+        },
+        log_wrapper,
+    )?;
+
+    TreeCounters::update(&tree_authority.to_account_info(), |counters| {
+        counters.record_flags_change(flags, Flags::from_bytes([updated_flags]))
+    })?;
+
     replace_leaf(
         Version::V2,
         &merkle_tree.key(),
-        ctx.bumps.tree_authority,
-        &ctx.accounts.compression_program.to_account_info(),
-        &ctx.accounts.tree_authority.to_account_info(),
-        &ctx.accounts.merkle_tree.to_account_info(),
-        &ctx.accounts.log_wrapper.to_account_info(),
-        ctx.remaining_accounts,
+        tree_authority_bump,
+        compression_program,
+        &tree_authority.to_account_info(),
+        merkle_tree,
+        log_wrapper,
+        proof,
         root,
         previous_leaf.to_node(),
         new_leaf.to_node(),
         index,
-    )
+    )?;
+
+    LeafUpdate::new(new_leaf, index, merkle_tree)
 }
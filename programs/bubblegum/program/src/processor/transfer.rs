@@ -5,11 +5,12 @@ use solana_program::{program::invoke, system_instruction};
 use spl_account_compression::{program::SplAccountCompression, Noop as SplNoop};
 
 use crate::{
+    asserts::assert_tree_not_paused,
     error::BubblegumError,
+    processor::{asset_validate_not_staked, emit_asset_event, OptionalAccounts},
     state::{
-        collect::TRANSFER_V2_FEE_LAMPORTS,
-        leaf_schema::{LeafSchema, Version},
-        TreeConfig,
+        leaf_schema::{LeafSchema, LeafUpdate, Version},
+        AssetUpdate, TreeConfig, TreeSettings,
     },
     traits::{MplCorePluginValidation, ValidationResult},
     utils::{
@@ -54,6 +55,8 @@ pub(crate) fn transfer_v1<'info>(
         BubblegumError::UnsupportedSchemaVersion
     );
 
+    assert_tree_not_paused(&ctx.accounts.tree_authority.to_account_info())?;
+
     let merkle_tree = ctx.accounts.merkle_tree.to_account_info();
     let leaf_owner = ctx.accounts.leaf_owner.to_account_info();
     let leaf_delegate = ctx.accounts.leaf_delegate.to_account_info();
@@ -117,7 +120,7 @@ pub struct TransferV2<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     /// Optional authority, defaults to `payer`.  Must be either
-    /// the leaf owner or collection permanent transfer delegate.
+    /// the leaf owner or collection or tree permanent transfer delegate.
     pub authority: Option<Signer<'info>>,
     /// CHECK: This account is neither written to nor read from
     pub leaf_owner: UncheckedAccount<'info>,
@@ -145,13 +148,18 @@ pub(crate) fn transfer_v2<'info>(
     flags: Option<u8>,
     nonce: u64,
     index: u32,
-) -> Result<()> {
+) -> Result<LeafUpdate> {
     // V2 instructions only work with V2 trees.
     require!(
         ctx.accounts.tree_authority.version == Version::V2,
         BubblegumError::UnsupportedSchemaVersion
     );
 
+    let optional =
+        OptionalAccounts::parse(&ctx.accounts.merkle_tree.key(), ctx.remaining_accounts)?;
+
+    assert_tree_not_paused(&ctx.accounts.tree_authority.to_account_info())?;
+
     let authority = ctx
         .accounts
         .authority
@@ -174,6 +182,18 @@ pub(crate) fn transfer_v2<'info>(
             &ctx.accounts.leaf_owner,
             &ctx.accounts.new_leaf_owner,
         )?
+    } else if TreeSettings::load(&ctx.accounts.tree_authority.to_account_info())?.has_plugins {
+        // Uncollected assets use the tree-level plugins instead.
+        let tree_plugins = optional
+            .tree_plugins
+            .as_ref()
+            .ok_or(BubblegumError::MissingTreePluginsAccount)?;
+
+        tree_plugins.validate_transfer(
+            authority,
+            &ctx.accounts.leaf_owner,
+            &ctx.accounts.new_leaf_owner,
+        )?
     } else {
         ValidationResult::Abstain
     };
@@ -200,6 +220,10 @@ pub(crate) fn transfer_v2<'info>(
         // Ensure asset is not frozen.  Note this is skipped if the permanent transfer delegate
         // force approved.
         asset_validate_non_frozen(flags)?;
+    } else {
+        // The permanent transfer delegate cannot move a staked asset, since its stake record
+        // could then never be closed.
+        asset_validate_not_staked(ctx.accounts.leaf_delegate.as_deref())?;
     }
 
     // Ensure asset is transferable.
@@ -249,18 +273,35 @@ pub(crate) fn transfer_v2<'info>(
         &ctx.accounts.log_wrapper,
     )?;
 
-    invoke(
-        &system_instruction::transfer(
-            ctx.accounts.payer.key,
-            &ctx.accounts.tree_authority.key(),
-            TRANSFER_V2_FEE_LAMPORTS,
-        ),
-        &[
-            ctx.accounts.payer.to_account_info(),
-            ctx.accounts.tree_authority.to_account_info(),
-        ],
+    emit_asset_event(
+        asset_id,
+        AssetUpdate::TransferredV2 {
+            authority,
+            previous_owner: leaf_owner,
+            new_owner: new_leaf_owner,
+        },
+        &ctx.accounts.log_wrapper,
     )?;
 
+    let settings = TreeSettings::load(&ctx.accounts.tree_authority.to_account_info())?;
+    let fee = optional
+        .fee_config
+        .unwrap_or_default()
+        .transfer_v2_fee(&settings);
+    if fee > 0 {
+        invoke(
+            &system_instruction::transfer(
+                ctx.accounts.payer.key,
+                &ctx.accounts.tree_authority.key(),
+                fee,
+            ),
+            &[
+                ctx.accounts.payer.to_account_info(),
+                ctx.accounts.tree_authority.to_account_info(),
+            ],
+        )?;
+    }
+
     replace_leaf(
         Version::V2,
         &merkle_tree.key(),
@@ -269,15 +310,17 @@ pub(crate) fn transfer_v2<'info>(
         &ctx.accounts.tree_authority.to_account_info(),
         &ctx.accounts.merkle_tree.to_account_info(),
         &ctx.accounts.log_wrapper.to_account_info(),
-        ctx.remaining_accounts,
+        optional.remaining_accounts,
         root,
         previous_leaf.to_node(),
         new_leaf.to_node(),
         index,
-    )
+    )?;
+
+    LeafUpdate::new(new_leaf, index, &ctx.accounts.merkle_tree.to_account_info())
 }
 
-fn mpl_core_collection_validate_transfer<'info>(
+pub(crate) fn mpl_core_collection_validate_transfer<'info>(
     collection: &Collection,
     authority: Pubkey,
     leaf_owner: &AccountInfo<'info>,
@@ -313,7 +356,7 @@ fn mpl_core_collection_validate_transfer<'info>(
     Ok(ValidationResult::Abstain)
 }
 
-fn asset_validate_non_frozen(flags: Flags) -> Result<()> {
+pub(crate) fn asset_validate_non_frozen(flags: Flags) -> Result<()> {
     if flags.asset_lvl_frozen() || flags.permanent_lvl_frozen() {
         return Err(BubblegumError::AssetIsFrozen.into());
     }
@@ -321,10 +364,18 @@ fn asset_validate_non_frozen(flags: Flags) -> Result<()> {
     Ok(())
 }
 
-fn asset_validate_transferable(flags: Flags) -> Result<()> {
+pub(crate) fn asset_validate_transferable(flags: Flags) -> Result<()> {
     if flags.non_transferable() {
         return Err(BubblegumError::AssetIsNonTransferable.into());
     }
 
+    asset_validate_not_revoked(flags)
+}
+
+pub(crate) fn asset_validate_not_revoked(flags: Flags) -> Result<()> {
+    if flags.revoked() {
+        return Err(BubblegumError::AssetIsRevoked.into());
+    }
+
     Ok(())
 }
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::BubblegumError,
+    state::{leaf_schema::Version, TreeConfig, TreeGroup, TREE_GROUP_PREFIX},
+};
+
+#[derive(Accounts)]
+#[instruction(group_seed: Pubkey, max_trees: u8)]
+pub struct CreateTreeGroup<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = TreeGroup::size(max_trees),
+        seeds = [TREE_GROUP_PREFIX.as_ref(), authority.key().as_ref(), group_seed.as_ref()],
+        bump,
+    )]
+    pub tree_group: Account<'info, TreeGroup>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn create_tree_group(
+    ctx: Context<CreateTreeGroup>,
+    group_seed: Pubkey,
+    max_trees: u8,
+) -> Result<()> {
+    ctx.accounts.tree_group.set_inner(TreeGroup {
+        authority: ctx.accounts.authority.key(),
+        group_seed,
+        max_trees,
+        current_tree_index: 0,
+        trees: Vec::with_capacity(max_trees as usize),
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AddTreeToGroup<'info> {
+    #[account(
+        mut,
+        seeds = [
+            TREE_GROUP_PREFIX.as_ref(),
+            tree_group.authority.as_ref(),
+            tree_group.group_seed.as_ref(),
+        ],
+        bump,
+        has_one = authority,
+    )]
+    pub tree_group: Account<'info, TreeGroup>,
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+        constraint = tree_authority.tree_creator == authority.key()
+            @ BubblegumError::TreeAuthorityIncorrect,
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    /// CHECK: Used to derive `tree_authority`
+    pub merkle_tree: UncheckedAccount<'info>,
+}
+
+pub(crate) fn add_tree_to_group(ctx: Context<AddTreeToGroup>) -> Result<()> {
+    // Groups are minted into with `mint_to_group_v2`, so only V2 trees can be added.
+    require!(
+        ctx.accounts.tree_authority.version == Version::V2,
+        BubblegumError::UnsupportedSchemaVersion
+    );
+
+    let merkle_tree = ctx.accounts.merkle_tree.key();
+    let tree_group = &mut ctx.accounts.tree_group;
+
+    require!(
+        !tree_group.trees.contains(&merkle_tree),
+        BubblegumError::TreeAlreadyInGroup
+    );
+    require!(
+        tree_group.trees.len() < tree_group.max_trees as usize,
+        BubblegumError::TreeGroupFull
+    );
+
+    tree_group.trees.push(merkle_tree);
+
+    Ok(())
+}
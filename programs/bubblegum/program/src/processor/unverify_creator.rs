@@ -2,13 +2,14 @@ use anchor_lang::prelude::*;
 
 use crate::{
     processor::{
-        process_creator_verification,
+        emit_asset_event, process_creator_verification,
         verify_creator::{CreatorVerification, CreatorVerificationV2},
         BubblegumError,
     },
     state::{
-        leaf_schema::Version,
+        leaf_schema::{LeafUpdate, Version},
         metaplex_adapter::{MetadataArgs, MetadataArgsV2},
+        AssetUpdate,
     },
     utils::{hash_collection_option, hash_creators, hash_metadata, replace_leaf},
 };
@@ -30,7 +31,7 @@ pub(crate) fn unverify_creator<'info>(
 
     let (previous_leaf, new_leaf) = process_creator_verification(
         ctx.accounts.merkle_tree.key(),
-        ctx.accounts.creator.key(),
+        &[ctx.accounts.creator.key()],
         data_hash,
         creator_hash,
         nonce,
@@ -73,7 +74,7 @@ pub(crate) fn unverify_creator_v2<'info>(
     nonce: u64,
     index: u32,
     message: MetadataArgsV2,
-) -> Result<()> {
+) -> Result<LeafUpdate> {
     // V2 instructions only work with V2 trees.
     require!(
         ctx.accounts.tree_authority.version == Version::V2,
@@ -101,7 +102,7 @@ pub(crate) fn unverify_creator_v2<'info>(
 
     let (previous_leaf, new_leaf) = process_creator_verification(
         ctx.accounts.merkle_tree.key(),
-        creator,
+        &[creator],
         previous_data_hash,
         previous_creator_hash,
         nonce,
@@ -120,6 +121,15 @@ pub(crate) fn unverify_creator_v2<'info>(
         &ctx.accounts.log_wrapper,
     )?;
 
+    emit_asset_event(
+        new_leaf.id(),
+        AssetUpdate::CreatorsVerifiedV2 {
+            creators: vec![creator],
+            verified: false,
+        },
+        &ctx.accounts.log_wrapper,
+    )?;
+
     replace_leaf(
         Version::V2,
         &ctx.accounts.merkle_tree.key(),
@@ -133,5 +143,7 @@ pub(crate) fn unverify_creator_v2<'info>(
         previous_leaf.to_node(),
         new_leaf.to_node(),
         index,
-    )
+    )?;
+
+    LeafUpdate::new(new_leaf, index, &ctx.accounts.merkle_tree.to_account_info())
 }
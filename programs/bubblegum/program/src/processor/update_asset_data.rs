@@ -4,10 +4,10 @@ use mpl_core::Collection as MplCoreCollection;
 
 use crate::{
     error::BubblegumError,
-    processor::mpl_core_collection_validate_update,
+    processor::{mpl_core_collection_validate_update, OptionalAccounts},
     state::{
-        leaf_schema::{LeafSchema, Version},
-        AssetDataSchema, TreeConfig,
+        leaf_schema::{LeafSchema, LeafUpdate, Version},
+        AssetDataSchema, TreeConfig, TreeDelegateRole,
     },
     traits::ValidationResult,
     utils::{
@@ -58,7 +58,7 @@ pub fn update_asset_data_v2<'info>(
     index: u32,
     new_asset_data: Option<Vec<u8>>,
     _new_asset_data_schema: Option<AssetDataSchema>,
-) -> Result<()> {
+) -> Result<LeafUpdate> {
     if !ASSET_DATA_FEATURE_ACTIVATED {
         return Err(BubblegumError::NotAvailable.into());
     }
@@ -69,6 +69,9 @@ pub fn update_asset_data_v2<'info>(
         BubblegumError::UnsupportedSchemaVersion
     );
 
+    let optional =
+        OptionalAccounts::parse(&ctx.accounts.merkle_tree.key(), ctx.remaining_accounts)?;
+
     let authority = ctx
         .accounts
         .authority
@@ -96,8 +99,12 @@ pub fn update_asset_data_v2<'info>(
     } else {
         // No collection case.
         require!(
-            authority == ctx.accounts.tree_authority.tree_creator
-                || authority == ctx.accounts.tree_authority.tree_delegate,
+            ctx.accounts.tree_authority.is_authorized(
+                &ctx.accounts.merkle_tree.key(),
+                &authority,
+                optional.tree_delegate_record.as_ref(),
+                TreeDelegateRole::UpdateAssetData,
+            ),
             BubblegumError::TreeAuthorityIncorrect,
         );
     }
@@ -171,10 +178,12 @@ pub fn update_asset_data_v2<'info>(
         &ctx.accounts.tree_authority.to_account_info(),
         &ctx.accounts.merkle_tree,
         &ctx.accounts.log_wrapper,
-        ctx.remaining_accounts,
+        optional.remaining_accounts,
         root,
         previous_leaf.to_node(),
         new_leaf.to_node(),
         index,
-    )
+    )?;
+
+    LeafUpdate::new(new_leaf, index, &ctx.accounts.merkle_tree)
 }
@@ -0,0 +1,169 @@
+use anchor_lang::prelude::*;
+use mpl_account_compression::{program::MplAccountCompression, Noop as MplNoop};
+
+use crate::{
+    error::BubblegumError,
+    processor::emit_asset_event,
+    state::{
+        leaf_schema::{LeafSchema, LeafUpdate, Version},
+        metaplex_adapter::MetadataArgsV2,
+        AssetUpdate, TreeConfig,
+    },
+    utils::{
+        get_asset_id, hash_collection_option, hash_creators, hash_metadata, replace_leaf,
+        DEFAULT_ASSET_DATA_HASH, DEFAULT_FLAGS,
+    },
+};
+
+#[derive(Accounts)]
+pub struct UpdateCreatorV2<'info> {
+    #[account(
+        mut,
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// Optional creator, defaults to `payer`
+    pub creator: Option<Signer<'info>>,
+    pub new_creator: Signer<'info>,
+    /// CHECK: This account is neither written to nor read from
+    pub leaf_owner: UncheckedAccount<'info>,
+    /// CHECK: This account is neither written to nor read from
+    /// Defaults to `leaf_owner`
+    pub leaf_delegate: Option<UncheckedAccount<'info>>,
+    /// CHECK: This account is modified in the downstream program
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+    pub log_wrapper: Program<'info, MplNoop>,
+    pub compression_program: Program<'info, MplAccountCompression>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn update_creator_v2<'info>(
+    ctx: Context<'_, '_, '_, 'info, UpdateCreatorV2<'info>>,
+    root: [u8; 32],
+    asset_data_hash: Option<[u8; 32]>,
+    flags: Option<u8>,
+    nonce: u64,
+    index: u32,
+    current_metadata: MetadataArgsV2,
+) -> Result<LeafUpdate> {
+    // V2 instructions only work with V2 trees.
+    require!(
+        ctx.accounts.tree_authority.version == Version::V2,
+        BubblegumError::UnsupportedSchemaVersion
+    );
+
+    require!(
+        current_metadata.is_mutable,
+        BubblegumError::MetadataImmutable
+    );
+
+    let creator = ctx
+        .accounts
+        .creator
+        .as_ref()
+        .map(|account| account.key())
+        .unwrap_or(ctx.accounts.payer.key());
+    let new_creator = ctx.accounts.new_creator.key();
+
+    // Only a verified creator can hand over its own entry.
+    let position = current_metadata
+        .creators
+        .iter()
+        .position(|c| c.address == creator)
+        .ok_or(BubblegumError::CreatorNotFound)?;
+    require!(
+        current_metadata.creators[position].verified,
+        BubblegumError::CreatorDidNotVerify
+    );
+
+    require!(
+        !current_metadata
+            .creators
+            .iter()
+            .any(|c| c.address == new_creator),
+        BubblegumError::DuplicateCreatorAddress
+    );
+
+    let previous_data_hash = hash_metadata(&current_metadata)?;
+    let previous_creator_hash = hash_creators(&current_metadata.creators)?;
+    let collection_hash = hash_collection_option(current_metadata.collection)?;
+
+    // The share and the verified flag move to the new address, which signs to accept them.
+    let mut updated_metadata = current_metadata;
+    updated_metadata.creators[position].address = new_creator;
+
+    let updated_data_hash = hash_metadata(&updated_metadata)?;
+    let updated_creator_hash = hash_creators(&updated_metadata.creators)?;
+
+    let leaf_owner = ctx.accounts.leaf_owner.key();
+    let leaf_delegate = ctx
+        .accounts
+        .leaf_delegate
+        .as_ref()
+        .map(|account| account.key())
+        .unwrap_or(leaf_owner);
+
+    let asset_id = get_asset_id(&ctx.accounts.merkle_tree.key(), nonce);
+    let asset_data_hash = asset_data_hash.unwrap_or(DEFAULT_ASSET_DATA_HASH);
+    let flags = flags.unwrap_or(DEFAULT_FLAGS);
+
+    let previous_leaf = LeafSchema::new_v2(
+        asset_id,
+        leaf_owner,
+        leaf_delegate,
+        nonce,
+        previous_data_hash,
+        previous_creator_hash,
+        collection_hash,
+        asset_data_hash,
+        flags,
+    );
+
+    let new_leaf = LeafSchema::new_v2(
+        asset_id,
+        leaf_owner,
+        leaf_delegate,
+        nonce,
+        updated_data_hash,
+        updated_creator_hash,
+        collection_hash,
+        asset_data_hash,
+        flags,
+    );
+
+    crate::utils::wrap_application_data_v1(
+        Version::V2,
+        new_leaf.to_event().try_to_vec()?,
+        &ctx.accounts.log_wrapper,
+    )?;
+
+    emit_asset_event(
+        asset_id,
+        AssetUpdate::CreatorUpdatedV2 {
+            previous_creator: creator,
+            new_creator,
+        },
+        &ctx.accounts.log_wrapper,
+    )?;
+
+    replace_leaf(
+        Version::V2,
+        &ctx.accounts.merkle_tree.key(),
+        ctx.bumps.tree_authority,
+        &ctx.accounts.compression_program.to_account_info(),
+        &ctx.accounts.tree_authority.to_account_info(),
+        &ctx.accounts.merkle_tree.to_account_info(),
+        &ctx.accounts.log_wrapper.to_account_info(),
+        ctx.remaining_accounts,
+        root,
+        previous_leaf.to_node(),
+        new_leaf.to_node(),
+        index,
+    )?;
+
+    LeafUpdate::new(new_leaf, index, &ctx.accounts.merkle_tree.to_account_info())
+}
@@ -7,15 +7,15 @@ use spl_account_compression::{program::SplAccountCompression, Noop as SplNoop};
 use crate::{
     asserts::{assert_has_collection_authority, assert_metadata_is_mpl_compatible},
     error::BubblegumError,
-    processor::mpl_core_collection_validate_update,
+    processor::{emit_asset_event, mpl_core_collection_validate_update, OptionalAccounts},
     state::{
-        leaf_schema::{LeafSchema, Version},
+        leaf_schema::{LeafSchema, LeafUpdate, Version},
         metaplex_adapter::{
-            Collection as MetaplexAdapterCollection, Creator, MetadataArgs, MetadataArgsCommon,
-            MetadataArgsV2, UpdateArgs,
+            Collection as MetaplexAdapterCollection, Creator, LeafMetadataUpdate, MetadataArgs,
+            MetadataArgsCommon, MetadataArgsV2, UpdateArgs,
         },
         metaplex_anchor::TokenMetadata,
-        TreeConfig,
+        AssetUpdate, TreeConfig, TreeDelegateRecord, TreeDelegateRole, UriTemplate,
     },
     traits::ValidationResult,
     utils::{
@@ -70,6 +70,9 @@ pub fn update_metadata<'info>(
         BubblegumError::UnsupportedSchemaVersion
     );
 
+    let optional =
+        OptionalAccounts::parse(&ctx.accounts.merkle_tree.key(), ctx.remaining_accounts)?;
+
     match &current_metadata.collection {
         // Verified collection case.
         Some(collection) if collection.verified => {
@@ -97,8 +100,12 @@ pub fn update_metadata<'info>(
         // No collection or unverified collection case.
         _ => {
             require!(
-                ctx.accounts.authority.key() == ctx.accounts.tree_authority.tree_creator
-                    || ctx.accounts.authority.key() == ctx.accounts.tree_authority.tree_delegate,
+                ctx.accounts.tree_authority.is_authorized(
+                    &ctx.accounts.merkle_tree.key(),
+                    &ctx.accounts.authority.key(),
+                    optional.tree_delegate_record.as_ref(),
+                    TreeDelegateRole::UpdateMetadata,
+                ),
                 BubblegumError::TreeAuthorityIncorrect,
             );
         }
@@ -131,7 +138,7 @@ pub fn update_metadata<'info>(
         &ctx.accounts.tree_authority.to_account_info(),
         &ctx.accounts.merkle_tree,
         &ctx.accounts.log_wrapper,
-        ctx.remaining_accounts,
+        optional.remaining_accounts,
         root,
         previous_leaf.to_node(),
         new_leaf.to_node(),
@@ -176,13 +183,16 @@ pub fn update_metadata_v2<'info>(
     index: u32,
     current_metadata: MetadataArgsV2,
     update_args: UpdateArgs,
-) -> Result<()> {
+) -> Result<LeafUpdate> {
     // V2 instructions only work with V2 trees.
     require!(
         ctx.accounts.tree_authority.version == Version::V2,
         BubblegumError::UnsupportedSchemaVersion
     );
 
+    let optional =
+        OptionalAccounts::parse(&ctx.accounts.merkle_tree.key(), ctx.remaining_accounts)?;
+
     let authority = ctx
         .accounts
         .authority
@@ -190,32 +200,13 @@ pub fn update_metadata_v2<'info>(
         .map(|account| account.key())
         .unwrap_or(ctx.accounts.payer.key());
 
-    // Validate authority.
-    if let Some(core_collection) = &ctx.accounts.core_collection {
-        require!(
-            *core_collection.owner == mpl_core::ID,
-            BubblegumError::IncorrectOwner
-        );
-
-        let core_collection_data = &core_collection.data.borrow()[..];
-        let collection: Box<MplCoreCollection> =
-            MplCoreCollection::from_bytes(core_collection_data)?;
-
-        // If there's a collection, the update authority or update delegate must be the
-        // authority.
-        if mpl_core_collection_validate_update(&collection, authority)?
-            != ValidationResult::Approved
-        {
-            return Err(BubblegumError::InvalidCollectionAuthority.into());
-        }
-    } else {
-        // No collection case.
-        require!(
-            authority == ctx.accounts.tree_authority.tree_creator
-                || authority == ctx.accounts.tree_authority.tree_delegate,
-            BubblegumError::TreeAuthorityIncorrect,
-        );
-    }
+    validate_update_authority_v2(
+        &ctx.accounts.tree_authority,
+        &ctx.accounts.merkle_tree.key(),
+        authority,
+        ctx.accounts.core_collection.as_deref(),
+        optional.tree_delegate_record.as_ref(),
+    )?;
 
     let leaf_owner = ctx.accounts.leaf_owner.key();
     let leaf_delegate = ctx
@@ -237,19 +228,34 @@ pub fn update_metadata_v2<'info>(
         leaf_owner,
         leaf_delegate,
         current_metadata,
-        update_args,
+        update_args.clone(),
         Some(collection_hash),
         asset_data_hash,
         flags,
         nonce,
     )?;
 
+    UriTemplate::freeze_for_leaf(
+        &ctx.accounts.tree_authority.to_account_info(),
+        update_args.is_mutable.unwrap_or(true),
+        ctx.accounts.core_collection.is_some(),
+    )?;
+
     crate::utils::wrap_application_data_v1(
         Version::V2,
         new_leaf.to_event().try_to_vec()?,
         &ctx.accounts.log_wrapper,
     )?;
 
+    emit_asset_event(
+        new_leaf.id(),
+        AssetUpdate::MetadataUpdatedV2 {
+            authority,
+            update_args,
+        },
+        &ctx.accounts.log_wrapper,
+    )?;
+
     replace_leaf(
         Version::V2,
         &ctx.accounts.merkle_tree.key(),
@@ -258,12 +264,190 @@ pub fn update_metadata_v2<'info>(
         &ctx.accounts.tree_authority.to_account_info(),
         &ctx.accounts.merkle_tree,
         &ctx.accounts.log_wrapper,
-        ctx.remaining_accounts,
+        optional.remaining_accounts,
         root,
         previous_leaf.to_node(),
         new_leaf.to_node(),
         index,
-    )
+    )?;
+
+    LeafUpdate::new(new_leaf, index, &ctx.accounts.merkle_tree)
+}
+
+#[derive(Accounts)]
+pub struct BatchUpdateMetadataV2<'info> {
+    #[account(
+        mut,
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// Either collection authority or tree owner/delegate, depending
+    /// on whether the items are in a verified collection.  Defaults to `payer`
+    pub authority: Option<Signer<'info>>,
+    /// CHECK: This account is modified in the downstream program
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+    /// CHECK: This account is checked in the instruction
+    pub core_collection: Option<UncheckedAccount<'info>>,
+    pub log_wrapper: Program<'info, MplNoop>,
+    pub compression_program: Program<'info, MplAccountCompression>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn batch_update_metadata_v2<'info>(
+    ctx: Context<'_, '_, '_, 'info, BatchUpdateMetadataV2<'info>>,
+    root: [u8; 32],
+    leaves: Vec<LeafMetadataUpdate>,
+    update_args: UpdateArgs,
+) -> Result<LeafUpdate> {
+    // V2 instructions only work with V2 trees.
+    require!(
+        ctx.accounts.tree_authority.version == Version::V2,
+        BubblegumError::UnsupportedSchemaVersion
+    );
+
+    let optional =
+        OptionalAccounts::parse(&ctx.accounts.merkle_tree.key(), ctx.remaining_accounts)?;
+
+    // All leaves are in the same tree, so their proofs have the same length.
+    require!(
+        !leaves.is_empty() && optional.remaining_accounts.len() % leaves.len() == 0,
+        BubblegumError::InvalidBatch
+    );
+    let proof_length = optional.remaining_accounts.len() / leaves.len();
+
+    let authority = ctx
+        .accounts
+        .authority
+        .as_ref()
+        .map(|account| account.key())
+        .unwrap_or(ctx.accounts.payer.key());
+
+    // All leaves are in the same collection, so the authority is only validated once.
+    validate_update_authority_v2(
+        &ctx.accounts.tree_authority,
+        &ctx.accounts.merkle_tree.key(),
+        authority,
+        ctx.accounts.core_collection.as_deref(),
+        optional.tree_delegate_record.as_ref(),
+    )?;
+
+    let collection_hash = hash_collection_option(
+        ctx.accounts
+            .core_collection
+            .as_ref()
+            .map(|account| *account.key),
+    )?;
+
+    UriTemplate::freeze_for_leaf(
+        &ctx.accounts.tree_authority.to_account_info(),
+        update_args.is_mutable.unwrap_or(true),
+        ctx.accounts.core_collection.is_some(),
+    )?;
+
+    // The update of the first leaf is returned, since the return data cannot hold all of them.
+    let mut update = None;
+
+    // The proofs are passed in the same order as the leaves.
+    for (i, leaf) in leaves.into_iter().enumerate() {
+        let proof = &optional.remaining_accounts[i * proof_length..(i + 1) * proof_length];
+
+        let (previous_leaf, new_leaf) = process_update_metadata(
+            ctx.accounts.merkle_tree.key(),
+            authority,
+            leaf.leaf_owner,
+            leaf.leaf_delegate,
+            leaf.current_metadata,
+            update_args.clone(),
+            Some(collection_hash),
+            leaf.asset_data_hash,
+            leaf.flags,
+            leaf.nonce,
+        )?;
+
+        crate::utils::wrap_application_data_v1(
+            Version::V2,
+            new_leaf.to_event().try_to_vec()?,
+            &ctx.accounts.log_wrapper,
+        )?;
+
+        emit_asset_event(
+            new_leaf.id(),
+            AssetUpdate::MetadataUpdatedV2 {
+                authority,
+                update_args: update_args.clone(),
+            },
+            &ctx.accounts.log_wrapper,
+        )?;
+
+        replace_leaf(
+            Version::V2,
+            &ctx.accounts.merkle_tree.key(),
+            ctx.bumps.tree_authority,
+            &ctx.accounts.compression_program,
+            &ctx.accounts.tree_authority.to_account_info(),
+            &ctx.accounts.merkle_tree,
+            &ctx.accounts.log_wrapper,
+            proof,
+            root,
+            previous_leaf.to_node(),
+            new_leaf.to_node(),
+            leaf.index,
+        )?;
+
+        if i == 0 {
+            update = Some(LeafUpdate::new(
+                new_leaf,
+                leaf.index,
+                &ctx.accounts.merkle_tree,
+            )?);
+        }
+    }
+
+    update.ok_or_else(|| BubblegumError::InvalidBatch.into())
+}
+
+fn validate_update_authority_v2(
+    tree_authority: &TreeConfig,
+    merkle_tree: &Pubkey,
+    authority: Pubkey,
+    core_collection: Option<&AccountInfo>,
+    tree_delegate_record: Option<&TreeDelegateRecord>,
+) -> Result<()> {
+    if let Some(core_collection) = core_collection {
+        require!(
+            *core_collection.owner == mpl_core::ID,
+            BubblegumError::IncorrectOwner
+        );
+
+        let core_collection_data = &core_collection.data.borrow()[..];
+        let collection: Box<MplCoreCollection> =
+            MplCoreCollection::from_bytes(core_collection_data)?;
+
+        // If there's a collection, the update authority or update delegate must be the
+        // authority.
+        if mpl_core_collection_validate_update(&collection, authority)?
+            != ValidationResult::Approved
+        {
+            return Err(BubblegumError::InvalidCollectionAuthority.into());
+        }
+    } else {
+        // No collection case.
+        require!(
+            tree_authority.is_authorized(
+                merkle_tree,
+                &authority,
+                tree_delegate_record,
+                TreeDelegateRole::UpdateMetadata,
+            ),
+            BubblegumError::TreeAuthorityIncorrect,
+        );
+    }
+
+    Ok(())
 }
 
 fn assert_authority_matches_collection<'info>(
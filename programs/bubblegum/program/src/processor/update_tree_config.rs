@@ -0,0 +1,241 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{allocate, assign, transfer, Allocate, Assign, Transfer},
+};
+
+use crate::{
+    asserts::assert_log_wrapper,
+    error::BubblegumError,
+    state::{
+        TreeConfig, TreeConfigEvent, TreeConfigUpdate, TreeCreatorProposal,
+        TREE_CREATOR_PROPOSAL_PREFIX, TREE_CREATOR_PROPOSAL_SIZE,
+    },
+    utils::wrap_application_data_v1,
+};
+
+#[derive(Accounts)]
+pub struct UpdateTreeConfig<'info> {
+    #[account(
+        mut,
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+        has_one = tree_creator
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub tree_creator: Signer<'info>,
+    /// CHECK: Used to derive `tree_authority`
+    pub merkle_tree: UncheckedAccount<'info>,
+    /// CHECK: This account is checked and created in the instruction.
+    /// Only required when proposing a new tree creator.
+    #[account(mut)]
+    pub tree_creator_proposal: Option<UncheckedAccount<'info>>,
+    /// CHECK: Program is checked against the tree version in the instruction.
+    pub log_wrapper: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn update_tree_config(
+    ctx: Context<UpdateTreeConfig>,
+    total_mint_capacity: Option<u64>,
+    is_public: Option<bool>,
+    proposed_tree_creator: Option<Pubkey>,
+) -> Result<()> {
+    let merkle_tree = ctx.accounts.merkle_tree.key();
+    let log_wrapper = &ctx.accounts.log_wrapper;
+    let tree_authority = &mut ctx.accounts.tree_authority;
+    assert_log_wrapper(tree_authority.version, &log_wrapper.key())?;
+
+    if let Some(total_mint_capacity) = total_mint_capacity {
+        // Capacity can only be lowered, and never below what has already been minted.
+        require!(
+            total_mint_capacity <= tree_authority.total_mint_capacity
+                && total_mint_capacity >= tree_authority.num_minted,
+            BubblegumError::InvalidTotalMintCapacity
+        );
+
+        let previous = tree_authority.total_mint_capacity;
+        tree_authority.total_mint_capacity = total_mint_capacity;
+
+        emit_tree_config_event(
+            tree_authority,
+            merkle_tree,
+            TreeConfigUpdate::TotalMintCapacity {
+                previous,
+                new: total_mint_capacity,
+            },
+            log_wrapper,
+        )?;
+    }
+
+    if let Some(is_public) = is_public {
+        tree_authority.is_public = is_public;
+
+        emit_tree_config_event(
+            tree_authority,
+            merkle_tree,
+            TreeConfigUpdate::IsPublic { is_public },
+            log_wrapper,
+        )?;
+    }
+
+    if let Some(proposed_tree_creator) = proposed_tree_creator {
+        // The proposal account is only created when a new tree creator is proposed, and
+        // proposing again overwrites any pending proposal.
+        let tree_creator_proposal = ctx
+            .accounts
+            .tree_creator_proposal
+            .as_ref()
+            .ok_or(BubblegumError::MissingTreeCreatorProposalAccount)?;
+        init_tree_creator_proposal(
+            tree_creator_proposal,
+            &ctx.accounts.payer,
+            &ctx.accounts.system_program,
+            &merkle_tree,
+        )?;
+
+        let proposal = TreeCreatorProposal {
+            proposed_tree_creator,
+        };
+        proposal.try_serialize(&mut &mut tree_creator_proposal.try_borrow_mut_data()?[..])?;
+
+        emit_tree_config_event(
+            tree_authority,
+            merkle_tree,
+            TreeConfigUpdate::TreeCreatorProposed {
+                proposed_tree_creator,
+            },
+            log_wrapper,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Creates the tree creator proposal PDA of `merkle_tree` if it does not exist yet.
+fn init_tree_creator_proposal<'info>(
+    tree_creator_proposal: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    merkle_tree: &Pubkey,
+) -> Result<()> {
+    let (expected, bump) = Pubkey::find_program_address(
+        &[TREE_CREATOR_PROPOSAL_PREFIX.as_ref(), merkle_tree.as_ref()],
+        &crate::id(),
+    );
+    require_keys_eq!(
+        tree_creator_proposal.key(),
+        expected,
+        BubblegumError::PublicKeyMismatch
+    );
+
+    if *tree_creator_proposal.owner == crate::id() {
+        return Ok(());
+    }
+
+    // The account may have been funded before, so top it up rather than create it.
+    let rent = Rent::get()?.minimum_balance(TREE_CREATOR_PROPOSAL_SIZE);
+    let top_up = rent.saturating_sub(tree_creator_proposal.lamports());
+    if top_up > 0 {
+        transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: tree_creator_proposal.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+
+    let seeds: &[&[u8]] = &[
+        TREE_CREATOR_PROPOSAL_PREFIX.as_ref(),
+        merkle_tree.as_ref(),
+        &[bump],
+    ];
+    allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Allocate {
+                account_to_allocate: tree_creator_proposal.clone(),
+            },
+            &[seeds],
+        ),
+        TREE_CREATOR_PROPOSAL_SIZE as u64,
+    )?;
+    assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Assign {
+                account_to_assign: tree_creator_proposal.clone(),
+            },
+            &[seeds],
+        ),
+        &crate::id(),
+    )
+}
+
+#[derive(Accounts)]
+pub struct AcceptTreeCreator<'info> {
+    #[account(
+        mut,
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    #[account(mut)]
+    pub new_tree_creator: Signer<'info>,
+    /// CHECK: Used to derive `tree_authority`
+    pub merkle_tree: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = new_tree_creator,
+        seeds = [TREE_CREATOR_PROPOSAL_PREFIX.as_ref(), merkle_tree.key().as_ref()],
+        bump,
+        constraint = tree_creator_proposal.proposed_tree_creator == new_tree_creator.key()
+            @ BubblegumError::InvalidAuthority,
+    )]
+    pub tree_creator_proposal: Account<'info, TreeCreatorProposal>,
+    /// CHECK: Program is checked against the tree version in the instruction.
+    pub log_wrapper: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn accept_tree_creator(ctx: Context<AcceptTreeCreator>) -> Result<()> {
+    let merkle_tree = ctx.accounts.merkle_tree.key();
+    let new_tree_creator = ctx.accounts.new_tree_creator.key();
+    let log_wrapper = &ctx.accounts.log_wrapper;
+    let tree_authority = &mut ctx.accounts.tree_authority;
+    assert_log_wrapper(tree_authority.version, &log_wrapper.key())?;
+
+    // Scoped delegate records are bound to the tree creator that set them, so they stop
+    // applying here.
+    let previous_tree_creator = tree_authority.tree_creator;
+    tree_authority.tree_creator = new_tree_creator;
+
+    // The tree delegate was chosen by the previous creator, so it is reset to the new creator
+    // like on tree creation.  The new creator can set a delegate again with `set_tree_delegate`.
+    tree_authority.tree_delegate = new_tree_creator;
+
+    emit_tree_config_event(
+        tree_authority,
+        merkle_tree,
+        TreeConfigUpdate::TreeCreatorAccepted {
+            previous_tree_creator,
+            new_tree_creator,
+        },
+        log_wrapper,
+    )
+}
+
+pub(crate) fn emit_tree_config_event(
+    tree_authority: &TreeConfig,
+    merkle_tree: Pubkey,
+    update: TreeConfigUpdate,
+    log_wrapper: &AccountInfo<'_>,
+) -> Result<()> {
+    let event = TreeConfigEvent::new(tree_authority.version, merkle_tree, update);
+    wrap_application_data_v1(tree_authority.version, event.try_to_vec()?, log_wrapper)
+}
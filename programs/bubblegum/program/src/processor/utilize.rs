@@ -0,0 +1,382 @@
+use anchor_lang::prelude::*;
+use mpl_account_compression::{program::MplAccountCompression, Noop as MplNoop};
+use mpl_core::{instructions::UpdateCollectionInfoV1CpiBuilder, types::UpdateType};
+use spl_account_compression::{program::SplAccountCompression, Node, Noop as SplNoop};
+
+use crate::{
+    asserts::assert_tree_not_paused,
+    error::BubblegumError,
+    processor::{
+        asset_validate_non_frozen, asset_validate_not_revoked, emit_asset_event,
+        mint::process_mint_v2, MintV2, OptionalAccounts,
+    },
+    state::{
+        leaf_schema::{LeafSchema, LeafUpdate, Version},
+        metaplex_adapter::{MetadataArgs, MetadataArgsV2, TokenStandard, UseMethod, Uses},
+        metaplex_anchor::MplCore,
+        AssetUpdate, TreeConfig, TreeCounters, MPL_CORE_CPI_SIGNER_PREFIX,
+    },
+    utils::{
+        get_asset_id, hash_collection_option, hash_creators, hash_metadata, hash_uses,
+        replace_leaf, Flags, DEFAULT_FLAGS,
+    },
+};
+
+#[derive(Accounts)]
+pub struct Utilize<'info> {
+    #[account(
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    /// CHECK: This account is checked in the instruction
+    pub leaf_owner: UncheckedAccount<'info>,
+    /// CHECK: This account is checked in the instruction
+    pub leaf_delegate: UncheckedAccount<'info>,
+    /// CHECK: This account is modified in the downstream program
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+    pub log_wrapper: Program<'info, SplNoop>,
+    pub compression_program: Program<'info, SplAccountCompression>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn utilize_v1<'info>(
+    ctx: Context<'_, '_, '_, 'info, Utilize<'info>>,
+    root: [u8; 32],
+    nonce: u64,
+    index: u32,
+    current_metadata: MetadataArgs,
+    number_of_uses: u64,
+) -> Result<()> {
+    // V1 instructions only work with V1 trees.
+    require!(
+        ctx.accounts.tree_authority.version == Version::V1,
+        BubblegumError::UnsupportedSchemaVersion
+    );
+
+    assert_tree_not_paused(&ctx.accounts.tree_authority.to_account_info())?;
+
+    // Uses are spent by the leaf owner or by the leaf delegate, which acts as the approved use
+    // authority of the asset.
+    let owner = ctx.accounts.leaf_owner.to_account_info();
+    let delegate = ctx.accounts.leaf_delegate.to_account_info();
+    require!(
+        owner.is_signer || delegate.is_signer,
+        BubblegumError::LeafAuthorityMustSign
+    );
+
+    let uses = current_metadata
+        .uses
+        .as_ref()
+        .ok_or(BubblegumError::Unusable)?;
+    let uses = spend_uses(uses, number_of_uses)?;
+
+    // Uses can be spent on immutable metadata, so the mutability of the metadata is not checked.
+    let data_hash = hash_metadata(&current_metadata)?;
+    let creator_hash = hash_creators(&current_metadata.creators)?;
+
+    let merkle_tree = ctx.accounts.merkle_tree.to_account_info();
+    let asset_id = get_asset_id(&merkle_tree.key(), nonce);
+
+    let previous_leaf = LeafSchema::new_v1(
+        asset_id,
+        owner.key(),
+        delegate.key(),
+        nonce,
+        data_hash,
+        creator_hash,
+    );
+
+    let burned = is_used_up(&uses);
+
+    let mut updated_metadata = current_metadata;
+    updated_metadata.uses = Some(uses);
+
+    let new_leaf = LeafSchema::new_v1(
+        asset_id,
+        owner.key(),
+        delegate.key(),
+        nonce,
+        hash_metadata(&updated_metadata)?,
+        creator_hash,
+    );
+
+    // The leaf with its last use spent is logged even when it is burned, so that indexers see
+    // the final uses before the leaf is removed from the tree.
+    crate::utils::wrap_application_data_v1(
+        Version::V1,
+        new_leaf.to_event().try_to_vec()?,
+        &ctx.accounts.log_wrapper,
+    )?;
+
+    let new_leaf = if burned {
+        Node::default()
+    } else {
+        new_leaf.to_node()
+    };
+
+    replace_leaf(
+        Version::V1,
+        &merkle_tree.key(),
+        ctx.bumps.tree_authority,
+        &ctx.accounts.compression_program.to_account_info(),
+        &ctx.accounts.tree_authority.to_account_info(),
+        &ctx.accounts.merkle_tree.to_account_info(),
+        &ctx.accounts.log_wrapper.to_account_info(),
+        ctx.remaining_accounts,
+        root,
+        previous_leaf.to_node(),
+        new_leaf,
+        index,
+    )
+}
+
+/// Mints a usable asset with the accounts of `mint_v2`.  V2 metadata has no uses, so the uses of
+/// the asset are committed to by its asset data hash instead.
+pub(crate) fn mint_usable_v2(
+    ctx: Context<MintV2>,
+    metadata_args: MetadataArgsV2,
+    uses: Uses,
+) -> Result<LeafUpdate> {
+    require!(
+        uses.remaining > 0
+            && uses.remaining <= uses.total
+            && (uses.use_method != UseMethod::Single || uses.total == 1),
+        BubblegumError::InvalidUses
+    );
+
+    let authority = ctx
+        .accounts
+        .tree_delegate
+        .as_ref()
+        .map(|account| account.key())
+        .unwrap_or(ctx.accounts.payer.key());
+    let log_wrapper = ctx.accounts.log_wrapper.to_account_info();
+    let asset_data_hash = hash_uses(&uses)?;
+
+    let update = process_mint_v2(
+        ctx,
+        metadata_args,
+        TokenStandard::NonFungible,
+        asset_data_hash,
+        &[],
+    )?;
+
+    emit_asset_event(
+        update.leaf.id(),
+        AssetUpdate::UsesUpdatedV2 {
+            authority,
+            uses,
+            burned: false,
+        },
+        &log_wrapper,
+    )?;
+
+    Ok(update)
+}
+
+#[derive(Accounts)]
+pub struct UtilizeV2<'info> {
+    #[account(
+        mut,
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// Optional authority, defaults to `payer`.  Must be the leaf owner or delegate.
+    pub authority: Option<Signer<'info>>,
+    /// CHECK: This account is neither written to nor read from
+    pub leaf_owner: UncheckedAccount<'info>,
+    /// CHECK: This account is neither written to nor read from
+    /// Defaults to `leaf_owner`
+    pub leaf_delegate: Option<UncheckedAccount<'info>>,
+    /// CHECK: This account is modified in the downstream program
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+    /// CHECK: This account is checked in the instruction
+    #[account(mut, owner = mpl_core_program.key())]
+    pub core_collection: Option<UncheckedAccount<'info>>,
+    /// CHECK: This is just used as a signing PDA.
+    #[account(
+        seeds = [MPL_CORE_CPI_SIGNER_PREFIX.as_ref()],
+        bump,
+    )]
+    pub mpl_core_cpi_signer: Option<UncheckedAccount<'info>>,
+    pub log_wrapper: Program<'info, MplNoop>,
+    pub compression_program: Program<'info, MplAccountCompression>,
+    pub mpl_core_program: Program<'info, MplCore>,
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn utilize_v2<'info>(
+    ctx: Context<'_, '_, '_, 'info, UtilizeV2<'info>>,
+    root: [u8; 32],
+    data_hash: [u8; 32],
+    creator_hash: [u8; 32],
+    flags: Option<u8>,
+    nonce: u64,
+    index: u32,
+    uses: Uses,
+    number_of_uses: u64,
+) -> Result<LeafUpdate> {
+    // V2 instructions only work with V2 trees.
+    require!(
+        ctx.accounts.tree_authority.version == Version::V2,
+        BubblegumError::UnsupportedSchemaVersion
+    );
+
+    let optional =
+        OptionalAccounts::parse(&ctx.accounts.merkle_tree.key(), ctx.remaining_accounts)?;
+
+    assert_tree_not_paused(&ctx.accounts.tree_authority.to_account_info())?;
+
+    let authority = ctx
+        .accounts
+        .authority
+        .as_ref()
+        .map(|account| account.key())
+        .unwrap_or(ctx.accounts.payer.key());
+
+    let leaf_owner = ctx.accounts.leaf_owner.key();
+    let leaf_delegate = ctx
+        .accounts
+        .leaf_delegate
+        .as_ref()
+        .map(|account| account.key())
+        .unwrap_or(leaf_owner);
+
+    // Uses are spent by the leaf owner or delegate, like `utilize_v1`.
+    require!(
+        authority == leaf_owner || authority == leaf_delegate,
+        BubblegumError::InvalidAuthority
+    );
+
+    let raw_flags = flags.unwrap_or(DEFAULT_FLAGS);
+    let flags = Flags::from_bytes([raw_flags]);
+    asset_validate_non_frozen(flags)?;
+    asset_validate_not_revoked(flags)?;
+
+    let updated_uses = spend_uses(&uses, number_of_uses)?;
+    let burned = is_used_up(&updated_uses);
+
+    let collection_hash = hash_collection_option(
+        ctx.accounts
+            .core_collection
+            .as_ref()
+            .map(|account| *account.key),
+    )?;
+
+    let merkle_tree = &ctx.accounts.merkle_tree;
+    let asset_id = get_asset_id(&merkle_tree.key(), nonce);
+
+    let previous_leaf = LeafSchema::new_v2(
+        asset_id,
+        leaf_owner,
+        leaf_delegate,
+        nonce,
+        data_hash,
+        creator_hash,
+        collection_hash,
+        hash_uses(&uses)?,
+        raw_flags,
+    );
+
+    let new_leaf = LeafSchema::new_v2(
+        asset_id,
+        leaf_owner,
+        leaf_delegate,
+        nonce,
+        data_hash,
+        creator_hash,
+        collection_hash,
+        hash_uses(&updated_uses)?,
+        raw_flags,
+    );
+
+    // The leaf with its last use spent is logged even when it is burned, so that indexers see
+    // the final uses before the leaf is removed from the tree.
+    crate::utils::wrap_application_data_v1(
+        Version::V2,
+        new_leaf.to_event().try_to_vec()?,
+        &ctx.accounts.log_wrapper,
+    )?;
+
+    emit_asset_event(
+        asset_id,
+        AssetUpdate::UsesUpdatedV2 {
+            authority,
+            uses: updated_uses,
+            burned,
+        },
+        &ctx.accounts.log_wrapper,
+    )?;
+
+    let new_node = if burned {
+        // Burned assets leave their collection, like with `burn_v2`.
+        if let Some(core_collection) = &ctx.accounts.core_collection {
+            let mpl_core_cpi_signer = &ctx
+                .accounts
+                .mpl_core_cpi_signer
+                .as_ref()
+                .ok_or(BubblegumError::MissingMplCoreCpiSignerAccount)?;
+
+            UpdateCollectionInfoV1CpiBuilder::new(&ctx.accounts.mpl_core_program)
+                .collection(core_collection)
+                .bubblegum_signer(mpl_core_cpi_signer)
+                .update_type(UpdateType::Remove)
+                .amount(1)
+                .invoke_signed(&[&[
+                    MPL_CORE_CPI_SIGNER_PREFIX.as_bytes(),
+                    &[ctx.bumps.mpl_core_cpi_signer],
+                ]])?;
+        }
+
+        TreeCounters::update(&ctx.accounts.tree_authority.to_account_info(), |counters| {
+            counters.record_burn(flags)
+        })?;
+
+        Node::default()
+    } else {
+        new_leaf.to_node()
+    };
+
+    replace_leaf(
+        Version::V2,
+        &merkle_tree.key(),
+        ctx.bumps.tree_authority,
+        &ctx.accounts.compression_program.to_account_info(),
+        &ctx.accounts.tree_authority.to_account_info(),
+        &ctx.accounts.merkle_tree.to_account_info(),
+        &ctx.accounts.log_wrapper.to_account_info(),
+        optional.remaining_accounts,
+        root,
+        previous_leaf.to_node(),
+        new_node,
+        index,
+    )?;
+
+    // A burned leaf is returned with its last use spent, as logged above.
+    LeafUpdate::new(new_leaf, index, &ctx.accounts.merkle_tree.to_account_info())
+}
+
+fn spend_uses(uses: &Uses, number_of_uses: u64) -> Result<Uses> {
+    require!(number_of_uses > 0, BubblegumError::NotEnoughUses);
+
+    let remaining = uses
+        .remaining
+        .checked_sub(number_of_uses)
+        .ok_or(BubblegumError::NotEnoughUses)?;
+
+    Ok(Uses {
+        remaining,
+        ..uses.clone()
+    })
+}
+
+/// Assets with `UseMethod::Burn` are burned once they have no uses remaining.
+fn is_used_up(uses: &Uses) -> bool {
+    uses.use_method == UseMethod::Burn && uses.remaining == 0
+}
@@ -3,11 +3,11 @@ use mpl_account_compression::{program::MplAccountCompression, Noop as MplNoop};
 use spl_account_compression::{program::SplAccountCompression, Noop as SplNoop};
 
 use crate::{
-    processor::{process_creator_verification, BubblegumError},
+    processor::{emit_asset_event, process_creator_verification, BubblegumError},
     state::{
-        leaf_schema::Version,
+        leaf_schema::{LeafUpdate, Version},
         metaplex_adapter::{MetadataArgs, MetadataArgsV2},
-        TreeConfig,
+        AssetUpdate, TreeConfig,
     },
     utils::{hash_collection_option, hash_creators, hash_metadata, replace_leaf},
 };
@@ -50,7 +50,7 @@ pub(crate) fn verify_creator<'info>(
 
     let (previous_leaf, new_leaf) = process_creator_verification(
         ctx.accounts.merkle_tree.key(),
-        ctx.accounts.creator.key(),
+        &[ctx.accounts.creator.key()],
         data_hash,
         creator_hash,
         nonce,
@@ -118,7 +118,7 @@ pub(crate) fn verify_creator_v2<'info>(
     nonce: u64,
     index: u32,
     message: MetadataArgsV2,
-) -> Result<()> {
+) -> Result<LeafUpdate> {
     // V2 instructions only work with V2 trees.
     require!(
         ctx.accounts.tree_authority.version == Version::V2,
@@ -146,7 +146,7 @@ pub(crate) fn verify_creator_v2<'info>(
 
     let (previous_leaf, new_leaf) = process_creator_verification(
         ctx.accounts.merkle_tree.key(),
-        creator,
+        &[creator],
         previous_data_hash,
         previous_creator_hash,
         nonce,
@@ -165,6 +165,15 @@ pub(crate) fn verify_creator_v2<'info>(
         &ctx.accounts.log_wrapper,
     )?;
 
+    emit_asset_event(
+        new_leaf.id(),
+        AssetUpdate::CreatorsVerifiedV2 {
+            creators: vec![creator],
+            verified: true,
+        },
+        &ctx.accounts.log_wrapper,
+    )?;
+
     replace_leaf(
         Version::V2,
         &ctx.accounts.merkle_tree.key(),
@@ -178,5 +187,103 @@ pub(crate) fn verify_creator_v2<'info>(
         previous_leaf.to_node(),
         new_leaf.to_node(),
         index,
-    )
+    )?;
+
+    LeafUpdate::new(new_leaf, index, &ctx.accounts.merkle_tree.to_account_info())
+}
+
+pub(crate) fn verify_creators_v2<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreatorVerificationV2<'info>>,
+    root: [u8; 32],
+    asset_data_hash: Option<[u8; 32]>,
+    flags: Option<u8>,
+    nonce: u64,
+    index: u32,
+    message: MetadataArgsV2,
+) -> Result<LeafUpdate> {
+    // V2 instructions only work with V2 trees.
+    require!(
+        ctx.accounts.tree_authority.version == Version::V2,
+        BubblegumError::UnsupportedSchemaVersion
+    );
+
+    // Signing creators are passed as the leading remaining accounts, followed by the proof.
+    let num_signers = ctx
+        .remaining_accounts
+        .iter()
+        .take_while(|account| account.is_signer)
+        .count();
+    let (signers, proof) = ctx.remaining_accounts.split_at(num_signers);
+
+    // Any signer can be counted as a verified creator.
+    let mut creators = vec![ctx.accounts.payer.key()];
+    creators.extend(ctx.accounts.creator.as_ref().map(|account| account.key()));
+    creators.extend(signers.iter().map(|account| account.key()));
+
+    // Only the signers in the creator Vec are verified.
+    let verified_creators = message
+        .creators
+        .iter()
+        .map(|creator| creator.address)
+        .filter(|address| creators.contains(address))
+        .collect::<Vec<Pubkey>>();
+
+    let previous_data_hash = hash_metadata(&message)?;
+    let previous_creator_hash = hash_creators(&message.creators)?;
+
+    let leaf_owner = ctx.accounts.leaf_owner.key();
+    let leaf_delegate = ctx
+        .accounts
+        .leaf_delegate
+        .as_ref()
+        .map(|account| account.key());
+
+    let collection_hash = hash_collection_option(message.collection)?;
+
+    let (previous_leaf, new_leaf) = process_creator_verification(
+        ctx.accounts.merkle_tree.key(),
+        &creators,
+        previous_data_hash,
+        previous_creator_hash,
+        nonce,
+        leaf_owner,
+        leaf_delegate,
+        message,
+        Some(collection_hash),
+        asset_data_hash,
+        flags,
+        true,
+    )?;
+
+    crate::utils::wrap_application_data_v1(
+        Version::V2,
+        new_leaf.to_event().try_to_vec()?,
+        &ctx.accounts.log_wrapper,
+    )?;
+
+    emit_asset_event(
+        new_leaf.id(),
+        AssetUpdate::CreatorsVerifiedV2 {
+            creators: verified_creators,
+            verified: true,
+        },
+        &ctx.accounts.log_wrapper,
+    )?;
+
+    replace_leaf(
+        Version::V2,
+        &ctx.accounts.merkle_tree.key(),
+        ctx.bumps.tree_authority,
+        &ctx.accounts.compression_program.to_account_info(),
+        &ctx.accounts.tree_authority.to_account_info(),
+        &ctx.accounts.merkle_tree.to_account_info(),
+        &ctx.accounts.log_wrapper.to_account_info(),
+        proof,
+        root,
+        previous_leaf.to_node(),
+        new_leaf.to_node(),
+        index,
+    )?;
+
+    LeafUpdate::new(new_leaf, index, &ctx.accounts.merkle_tree.to_account_info())
 }
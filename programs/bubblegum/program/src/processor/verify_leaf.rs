@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use mpl_account_compression::program::MplAccountCompression;
+
+use crate::{
+    error::BubblegumError,
+    processor::{asset_validate_non_frozen, asset_validate_not_revoked},
+    state::{
+        leaf_schema::{LeafSchema, Version},
+        TreeConfig,
+    },
+    utils::{
+        get_asset_id, hash_collection_option, verify_leaf, Flags, DEFAULT_ASSET_DATA_HASH,
+        DEFAULT_COLLECTION_HASH, DEFAULT_FLAGS,
+    },
+};
+
+#[derive(Accounts)]
+pub struct VerifyLeafV2<'info> {
+    #[account(
+        seeds = [merkle_tree.key().as_ref()],
+        bump,
+    )]
+    pub tree_authority: Account<'info, TreeConfig>,
+    /// CHECK: This account is neither written to nor read from
+    pub leaf_owner: UncheckedAccount<'info>,
+    /// CHECK: This account is neither written to nor read from
+    /// Defaults to `leaf_owner`
+    pub leaf_delegate: Option<UncheckedAccount<'info>>,
+    /// CHECK: This account is checked in the downstream program
+    pub merkle_tree: UncheckedAccount<'info>,
+    /// CHECK: This account is checked in the instruction
+    /// Optional mpl-core collection the asset must belong to.
+    pub core_collection: Option<UncheckedAccount<'info>>,
+    pub compression_program: Program<'info, MplAccountCompression>,
+}
+
+pub(crate) fn verify_leaf_v2<'info>(
+    ctx: Context<'_, '_, '_, 'info, VerifyLeafV2<'info>>,
+    root: [u8; 32],
+    data_hash: [u8; 32],
+    creator_hash: [u8; 32],
+    collection_hash: Option<[u8; 32]>,
+    asset_data_hash: Option<[u8; 32]>,
+    flags: Option<u8>,
+    nonce: u64,
+    index: u32,
+    require_not_frozen: bool,
+) -> Result<Pubkey> {
+    // V2 instructions only work with V2 trees.
+    require!(
+        ctx.accounts.tree_authority.version == Version::V2,
+        BubblegumError::UnsupportedSchemaVersion
+    );
+
+    let raw_flags = flags.unwrap_or(DEFAULT_FLAGS);
+
+    // Revoked assets no longer prove ownership.
+    asset_validate_not_revoked(Flags::from_bytes([raw_flags]))?;
+
+    if require_not_frozen {
+        asset_validate_non_frozen(Flags::from_bytes([raw_flags]))?;
+    }
+
+    // When a collection is provided, the leaf only verifies if the asset belongs to it.
+    let collection_hash = if let Some(core_collection) = &ctx.accounts.core_collection {
+        require!(
+            *core_collection.owner == mpl_core::ID,
+            BubblegumError::IncorrectOwner
+        );
+
+        hash_collection_option(Some(core_collection.key()))?
+    } else {
+        collection_hash.unwrap_or(DEFAULT_COLLECTION_HASH)
+    };
+
+    let merkle_tree = &ctx.accounts.merkle_tree;
+    let asset_id = get_asset_id(&merkle_tree.key(), nonce);
+    let leaf_owner = ctx.accounts.leaf_owner.key();
+    let leaf_delegate = ctx
+        .accounts
+        .leaf_delegate
+        .as_ref()
+        .map(|account| account.key())
+        .unwrap_or(leaf_owner);
+
+    let leaf = LeafSchema::new_v2(
+        asset_id,
+        leaf_owner,
+        leaf_delegate,
+        nonce,
+        data_hash,
+        creator_hash,
+        collection_hash,
+        asset_data_hash.unwrap_or(DEFAULT_ASSET_DATA_HASH),
+        raw_flags,
+    );
+
+    verify_leaf(
+        Version::V2,
+        &ctx.accounts.compression_program.to_account_info(),
+        &merkle_tree.to_account_info(),
+        ctx.remaining_accounts,
+        root,
+        leaf.to_node(),
+        index,
+    )?;
+
+    Ok(asset_id)
+}
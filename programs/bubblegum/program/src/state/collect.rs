@@ -1,7 +1,84 @@
 use anchor_lang::prelude::*;
 
-pub(crate) const COLLECT_RECIPIENT: Pubkey =
+use crate::{error::BubblegumError, state::TreeSettings};
+
+pub const FEE_CONFIG_SIZE: usize = 8 + 32 + 8 + 8;
+pub const FEE_CONFIG_PREFIX: &str = "fee_config";
+pub const TREE_CREATOR_FEES_SIZE: usize = 8 + 32 + 32 + 8;
+pub const TREE_CREATOR_FEES_PREFIX: &str = "tree_creator_fees";
+
+/// Fees charged when the `FeeConfig` account is not passed to an instruction.
+pub(crate) const DEFAULT_FEE_RECIPIENT: Pubkey =
     solana_program::pubkey!("2dgJVPC5fjLTBTmMvKDRig9JJUGK2Fgwr3EHShFxckhv");
+pub(crate) const DEFAULT_MINT_V2_FEE_LAMPORTS: u64 = 90000;
+pub(crate) const DEFAULT_TRANSFER_V2_FEE_LAMPORTS: u64 = 6000;
+
+/// Highest fees `set_fee_config` accepts.  Mints and transfers read the fee config as an optional
+/// account and charge the default fees without it, so a configured fee above the default could be
+/// avoided by leaving the account out.  The maximums are therefore the default fees.
+pub const MAX_MINT_V2_FEE_LAMPORTS: u64 = DEFAULT_MINT_V2_FEE_LAMPORTS;
+pub const MAX_TRANSFER_V2_FEE_LAMPORTS: u64 = DEFAULT_TRANSFER_V2_FEE_LAMPORTS;
+
+/// Protocol fee schedule, set by the program upgrade authority.  Fees are paid into the tree
+/// config and swept to `fee_recipient` by `collect_v2`.  Instructions read it as an optional
+/// account ahead of their remaining accounts, so that it does not change their account lists.
+#[account]
+#[derive(Copy, Debug, PartialEq, Eq)]
+pub struct FeeConfig {
+    pub fee_recipient: Pubkey,
+    pub mint_v2_fee_lamports: u64,
+    pub transfer_v2_fee_lamports: u64,
+}
+
+impl Default for FeeConfig {
+    fn default() -> Self {
+        Self {
+            fee_recipient: DEFAULT_FEE_RECIPIENT,
+            mint_v2_fee_lamports: DEFAULT_MINT_V2_FEE_LAMPORTS,
+            transfer_v2_fee_lamports: DEFAULT_TRANSFER_V2_FEE_LAMPORTS,
+        }
+    }
+}
+
+impl FeeConfig {
+    /// Fee charged by `mint_v2` and `mint_to_group_v2` on the given tree.
+    pub fn mint_v2_fee(&self, settings: &TreeSettings) -> u64 {
+        if settings.is_fee_exempt {
+            0
+        } else {
+            self.mint_v2_fee_lamports
+        }
+    }
+
+    /// Fee charged by `transfer_v2` on the given tree.
+    pub fn transfer_v2_fee(&self, settings: &TreeSettings) -> u64 {
+        if settings.is_fee_exempt {
+            0
+        } else {
+            self.transfer_v2_fee_lamports
+        }
+    }
+
+    /// Reads the fee config PDA.
+    pub(crate) fn load(fee_config: &AccountInfo) -> Result<Self> {
+        require!(
+            *fee_config.owner == crate::id(),
+            BubblegumError::IncorrectOwner
+        );
+
+        let data = fee_config.try_borrow_data()?;
+        FeeConfig::try_deserialize(&mut data.as_ref())
+    }
+}
 
-pub(crate) const MINT_V2_FEE_LAMPORTS: u64 = 90000;
-pub(crate) const TRANSFER_V2_FEE_LAMPORTS: u64 = 6000;
+/// Creator fee charged on third-party mints into a public tree.  The fees are held by this
+/// account rather than the tree config, so that `collect_v2` never sweeps them, and are withdrawn
+/// by the tree creator that set the fee, which does not need the tree config to still exist.
+#[account]
+#[derive(Copy, Debug, PartialEq, Eq)]
+pub struct TreeCreatorFees {
+    pub merkle_tree: Pubkey,
+    /// Tree creator that last set the fee, and can withdraw the fees.
+    pub tree_creator: Pubkey,
+    pub creator_fee_lamports: u64,
+}
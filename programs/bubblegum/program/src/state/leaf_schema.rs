@@ -242,3 +242,23 @@ impl LeafSchema {
         hashed_leaf
     }
 }
+
+/// Return data of the V2 instructions that mint or replace a leaf, describing the leaf they wrote.
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct LeafUpdate {
+    pub leaf: LeafSchema,
+    /// Index of the leaf in the tree.
+    pub index: u32,
+    /// Sequence number of the tree after the update.
+    pub seq: u64,
+}
+
+impl LeafUpdate {
+    pub fn new(leaf: LeafSchema, index: u32, merkle_tree: &AccountInfo) -> Result<Self> {
+        Ok(Self {
+            leaf,
+            index,
+            seq: crate::utils::get_tree_seq(merkle_tree)?,
+        })
+    }
+}
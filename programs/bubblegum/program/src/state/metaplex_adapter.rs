@@ -113,7 +113,7 @@ pub struct MetadataArgsV2 {
     pub name: String,
     /// The symbol for the asset
     pub symbol: String,
-    /// URI pointing to JSON representing the asset
+    /// URI pointing to JSON representing the asset, or its suffix for trees with a base URI
     pub uri: String,
     /// Royalty basis points that goes to creators in secondary sales (0-10000)
     pub seller_fee_basis_points: u16,
@@ -121,7 +121,7 @@ pub struct MetadataArgsV2 {
     pub primary_sale_happened: bool,
     /// Whether or not the data struct is mutable, default is not
     pub is_mutable: bool,
-    /// Token standard.  Currently only `NonFungible` is allowed.
+    /// Token standard.  Only `NonFungible` is allowed, or `FungibleAsset` with `mint_fungible_v2`.
     pub token_standard: Option<TokenStandard>,
     /// Creator array
     pub creators: Vec<Creator>,
@@ -310,3 +310,17 @@ pub struct UpdateArgs {
     pub primary_sale_happened: Option<bool>,
     pub is_mutable: Option<bool>,
 }
+
+/// A leaf updated by `batch_update_metadata_v2`, with the current values of its `LeafSchema` V2
+/// fields.
+#[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct LeafMetadataUpdate {
+    pub leaf_owner: Pubkey,
+    /// Defaults to `leaf_owner`
+    pub leaf_delegate: Option<Pubkey>,
+    pub asset_data_hash: Option<[u8; 32]>,
+    pub flags: Option<u8>,
+    pub nonce: u64,
+    pub index: u32,
+    pub current_metadata: MetadataArgsV2,
+}
@@ -2,10 +2,14 @@ pub mod collect;
 pub mod leaf_schema;
 pub mod metaplex_adapter;
 pub mod metaplex_anchor;
+pub mod tree_extension;
 
 use anchor_lang::prelude::*;
 use borsh::{BorshDeserialize, BorshSerialize};
 use leaf_schema::{LeafSchema, Version};
+use metaplex_adapter::{MetadataArgsV2, UpdateArgs, Uses};
+
+use crate::{error::BubblegumError, traits::ValidationResult, utils::Flags};
 
 pub const TREE_AUTHORITY_SIZE: usize = 8 + 32 + 32 + 8 + 8 + 1 + 1 + 1 + 5; // 5 bytes padding
 pub const VOUCHER_SIZE: usize = 8 + 1 + 32 + 32 + 32 + 8 + 32 + 32 + 4 + 32;
@@ -13,6 +17,19 @@ pub const VOUCHER_PREFIX: &str = "voucher";
 pub const ASSET_PREFIX: &str = "asset";
 pub const COLLECTION_CPI_PREFIX: &str = "collection_cpi";
 pub const MPL_CORE_CPI_SIGNER_PREFIX: &str = "mpl_core_cpi_signer";
+pub const TREE_CREATOR_PROPOSAL_SIZE: usize = 8 + 32;
+pub const TREE_CREATOR_PROPOSAL_PREFIX: &str = "tree_creator_proposal";
+pub const TREE_DELEGATE_RECORD_SIZE: usize = 8 + 32 + 32 + 32 + 1;
+pub const TREE_DELEGATE_RECORD_PREFIX: &str = "tree_delegate_record";
+pub const TREE_GROUP_BASE_SIZE: usize = 8 + 32 + 32 + 1 + 4 + 4;
+pub const TREE_GROUP_PREFIX: &str = "tree_group";
+pub const MAX_TREE_RULE_SET_PROGRAMS: usize = 8;
+pub const TREE_PLUGINS_SIZE: usize = 8 + 1 + 4 + 32 * MAX_TREE_RULE_SET_PROGRAMS + 33 + 33 + 33;
+pub const TREE_PLUGINS_PREFIX: &str = "tree_plugins";
+pub const MASTER_EDITION_SIZE: usize = 8 + 32 + 32 + 32 + 9 + 8;
+pub const MASTER_EDITION_PREFIX: &str = "master_edition";
+pub const STAKE_RECORD_SIZE: usize = 8 + 32 + 32 + 32 + 8 + 8;
+pub const STAKE_RECORD_PREFIX: &str = "stake_record";
 
 #[account]
 #[derive(Copy, Debug, PartialEq, Eq)]
@@ -35,6 +52,381 @@ impl TreeConfig {
         let remaining_mints = self.total_mint_capacity.saturating_sub(self.num_minted);
         requested_capacity <= remaining_mints
     }
+
+    /// Whether `authority` can act on the tree in the given role, either as the tree creator or
+    /// tree delegate, or through a scoped delegate record that grants the role.  Records set by a
+    /// previous tree creator no longer apply.
+    pub fn is_authorized(
+        &self,
+        merkle_tree: &Pubkey,
+        authority: &Pubkey,
+        delegate_record: Option<&TreeDelegateRecord>,
+        role: TreeDelegateRole,
+    ) -> bool {
+        *authority == self.tree_creator
+            || *authority == self.tree_delegate
+            || delegate_record.map_or(false, |record| {
+                record.merkle_tree == *merkle_tree
+                    && record.tree_creator == self.tree_creator
+                    && record.delegate == *authority
+                    && record.has_role(role)
+            })
+    }
+}
+
+/// Lifecycle counters for the assets of a tree, kept next to `TreeConfig::num_minted`.  Stored as
+/// a tree config extension, which V2 trees get on creation.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TreeCounters {
+    /// Leaves burned, including fungible asset leaves merged into another leaf.
+    pub num_burned: u64,
+    /// Leaves redeemed for a voucher that was neither cancelled nor decompressed yet.  Always zero
+    /// until V2 trees support redeeming.
+    pub num_redeemed: u64,
+    /// Always zero until V2 trees support decompression.
+    pub num_decompressed: u64,
+    /// Leaves currently frozen, at the asset level or the permanent level.
+    pub num_frozen: u64,
+    /// Leaves currently non-transferable.
+    pub num_non_transferable: u64,
+}
+
+impl TreeCounters {
+    /// Number of minted leaves that are still in the tree.
+    pub fn num_live(&self, num_minted: u64) -> u64 {
+        num_minted
+            .saturating_sub(self.num_burned)
+            .saturating_sub(self.num_redeemed)
+            .saturating_sub(self.num_decompressed)
+    }
+
+    /// Records a leaf whose flags change from `previous` to `new`.
+    pub(crate) fn record_flags_change(&mut self, previous: Flags, new: Flags) {
+        let is_frozen = |flags: Flags| flags.asset_lvl_frozen() || flags.permanent_lvl_frozen();
+        Self::record_change(&mut self.num_frozen, is_frozen(previous), is_frozen(new));
+        Self::record_change(
+            &mut self.num_non_transferable,
+            previous.non_transferable(),
+            new.non_transferable(),
+        );
+    }
+
+    /// Records the burn of a leaf with the given flags.
+    pub(crate) fn record_burn(&mut self, flags: Flags) {
+        self.num_burned = self.num_burned.saturating_add(1);
+        self.record_flags_change(flags, Flags::new());
+    }
+
+    fn record_change(counter: &mut u64, previous: bool, new: bool) {
+        match (previous, new) {
+            (false, true) => *counter = counter.saturating_add(1),
+            (true, false) => *counter = counter.saturating_sub(1),
+            _ => {}
+        }
+    }
+
+    /// Applies `update` to the counters of the tree, if it keeps them.
+    pub(crate) fn update(
+        tree_authority: &AccountInfo,
+        update: impl FnOnce(&mut Self),
+    ) -> Result<()> {
+        if let Some(mut counters) = tree_extension::get_extension::<Self>(tree_authority)? {
+            update(&mut counters);
+            tree_extension::set_extension(tree_authority, &counters)?;
+        }
+        Ok(())
+    }
+}
+
+/// Switches of a tree that are off by default.  Stored as a tree config extension, which is added
+/// the first time one of them is set.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TreeSettings {
+    /// Blocks minting, transferring, delegating and burning while set.
+    pub is_paused: bool,
+    /// Whether a `TreePlugins` account applies to uncollected assets in the tree.
+    pub has_plugins: bool,
+    /// Exempts the tree from the protocol fees in `FeeConfig`.
+    pub is_fee_exempt: bool,
+    /// Whether mints into a public tree by anyone other than the tree creator or delegates pay the
+    /// creator fee in `TreeCreatorFees`.
+    pub has_creator_fee: bool,
+}
+
+impl TreeSettings {
+    /// Reads the settings of the tree, which are all off if the tree does not have them.
+    pub fn load(tree_authority: &AccountInfo) -> Result<Self> {
+        Ok(tree_extension::get_extension::<Self>(tree_authority)?.unwrap_or_default())
+    }
+
+    /// Applies `update` to the settings of the tree, adding the extension first if the tree does
+    /// not have it.  `payer` pays the rent of the extension.
+    pub(crate) fn update<'info>(
+        tree_authority: &AccountInfo<'info>,
+        payer: &AccountInfo<'info>,
+        system_program: &AccountInfo<'info>,
+        update: impl FnOnce(&mut Self),
+    ) -> Result<()> {
+        let mut settings = match tree_extension::get_extension::<Self>(tree_authority)? {
+            Some(settings) => settings,
+            None => {
+                tree_extension::add_extension::<Self>(tree_authority, payer, system_program)?;
+                Self::default()
+            }
+        };
+
+        update(&mut settings);
+        tree_extension::set_extension(tree_authority, &settings)
+    }
+}
+
+/// Placeholder of a `UriTemplate` base URI replaced by the leaf nonce.
+pub const URI_TEMPLATE_INDEX_PLACEHOLDER: &str = "{index}";
+
+/// Base URI shared by the leaves of a tree, which then only store the rest of their URI, or
+/// nothing, in their metadata.  Stored as a tree config extension.
+///
+/// Leaf data hashes commit to the URI stored in the leaf, so the base URI can be changed for every
+/// leaf at once with `set_base_uri_v2`.  Only the tree creator or its delegates sign that change,
+/// so the base URI is frozen for good once the tree has an immutable leaf, whose URI must not
+/// change, or a leaf in a collection, whose metadata only the collection authority may change.
+/// Trees mixing such leaves with mutable ones give up changing the base URI of the latter.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct UriTemplate {
+    /// Prefix of every leaf URI, in which `{index}` is replaced by the leaf nonce.
+    pub base_uri: String,
+    /// Whether the base URI can no longer be changed.
+    pub is_frozen: bool,
+}
+
+impl UriTemplate {
+    /// Full URI of the leaf with the given nonce, storing `uri` in its metadata.
+    pub fn resolve(&self, uri: &str, nonce: u64) -> String {
+        let mut resolved = self
+            .base_uri
+            .replace(URI_TEMPLATE_INDEX_PLACEHOLDER, &nonce.to_string());
+        resolved.push_str(uri);
+        resolved
+    }
+
+    /// Freezes the base URI of the tree, if it has one, once a leaf is immutable or in a
+    /// collection.
+    pub(crate) fn freeze_for_leaf(
+        tree_authority: &AccountInfo,
+        is_mutable: bool,
+        has_collection: bool,
+    ) -> Result<()> {
+        if is_mutable && !has_collection {
+            return Ok(());
+        }
+
+        if let Some(mut uri_template) = tree_extension::get_extension::<Self>(tree_authority)? {
+            if !uri_template.is_frozen {
+                uri_template.is_frozen = true;
+                tree_extension::set_extension(tree_authority, &uri_template)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Delegate limited to a set of roles on a single tree, set by the tree creator.  A tree creator
+/// hand-off revokes the records of the previous creator, which the new creator can then close.
+#[account]
+#[derive(Copy, Debug, PartialEq, Eq)]
+pub struct TreeDelegateRecord {
+    pub merkle_tree: Pubkey,
+    pub delegate: Pubkey,
+    /// Tree creator that set the record.
+    pub tree_creator: Pubkey,
+    /// Bitmask of `TreeDelegateRole` values.
+    pub roles: u8,
+}
+
+impl TreeDelegateRecord {
+    pub fn has_role(&self, role: TreeDelegateRole) -> bool {
+        self.roles & role as u8 != 0
+    }
+}
+
+/// Roles that can be granted through a `TreeDelegateRecord`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[repr(u8)]
+pub enum TreeDelegateRole {
+    Mint = 1 << 0,
+    UpdateMetadata = 1 << 1,
+    UpdateAssetData = 1 << 2,
+    Close = 1 << 3,
+    /// Revocation of the assets of the tree with `revoke_v2`.
+    Revoke = 1 << 4,
+}
+
+impl TreeDelegateRole {
+    pub const ALL: u8 = Self::Mint as u8
+        | Self::UpdateMetadata as u8
+        | Self::UpdateAssetData as u8
+        | Self::Close as u8;
+
+    /// Every role a record can hold.  `Revoke` is kept out of `ALL` since it permanently
+    /// restricts assets, so it has to be granted explicitly.
+    pub const VALID: u8 = Self::ALL | Self::Revoke as u8;
+}
+
+/// Tree-level counterpart of the mpl-core collection plugins, evaluated for V2 assets that are
+/// not in a collection.
+#[account]
+#[derive(Debug, PartialEq, Eq)]
+pub struct TreePlugins {
+    /// Program allow or deny list applied to the current and new owner on transfer.
+    pub royalties_rule_set: TreeRuleSet,
+    /// Can freeze and thaw any asset, and frozen assets cannot be transferred by their owner.
+    pub permanent_freeze_delegate: Option<Pubkey>,
+    /// Can transfer any asset, including frozen ones.
+    pub permanent_transfer_delegate: Option<Pubkey>,
+    /// Can burn any asset, including frozen ones.
+    pub permanent_burn_delegate: Option<Pubkey>,
+}
+
+impl TreePlugins {
+    pub fn is_empty(&self) -> bool {
+        self.royalties_rule_set == TreeRuleSet::None
+            && self.permanent_freeze_delegate.is_none()
+            && self.permanent_transfer_delegate.is_none()
+            && self.permanent_burn_delegate.is_none()
+    }
+
+    pub(crate) fn validate_transfer(
+        &self,
+        authority: Pubkey,
+        leaf_owner: &AccountInfo,
+        new_leaf_owner: &AccountInfo,
+    ) -> Result<ValidationResult> {
+        // Permanent Transfer Delegate can force approve.
+        if self.permanent_transfer_delegate == Some(authority) {
+            return Ok(ValidationResult::ForceApproved);
+        }
+
+        // Fail if the royalties rule set rejects the transfer.
+        if !self.royalties_rule_set.allows(leaf_owner.owner)
+            || !self.royalties_rule_set.allows(new_leaf_owner.owner)
+        {
+            return Err(BubblegumError::InvalidAuthority.into());
+        }
+
+        Ok(ValidationResult::Abstain)
+    }
+
+    pub(crate) fn validate_burn(&self, authority: Pubkey) -> ValidationResult {
+        if self.permanent_burn_delegate == Some(authority) {
+            ValidationResult::ForceApproved
+        } else {
+            ValidationResult::Abstain
+        }
+    }
+
+    pub(crate) fn validate_freeze(&self, authority: Pubkey) -> ValidationResult {
+        if self.permanent_freeze_delegate == Some(authority) {
+            ValidationResult::ForceApproved
+        } else {
+            ValidationResult::Abstain
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Eq, Debug, Clone, Default)]
+pub enum TreeRuleSet {
+    #[default]
+    None,
+    ProgramAllowList(Vec<Pubkey>),
+    ProgramDenyList(Vec<Pubkey>),
+}
+
+impl TreeRuleSet {
+    pub fn allows(&self, program: &Pubkey) -> bool {
+        match self {
+            TreeRuleSet::None => true,
+            TreeRuleSet::ProgramAllowList(allow_list) => allow_list.contains(program),
+            TreeRuleSet::ProgramDenyList(deny_list) => !deny_list.contains(program),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        match self {
+            TreeRuleSet::None => 0,
+            TreeRuleSet::ProgramAllowList(list) | TreeRuleSet::ProgramDenyList(list) => list.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Pending hand-off of `tree_creator`, completed when the proposed creator accepts it.
+#[account]
+#[derive(Copy, Debug, PartialEq, Eq)]
+pub struct TreeCreatorProposal {
+    pub proposed_tree_creator: Pubkey,
+}
+
+/// Ordered set of V2 trees sharing one authority, minted into one after the other.
+#[account]
+#[derive(Debug, PartialEq, Eq)]
+pub struct TreeGroup {
+    pub authority: Pubkey,
+    /// Arbitrary key chosen at creation so an authority can own several groups.
+    pub group_seed: Pubkey,
+    pub max_trees: u8,
+    /// Index in `trees` of the tree currently being minted into.
+    pub current_tree_index: u32,
+    pub trees: Vec<Pubkey>,
+}
+
+impl TreeGroup {
+    pub fn size(max_trees: u8) -> usize {
+        TREE_GROUP_BASE_SIZE + 32 * max_trees as usize
+    }
+
+    pub fn current_tree(&self) -> Option<&Pubkey> {
+        self.trees.get(self.current_tree_index as usize)
+    }
+}
+
+/// Master edition of compressed prints.  Each print is a V2 leaf minted with the metadata of the
+/// master edition by `print_edition_v2`, numbered from 1.
+#[account]
+#[derive(Copy, Debug, PartialEq, Eq)]
+pub struct MasterEdition {
+    pub authority: Pubkey,
+    /// Arbitrary key chosen at creation so an authority can own several master editions.
+    pub edition_seed: Pubkey,
+    /// `data_hash` of the metadata every print is minted with.
+    pub data_hash: [u8; 32],
+    /// Maximum number of prints, unlimited if `None`.
+    pub max_supply: Option<u64>,
+    /// Number of prints so far, which is also the edition number of the last print.
+    pub supply: u64,
+}
+
+impl MasterEdition {
+    pub fn contains_supply(&self) -> bool {
+        self.max_supply
+            .map_or(true, |max_supply| self.supply < max_supply)
+    }
+}
+
+/// Stake of a V2 asset by `stake_v2`.  The record is the leaf delegate of the asset, which stays
+/// frozen in the wallet of its owner until the staking authority signs `unstake_v2`.
+#[account]
+#[derive(Copy, Debug, PartialEq, Eq)]
+pub struct StakeRecord {
+    pub merkle_tree: Pubkey,
+    pub owner: Pubkey,
+    /// Authority of the staking program, which must sign the unstake.
+    pub authority: Pubkey,
+    pub nonce: u64,
+    /// Unix timestamp of the stake.
+    pub staked_at: i64,
 }
 
 #[account]
@@ -82,6 +474,173 @@ pub enum BubblegumEventType {
     Uninitialized,
     /// Leaf schema event.
     LeafSchemaEvent,
+    /// Tree config update event.
+    TreeConfigEvent,
+    /// Edition print event.
+    PrintEditionEvent,
+    /// Asset event.
+    AssetEvent,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct TreeConfigEvent {
+    pub event_type: BubblegumEventType,
+    pub version: Version,
+    pub merkle_tree: Pubkey,
+    pub update: TreeConfigUpdate,
+}
+
+impl TreeConfigEvent {
+    pub fn new(version: Version, merkle_tree: Pubkey, update: TreeConfigUpdate) -> Self {
+        Self {
+            event_type: BubblegumEventType::TreeConfigEvent,
+            version,
+            merkle_tree,
+            update,
+        }
+    }
+}
+
+/// Emitted after the leaf schema event of a print, linking the asset to its master edition.
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct PrintEditionEvent {
+    pub event_type: BubblegumEventType,
+    pub version: Version,
+    pub master_edition: Pubkey,
+    pub asset_id: Pubkey,
+    pub edition: u64,
+}
+
+impl PrintEditionEvent {
+    pub fn new(version: Version, master_edition: Pubkey, asset_id: Pubkey, edition: u64) -> Self {
+        Self {
+            event_type: BubblegumEventType::PrintEditionEvent,
+            version,
+            master_edition,
+            asset_id,
+            edition,
+        }
+    }
+}
+
+/// Emitted by V2 instructions after the leaf schema event, describing what happened to the asset
+/// so that indexers do not need to decode the instruction data.
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub struct AssetEvent {
+    pub event_type: BubblegumEventType,
+    pub version: Version,
+    pub asset_id: Pubkey,
+    pub update: AssetUpdate,
+}
+
+impl AssetEvent {
+    pub fn new(version: Version, asset_id: Pubkey, update: AssetUpdate) -> Self {
+        Self {
+            event_type: BubblegumEventType::AssetEvent,
+            version,
+            asset_id,
+            update,
+        }
+    }
+}
+
+/// What happened to an asset.  Variants are suffixed with the version of their layout, and a
+/// layout change is made as a new variant so that existing ones keep decoding.
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub enum AssetUpdate {
+    /// The asset was minted with the given metadata.
+    MintedV2 {
+        authority: Pubkey,
+        leaf_owner: Pubkey,
+        metadata: MetadataArgsV2,
+    },
+    /// The asset was transferred.
+    TransferredV2 {
+        authority: Pubkey,
+        previous_owner: Pubkey,
+        new_owner: Pubkey,
+    },
+    /// The asset was frozen, at the permanent level if frozen by a permanent freeze delegate.
+    FrozenV2 { authority: Pubkey, permanent: bool },
+    /// The asset was thawed, at the permanent level if thawed by a permanent freeze delegate.
+    ThawedV2 { authority: Pubkey, permanent: bool },
+    /// The metadata of the asset was updated, with only the changed fields set.
+    MetadataUpdatedV2 {
+        authority: Pubkey,
+        update_args: UpdateArgs,
+    },
+    /// The non-transferable asset was moved to a new owner by a recovery authority.
+    RecoveredV2 {
+        authority: Pubkey,
+        previous_owner: Pubkey,
+        new_owner: Pubkey,
+        reason: String,
+    },
+    /// The asset was revoked by the collection or tree authority.
+    RevokedV2 { authority: Pubkey },
+    /// The uses of the asset were set on mint or spent, and the asset was burned if `burned`.
+    UsesUpdatedV2 {
+        authority: Pubkey,
+        uses: Uses,
+        burned: bool,
+    },
+    /// The asset was burned.
+    BurnedV2 { authority: Pubkey },
+    /// The leaf delegate of the asset was changed, back to the owner if revoked.
+    DelegatedV2 {
+        authority: Pubkey,
+        previous_delegate: Pubkey,
+        new_delegate: Pubkey,
+    },
+    /// The asset was added to a collection, or removed from its collection if `None`.
+    CollectionSetV2 {
+        authority: Pubkey,
+        collection: Option<Pubkey>,
+    },
+    /// The given creators of the asset were verified, or unverified if not `verified`.
+    CreatorsVerifiedV2 {
+        creators: Vec<Pubkey>,
+        verified: bool,
+    },
+    /// The asset was made non-transferable.
+    NonTransferableSetV2 { authority: Pubkey },
+    /// Part of the amount of the fungible asset was split off into a new asset.
+    SplitV2 {
+        authority: Pubkey,
+        split_asset_id: Pubkey,
+        new_owner: Pubkey,
+        split_amount: u64,
+    },
+    /// Another fungible asset of the same class was merged into the asset, and burned.
+    MergedV2 {
+        authority: Pubkey,
+        merged_asset_id: Pubkey,
+        merged_amount: u64,
+    },
+    /// A verified creator of the asset handed its entry over to a new address.
+    CreatorUpdatedV2 {
+        previous_creator: Pubkey,
+        new_creator: Pubkey,
+    },
+}
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+pub enum TreeConfigUpdate {
+    /// The total mint capacity was lowered.
+    TotalMintCapacity { previous: u64, new: u64 },
+    /// The tree was made public or private.
+    IsPublic { is_public: bool },
+    /// A new tree creator was proposed by the current one.
+    TreeCreatorProposed { proposed_tree_creator: Pubkey },
+    /// The proposed tree creator accepted the hand-off.
+    TreeCreatorAccepted {
+        previous_tree_creator: Pubkey,
+        new_tree_creator: Pubkey,
+    },
+    /// The tree was paused or unpaused.
+    IsPaused { is_paused: bool },
+    /// The base URI of the leaves was changed.
+    BaseUri { base_uri: String },
 }
 
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, Copy)]
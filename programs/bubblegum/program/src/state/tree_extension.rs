@@ -0,0 +1,150 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+
+use crate::{
+    error::BubblegumError,
+    state::{TreeCounters, TreeSettings, UriTemplate, TREE_AUTHORITY_SIZE},
+};
+
+/// Size of the type and length header in front of each extension.
+pub const TREE_EXTENSION_HEADER_SIZE: usize = 2 + 2;
+
+/// Types of the extensions stored after the `TreeConfig` in the tree config account.  Each
+/// extension is stored as a little-endian `u16` type, a little-endian `u16` length and the
+/// Borsh-serialized value, one after another.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u16)]
+pub enum TreeExtensionType {
+    /// Marks the end of the extensions.
+    Uninitialized,
+    Counters,
+    Settings,
+    UriTemplate,
+}
+
+/// Value stored in a tree config extension.
+pub trait TreeExtension: AnchorSerialize + AnchorDeserialize + Default {
+    const TYPE: TreeExtensionType;
+    const LEN: usize;
+}
+
+impl TreeExtension for TreeCounters {
+    const TYPE: TreeExtensionType = TreeExtensionType::Counters;
+    const LEN: usize = 8 + 8 + 8 + 8 + 8;
+}
+
+impl TreeExtension for TreeSettings {
+    const TYPE: TreeExtensionType = TreeExtensionType::Settings;
+    const LEN: usize = 1 + 1 + 1 + 1;
+}
+
+impl TreeExtension for UriTemplate {
+    const TYPE: TreeExtensionType = TreeExtensionType::UriTemplate;
+    const LEN: usize = 4 + mpl_token_metadata::MAX_URI_LENGTH + 1;
+}
+
+/// Walks the extensions of a tree config account, returning the offset and length of the value
+/// of `extension_type` if found, and the offset after the last extension.
+fn find_extension(
+    data: &[u8],
+    extension_type: TreeExtensionType,
+) -> Result<(Option<(usize, usize)>, usize)> {
+    let mut offset = TREE_AUTHORITY_SIZE;
+    while offset + TREE_EXTENSION_HEADER_SIZE <= data.len() {
+        let current_type = u16::from_le_bytes([data[offset], data[offset + 1]]);
+        if current_type == TreeExtensionType::Uninitialized as u16 {
+            break;
+        }
+
+        let len = u16::from_le_bytes([data[offset + 2], data[offset + 3]]) as usize;
+        let value_offset = offset + TREE_EXTENSION_HEADER_SIZE;
+        require!(
+            value_offset + len <= data.len(),
+            BubblegumError::InvalidTreeExtension
+        );
+
+        if current_type == extension_type as u16 {
+            return Ok((Some((value_offset, len)), value_offset + len));
+        }
+
+        offset = value_offset + len;
+    }
+
+    Ok((None, offset))
+}
+
+/// Reads an extension from the tree config account, or `None` if the tree does not have it.
+pub fn get_extension<T: TreeExtension>(tree_authority: &AccountInfo) -> Result<Option<T>> {
+    let data = tree_authority.try_borrow_data()?;
+    match find_extension(&data, T::TYPE)?.0 {
+        Some((offset, len)) => Ok(Some(T::deserialize(&mut &data[offset..offset + len])?)),
+        None => Ok(None),
+    }
+}
+
+/// Overwrites an extension the tree already has.
+pub(crate) fn set_extension<T: TreeExtension>(
+    tree_authority: &AccountInfo,
+    value: &T,
+) -> Result<()> {
+    let mut data = tree_authority.try_borrow_mut_data()?;
+    let (offset, len) = find_extension(&data, T::TYPE)?
+        .0
+        .ok_or(BubblegumError::InvalidTreeExtension)?;
+
+    let mut value_data = &mut data[offset..offset + len];
+    value.serialize(&mut value_data)?;
+
+    Ok(())
+}
+
+/// Adds an extension with its default value after the existing ones, reallocating the tree
+/// config account if there is no room left.  `payer` tops up the rent.
+pub(crate) fn add_extension<'info, T: TreeExtension>(
+    tree_authority: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let (existing, end) = find_extension(&tree_authority.try_borrow_data()?, T::TYPE)?;
+    require!(
+        existing.is_none(),
+        BubblegumError::TreeExtensionAlreadyExists
+    );
+
+    let value_offset = end + TREE_EXTENSION_HEADER_SIZE;
+    let new_len = value_offset + T::LEN;
+
+    if new_len > tree_authority.data_len() {
+        // Only the rent for the new bytes is paid, so that fees held by the tree config are not
+        // used as rent.
+        let rent = Rent::get()?;
+        let rent_amount = rent
+            .minimum_balance(new_len)
+            .saturating_sub(rent.minimum_balance(tree_authority.data_len()));
+        if rent_amount > 0 {
+            transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    Transfer {
+                        from: payer.clone(),
+                        to: tree_authority.clone(),
+                    },
+                ),
+                rent_amount,
+            )?;
+        }
+
+        tree_authority.realloc(new_len, true)?;
+    }
+
+    let mut data = tree_authority.try_borrow_mut_data()?;
+    data[end..end + 2].copy_from_slice(&(T::TYPE as u16).to_le_bytes());
+    data[end + 2..value_offset].copy_from_slice(&(T::LEN as u16).to_le_bytes());
+
+    let mut value_data = &mut data[value_offset..new_len];
+    T::default().serialize(&mut value_data)?;
+
+    Ok(())
+}
@@ -1,15 +1,15 @@
 use crate::state::{
     leaf_schema::Version,
-    metaplex_adapter::{Creator, MetadataArgsCommon},
+    metaplex_adapter::{Creator, MetadataArgsCommon, Uses},
     ASSET_PREFIX,
 };
 use anchor_lang::{
     prelude::*,
     solana_program::{program_memory::sol_memcmp, pubkey::PUBKEY_BYTES},
 };
-use modular_bitfield::{bitfield, specifiers::B5};
+use modular_bitfield::{bitfield, specifiers::B4};
 use solana_program::{keccak, program::invoke};
-use spl_account_compression::Node;
+use spl_account_compression::{state::CONCURRENT_MERKLE_TREE_HEADER_SIZE_V1, Node};
 
 pub fn hash_creators(creators: &[Creator]) -> Result<[u8; 32]> {
     // Convert creator Vec to bytes Vec.
@@ -62,6 +62,26 @@ pub const DEFAULT_ASSET_DATA_HASH: [u8; 32] = [
 
 pub const DEFAULT_FLAGS: u8 = 0;
 
+/// Asset data hash of a fungible asset leaf, committing to its class and amount.  The class id
+/// is the asset id of the leaf originally minted, so that leaves split off from different mints
+/// never merge, and the hash is prefixed so that it cannot collide with the asset data hash of
+/// another kind of leaf.
+pub fn hash_amount(class_id: &Pubkey, amount: u64) -> [u8; 32] {
+    keccak::hashv(&[b"amount", class_id.as_ref(), &amount.to_le_bytes()]).to_bytes()
+}
+
+/// Asset data hash of a usable asset leaf, committing to its uses.  Prefixed so that it cannot
+/// collide with the asset data hash of another kind of leaf.
+pub fn hash_uses(uses: &Uses) -> Result<[u8; 32]> {
+    Ok(keccak::hashv(&[b"uses", uses.try_to_vec()?.as_slice()]).to_bytes())
+}
+
+/// Asset data hash of a print, committing to its master edition and edition number.  Prefixed so
+/// that it cannot collide with the asset data hash of another kind of leaf.
+pub fn hash_edition(master_edition: &Pubkey, edition: u64) -> [u8; 32] {
+    keccak::hashv(&[b"edition", master_edition.as_ref(), &edition.to_le_bytes()]).to_bytes()
+}
+
 pub fn replace_leaf<'info>(
     version: Version,
     seed: &Pubkey,
@@ -121,6 +141,51 @@ pub fn replace_leaf<'info>(
     }
 }
 
+/// Verifies a leaf against the tree without modifying it.
+pub fn verify_leaf<'info>(
+    version: Version,
+    compression_program: &AccountInfo<'info>,
+    merkle_tree: &AccountInfo<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    root_node: Node,
+    leaf: Node,
+    index: u32,
+) -> Result<()> {
+    match version {
+        Version::V1 => {
+            let cpi_ctx = CpiContext::new(
+                compression_program.clone(),
+                spl_account_compression::cpi::accounts::VerifyLeaf {
+                    merkle_tree: merkle_tree.clone(),
+                },
+            )
+            .with_remaining_accounts(remaining_accounts.to_vec());
+            spl_account_compression::cpi::verify_leaf(cpi_ctx, root_node, leaf, index)
+        }
+        Version::V2 => {
+            let cpi_ctx = CpiContext::new(
+                compression_program.clone(),
+                mpl_account_compression::cpi::accounts::VerifyLeaf {
+                    merkle_tree: merkle_tree.clone(),
+                },
+            )
+            .with_remaining_accounts(remaining_accounts.to_vec());
+            mpl_account_compression::cpi::verify_leaf(cpi_ctx, root_node, leaf, index)
+        }
+    }
+}
+
+/// Reads the sequence number of a concurrent merkle tree, which is the first field after its
+/// header for both account compression programs.
+pub fn get_tree_seq(merkle_tree: &AccountInfo) -> Result<u64> {
+    let data = merkle_tree.try_borrow_data()?;
+    let seq_bytes = data
+        .get(CONCURRENT_MERKLE_TREE_HEADER_SIZE_V1..CONCURRENT_MERKLE_TREE_HEADER_SIZE_V1 + 8)
+        .ok_or(ProgramError::InvalidAccountData)?;
+
+    Ok(u64::from_le_bytes(seq_bytes.try_into().unwrap()))
+}
+
 pub fn append_leaf<'info>(
     version: Version,
     seed: &Pubkey,
@@ -193,8 +258,10 @@ pub struct Flags {
     pub permanent_lvl_frozen: bool,
     /// Set to permanently non-transferable (soulbound).
     pub non_transferable: bool,
+    /// Permanently revoked by the collection or tree authority, without burning the asset.
+    pub revoked: bool,
     /// Unused flags for future asset-level usage.
-    pub empty_bits: B5,
+    pub empty_bits: B4,
 }
 
 /// Wraps a custom event in the most recent version of application event data.
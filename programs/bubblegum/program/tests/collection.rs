@@ -1,8 +1,8 @@
 #![cfg(feature = "test-sbf")]
 pub mod utils;
 
-use anchor_lang::solana_program::instruction::InstructionError;
-use bubblegum::error::BubblegumError;
+use anchor_lang::solana_program::instruction::{AccountMeta, InstructionError};
+use bubblegum::{error::BubblegumError, state::TreeDelegateRole};
 use solana_program::native_token::LAMPORTS_PER_SOL;
 use solana_program_test::{tokio, BanksClientError};
 
@@ -40,6 +40,44 @@ async fn verify_collection() {
     }
 }
 
+#[tokio::test]
+async fn verify_collection_with_tree_delegate_record_ahead_of_proof() {
+    let context = BubblegumTestContext::new().await.unwrap();
+
+    let (mut tree, mut leaves) = context
+        .default_create_and_mint::<MAX_DEPTH, MAX_BUF_SIZE>(1)
+        .await
+        .unwrap();
+
+    // Optional Bubblegum accounts are passed ahead of the proof, which is read after them.
+    let delegate = Keypair::new().pubkey();
+    tree.set_tree_delegate_record(delegate, TreeDelegateRole::UpdateMetadata as u8)
+        .await
+        .unwrap();
+
+    let leaf = leaves.first_mut().unwrap();
+    let mut remaining_accounts = vec![AccountMeta::new_readonly(
+        tree.tree_delegate_record(delegate),
+        false,
+    )];
+    remaining_accounts.extend(tree.proof_of_leaf_metas(leaf.index));
+
+    tree.verify_collection_tx(
+        leaf,
+        &context.payer(),
+        context.default_collection.mint.pubkey(),
+        context.default_collection.metadata,
+        context.default_collection.edition.unwrap(),
+        None,
+    )
+    .await
+    .unwrap()
+    .set_additional_account_metas(&remaining_accounts)
+    .execute()
+    .await
+    .unwrap();
+}
+
 #[tokio::test]
 async fn verify_collection_with_old_delegate() {
     // Uses Collection Authority Record to verify a collection item.
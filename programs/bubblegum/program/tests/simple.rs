@@ -1,7 +1,15 @@
 #![cfg(feature = "test-sbf")]
 pub mod utils;
 
-use anchor_lang::solana_program::instruction::InstructionError;
+use anchor_lang::solana_program::instruction::{AccountMeta, InstructionError};
+use bubblegum::{
+    state::{
+        metaplex_adapter::{MetadataArgsV2, TokenStandard, UseMethod, Uses},
+        tree_extension::TreeExtensionType,
+        TreeDelegateRole, TreeRuleSet, TREE_AUTHORITY_SIZE,
+    },
+    utils::hash_metadata,
+};
 
 use solana_program_test::{tokio, BanksClientError};
 use solana_sdk::{
@@ -57,6 +65,7 @@ async fn test_create_tree_and_mint_passes() {
     assert_eq!(cfg.tree_delegate, payer.pubkey());
     assert_eq!(cfg.total_mint_capacity, 1 << MAX_DEPTH);
     assert_eq!(cfg.num_minted, DEFAULT_NUM_MINTS);
+    assert!(!tree.read_tree_settings().await.unwrap().is_fee_exempt);
 }
 
 #[tokio::test]
@@ -309,3 +318,728 @@ async fn test_create_small_public_tree() {
     assert_eq!(cfg.tree_delegate, payer.pubkey());
     assert!(cfg.is_public);
 }
+
+#[tokio::test]
+async fn test_close_empty_tree_passes() {
+    let context = BubblegumTestContext::new().await.unwrap();
+    let payer = context.payer();
+
+    let mut tree = context
+        .default_create_tree::<MAX_DEPTH, MAX_BUF_SIZE>()
+        .await
+        .unwrap();
+
+    tree.close(&payer, payer.pubkey()).await.unwrap();
+
+    // Both the merkle tree and the tree config PDA are gone.
+    assert!(tree.read_account(tree.tree_pubkey()).await.is_err());
+    assert!(tree.read_account(tree.authority()).await.is_err());
+}
+
+#[tokio::test]
+async fn test_close_tree_after_burning_all_leaves_passes() {
+    let (context, mut tree, leaves) = context_tree_and_leaves().await.unwrap();
+    let payer = context.payer();
+
+    for leaf in leaves.iter() {
+        tree.burn(leaf).await.unwrap();
+    }
+
+    tree.close(&payer, payer.pubkey()).await.unwrap();
+
+    assert!(tree.read_account(tree.authority()).await.is_err());
+}
+
+#[tokio::test]
+async fn test_close_tree_with_invalid_authority_fails() {
+    let mut context = BubblegumTestContext::new().await.unwrap();
+    let payer = context.payer();
+    let mut tree = context
+        .default_create_tree::<MAX_DEPTH, MAX_BUF_SIZE>()
+        .await
+        .unwrap();
+
+    let not_authority = Keypair::new();
+    context
+        .fund_account(not_authority.pubkey(), DEFAULT_LAMPORTS_FUND_AMOUNT)
+        .await
+        .unwrap();
+
+    if let Err(err) = tree.close(&not_authority, payer.pubkey()).await {
+        if let BanksClient(BanksClientError::TransactionError(e)) = *err {
+            assert_eq!(
+                e,
+                TransactionError::InstructionError(0, InstructionError::Custom(6047),)
+            );
+        } else {
+            panic!("Wrong variant");
+        }
+    } else {
+        panic!("Should have failed");
+    }
+
+    // The tree config is still there.
+    tree.read_tree_config().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_update_tree_config_passes() {
+    let context = BubblegumTestContext::new().await.unwrap();
+    let mut tree = context
+        .default_create_tree::<MAX_DEPTH, MAX_BUF_SIZE>()
+        .await
+        .unwrap();
+
+    let tree_config = tree.read_tree_config().await.unwrap();
+    assert!(!tree_config.is_public);
+
+    tree.update_tree_config(Some(DEFAULT_NUM_MINTS), Some(true), None)
+        .await
+        .unwrap();
+
+    let tree_config = tree.read_tree_config().await.unwrap();
+    assert_eq!(tree_config.total_mint_capacity, DEFAULT_NUM_MINTS);
+    assert!(tree_config.is_public);
+}
+
+#[tokio::test]
+async fn test_update_tree_config_cannot_raise_mint_capacity() {
+    let context = BubblegumTestContext::new().await.unwrap();
+    let mut tree = context
+        .default_create_tree::<MAX_DEPTH, MAX_BUF_SIZE>()
+        .await
+        .unwrap();
+
+    let total_mint_capacity = tree.read_tree_config().await.unwrap().total_mint_capacity;
+
+    if let Err(err) = tree
+        .update_tree_config(Some(total_mint_capacity + 1), None, None)
+        .await
+    {
+        if let BanksClient(BanksClientError::TransactionError(e)) = *err {
+            assert_eq!(
+                e,
+                TransactionError::InstructionError(0, InstructionError::Custom(6057),)
+            );
+        } else {
+            panic!("Wrong variant");
+        }
+    } else {
+        panic!("Should have failed");
+    }
+}
+
+#[tokio::test]
+async fn test_update_tree_config_cannot_lower_mint_capacity_below_num_minted() {
+    let (_context, mut tree, leaves) = context_tree_and_leaves().await.unwrap();
+
+    if let Err(err) = tree
+        .update_tree_config(Some(leaves.len() as u64 - 1), None, None)
+        .await
+    {
+        if let BanksClient(BanksClientError::TransactionError(e)) = *err {
+            assert_eq!(
+                e,
+                TransactionError::InstructionError(0, InstructionError::Custom(6057),)
+            );
+        } else {
+            panic!("Wrong variant");
+        }
+    } else {
+        panic!("Should have failed");
+    }
+
+    // Lowering the capacity down to the number minted is allowed, after which no more
+    // leaves can be minted.
+    tree.update_tree_config(Some(leaves.len() as u64), None, None)
+        .await
+        .unwrap();
+
+    let tree_config = tree.read_tree_config().await.unwrap();
+    assert!(!tree_config.contains_mint_capacity(1));
+}
+
+#[tokio::test]
+async fn test_tree_creator_handoff_passes() {
+    let mut context = BubblegumTestContext::new().await.unwrap();
+    let mut tree = context
+        .default_create_tree::<MAX_DEPTH, MAX_BUF_SIZE>()
+        .await
+        .unwrap();
+
+    let previous_tree_creator = tree.creator_pubkey();
+    let new_tree_creator = Keypair::new();
+    context
+        .fund_account(new_tree_creator.pubkey(), DEFAULT_LAMPORTS_FUND_AMOUNT)
+        .await
+        .unwrap();
+
+    tree.update_tree_config(None, None, Some(new_tree_creator.pubkey()))
+        .await
+        .unwrap();
+
+    // Proposing does not change the creator.
+    let proposal = tree.read_tree_creator_proposal().await.unwrap();
+    assert_eq!(proposal.proposed_tree_creator, new_tree_creator.pubkey());
+    let tree_config = tree.read_tree_config().await.unwrap();
+    assert_eq!(tree_config.tree_creator, previous_tree_creator);
+
+    tree.accept_tree_creator(&new_tree_creator).await.unwrap();
+
+    // The delegate was the previous creator, so it moves along with the creator.
+    let tree_config = tree.read_tree_config().await.unwrap();
+    assert_eq!(tree_config.tree_creator, new_tree_creator.pubkey());
+    assert_eq!(tree_config.tree_delegate, new_tree_creator.pubkey());
+
+    // The proposal is closed once accepted.
+    assert!(tree
+        .read_account(tree.tree_creator_proposal())
+        .await
+        .is_err());
+
+    // The new creator can update the config.
+    tree.update_tree_config(None, Some(true), None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_accept_tree_creator_with_wrong_signer_fails() {
+    let mut context = BubblegumTestContext::new().await.unwrap();
+    let mut tree = context
+        .default_create_tree::<MAX_DEPTH, MAX_BUF_SIZE>()
+        .await
+        .unwrap();
+
+    let proposed_tree_creator = Keypair::new();
+    let not_proposed = Keypair::new();
+    context
+        .fund_account(not_proposed.pubkey(), DEFAULT_LAMPORTS_FUND_AMOUNT)
+        .await
+        .unwrap();
+
+    tree.update_tree_config(None, None, Some(proposed_tree_creator.pubkey()))
+        .await
+        .unwrap();
+
+    if let Err(err) = tree.accept_tree_creator(&not_proposed).await {
+        if let BanksClient(BanksClientError::TransactionError(e)) = *err {
+            assert_eq!(
+                e,
+                TransactionError::InstructionError(0, InstructionError::Custom(6047),)
+            );
+        } else {
+            panic!("Wrong variant");
+        }
+    } else {
+        panic!("Should have failed");
+    }
+}
+
+#[tokio::test]
+async fn test_update_tree_config_without_proposal_does_not_create_it() {
+    let context = BubblegumTestContext::new().await.unwrap();
+    let mut tree = context
+        .default_create_tree::<MAX_DEPTH, MAX_BUF_SIZE>()
+        .await
+        .unwrap();
+
+    // Pass the proposal account without proposing a new tree creator.
+    let mut ix = tree.update_tree_config_ix(None, Some(true), None);
+    ix.accounts[4] = AccountMeta::new(tree.tree_creator_proposal(), false);
+
+    let tree_creator = tree.clone_creator();
+    tree.process_tx(ix, &tree_creator.pubkey(), &[&tree_creator])
+        .await
+        .unwrap();
+
+    assert!(tree.read_tree_config().await.unwrap().is_public);
+    assert!(tree
+        .read_account(tree.tree_creator_proposal())
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_tree_creator_handoff_revokes_delegate_records() {
+    let mut context = BubblegumTestContext::new().await.unwrap();
+    let mut tree = context
+        .default_create_tree::<MAX_DEPTH, MAX_BUF_SIZE>()
+        .await
+        .unwrap();
+    let minter = Keypair::new();
+    let new_tree_creator = Keypair::new();
+    for account in [minter.pubkey(), new_tree_creator.pubkey()] {
+        context
+            .fund_account(account, DEFAULT_LAMPORTS_FUND_AMOUNT)
+            .await
+            .unwrap();
+    }
+
+    tree.set_tree_delegate_record(minter.pubkey(), TreeDelegateRole::Mint as u8)
+        .await
+        .unwrap();
+
+    tree.update_tree_config(None, None, Some(new_tree_creator.pubkey()))
+        .await
+        .unwrap();
+    tree.accept_tree_creator(&new_tree_creator).await.unwrap();
+
+    // The record set by the previous creator no longer allows minting.
+    let tree_delegate_record = tree.tree_delegate_record(minter.pubkey());
+    let mut args = LeafArgs::new(
+        &minter,
+        context.default_metadata_args("test".to_owned(), "tst".to_owned()),
+    );
+
+    let mut tx = tree.mint_v1_non_owner_tx(&minter, &mut args);
+    tx.set_additional_account_metas(&[AccountMeta::new_readonly(tree_delegate_record, false)]);
+
+    if let Err(err) = tx.execute().await {
+        if let BanksClient(BanksClientError::TransactionError(e)) = *err {
+            assert_eq!(
+                e,
+                TransactionError::InstructionError(0, InstructionError::Custom(6016),)
+            );
+        } else {
+            panic!("Wrong variant");
+        }
+    } else {
+        panic!("Should have failed");
+    }
+
+    // Until the new creator sets it again.
+    tree.set_tree_delegate_record(minter.pubkey(), TreeDelegateRole::Mint as u8)
+        .await
+        .unwrap();
+
+    let mut tx = tree.mint_v1_non_owner_tx(&minter, &mut args);
+    tx.set_additional_account_metas(&[AccountMeta::new_readonly(tree_delegate_record, false)]);
+    tx.execute().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_scoped_mint_delegate_passes() {
+    let mut context = BubblegumTestContext::new().await.unwrap();
+    let mut tree = context
+        .default_create_tree::<MAX_DEPTH, MAX_BUF_SIZE>()
+        .await
+        .unwrap();
+    let minter = Keypair::new();
+    context
+        .fund_account(minter.pubkey(), DEFAULT_LAMPORTS_FUND_AMOUNT)
+        .await
+        .unwrap();
+
+    tree.set_tree_delegate_record(minter.pubkey(), TreeDelegateRole::Mint as u8)
+        .await
+        .unwrap();
+
+    let tree_delegate_record = tree.tree_delegate_record(minter.pubkey());
+    let mut args = LeafArgs::new(
+        &minter,
+        context.default_metadata_args("test".to_owned(), "tst".to_owned()),
+    );
+
+    let mut tx = tree.mint_v1_non_owner_tx(&minter, &mut args);
+    tx.set_additional_account_metas(&[AccountMeta::new_readonly(tree_delegate_record, false)]);
+    tx.execute().await.unwrap();
+
+    let cfg = tree.read_tree_config().await.unwrap();
+    assert_eq!(cfg.num_minted, 1);
+}
+
+#[tokio::test]
+async fn test_scoped_mint_delegate_cannot_close_tree() {
+    let mut context = BubblegumTestContext::new().await.unwrap();
+    let payer = context.payer();
+    let mut tree = context
+        .default_create_tree::<MAX_DEPTH, MAX_BUF_SIZE>()
+        .await
+        .unwrap();
+    let minter = Keypair::new();
+    context
+        .fund_account(minter.pubkey(), DEFAULT_LAMPORTS_FUND_AMOUNT)
+        .await
+        .unwrap();
+
+    tree.set_tree_delegate_record(minter.pubkey(), TreeDelegateRole::Mint as u8)
+        .await
+        .unwrap();
+
+    let ix = tree.close_tree_ix(
+        minter.pubkey(),
+        payer.pubkey(),
+        Some(tree.tree_delegate_record(minter.pubkey())),
+    );
+
+    if let Err(err) = tree.process_tx(ix, &minter.pubkey(), &[&minter]).await {
+        if let BanksClient(BanksClientError::TransactionError(e)) = *err {
+            assert_eq!(
+                e,
+                TransactionError::InstructionError(0, InstructionError::Custom(6047),)
+            );
+        } else {
+            panic!("Wrong variant");
+        }
+    } else {
+        panic!("Should have failed");
+    }
+
+    // Granting the close role allows the scoped delegate to close the tree.
+    tree.set_tree_delegate_record(
+        minter.pubkey(),
+        TreeDelegateRole::Mint as u8 | TreeDelegateRole::Close as u8,
+    )
+    .await
+    .unwrap();
+
+    let ix = tree.close_tree_ix(
+        minter.pubkey(),
+        payer.pubkey(),
+        Some(tree.tree_delegate_record(minter.pubkey())),
+    );
+    tree.process_tx(ix, &minter.pubkey(), &[&minter])
+        .await
+        .unwrap();
+
+    assert!(tree.read_account(tree.authority()).await.is_err());
+}
+
+#[tokio::test]
+async fn test_closed_tree_delegate_record_cannot_mint() {
+    let mut context = BubblegumTestContext::new().await.unwrap();
+    let mut tree = context
+        .default_create_tree::<MAX_DEPTH, MAX_BUF_SIZE>()
+        .await
+        .unwrap();
+    let minter = Keypair::new();
+    context
+        .fund_account(minter.pubkey(), DEFAULT_LAMPORTS_FUND_AMOUNT)
+        .await
+        .unwrap();
+
+    tree.set_tree_delegate_record(minter.pubkey(), TreeDelegateRole::Mint as u8)
+        .await
+        .unwrap();
+    tree.close_tree_delegate_record(minter.pubkey())
+        .await
+        .unwrap();
+
+    assert!(tree
+        .read_account(tree.tree_delegate_record(minter.pubkey()))
+        .await
+        .is_err());
+
+    let mut args = LeafArgs::new(
+        &minter,
+        context.default_metadata_args("test".to_owned(), "tst".to_owned()),
+    );
+
+    if let Err(err) = tree.mint_v1_non_owner(&minter, &mut args).await {
+        if let BanksClient(BanksClientError::TransactionError(e)) = *err {
+            assert_eq!(
+                e,
+                TransactionError::InstructionError(0, InstructionError::Custom(6016),)
+            );
+        } else {
+            panic!("Wrong variant");
+        }
+    } else {
+        panic!("Should have failed");
+    }
+}
+
+#[tokio::test]
+async fn test_set_tree_delegate_record_with_invalid_roles_fails() {
+    let context = BubblegumTestContext::new().await.unwrap();
+    let mut tree = context
+        .default_create_tree::<MAX_DEPTH, MAX_BUF_SIZE>()
+        .await
+        .unwrap();
+
+    if let Err(err) = tree
+        .set_tree_delegate_record(Keypair::new().pubkey(), 0)
+        .await
+    {
+        if let BanksClient(BanksClientError::TransactionError(e)) = *err {
+            assert_eq!(
+                e,
+                TransactionError::InstructionError(0, InstructionError::Custom(6059),)
+            );
+        } else {
+            panic!("Wrong variant");
+        }
+    } else {
+        panic!("Should have failed");
+    }
+}
+
+#[tokio::test]
+async fn test_paused_tree_blocks_mint_and_transfer() {
+    let (context, mut tree, mut leaves) = context_tree_and_leaves().await.unwrap();
+    let payer = context.payer();
+
+    tree.set_tree_paused(true).await.unwrap();
+    assert!(tree.read_tree_settings().await.unwrap().is_paused);
+
+    let new_owner = Keypair::new();
+    let leaf = leaves.first_mut().unwrap();
+
+    if let Err(err) = tree.transfer(leaf, &new_owner).await {
+        if let BanksClient(BanksClientError::TransactionError(e)) = *err {
+            assert_eq!(
+                e,
+                TransactionError::InstructionError(0, InstructionError::Custom(6060),)
+            );
+        } else {
+            panic!("Wrong variant");
+        }
+    } else {
+        panic!("Should have failed");
+    }
+
+    let mut args = LeafArgs::new(
+        &payer,
+        context.default_metadata_args("test".to_owned(), "tst".to_owned()),
+    );
+
+    if let Err(err) = tree.mint_v1(&payer, &mut args).await {
+        if let BanksClient(BanksClientError::TransactionError(e)) = *err {
+            assert_eq!(
+                e,
+                TransactionError::InstructionError(0, InstructionError::Custom(6060),)
+            );
+        } else {
+            panic!("Wrong variant");
+        }
+    } else {
+        panic!("Should have failed");
+    }
+
+    // Unpausing restores normal operation.
+    tree.set_tree_paused(false).await.unwrap();
+    tree.transfer(leaf, &new_owner).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_paused_tree_can_be_closed() {
+    let context = BubblegumTestContext::new().await.unwrap();
+    let payer = context.payer();
+    let mut tree = context
+        .default_create_tree::<MAX_DEPTH, MAX_BUF_SIZE>()
+        .await
+        .unwrap();
+
+    tree.set_tree_paused(true).await.unwrap();
+    tree.close(&payer, payer.pubkey()).await.unwrap();
+
+    assert!(tree.read_account(tree.authority()).await.is_err());
+}
+
+#[tokio::test]
+async fn test_create_tree_group_passes() {
+    let context = BubblegumTestContext::new().await.unwrap();
+    let mut tree = context
+        .default_create_tree::<MAX_DEPTH, MAX_BUF_SIZE>()
+        .await
+        .unwrap();
+
+    let group_seed = Keypair::new().pubkey();
+    tree.create_tree_group(group_seed, 4).await.unwrap();
+
+    let tree_group = tree.read_tree_group(group_seed).await.unwrap();
+    assert_eq!(tree_group.authority, tree.creator_pubkey());
+    assert_eq!(tree_group.group_seed, group_seed);
+    assert_eq!(tree_group.max_trees, 4);
+    assert_eq!(tree_group.current_tree_index, 0);
+    assert!(tree_group.trees.is_empty());
+}
+
+#[tokio::test]
+async fn test_add_v1_tree_to_group_fails() {
+    let context = BubblegumTestContext::new().await.unwrap();
+    let mut tree = context
+        .default_create_tree::<MAX_DEPTH, MAX_BUF_SIZE>()
+        .await
+        .unwrap();
+
+    let group_seed = Keypair::new().pubkey();
+    tree.create_tree_group(group_seed, 4).await.unwrap();
+
+    // Groups are minted into with `mint_to_group_v2`, which only works with V2 trees.
+    if let Err(err) = tree.add_tree_to_group(group_seed).await {
+        if let BanksClient(BanksClientError::TransactionError(e)) = *err {
+            assert_eq!(
+                e,
+                TransactionError::InstructionError(0, InstructionError::Custom(6003),)
+            );
+        } else {
+            panic!("Wrong variant");
+        }
+    } else {
+        panic!("Should have failed");
+    }
+
+    assert!(tree
+        .read_tree_group(group_seed)
+        .await
+        .unwrap()
+        .trees
+        .is_empty());
+}
+
+#[tokio::test]
+async fn test_set_tree_plugins_on_v1_tree_fails() {
+    let context = BubblegumTestContext::new().await.unwrap();
+    let mut tree = context
+        .default_create_tree::<MAX_DEPTH, MAX_BUF_SIZE>()
+        .await
+        .unwrap();
+
+    // Tree-level plugins only apply to `LeafSchema` V2 assets.
+    if let Err(err) = tree
+        .set_tree_plugins(TreeRuleSet::None, None, None, Some(Keypair::new().pubkey()))
+        .await
+    {
+        if let BanksClient(BanksClientError::TransactionError(e)) = *err {
+            assert_eq!(
+                e,
+                TransactionError::InstructionError(0, InstructionError::Custom(6003),)
+            );
+        } else {
+            panic!("Wrong variant");
+        }
+    } else {
+        panic!("Should have failed");
+    }
+
+    assert!(!tree.read_tree_settings().await.unwrap().has_plugins);
+    assert!(tree.read_account(tree.tree_plugins()).await.is_err());
+}
+
+#[tokio::test]
+async fn test_set_tree_creator_fee_on_v1_tree_fails() {
+    let context = BubblegumTestContext::new().await.unwrap();
+    let mut tree = context
+        .default_create_tree::<MAX_DEPTH, MAX_BUF_SIZE>()
+        .await
+        .unwrap();
+
+    // The creator fee is only charged by the V2 mint instructions.
+    if let Err(err) = tree.set_tree_creator_fee(10_000).await {
+        if let BanksClient(BanksClientError::TransactionError(e)) = *err {
+            assert_eq!(
+                e,
+                TransactionError::InstructionError(0, InstructionError::Custom(6003),)
+            );
+        } else {
+            panic!("Wrong variant");
+        }
+    } else {
+        panic!("Should have failed");
+    }
+
+    assert!(!tree.read_tree_settings().await.unwrap().has_creator_fee);
+    assert!(tree.read_account(tree.tree_creator_fees()).await.is_err());
+}
+
+#[tokio::test]
+async fn test_add_counters_extension_to_v1_tree_fails() {
+    let context = BubblegumTestContext::new().await.unwrap();
+    let mut tree = context
+        .default_create_tree::<MAX_DEPTH, MAX_BUF_SIZE>()
+        .await
+        .unwrap();
+
+    // Counters are only kept by the V2 instructions.
+    if let Err(err) = tree.add_tree_extension(TreeExtensionType::Counters).await {
+        if let BanksClient(BanksClientError::TransactionError(e)) = *err {
+            assert_eq!(
+                e,
+                TransactionError::InstructionError(0, InstructionError::Custom(6003),)
+            );
+        } else {
+            panic!("Wrong variant");
+        }
+    } else {
+        panic!("Should have failed");
+    }
+
+    let tree_config = tree.read_account(tree.authority()).await.unwrap();
+    assert_eq!(tree_config.data.len(), TREE_AUTHORITY_SIZE);
+}
+
+#[tokio::test]
+async fn test_create_master_edition_passes() {
+    let context = BubblegumTestContext::new().await.unwrap();
+    let mut tree = context
+        .default_create_tree::<MAX_DEPTH, MAX_BUF_SIZE>()
+        .await
+        .unwrap();
+
+    let metadata = MetadataArgsV2 {
+        name: "Edition".to_owned(),
+        symbol: "ED".to_owned(),
+        uri: "https://www.bubblegum-nfts.com/".to_owned(),
+        seller_fee_basis_points: 0,
+        primary_sale_happened: false,
+        is_mutable: false,
+        token_standard: Some(TokenStandard::NonFungible),
+        creators: vec![],
+        collection: None,
+    };
+
+    let edition_seed = Keypair::new().pubkey();
+    tree.create_master_edition_v2(edition_seed, metadata.clone(), Some(10))
+        .await
+        .unwrap();
+
+    let master_edition = tree.read_master_edition(edition_seed).await.unwrap();
+    assert_eq!(master_edition.authority, tree.creator_pubkey());
+    assert_eq!(master_edition.edition_seed, edition_seed);
+    assert_eq!(master_edition.data_hash, hash_metadata(&metadata).unwrap());
+    assert_eq!(master_edition.max_supply, Some(10));
+    assert_eq!(master_edition.supply, 0);
+}
+
+#[tokio::test]
+async fn test_utilize_until_burned_passes() {
+    let context = BubblegumTestContext::new().await.unwrap();
+    let mut tree = context
+        .default_create_tree::<MAX_DEPTH, MAX_BUF_SIZE>()
+        .await
+        .unwrap();
+
+    let payer = context.payer();
+    let mut metadata = context.default_metadata_args("test".to_owned(), "tst".to_owned());
+    metadata.uses = Some(Uses {
+        use_method: UseMethod::Burn,
+        remaining: 2,
+        total: 2,
+    });
+    let mut args = LeafArgs::new(&payer, metadata);
+    tree.mint_v1(&payer, &mut args).await.unwrap();
+
+    // `utilize_v1` also validates whether the on-chain tree root always has the expected
+    // value via the inner `TxBuilder::execute` call.
+    tree.utilize_v1(&mut args, 1).await.unwrap();
+    assert_eq!(args.metadata.uses.as_ref().unwrap().remaining, 1);
+
+    // Spending more uses than remaining fails.
+    if let Err(err) = tree.utilize_v1(&mut args.clone(), 2).await {
+        if let BanksClient(BanksClientError::TransactionError(e)) = *err {
+            assert_eq!(
+                e,
+                TransactionError::InstructionError(0, InstructionError::Custom(6075)),
+            );
+        } else {
+            panic!("Wrong variant");
+        }
+    } else {
+        panic!("Utilizing more uses than remaining should have failed");
+    }
+
+    // The last use burns the leaf.
+    tree.utilize_v1(&mut args, 1).await.unwrap();
+}
@@ -1,23 +1,30 @@
 use super::{
-    clone_keypair, compute_metadata_hashes,
+    clone_keypair, compute_metadata_hashes, instruction,
     tx_builder::{
         BurnBuilder, CancelRedeemBuilder, CollectionVerificationInner, CreateBuilder,
         CreatorVerificationInner, DelegateBuilder, DelegateInner, MintToCollectionV1Builder,
         MintV1Builder, RedeemBuilder, SetDecompressibleStateBuilder, SetTreeDelegateBuilder,
-        TransferBuilder, TransferInner, TxBuilder, UnverifyCreatorBuilder, VerifyCollectionBuilder,
-        VerifyCreatorBuilder,
+        TransferBuilder, TransferInner, TxBuilder, UnverifyCreatorBuilder, UtilizeBuilder,
+        VerifyCollectionBuilder, VerifyCreatorBuilder,
     },
     Error, LeafArgs, Result,
 };
 use crate::utils::tx_builder::DecompressV1Builder;
 use anchor_lang::{self, AccountDeserialize};
 use bubblegum::{
-    state::{leaf_schema::LeafSchema, DecompressibleState, TreeConfig, Voucher, VOUCHER_PREFIX},
+    state::{
+        collect::TREE_CREATOR_FEES_PREFIX, leaf_schema::LeafSchema,
+        metaplex_adapter::MetadataArgsV2, tree_extension::TreeExtensionType, DecompressibleState,
+        TreeConfig, TreeCreatorProposal, TreeGroup, TreeRuleSet, TreeSettings, Voucher,
+        MASTER_EDITION_PREFIX, TREE_CREATOR_PROPOSAL_PREFIX, TREE_DELEGATE_RECORD_PREFIX,
+        TREE_GROUP_PREFIX, TREE_PLUGINS_PREFIX, VOUCHER_PREFIX,
+    },
     utils::get_asset_id,
 };
 use bytemuck::try_from_bytes;
 use mpl_token_metadata::accounts::{MasterEdition, Metadata};
 use solana_program::{
+    account_info::IntoAccountInfo,
     instruction::{AccountMeta, Instruction},
     pubkey,
     pubkey::Pubkey,
@@ -100,6 +107,10 @@ impl<const MAX_DEPTH: usize, const MAX_BUFFER_SIZE: usize> Tree<MAX_DEPTH, MAX_B
         clone_keypair(&self.tree_delegate)
     }
 
+    pub fn clone_creator(&self) -> Keypair {
+        clone_keypair(&self.tree_creator)
+    }
+
     // Not to be confused with the `set_tree_delegate` below.
     pub fn replace_tree_delegate(&mut self, key: &Keypair) {
         self.tree_delegate = clone_keypair(key);
@@ -125,6 +136,72 @@ impl<const MAX_DEPTH: usize, const MAX_BUFFER_SIZE: usize> Tree<MAX_DEPTH, MAX_B
         .0
     }
 
+    pub fn tree_creator_proposal(&self) -> Pubkey {
+        Pubkey::find_program_address(
+            &[
+                TREE_CREATOR_PROPOSAL_PREFIX.as_ref(),
+                self.tree_pubkey().as_ref(),
+            ],
+            &bubblegum::id(),
+        )
+        .0
+    }
+
+    pub fn tree_delegate_record(&self, delegate: Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[
+                TREE_DELEGATE_RECORD_PREFIX.as_ref(),
+                self.tree_pubkey().as_ref(),
+                delegate.as_ref(),
+            ],
+            &bubblegum::id(),
+        )
+        .0
+    }
+
+    pub fn tree_group(&self, group_seed: Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[
+                TREE_GROUP_PREFIX.as_ref(),
+                self.creator_pubkey().as_ref(),
+                group_seed.as_ref(),
+            ],
+            &bubblegum::id(),
+        )
+        .0
+    }
+
+    pub fn master_edition(&self, edition_seed: Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[
+                MASTER_EDITION_PREFIX.as_ref(),
+                self.creator_pubkey().as_ref(),
+                edition_seed.as_ref(),
+            ],
+            &bubblegum::id(),
+        )
+        .0
+    }
+
+    pub fn tree_creator_fees(&self) -> Pubkey {
+        Pubkey::find_program_address(
+            &[
+                TREE_CREATOR_FEES_PREFIX.as_ref(),
+                self.tree_pubkey().as_ref(),
+            ],
+            &bubblegum::id(),
+        )
+        .0
+    }
+
+    pub fn tree_plugins(&self) -> Pubkey {
+        Pubkey::find_program_address(
+            &[TREE_PLUGINS_PREFIX.as_ref(), self.tree_pubkey().as_ref()],
+            &bubblegum::id(),
+        )
+        .0
+    }
+
     pub fn merkle_tree_account_size(&self) -> usize {
         let canopy_size = 32 * std::cmp::max((1 << (self.canopy_depth + 1)) - 2, 0);
         CONCURRENT_MERKLE_TREE_HEADER_SIZE_V1
@@ -461,6 +538,44 @@ impl<const MAX_DEPTH: usize, const MAX_BUFFER_SIZE: usize> Tree<MAX_DEPTH, MAX_B
         self.burn_tx(args).await?.execute().await
     }
 
+    pub async fn utilize_v1_tx<'a>(
+        &'a mut self,
+        args: &'a mut LeafArgs,
+        number_of_uses: u64,
+    ) -> Result<UtilizeBuilder<MAX_DEPTH, MAX_BUFFER_SIZE>> {
+        let root = self.decode_root().await?;
+
+        let accounts = bubblegum::accounts::Utilize {
+            tree_authority: self.authority(),
+            leaf_owner: args.owner.pubkey(),
+            leaf_delegate: args.delegate.pubkey(),
+            merkle_tree: self.tree_pubkey(),
+            log_wrapper: spl_noop::id(),
+            compression_program: spl_account_compression::id(),
+            system_program: system_program::id(),
+        };
+
+        let data = bubblegum::instruction::UtilizeV1 {
+            root,
+            nonce: args.nonce,
+            index: args.index,
+            current_metadata: args.metadata.clone(),
+            number_of_uses,
+        };
+
+        let need_proof = Some(args.index);
+        let owner = clone_keypair(&args.owner);
+
+        Ok(self.tx_builder(accounts, data, need_proof, args, owner.pubkey(), &[&owner]))
+    }
+
+    pub async fn utilize_v1(&mut self, args: &mut LeafArgs, number_of_uses: u64) -> Result<()> {
+        self.utilize_v1_tx(args, number_of_uses)
+            .await?
+            .execute()
+            .await
+    }
+
     pub async fn verify_creator_tx<'a>(
         &'a mut self,
         args: &'a mut LeafArgs,
@@ -897,6 +1012,307 @@ impl<const MAX_DEPTH: usize, const MAX_BUFFER_SIZE: usize> Tree<MAX_DEPTH, MAX_B
         self.set_tree_delegate_tx(new_tree_delegate).execute().await
     }
 
+    pub fn close_tree_ix(
+        &self,
+        authority: Pubkey,
+        recipient: Pubkey,
+        tree_delegate_record: Option<Pubkey>,
+    ) -> Instruction {
+        let accounts = bubblegum::accounts::CloseTree {
+            tree_authority: self.authority(),
+            authority,
+            merkle_tree: self.tree_pubkey(),
+            recipient,
+            compression_program: spl_account_compression::id(),
+            log_wrapper: spl_noop::id(),
+            system_program: system_program::id(),
+        };
+
+        let mut ix = instruction(&accounts, &bubblegum::instruction::CloseTree);
+        if let Some(tree_delegate_record) = tree_delegate_record {
+            ix.accounts
+                .push(AccountMeta::new_readonly(tree_delegate_record, false));
+        }
+        ix
+    }
+
+    // Closing the tree removes the merkle tree account, so this does not go through
+    // `TxBuilder::execute` which checks the on-chain root after each transaction.
+    pub async fn close(&mut self, authority: &Keypair, recipient: Pubkey) -> Result<()> {
+        let ix = self.close_tree_ix(authority.pubkey(), recipient, None);
+        self.process_tx(ix, &authority.pubkey(), &[authority]).await
+    }
+
+    pub fn update_tree_config_ix(
+        &self,
+        total_mint_capacity: Option<u64>,
+        is_public: Option<bool>,
+        proposed_tree_creator: Option<Pubkey>,
+    ) -> Instruction {
+        let accounts = bubblegum::accounts::UpdateTreeConfig {
+            tree_authority: self.authority(),
+            payer: self.creator_pubkey(),
+            tree_creator: self.creator_pubkey(),
+            merkle_tree: self.tree_pubkey(),
+            tree_creator_proposal: proposed_tree_creator.map(|_| self.tree_creator_proposal()),
+            log_wrapper: spl_noop::id(),
+            system_program: system_program::id(),
+        };
+
+        let data = bubblegum::instruction::UpdateTreeConfig {
+            total_mint_capacity,
+            is_public,
+            proposed_tree_creator,
+        };
+
+        instruction(&accounts, &data)
+    }
+
+    // Tree config updates leave the merkle tree untouched, so these are sent directly
+    // rather than through a `TxBuilder`.
+    pub async fn update_tree_config(
+        &mut self,
+        total_mint_capacity: Option<u64>,
+        is_public: Option<bool>,
+        proposed_tree_creator: Option<Pubkey>,
+    ) -> Result<()> {
+        let ix = self.update_tree_config_ix(total_mint_capacity, is_public, proposed_tree_creator);
+        let tree_creator = clone_keypair(&self.tree_creator);
+        self.process_tx(ix, &tree_creator.pubkey(), &[&tree_creator])
+            .await
+    }
+
+    pub fn accept_tree_creator_ix(&self, new_tree_creator: Pubkey) -> Instruction {
+        let accounts = bubblegum::accounts::AcceptTreeCreator {
+            tree_authority: self.authority(),
+            new_tree_creator,
+            merkle_tree: self.tree_pubkey(),
+            tree_creator_proposal: self.tree_creator_proposal(),
+            log_wrapper: spl_noop::id(),
+            system_program: system_program::id(),
+        };
+
+        instruction(&accounts, &bubblegum::instruction::AcceptTreeCreator)
+    }
+
+    pub async fn accept_tree_creator(&mut self, new_tree_creator: &Keypair) -> Result<()> {
+        let ix = self.accept_tree_creator_ix(new_tree_creator.pubkey());
+        self.process_tx(ix, &new_tree_creator.pubkey(), &[new_tree_creator])
+            .await?;
+
+        // Mirror the on-chain hand-off, where a delegate that was the previous creator moves
+        // along with it.
+        if self.tree_delegate.pubkey() == self.tree_creator.pubkey() {
+            self.tree_delegate = clone_keypair(new_tree_creator);
+        }
+        self.tree_creator = clone_keypair(new_tree_creator);
+
+        Ok(())
+    }
+
+    pub fn set_tree_paused_ix(&self, is_paused: bool) -> Instruction {
+        let accounts = bubblegum::accounts::SetTreePaused {
+            tree_authority: self.authority(),
+            payer: self.creator_pubkey(),
+            tree_creator: self.creator_pubkey(),
+            merkle_tree: self.tree_pubkey(),
+            log_wrapper: spl_noop::id(),
+            system_program: system_program::id(),
+        };
+
+        instruction(
+            &accounts,
+            &bubblegum::instruction::SetTreePaused { is_paused },
+        )
+    }
+
+    pub async fn set_tree_paused(&mut self, is_paused: bool) -> Result<()> {
+        let ix = self.set_tree_paused_ix(is_paused);
+        let tree_creator = clone_keypair(&self.tree_creator);
+        self.process_tx(ix, &tree_creator.pubkey(), &[&tree_creator])
+            .await
+    }
+
+    // Creates a tree group owned by the tree creator. The tree itself is not added to it.
+    pub async fn create_tree_group(&mut self, group_seed: Pubkey, max_trees: u8) -> Result<()> {
+        let accounts = bubblegum::accounts::CreateTreeGroup {
+            tree_group: self.tree_group(group_seed),
+            payer: self.creator_pubkey(),
+            authority: self.creator_pubkey(),
+            system_program: system_program::id(),
+        };
+
+        let data = bubblegum::instruction::CreateTreeGroup {
+            group_seed,
+            max_trees,
+        };
+
+        let tree_creator = clone_keypair(&self.tree_creator);
+        self.process_tx(
+            instruction(&accounts, &data),
+            &tree_creator.pubkey(),
+            &[&tree_creator],
+        )
+        .await
+    }
+
+    pub async fn add_tree_to_group(&mut self, group_seed: Pubkey) -> Result<()> {
+        let accounts = bubblegum::accounts::AddTreeToGroup {
+            tree_group: self.tree_group(group_seed),
+            authority: self.creator_pubkey(),
+            tree_authority: self.authority(),
+            merkle_tree: self.tree_pubkey(),
+        };
+
+        let tree_creator = clone_keypair(&self.tree_creator);
+        self.process_tx(
+            instruction(&accounts, &bubblegum::instruction::AddTreeToGroup),
+            &tree_creator.pubkey(),
+            &[&tree_creator],
+        )
+        .await
+    }
+
+    // Creates a master edition owned by the tree creator.
+    pub async fn create_master_edition_v2(
+        &mut self,
+        edition_seed: Pubkey,
+        metadata: MetadataArgsV2,
+        max_supply: Option<u64>,
+    ) -> Result<()> {
+        let accounts = bubblegum::accounts::CreateMasterEditionV2 {
+            master_edition: self.master_edition(edition_seed),
+            payer: self.creator_pubkey(),
+            authority: self.creator_pubkey(),
+            system_program: system_program::id(),
+        };
+
+        let data = bubblegum::instruction::CreateMasterEditionV2 {
+            edition_seed,
+            metadata,
+            max_supply,
+        };
+
+        let tree_creator = clone_keypair(&self.tree_creator);
+        self.process_tx(
+            instruction(&accounts, &data),
+            &tree_creator.pubkey(),
+            &[&tree_creator],
+        )
+        .await
+    }
+
+    pub async fn set_tree_plugins(
+        &mut self,
+        royalties_rule_set: TreeRuleSet,
+        permanent_freeze_delegate: Option<Pubkey>,
+        permanent_transfer_delegate: Option<Pubkey>,
+        permanent_burn_delegate: Option<Pubkey>,
+    ) -> Result<()> {
+        let accounts = bubblegum::accounts::SetTreePlugins {
+            tree_authority: self.authority(),
+            payer: self.creator_pubkey(),
+            tree_creator: self.creator_pubkey(),
+            merkle_tree: self.tree_pubkey(),
+            tree_plugins: self.tree_plugins(),
+            system_program: system_program::id(),
+        };
+
+        let data = bubblegum::instruction::SetTreePlugins {
+            royalties_rule_set,
+            permanent_freeze_delegate,
+            permanent_transfer_delegate,
+            permanent_burn_delegate,
+        };
+
+        let tree_creator = clone_keypair(&self.tree_creator);
+        self.process_tx(
+            instruction(&accounts, &data),
+            &tree_creator.pubkey(),
+            &[&tree_creator],
+        )
+        .await
+    }
+
+    pub async fn add_tree_extension(&mut self, extension_type: TreeExtensionType) -> Result<()> {
+        let accounts = bubblegum::accounts::AddTreeExtension {
+            tree_authority: self.authority(),
+            payer: self.creator_pubkey(),
+            tree_creator: self.creator_pubkey(),
+            merkle_tree: self.tree_pubkey(),
+            system_program: system_program::id(),
+        };
+
+        let data = bubblegum::instruction::AddTreeExtension { extension_type };
+
+        let tree_creator = clone_keypair(&self.tree_creator);
+        self.process_tx(
+            instruction(&accounts, &data),
+            &tree_creator.pubkey(),
+            &[&tree_creator],
+        )
+        .await
+    }
+
+    pub async fn set_tree_creator_fee(&mut self, creator_fee_lamports: u64) -> Result<()> {
+        let accounts = bubblegum::accounts::SetTreeCreatorFee {
+            tree_authority: self.authority(),
+            payer: self.creator_pubkey(),
+            tree_creator: self.creator_pubkey(),
+            merkle_tree: self.tree_pubkey(),
+            tree_creator_fees: self.tree_creator_fees(),
+            system_program: system_program::id(),
+        };
+
+        let data = bubblegum::instruction::SetTreeCreatorFee {
+            creator_fee_lamports,
+        };
+
+        let tree_creator = clone_keypair(&self.tree_creator);
+        self.process_tx(
+            instruction(&accounts, &data),
+            &tree_creator.pubkey(),
+            &[&tree_creator],
+        )
+        .await
+    }
+
+    pub async fn set_tree_delegate_record(&mut self, delegate: Pubkey, roles: u8) -> Result<()> {
+        let accounts = bubblegum::accounts::SetTreeDelegateRecord {
+            tree_authority: self.authority(),
+            payer: self.creator_pubkey(),
+            tree_creator: self.creator_pubkey(),
+            delegate,
+            merkle_tree: self.tree_pubkey(),
+            tree_delegate_record: self.tree_delegate_record(delegate),
+            system_program: system_program::id(),
+        };
+
+        let ix = instruction(
+            &accounts,
+            &bubblegum::instruction::SetTreeDelegateRecord { roles },
+        );
+        let tree_creator = clone_keypair(&self.tree_creator);
+        self.process_tx(ix, &tree_creator.pubkey(), &[&tree_creator])
+            .await
+    }
+
+    pub async fn close_tree_delegate_record(&mut self, delegate: Pubkey) -> Result<()> {
+        let accounts = bubblegum::accounts::CloseTreeDelegateRecord {
+            tree_authority: self.authority(),
+            tree_creator: self.creator_pubkey(),
+            delegate,
+            merkle_tree: self.tree_pubkey(),
+            tree_delegate_record: self.tree_delegate_record(delegate),
+            system_program: system_program::id(),
+        };
+
+        let ix = instruction(&accounts, &bubblegum::instruction::CloseTreeDelegateRecord);
+        let tree_creator = clone_keypair(&self.tree_creator);
+        self.process_tx(ix, &tree_creator.pubkey(), &[&tree_creator])
+            .await
+    }
+
     // The following methods provide convenience when reading data from accounts.
     pub async fn read_account(&mut self, key: Pubkey) -> Result<Account> {
         self.client
@@ -921,6 +1337,29 @@ impl<const MAX_DEPTH: usize, const MAX_BUFFER_SIZE: usize> Tree<MAX_DEPTH, MAX_B
         self.read_account_data(self.authority()).await
     }
 
+    pub async fn read_tree_settings(&mut self) -> Result<TreeSettings> {
+        let key = self.authority();
+        let mut account = self.read_account(key).await?;
+        TreeSettings::load(&(&key, &mut account).into_account_info())
+            .map_err(|err| Box::new(Error::Anchor(err)))
+    }
+
+    pub async fn read_tree_creator_proposal(&mut self) -> Result<TreeCreatorProposal> {
+        self.read_account_data(self.tree_creator_proposal()).await
+    }
+
+    pub async fn read_tree_group(&mut self, group_seed: Pubkey) -> Result<TreeGroup> {
+        self.read_account_data(self.tree_group(group_seed)).await
+    }
+
+    pub async fn read_master_edition(
+        &mut self,
+        edition_seed: Pubkey,
+    ) -> Result<bubblegum::state::MasterEdition> {
+        self.read_account_data(self.master_edition(edition_seed))
+            .await
+    }
+
     pub async fn read_voucher(&mut self, nonce: u64) -> Result<Voucher> {
         self.read_account_data(self.voucher(nonce)).await
     }
@@ -1,4 +1,5 @@
 use anchor_lang::{self, InstructionData, ToAccountMetas};
+use bubblegum::state::metaplex_adapter::UseMethod;
 use solana_program::pubkey::Pubkey;
 use solana_program_test::BanksClient;
 use solana_sdk::{instruction::AccountMeta, signature::Keypair, transaction::Transaction};
@@ -216,6 +217,31 @@ impl<'a, const MAX_DEPTH: usize, const MAX_BUFFER_SIZE: usize> OnSuccessfulTxExe
     }
 }
 
+pub type UtilizeBuilder<'a, const MAX_DEPTH: usize, const MAX_BUFFER_SIZE: usize> = TxBuilder<
+    'a,
+    bubblegum::accounts::Utilize,
+    bubblegum::instruction::UtilizeV1,
+    &'a mut LeafArgs,
+    MAX_DEPTH,
+    MAX_BUFFER_SIZE,
+>;
+
+impl<'a, const MAX_DEPTH: usize, const MAX_BUFFER_SIZE: usize> OnSuccessfulTxExec
+    for UtilizeBuilder<'a, MAX_DEPTH, MAX_BUFFER_SIZE>
+{
+    fn on_successful_execute(&mut self) -> Result<()> {
+        let uses = self.inner.metadata.uses.as_mut().unwrap();
+        uses.remaining -= self.data.number_of_uses;
+
+        // The leaf is burned once a `UseMethod::Burn` asset runs out of uses.
+        if uses.use_method == UseMethod::Burn && uses.remaining == 0 {
+            self.tree.zero_leaf(self.inner.index)
+        } else {
+            self.tree.update_leaf(self.inner)
+        }
+    }
+}
+
 pub struct TransferInner<'a> {
     pub args: &'a mut LeafArgs,
     pub new_owner: Keypair,